@@ -4,6 +4,9 @@
 //! free functions so that `FileStorage`, `DirStorage`, and `AsyncDirStorage`
 //! can all delegate to a single implementation instead of duplicating the
 //! logic in each `impl` block.
+//!
+//! `ensure_dir` and `list_files` cover the directory-level IO that
+//! higher-level stores (e.g. sharded configs) need around those files.
 
 use crate::errors::{IoOperationKind, StoreError};
 use std::path::{Path, PathBuf};
@@ -78,6 +81,144 @@ pub fn atomic_rename(
     })
 }
 
+/// Apply Unix permission bits to `tmp_path` so the renamed file carries them.
+///
+/// A no-op when `mode` is `None` or on non-Unix platforms.
+///
+/// # Errors
+///
+/// `StoreError::IoError { operation: Write, … }` if the permissions cannot
+/// be set.
+pub fn set_mode(tmp_path: &Path, mode: Option<u32>) -> Result<(), StoreError> {
+    #[cfg(unix)]
+    if let Some(mode) = mode {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::set_permissions(tmp_path, std::fs::Permissions::from_mode(mode)).map_err(|e| {
+            StoreError::IoError {
+                operation: IoOperationKind::Write,
+                path: tmp_path.display().to_string(),
+                context: Some("temporary file permissions".to_string()),
+                error: e.to_string(),
+            }
+        })?;
+    }
+
+    #[cfg(not(unix))]
+    let _ = (tmp_path, mode);
+
+    Ok(())
+}
+
+/// Fsync the directory containing `target_path` so that a preceding rename
+/// is durable.
+///
+/// A no-op on platforms where directories cannot be opened for syncing.
+///
+/// # Errors
+///
+/// `StoreError::IoError { operation: Sync, … }` if the directory cannot be
+/// opened or synced.
+pub fn sync_parent_dir(target_path: &Path) -> Result<(), StoreError> {
+    #[cfg(unix)]
+    {
+        let parent = match target_path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+
+        std::fs::File::open(parent)
+            .and_then(|dir| dir.sync_all())
+            .map_err(|e| StoreError::IoError {
+                operation: IoOperationKind::Sync,
+                path: parent.display().to_string(),
+                context: Some("parent directory".to_string()),
+                error: e.to_string(),
+            })?;
+    }
+
+    #[cfg(not(unix))]
+    let _ = target_path;
+
+    Ok(())
+}
+
+/// Fsync the directory `dir` itself so that entries created in it are
+/// durable.
+///
+/// A no-op on macOS, where `fsync` on a directory does not flush entries
+/// (APFS/HFS+ need `F_FULLFSYNC` on the files instead), and on non-Unix
+/// platforms.
+///
+/// # Errors
+///
+/// `StoreError::IoError { operation: Sync, … }` if the directory cannot be
+/// opened or synced.
+pub fn sync_dir(dir: &Path) -> Result<(), StoreError> {
+    #[cfg(all(unix, not(target_os = "macos")))]
+    std::fs::File::open(dir)
+        .and_then(|d| d.sync_all())
+        .map_err(|e| StoreError::IoError {
+            operation: IoOperationKind::Sync,
+            path: dir.display().to_string(),
+            context: Some("directory".to_string()),
+            error: e.to_string(),
+        })?;
+
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
+    let _ = dir;
+
+    Ok(())
+}
+
+/// Create `dir` and any missing parents.
+///
+/// # Errors
+///
+/// `StoreError::IoError { operation: CreateDir, … }` if the directory cannot
+/// be created.
+pub fn ensure_dir(dir: &Path) -> Result<(), StoreError> {
+    std::fs::create_dir_all(dir).map_err(|e| StoreError::IoError {
+        operation: IoOperationKind::CreateDir,
+        path: dir.display().to_string(),
+        context: Some("directory".to_string()),
+        error: e.to_string(),
+    })
+}
+
+/// List the regular files directly inside `dir` whose extension is
+/// `extension`, sorted by path.
+///
+/// Hidden files (names starting with `.`), which include temporary files and
+/// lock files, are skipped.
+///
+/// # Errors
+///
+/// `StoreError::IoError { operation: ReadDir, … }` if the directory cannot be
+/// read.
+pub fn list_files(dir: &Path, extension: &str) -> Result<Vec<PathBuf>, StoreError> {
+    let read_dir_error = |e: std::io::Error| StoreError::IoError {
+        operation: IoOperationKind::ReadDir,
+        path: dir.display().to_string(),
+        context: None,
+        error: e.to_string(),
+    };
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(read_dir_error)? {
+        let path = entry.map_err(read_dir_error)?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if !hidden && path.is_file() && path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
 /// Remove orphaned `.<filename>.tmp.*` files in the same directory as
 /// `target_path`.
 ///
@@ -122,6 +263,35 @@ pub mod async_io {
     use crate::errors::{IoOperationKind, StoreError};
     use std::path::Path;
 
+    /// Apply Unix permission bits to `tmp_path` (async).
+    ///
+    /// A no-op when `mode` is `None` or on non-Unix platforms.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Write, … }` if the permissions
+    /// cannot be set.
+    pub async fn set_mode(tmp_path: &Path, mode: Option<u32>) -> Result<(), StoreError> {
+        #[cfg(unix)]
+        if let Some(mode) = mode {
+            use std::os::unix::fs::PermissionsExt;
+
+            tokio::fs::set_permissions(tmp_path, std::fs::Permissions::from_mode(mode))
+                .await
+                .map_err(|e| StoreError::IoError {
+                    operation: IoOperationKind::Write,
+                    path: tmp_path.display().to_string(),
+                    context: Some("temporary file permissions (async)".to_string()),
+                    error: e.to_string(),
+                })?;
+        }
+
+        #[cfg(not(unix))]
+        let _ = (tmp_path, mode);
+
+        Ok(())
+    }
+
     /// Rename `tmp_path` to `target_path` atomically (async), retrying up to
     /// `retry_count` times with a 10 ms `tokio::time::sleep` between attempts.
     ///
@@ -158,6 +328,71 @@ pub mod async_io {
         })
     }
 
+    /// Fsync the directory containing `target_path` (async).
+    ///
+    /// A no-op on platforms where directories cannot be opened for syncing.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Sync, … }` if the directory cannot be
+    /// opened or synced.
+    pub async fn sync_parent_dir(target_path: &Path) -> Result<(), StoreError> {
+        #[cfg(unix)]
+        {
+            let parent = match target_path.parent() {
+                Some(p) if !p.as_os_str().is_empty() => p,
+                _ => Path::new("."),
+            };
+
+            let dir = tokio::fs::File::open(parent)
+                .await
+                .map_err(|e| StoreError::IoError {
+                    operation: IoOperationKind::Sync,
+                    path: parent.display().to_string(),
+                    context: Some("parent directory (async)".to_string()),
+                    error: e.to_string(),
+                })?;
+            dir.sync_all().await.map_err(|e| StoreError::IoError {
+                operation: IoOperationKind::Sync,
+                path: parent.display().to_string(),
+                context: Some("parent directory (async)".to_string()),
+                error: e.to_string(),
+            })?;
+        }
+
+        #[cfg(not(unix))]
+        let _ = target_path;
+
+        Ok(())
+    }
+
+    /// Fsync the directory `dir` itself (async).
+    ///
+    /// A no-op on macOS and non-Unix platforms, as for the sync `sync_dir`.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Sync, … }` if the directory cannot be
+    /// opened or synced.
+    pub async fn sync_dir(dir: &Path) -> Result<(), StoreError> {
+        #[cfg(all(unix, not(target_os = "macos")))]
+        {
+            let sync_error = |e: std::io::Error| StoreError::IoError {
+                operation: IoOperationKind::Sync,
+                path: dir.display().to_string(),
+                context: Some("directory (async)".to_string()),
+                error: e.to_string(),
+            };
+            let handle = tokio::fs::File::open(dir).await.map_err(sync_error)?;
+            handle.sync_all().await.map_err(sync_error)?;
+        }
+
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        let _ = dir;
+
+        Ok(())
+    }
+
     /// Remove orphaned `.<filename>.tmp.*` files in the same directory as
     /// `target_path` (async).
     ///
@@ -252,6 +487,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sync_parent_dir_success() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("data.toml");
+        fs::write(&target, "data").unwrap();
+        sync_parent_dir(&target).unwrap();
+    }
+
     #[test]
     fn test_cleanup_temp_files_removes_matching() {
         let dir = TempDir::new().unwrap();
@@ -278,4 +521,43 @@ mod tests {
         // No .tmp files — should not error.
         cleanup_temp_files(&target).unwrap();
     }
+
+    #[test]
+    fn test_ensure_dir_creates_parents() {
+        let dir = TempDir::new().unwrap();
+        let nested = dir.path().join("a").join("b");
+        ensure_dir(&nested).unwrap();
+        assert!(nested.is_dir());
+        // Existing directories are fine
+        ensure_dir(&nested).unwrap();
+    }
+
+    #[test]
+    fn test_list_files_filters_extension_and_hidden() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("b.toml"), "").unwrap();
+        fs::write(dir.path().join("a.toml"), "").unwrap();
+        fs::write(dir.path().join("c.json"), "").unwrap();
+        fs::write(dir.path().join(".hidden.toml"), "").unwrap();
+        fs::create_dir(dir.path().join("sub.toml")).unwrap();
+
+        let files = list_files(dir.path(), "toml").unwrap();
+        assert_eq!(
+            files,
+            vec![dir.path().join("a.toml"), dir.path().join("b.toml")]
+        );
+    }
+
+    #[test]
+    fn test_list_files_missing_dir_errors() {
+        let dir = TempDir::new().unwrap();
+        let result = list_files(&dir.path().join("missing"), "toml");
+        assert!(matches!(
+            result,
+            Err(StoreError::IoError {
+                operation: IoOperationKind::ReadDir,
+                ..
+            })
+        ));
+    }
 }
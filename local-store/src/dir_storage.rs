@@ -13,6 +13,7 @@
 use crate::{
     atomic_io,
     errors::{IoOperationKind, StoreError},
+    file_lock::FileLock,
     AppPaths,
 };
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
@@ -20,6 +21,7 @@ use base64::Engine;
 use std::fs::{self, File};
 use std::io::Write as IoWrite;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 // Re-export shared types from storage module so callers can use them from
 // a single import path.
@@ -29,6 +31,15 @@ pub use crate::storage::{AtomicWriteConfig, FormatStrategy};
 // Configuration types
 // ============================================================================
 
+/// File name of the optional ID → version index kept in the base directory.
+///
+/// The name is reserved: it is never reported by `list_ids`, and an ID whose
+/// file would land on it (e.g. `_index` with the `json` extension) is
+/// rejected with `StoreError::FilenameEncoding`. Concurrent read-modify-write
+/// of the index is only serialized when `DirStorageStrategy::locking` is set
+/// (see `DirStorage::lock_index`).
+pub const INDEX_FILE_NAME: &str = "_index.json";
+
 /// File-naming encoding strategy for entity IDs.
 ///
 /// Determines how entity IDs are encoded into filesystem-safe filenames.
@@ -60,6 +71,14 @@ pub struct DirStorageStrategy {
     pub extension: Option<String>,
     /// Filename encoding strategy for entity IDs.
     pub filename_encoding: FilenameEncoding,
+    /// Whether to maintain an ID → version index file (`INDEX_FILE_NAME`).
+    pub index: bool,
+    /// Whether loading an entity stored at an older version rewrites its file
+    /// at the latest version.
+    pub rewrite_on_load: bool,
+    /// Whether saves and deletes hold an advisory lock on `.{id}.lock`, and
+    /// index updates one on `._index.json.lock` (sync `DirStorage` only).
+    pub locking: bool,
 }
 
 impl Default for DirStorageStrategy {
@@ -69,6 +88,9 @@ impl Default for DirStorageStrategy {
             atomic_write: AtomicWriteConfig::default(),
             extension: None,
             filename_encoding: FilenameEncoding::default(),
+            index: false,
+            rewrite_on_load: false,
+            locking: false,
         }
     }
 }
@@ -150,6 +172,88 @@ impl DirStorageStrategy {
         self
     }
 
+    /// Set whether to fsync the base directory after each atomic rename.
+    ///
+    /// # Arguments
+    ///
+    /// * `fsync_dir` - When `true`, the parent directory is synced after the
+    ///   rename so the new directory entry survives a crash (no-op on
+    ///   platforms without directory fsync).
+    ///
+    /// # Returns
+    ///
+    /// `self` with the updated flag (builder pattern).
+    pub fn with_fsync_dir(mut self, fsync_dir: bool) -> Self {
+        self.atomic_write.fsync_dir = fsync_dir;
+        self
+    }
+
+    /// Set the Unix permission bits for entity files.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Permission bits such as `0o600`, applied to the temporary
+    ///   file before the rename so the final file carries them. Ignored on
+    ///   non-Unix platforms.
+    ///
+    /// # Returns
+    ///
+    /// `self` with the updated mode (builder pattern).
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.atomic_write.mode = Some(mode);
+        self
+    }
+
+    /// Set whether to maintain the ID → version index file.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - When `true`, higher-level stores keep `INDEX_FILE_NAME`
+    ///   up to date on every save and delete.
+    ///
+    /// # Returns
+    ///
+    /// `self` with the updated flag (builder pattern).
+    pub fn with_index(mut self, enabled: bool) -> Self {
+        self.index = enabled;
+        self
+    }
+
+    /// Set whether loads rewrite stale entity files at the latest version.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - When `true`, higher-level stores atomically re-save an
+    ///   entity whose file was at an older version after loading it. Files
+    ///   already at the latest version are never rewritten.
+    ///
+    /// # Returns
+    ///
+    /// `self` with the updated flag (builder pattern).
+    pub fn with_rewrite_on_load(mut self, enabled: bool) -> Self {
+        self.rewrite_on_load = enabled;
+        self
+    }
+
+    /// Set whether saves and deletes take a per-ID advisory lock.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - When `true`, `DirStorage` holds a `FileLock` on
+    ///   `.{id}.lock` in the base directory for the duration of each save and
+    ///   delete, so concurrent writers to the same ID (threads or processes)
+    ///   take turns. `lock_entry` and `lock_index` expose the same locks to
+    ///   higher-level stores. Lock files are left in place. `AsyncDirStorage`
+    ///   ignores this flag.
+    ///
+    /// # Returns
+    ///
+    /// `self` with the updated flag (builder pattern).
+    pub fn with_locking(mut self, enabled: bool) -> Self {
+        self.locking = enabled;
+        self
+    }
+
     /// Returns the effective file extension for this strategy.
     ///
     /// Uses `self.extension` when set; otherwise derives `"json"` or `"toml"`
@@ -177,6 +281,7 @@ impl DirStorageStrategy {
 /// This type holds no `Migrator` and performs no schema migration.
 /// Content is stored and retrieved as opaque UTF-8 strings; callers are
 /// responsible for any serialisation/deserialisation.
+#[derive(Debug, Clone)]
 pub struct DirStorage {
     /// Resolved absolute path to the storage directory.
     base_path: PathBuf,
@@ -249,10 +354,7 @@ impl DirStorage {
         id: impl Into<String>,
         content: &str,
     ) -> Result<(), StoreError> {
-        let id: String = id.into();
-        let file_path = self.id_to_path(&id)?;
-        self.atomic_write(&file_path, content)?;
-        Ok(())
+        self.lock_entry(id)?.save_raw_string(content)
     }
 
     /// Read the raw string content for an entity.
@@ -327,7 +429,7 @@ impl DirStorage {
 
             let path = entry.path();
 
-            if path.is_file() {
+            if path.is_file() && !is_index_file(&path) {
                 if let Some(ext) = path.extension() {
                     if ext == extension.as_str() {
                         if let Some(id) = self.path_to_id(&path)? {
@@ -382,19 +484,196 @@ impl DirStorage {
     /// - `StoreError::IoError { operation: Delete, … }` if the file exists but
     ///   cannot be removed.
     pub fn delete(&self, id: impl Into<String>) -> Result<(), StoreError> {
+        self.lock_entry(id)?.delete()
+    }
+
+    /// Take exclusive hold of one entity ID.
+    ///
+    /// With `strategy.locking`, this blocks until the advisory lock on
+    /// `.{id}.lock` is acquired and keeps it until the returned guard is
+    /// dropped; without it, no lock is taken. Reads and writes made through
+    /// the guard do not lock again, so a caller can check the current
+    /// content and write a new one, or update related files such as the
+    /// index, while other writers to the same ID wait.
+    ///
+    /// # Errors
+    ///
+    /// - `StoreError::FilenameEncoding` if `id` cannot be encoded.
+    /// - `StoreError::IoError` if the lock cannot be acquired.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let entry = storage.lock_entry("session-1")?;
+    /// let current = entry.load_raw_string()?;
+    /// entry.save_raw_string(&update(&current))?;
+    /// ```
+    pub fn lock_entry(&self, id: impl Into<String>) -> Result<EntryLock<'_>, StoreError> {
         let id: String = id.into();
-        let file_path = self.id_to_path(&id)?;
+        let path = self.id_to_path(&id)?;
+        let lock = self.lock_id(&id)?;
+        Ok(EntryLock {
+            storage: self,
+            id,
+            path,
+            _lock: lock,
+        })
+    }
 
-        if file_path.exists() {
-            fs::remove_file(&file_path).map_err(|e| StoreError::IoError {
-                operation: IoOperationKind::Delete,
-                path: file_path.display().to_string(),
-                context: None,
-                error: e.to_string(),
-            })?;
+    /// Take the advisory lock on the index file when `strategy.locking` is
+    /// set.
+    ///
+    /// Hold the returned lock across `read_index` and `write_index` so that
+    /// concurrent writers (threads or processes) do not lose each other's
+    /// index updates. Returns `Ok(None)` without locking when
+    /// `strategy.locking` is off.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError` if the lock cannot be acquired.
+    pub fn lock_index(&self) -> Result<Option<FileLock>, StoreError> {
+        if !self.strategy.locking {
+            return Ok(None);
         }
+        let lock_path = self.base_path.join(format!(".{}.lock", INDEX_FILE_NAME));
+        FileLock::acquire(&lock_path).map(Some)
+    }
 
-        Ok(())
+    /// Size in bytes of the file stored for `id`.
+    ///
+    /// # Errors
+    ///
+    /// - `StoreError::FilenameEncoding` if `id` cannot be encoded.
+    /// - `StoreError::IoError { operation: Read, … }` if the file is missing
+    ///   or its metadata cannot be read.
+    pub fn entity_size(&self, id: impl Into<String>) -> Result<u64, StoreError> {
+        file_len(&self.id_to_path(&id.into())?)
+    }
+
+    /// Modification time of the file stored for `id`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` when no file is stored for `id`.
+    ///
+    /// # Errors
+    ///
+    /// - `StoreError::FilenameEncoding` if `id` cannot be encoded.
+    /// - `StoreError::IoError { operation: Read, … }` if the metadata cannot
+    ///   be read.
+    pub fn modified(&self, id: impl Into<String>) -> Result<Option<SystemTime>, StoreError> {
+        let path = self.id_to_path(&id.into())?;
+        let read_error = |e: std::io::Error| StoreError::IoError {
+            operation: IoOperationKind::Read,
+            path: path.display().to_string(),
+            context: Some("modification time".to_string()),
+            error: e.to_string(),
+        };
+        match fs::metadata(&path) {
+            Ok(metadata) => metadata.modified().map(Some).map_err(read_error),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(read_error(e)),
+        }
+    }
+
+    /// Total size in bytes of every regular file in the base directory,
+    /// including temporary, lock and index files.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError` if the directory cannot be read or a file's
+    /// metadata cannot be queried.
+    pub fn directory_size(&self) -> Result<u64, StoreError> {
+        let read_dir_error = |e: std::io::Error| StoreError::IoError {
+            operation: IoOperationKind::ReadDir,
+            path: self.base_path.display().to_string(),
+            context: None,
+            error: e.to_string(),
+        };
+
+        let mut total = 0;
+        for entry in fs::read_dir(&self.base_path).map_err(read_dir_error)? {
+            let path = entry.map_err(read_dir_error)?.path();
+            if path.is_file() {
+                total += file_len(&path)?;
+            }
+        }
+        Ok(total)
+    }
+
+    /// Resolve the file path an entity ID is stored at.
+    ///
+    /// The file does not need to exist.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::FilenameEncoding` if `id` cannot be encoded with the
+    /// configured strategy.
+    pub fn path_for_id(&self, id: impl Into<String>) -> Result<PathBuf, StoreError> {
+        self.id_to_path(&id.into())
+    }
+
+    /// Map a path inside the base directory back to its entity ID.
+    ///
+    /// Works for paths that no longer exist (e.g. after a delete), which
+    /// makes it suitable for translating file-system events.
+    ///
+    /// # Returns
+    ///
+    /// `Some(id)` when `path` is an entity file of this storage; `None` for
+    /// paths outside the base directory, other extensions, temporary files,
+    /// the index file, or stems that cannot be decoded.
+    pub fn id_for_path(&self, path: &Path) -> Option<String> {
+        if path.parent() != Some(self.base_path.as_path()) || is_index_file(path) {
+            return None;
+        }
+
+        let file_name = path.file_name()?.to_string_lossy();
+        if file_name.starts_with('.') && file_name.contains(".tmp.") {
+            return None;
+        }
+
+        let extension = self.strategy.get_extension();
+        if path.extension()? != extension.as_str() {
+            return None;
+        }
+
+        self.path_to_id(path).ok().flatten()
+    }
+
+    /// Write the raw content of the index file (`INDEX_FILE_NAME`), atomically.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError` if the file cannot be written.
+    pub fn write_index(&self, content: &str) -> Result<(), StoreError> {
+        self.atomic_write(&self.base_path.join(INDEX_FILE_NAME), content)
+    }
+
+    /// Read the raw content of the index file (`INDEX_FILE_NAME`).
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` when no index file exists.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Read, … }` if the file exists but
+    /// cannot be read.
+    pub fn read_index(&self) -> Result<Option<String>, StoreError> {
+        let index_path = self.base_path.join(INDEX_FILE_NAME);
+        if !index_path.is_file() {
+            return Ok(None);
+        }
+
+        fs::read_to_string(&index_path)
+            .map(Some)
+            .map_err(|e| StoreError::IoError {
+                operation: IoOperationKind::Read,
+                path: index_path.display().to_string(),
+                context: Some("index file".to_string()),
+                error: e.to_string(),
+            })
     }
 
     /// Returns a reference to the resolved base directory path.
@@ -406,6 +685,70 @@ impl DirStorage {
         &self.base_path
     }
 
+    /// Fsync the base directory so that newly created or renamed entity
+    /// files survive a crash.
+    ///
+    /// Call once after writing a batch of entities instead of enabling
+    /// `fsync_dir` for every write. A no-op on macOS and non-Unix platforms.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Sync, … }` if the directory cannot
+    /// be opened or synced.
+    pub fn fsync_directory(&self) -> Result<(), StoreError> {
+        atomic_io::sync_dir(&self.base_path)
+    }
+
+    /// Move the whole storage directory to `new_base` with a single rename.
+    ///
+    /// The rename is atomic when both paths are on the same filesystem.
+    /// On success this instance points at `new_base`.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Rename, … }` if the directory cannot
+    /// be moved (e.g. `new_base` is a non-empty directory, its parent does not
+    /// exist, or it lives on another filesystem).
+    pub fn rename_base(&mut self, new_base: PathBuf) -> Result<(), StoreError> {
+        rename_dir(&self.base_path, &new_base, None)?;
+        self.base_path = new_base;
+        Ok(())
+    }
+
+    /// Exchange the contents of the base directory and `other` by renaming
+    /// through a temporary sibling path.
+    ///
+    /// Each rename is atomic, but the swap as a whole is not: readers may
+    /// briefly observe the base path missing. If a later rename fails, the
+    /// earlier ones are rolled back on a best-effort basis.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Rename, … }` if any rename fails.
+    pub fn swap_base_with(&self, other: &Path) -> Result<(), StoreError> {
+        let base = &self.base_path;
+        let dir_name = base
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let swap_path = base.with_file_name(format!(".{}.swap.{}", dir_name, std::process::id()));
+
+        rename_dir(base, &swap_path, Some("to swap path"))?;
+
+        if let Err(e) = rename_dir(other, base, Some("into base path")) {
+            let _ = fs::rename(&swap_path, base);
+            return Err(e);
+        }
+
+        if let Err(e) = rename_dir(&swap_path, other, Some("from swap path")) {
+            let _ = fs::rename(base, other);
+            let _ = fs::rename(&swap_path, base);
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
     // =========================================================================
     // Private helpers
     // =========================================================================
@@ -414,14 +757,31 @@ impl DirStorage {
     ///
     /// # Errors
     ///
-    /// `StoreError::FilenameEncoding` if the encoding strategy rejects the ID.
+    /// `StoreError::FilenameEncoding` if the encoding strategy rejects the ID
+    /// or the file name is reserved for the index.
     fn id_to_path(&self, id: &str) -> Result<PathBuf, StoreError> {
         let encoded_id = self.encode_id(id)?;
         let extension = self.strategy.get_extension();
-        let filename = format!("{}.{}", encoded_id, extension);
+        let filename = entity_filename(id, &encoded_id, &extension)?;
         Ok(self.base_path.join(filename))
     }
 
+    /// Take the advisory lock for `id` when `strategy.locking` is set.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::FilenameEncoding` if `id` cannot be encoded, or
+    /// `StoreError::IoError` if the lock cannot be acquired.
+    fn lock_id(&self, id: &str) -> Result<Option<FileLock>, StoreError> {
+        if !self.strategy.locking {
+            return Ok(None);
+        }
+        let lock_path = self
+            .base_path
+            .join(format!(".{}.lock", self.encode_id(id)?));
+        FileLock::acquire(&lock_path).map(Some)
+    }
+
     /// Encode an entity ID to a filesystem-safe stem using the configured
     /// encoding strategy.
     ///
@@ -546,6 +906,8 @@ impl DirStorage {
             error: e.to_string(),
         })?;
 
+        atomic_io::set_mode(&tmp_path, self.strategy.atomic_write.mode)?;
+
         tmp_file
             .write_all(content.as_bytes())
             .map_err(|e| StoreError::IoError {
@@ -566,6 +928,10 @@ impl DirStorage {
 
         atomic_io::atomic_rename(&tmp_path, path, self.strategy.atomic_write.retry_count)?;
 
+        if self.strategy.atomic_write.fsync_dir {
+            atomic_io::sync_parent_dir(path)?;
+        }
+
         if self.strategy.atomic_write.cleanup_tmp_files {
             let _ = atomic_io::cleanup_temp_files(path);
         }
@@ -574,6 +940,119 @@ impl DirStorage {
     }
 }
 
+/// Exclusive hold on one entity ID of a [`DirStorage`], released on drop.
+///
+/// Returned by [`DirStorage::lock_entry`]. Holds the advisory lock on
+/// `.{id}.lock` when `strategy.locking` is set.
+#[derive(Debug)]
+pub struct EntryLock<'a> {
+    storage: &'a DirStorage,
+    id: String,
+    path: PathBuf,
+    _lock: Option<FileLock>,
+}
+
+impl EntryLock<'_> {
+    /// Returns the entity ID this guard holds.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Read the raw string content stored for the ID.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` when no file is stored for the ID.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Read, … }` if the file exists but
+    /// cannot be read.
+    pub fn load_raw_string(&self) -> Result<Option<String>, StoreError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(Some(content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(StoreError::IoError {
+                operation: IoOperationKind::Read,
+                path: self.path.display().to_string(),
+                context: None,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    /// Write raw string content for the ID, atomically.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError` if the file cannot be written.
+    pub fn save_raw_string(&self, content: &str) -> Result<(), StoreError> {
+        self.storage.atomic_write(&self.path, content)
+    }
+
+    /// Delete the file stored for the ID (idempotent).
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Delete, … }` if the file exists but
+    /// cannot be removed.
+    pub fn delete(&self) -> Result<(), StoreError> {
+        if self.path.exists() {
+            fs::remove_file(&self.path).map_err(|e| StoreError::IoError {
+                operation: IoOperationKind::Delete,
+                path: self.path.display().to_string(),
+                context: None,
+                error: e.to_string(),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Rename a directory, mapping failures to `StoreError::IoError`.
+fn rename_dir(from: &Path, to: &Path, context: Option<&str>) -> Result<(), StoreError> {
+    fs::rename(from, to).map_err(|e| StoreError::IoError {
+        operation: IoOperationKind::Rename,
+        path: format!("{} -> {}", from.display(), to.display()),
+        context: context.map(str::to_string),
+        error: e.to_string(),
+    })
+}
+
+/// Size of the file at `path` in bytes.
+fn file_len(path: &Path) -> Result<u64, StoreError> {
+    fs::metadata(path)
+        .map(|m| m.len())
+        .map_err(|e| StoreError::IoError {
+            operation: IoOperationKind::Read,
+            path: path.display().to_string(),
+            context: Some("metadata".to_string()),
+            error: e.to_string(),
+        })
+}
+
+/// Build the file name for an encoded ID, rejecting the reserved index name.
+///
+/// # Errors
+///
+/// `StoreError::FilenameEncoding` if the file name would be `INDEX_FILE_NAME`.
+fn entity_filename(id: &str, encoded_id: &str, extension: &str) -> Result<String, StoreError> {
+    let filename = format!("{}.{}", encoded_id, extension);
+    if filename == INDEX_FILE_NAME {
+        return Err(StoreError::FilenameEncoding {
+            id: id.to_string(),
+            reason: format!("'{}' is reserved for the index file", INDEX_FILE_NAME),
+        });
+    }
+    Ok(filename)
+}
+
+/// Returns `true` if `path` names the reserved index file.
+fn is_index_file(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == std::ffi::OsStr::new(INDEX_FILE_NAME))
+}
+
 // ============================================================================
 // Async implementation
 // ============================================================================
@@ -583,7 +1062,7 @@ pub use async_impl::AsyncDirStorage;
 
 #[cfg(feature = "async")]
 mod async_impl {
-    use super::{DirStorageStrategy, FilenameEncoding};
+    use super::{entity_filename, DirStorageStrategy, FilenameEncoding};
     use crate::{
         atomic_io,
         errors::{IoOperationKind, StoreError},
@@ -765,7 +1244,7 @@ mod async_impl {
                             error: e.to_string(),
                         })?;
 
-                if metadata.is_file() {
+                if metadata.is_file() && !super::is_index_file(&path) {
                     if let Some(ext) = path.extension() {
                         if ext == extension.as_str() {
                             if let Some(id) = self.path_to_id(&path)? {
@@ -857,6 +1336,21 @@ mod async_impl {
             &self.base_path
         }
 
+        /// Fsync the base directory so that newly created or renamed entity
+        /// files survive a crash (async).
+        ///
+        /// Call once after writing a batch of entities instead of enabling
+        /// `fsync_dir` for every write. A no-op on macOS and non-Unix
+        /// platforms.
+        ///
+        /// # Errors
+        ///
+        /// `StoreError::IoError { operation: Sync, … }` if the directory
+        /// cannot be opened or synced.
+        pub async fn fsync_directory(&self) -> Result<(), StoreError> {
+            atomic_io::async_io::sync_dir(&self.base_path).await
+        }
+
         // =================================================================
         // Private helpers (async)
         // =================================================================
@@ -864,7 +1358,7 @@ mod async_impl {
         fn id_to_path(&self, id: &str) -> Result<PathBuf, StoreError> {
             let encoded_id = self.encode_id(id)?;
             let extension = self.strategy.get_extension();
-            let filename = format!("{}.{}", encoded_id, extension);
+            let filename = entity_filename(id, &encoded_id, &extension)?;
             Ok(self.base_path.join(filename))
         }
 
@@ -952,6 +1446,8 @@ mod async_impl {
                         error: e.to_string(),
                     })?;
 
+            atomic_io::async_io::set_mode(&tmp_path, self.strategy.atomic_write.mode).await?;
+
             tmp_file
                 .write_all(content.as_bytes())
                 .await
@@ -978,6 +1474,10 @@ mod async_impl {
             )
             .await?;
 
+            if self.strategy.atomic_write.fsync_dir {
+                atomic_io::async_io::sync_parent_dir(path).await?;
+            }
+
             if self.strategy.atomic_write.cleanup_tmp_files {
                 let _ = atomic_io::async_io::cleanup_temp_files(path).await;
             }
@@ -1036,6 +1536,38 @@ mod async_impl {
             assert_eq!(content, r#"{"value":42}"#);
         }
 
+        /// T1': save_raw_string with `fsync_dir` enabled still succeeds.
+        #[tokio::test]
+        async fn test_async_save_with_fsync_dir() {
+            let tmp = TempDir::new().unwrap();
+            let paths = make_paths(&tmp);
+            let strategy = DirStorageStrategy::default().with_fsync_dir(true);
+            let storage = AsyncDirStorage::new(paths, "items", strategy)
+                .await
+                .unwrap();
+
+            storage
+                .save_raw_string("item", "item-1", r#"{"value":1}"#)
+                .await
+                .expect("save_raw_string should succeed");
+            assert!(storage.exists("item-1").await.unwrap());
+        }
+
+        /// T1'': fsync_directory succeeds after a batch of writes.
+        #[tokio::test]
+        async fn test_async_fsync_directory_after_batch() {
+            let tmp = TempDir::new().unwrap();
+            let paths = make_paths(&tmp);
+            let storage = AsyncDirStorage::new(paths, "items", DirStorageStrategy::default())
+                .await
+                .unwrap();
+
+            for id in ["a", "b"] {
+                storage.save_raw_string("item", id, "{}").await.unwrap();
+            }
+            storage.fsync_directory().await.expect("fsync ok");
+        }
+
         /// T2: load_raw_string on missing id returns IoError.
         #[tokio::test]
         async fn test_async_load_missing_id_returns_error() {
@@ -1109,6 +1641,56 @@ mod tests {
         assert_eq!(content, r#"{"value":99}"#);
     }
 
+    /// T1-b': saving with `fsync_dir` enabled still succeeds.
+    #[test]
+    fn test_save_with_fsync_dir() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let strategy = DirStorageStrategy::default().with_fsync_dir(true);
+        let storage = DirStorage::new(paths, "items", strategy).expect("new ok");
+
+        storage
+            .save_raw_string("item", "item-1", r#"{"value":1}"#)
+            .expect("save ok");
+        assert_eq!(storage.load_raw_string("item-1").unwrap(), r#"{"value":1}"#);
+    }
+
+    /// T1-b''': `with_mode` carries the permission bits to the final file.
+    #[cfg(unix)]
+    #[test]
+    fn test_save_with_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let strategy = DirStorageStrategy::default().with_mode(0o600);
+        let storage = DirStorage::new(paths, "items", strategy).expect("new ok");
+
+        storage
+            .save_raw_string("item", "item-1", r#"{"value":1}"#)
+            .expect("save ok");
+        let path = storage.path_for_id("item-1").unwrap();
+        let mode = std::fs::metadata(path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    /// T1-b'': fsync_directory succeeds after a batch of writes.
+    #[test]
+    fn test_fsync_directory_after_batch() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let storage =
+            DirStorage::new(paths, "items", DirStorageStrategy::default()).expect("new ok");
+
+        for i in 0..3 {
+            storage
+                .save_raw_string("item", format!("item-{}", i), "{}")
+                .expect("save ok");
+        }
+        storage.fsync_directory().expect("fsync ok");
+        assert_eq!(storage.list_ids().unwrap().len(), 3);
+    }
+
     /// T1-c: list_ids returns all stored IDs and excludes tmp files.
     #[test]
     fn test_list_ids_excludes_tmp_files() {
@@ -1128,6 +1710,60 @@ mod tests {
         assert_eq!(ids, vec!["alpha".to_string(), "beta".to_string()]);
     }
 
+    /// T1-c': the index file round-trips and is never listed as an ID.
+    #[test]
+    fn test_index_file_roundtrip_and_excluded_from_list_ids() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let storage =
+            DirStorage::new(paths, "items", DirStorageStrategy::default()).expect("new ok");
+
+        assert_eq!(storage.read_index().unwrap(), None);
+
+        storage.save_raw_string("x", "alpha", "a").expect("save ok");
+        storage.write_index(r#"{"alpha":"1.0.0"}"#).unwrap();
+
+        assert_eq!(
+            storage.read_index().unwrap().as_deref(),
+            Some(r#"{"alpha":"1.0.0"}"#)
+        );
+        assert_eq!(storage.list_ids().unwrap(), vec!["alpha".to_string()]);
+    }
+
+    /// T1-c'': id_for_path decodes entity files and rejects everything else.
+    #[test]
+    fn test_id_for_path() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let strategy =
+            DirStorageStrategy::default().with_filename_encoding(FilenameEncoding::UrlEncode);
+        let storage = DirStorage::new(paths, "items", strategy).expect("new ok");
+        let base = storage.base_path().to_path_buf();
+
+        assert_eq!(
+            storage.id_for_path(&base.join("user%40example.json")),
+            Some("user@example".to_string())
+        );
+        assert_eq!(storage.id_for_path(&base.join(".a.json.tmp.123")), None);
+        assert_eq!(storage.id_for_path(&base.join(INDEX_FILE_NAME)), None);
+        assert_eq!(storage.id_for_path(&base.join("a.toml")), None);
+        assert_eq!(storage.id_for_path(&tmp.path().join("a.json")), None);
+    }
+
+    /// T1-c''': path_for_id is the inverse of id_for_path.
+    #[test]
+    fn test_path_for_id_round_trips() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let strategy =
+            DirStorageStrategy::default().with_filename_encoding(FilenameEncoding::UrlEncode);
+        let storage = DirStorage::new(paths, "items", strategy).expect("new ok");
+
+        let path = storage.path_for_id("user@example").expect("path ok");
+        assert_eq!(path, storage.base_path().join("user%40example.json"));
+        assert_eq!(storage.id_for_path(&path), Some("user@example".to_string()));
+    }
+
     /// T1-d: exists returns true for a stored id and false for an unknown id.
     #[test]
     fn test_exists_reflects_storage_state() {
@@ -1143,8 +1779,66 @@ mod tests {
         assert!(!storage.exists("absent").expect("exists ok"));
     }
 
+    /// T1-e: entity_size covers one file; directory_size covers every file.
+    #[test]
+    fn test_entity_and_directory_size() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let storage =
+            DirStorage::new(paths, "items", DirStorageStrategy::default()).expect("new ok");
+
+        storage.save_raw_string("x", "a", "1234").expect("save ok");
+        storage.write_index("{}").unwrap();
+
+        assert_eq!(storage.entity_size("a").unwrap(), 4);
+        assert_eq!(storage.directory_size().unwrap(), 6);
+        assert!(storage.entity_size("missing").is_err());
+    }
+
+    /// T1-f: lock_entry reads, writes and deletes without locking again.
+    #[test]
+    fn test_lock_entry_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let strategy = DirStorageStrategy::default().with_locking(true);
+        let storage = DirStorage::new(paths, "items", strategy).expect("new ok");
+
+        let entry = storage.lock_entry("a").unwrap();
+        assert_eq!(entry.id(), "a");
+        assert_eq!(entry.load_raw_string().unwrap(), None);
+        entry.save_raw_string("one").unwrap();
+        assert_eq!(entry.load_raw_string().unwrap().as_deref(), Some("one"));
+        let _index_lock = storage.lock_index().unwrap();
+        entry.delete().unwrap();
+        assert!(!storage.exists("a").unwrap());
+    }
+
     // ---- T2: boundary / edge cases ---------------------------------------
 
+    /// T2-0: an ID that would overwrite the index file is rejected.
+    #[test]
+    fn test_index_file_name_is_reserved() {
+        let tmp = TempDir::new().unwrap();
+        let paths = make_paths(&tmp);
+        let storage =
+            DirStorage::new(paths, "items", DirStorageStrategy::default()).expect("new ok");
+
+        let result = storage.save_raw_string("x", "_index", "{}");
+        assert!(
+            matches!(result, Err(StoreError::FilenameEncoding { ref id, .. }) if id == "_index")
+        );
+        assert!(!storage.base_path().join(INDEX_FILE_NAME).exists());
+
+        // Other extensions do not collide
+        let toml = DirStorage::new(
+            make_paths(&tmp),
+            "toml-items",
+            DirStorageStrategy::default().with_format(FormatStrategy::Toml),
+        )
+        .expect("new ok");
+        toml.save_raw_string("x", "_index", "").expect("save ok");
+    }
+
     /// T2-a: empty string id fails Direct encoding.
     #[test]
     fn test_direct_encoding_empty_id() {
@@ -82,6 +82,12 @@ pub enum StoreError {
     #[error("Cannot determine home directory")]
     HomeDirNotFound,
 
+    /// Failed to determine the directory of the running executable.
+    ///
+    /// Raised by `PathStrategy::PortableRelativeToExe`.
+    #[error("Cannot determine executable directory: {0}")]
+    ExeDirNotFound(String),
+
     /// Failed to encode or decode a filename for the given entity ID.
     ///
     /// Raised when a filename encoding strategy (Direct/UrlEncode/Base64) cannot
@@ -110,6 +116,14 @@ pub enum StoreError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_store_error_exe_dir_not_found_display() {
+        let err = StoreError::ExeDirNotFound("no such file".to_string());
+        let display = format!("{}", err);
+        assert!(display.contains("executable directory"));
+        assert!(display.contains("no such file"));
+    }
+
     #[test]
     fn test_store_error_io_error_display_without_context() {
         let err = StoreError::IoError {
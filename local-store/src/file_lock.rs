@@ -0,0 +1,96 @@
+//! Advisory file locks used to coordinate writers across threads and
+//! processes.
+
+use crate::errors::{IoOperationKind, StoreError};
+use fs2::FileExt;
+use std::fs::{File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+/// An exclusive advisory lock on a lock file, released on drop.
+///
+/// The lock file itself is created on first use and left in place; removing
+/// it while another process waits on it would let two writers in at once.
+/// Locks are advisory: only code that takes the same lock is excluded.
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Block until the exclusive lock on `path` is acquired.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Lock file path; created if it does not exist.
+    ///
+    /// # Returns
+    ///
+    /// The held lock.
+    ///
+    /// # Errors
+    ///
+    /// - `StoreError::IoError { operation: Create, … }` if the lock file
+    ///   cannot be opened.
+    /// - `StoreError::IoError { operation: Write, … }` if the lock cannot be
+    ///   acquired.
+    pub fn acquire(path: &Path) -> Result<Self, StoreError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| StoreError::IoError {
+                operation: IoOperationKind::Create,
+                path: path.display().to_string(),
+                context: Some("lock file".to_string()),
+                error: e.to_string(),
+            })?;
+
+        file.lock_exclusive().map_err(|e| StoreError::IoError {
+            operation: IoOperationKind::Write,
+            path: path.display().to_string(),
+            context: Some("acquire lock".to_string()),
+            error: e.to_string(),
+        })?;
+
+        Ok(Self {
+            file,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Returns the lock file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = FileExt::unlock(&self.file);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileLock;
+    use fs2::FileExt;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_excludes_other_handles_until_dropped() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(".item.lock");
+
+        let lock = FileLock::acquire(&path).unwrap();
+        assert_eq!(lock.path(), path.as_path());
+
+        let other = std::fs::File::open(&path).unwrap();
+        assert!(other.try_lock_exclusive().is_err());
+
+        drop(lock);
+        other.try_lock_exclusive().unwrap();
+    }
+}
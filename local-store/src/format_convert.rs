@@ -10,6 +10,7 @@ use thiserror::Error;
 ///
 /// Each variant carries a human-readable message describing the failed step.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum FormatConvertError {
     /// Failed to serialize a JSON value to an intermediate string.
     #[error("json→toml serialize: {0}")]
@@ -24,6 +25,13 @@ pub enum FormatConvertError {
     /// Reserved for conversion paths that use `toml::from_str` directly.
     #[error("toml parse: {0}")]
     TomlParse(String),
+
+    /// A field holds JSON `null`, which TOML cannot represent.
+    ///
+    /// Carries the path of the first null field, e.g. `"data.description"`
+    /// or `"tags[2]"`.
+    #[error("json→toml: field '{0}' is null")]
+    NullField(String),
 }
 
 /// Convert a `serde_json::Value` to a `toml::Value`.
@@ -42,9 +50,13 @@ pub enum FormatConvertError {
 ///
 /// # Errors
 ///
+/// - `FormatConvertError::NullField` — when a field or array element is `null`.
 /// - `FormatConvertError::Serialize` — when `serde_json::to_string` fails.
 /// - `FormatConvertError::Deserialize` — when `serde_json::from_str::<toml::Value>` fails.
 pub fn json_to_toml(json_value: &JsonValue) -> Result<toml::Value, FormatConvertError> {
+    if let Some(field) = find_null(json_value, "") {
+        return Err(FormatConvertError::NullField(field));
+    }
     let json_str = serde_json::to_string(json_value)
         .map_err(|e| FormatConvertError::Serialize(e.to_string()))?;
     let toml_value: toml::Value = serde_json::from_str(&json_str)
@@ -52,6 +64,33 @@ pub fn json_to_toml(json_value: &JsonValue) -> Result<toml::Value, FormatConvert
     Ok(toml_value)
 }
 
+/// Return the path of the first `null` nested inside `value`, if any.
+fn find_null(value: &JsonValue, prefix: &str) -> Option<String> {
+    match value {
+        JsonValue::Object(map) => map.iter().find_map(|(key, v)| {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            if v.is_null() {
+                Some(path)
+            } else {
+                find_null(v, &path)
+            }
+        }),
+        JsonValue::Array(items) => items.iter().enumerate().find_map(|(i, v)| {
+            let path = format!("{}[{}]", prefix, i);
+            if v.is_null() {
+                Some(path)
+            } else {
+                find_null(v, &path)
+            }
+        }),
+        _ => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -105,6 +144,21 @@ mod tests {
         let _ = json_to_toml(&json); // just must not panic
     }
 
+    #[test]
+    fn test_json_to_toml_reports_null_field_path() {
+        let json = json!({"version": "1.0.0", "data": {"id": "a", "note": null}});
+        match json_to_toml(&json) {
+            Err(FormatConvertError::NullField(field)) => assert_eq!(field, "data.note"),
+            other => panic!("expected NullField, got {:?}", other),
+        }
+
+        let json = json!({"tags": ["a", null]});
+        match json_to_toml(&json) {
+            Err(FormatConvertError::NullField(field)) => assert_eq!(field, "tags[1]"),
+            other => panic!("expected NullField, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_json_to_toml_string_with_unicode() {
         let json = json!({"emoji": "🦀", "text": "日本語"});
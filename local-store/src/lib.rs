@@ -3,12 +3,16 @@
 pub mod atomic_io;
 pub mod dir_storage;
 pub mod errors;
+pub mod file_lock;
 pub mod format_convert;
 pub mod paths;
 pub mod storage;
 
-pub use dir_storage::{DirStorage, DirStorageStrategy, FilenameEncoding};
+pub use dir_storage::{
+    DirStorage, DirStorageStrategy, EntryLock, FilenameEncoding, INDEX_FILE_NAME,
+};
 pub use errors::{IoOperationKind, StoreError};
+pub use file_lock::FileLock;
 pub use format_convert::{json_to_toml, FormatConvertError};
 pub use paths::{AppPaths, PathStrategy, PrefPath};
 pub use storage::{
@@ -9,6 +9,7 @@ use std::path::PathBuf;
 ///
 /// Determines how configuration and data directories are resolved.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
 pub enum PathStrategy {
     /// Use OS-standard directories (default).
     ///
@@ -31,6 +32,25 @@ pub enum PathStrategy {
     ///
     /// All paths will be resolved relative to this base directory.
     CustomBase(PathBuf),
+
+    /// Resolve directories next to the running executable.
+    ///
+    /// Config lives in `<exe_dir>/config` and data in `<exe_dir>/data`, without
+    /// an app-name subdirectory. Intended for portable deployments (e.g. a USB
+    /// stick) that ship their config alongside the binary.
+    PortableRelativeToExe,
+}
+
+/// Resolve `subdir` inside the directory containing the executable at `exe`.
+///
+/// Takes the result of `std::env::current_exe()` so the logic can be tested
+/// with a fake path.
+fn exe_relative_dir(exe: std::io::Result<PathBuf>, subdir: &str) -> Result<PathBuf, StoreError> {
+    let exe = exe.map_err(|e| StoreError::ExeDirNotFound(e.to_string()))?;
+    let dir = exe.parent().ok_or_else(|| {
+        StoreError::ExeDirNotFound(format!("'{}' has no parent directory", exe.display()))
+    })?;
+    Ok(dir.join(subdir))
 }
 
 /// Application path manager with configurable resolution strategies.
@@ -84,6 +104,22 @@ impl AppPaths {
         }
     }
 
+    /// Create a path manager that keeps config and data next to the executable.
+    ///
+    /// Shorthand for using `PathStrategy::PortableRelativeToExe` for both.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let paths = AppPaths::portable("myapp");
+    /// // /media/usb/myapp/myapp.exe → /media/usb/myapp/config, /media/usb/myapp/data
+    /// ```
+    pub fn portable(app_name: impl Into<String>) -> Self {
+        Self::new(app_name)
+            .config_strategy(PathStrategy::PortableRelativeToExe)
+            .data_strategy(PathStrategy::PortableRelativeToExe)
+    }
+
     /// Set the configuration directory resolution strategy.
     ///
     /// # Example
@@ -121,6 +157,7 @@ impl AppPaths {
     /// # Errors
     ///
     /// Returns `StoreError::HomeDirNotFound` if the home directory cannot be determined.
+    /// Returns `StoreError::ExeDirNotFound` if a portable strategy cannot locate the executable.
     /// Returns `StoreError::IoError` if directory creation fails.
     ///
     /// # Example
@@ -147,6 +184,7 @@ impl AppPaths {
     /// # Errors
     ///
     /// Returns `StoreError::HomeDirNotFound` if the home directory cannot be determined.
+    /// Returns `StoreError::ExeDirNotFound` if a portable strategy cannot locate the executable.
     /// Returns `StoreError::IoError` if directory creation fails.
     ///
     /// # Example
@@ -181,6 +219,19 @@ impl AppPaths {
         Ok(self.config_dir()?.join(filename))
     }
 
+    /// Resolve a configuration file path without touching the file system.
+    ///
+    /// Unlike [`config_file`](Self::config_file), the directory is not
+    /// created, which makes this suitable for probing candidate locations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StoreError::HomeDirNotFound` if the home directory cannot be determined.
+    /// Returns `StoreError::ExeDirNotFound` if a portable strategy cannot locate the executable.
+    pub fn config_path(&self, filename: &str) -> Result<PathBuf, StoreError> {
+        Ok(self.resolve_config_dir()?.join(filename))
+    }
+
     /// Get a data file path.
     ///
     /// This is a convenience method that joins the filename to the data directory.
@@ -214,6 +265,9 @@ impl AppPaths {
                 Ok(home.join(".config").join(&self.app_name))
             }
             PathStrategy::CustomBase(base) => Ok(base.join(&self.app_name)),
+            PathStrategy::PortableRelativeToExe => {
+                exe_relative_dir(std::env::current_exe(), "config")
+            }
         }
     }
 
@@ -231,6 +285,9 @@ impl AppPaths {
                 Ok(home.join(".local/share").join(&self.app_name))
             }
             PathStrategy::CustomBase(base) => Ok(base.join("data").join(&self.app_name)),
+            PathStrategy::PortableRelativeToExe => {
+                exe_relative_dir(std::env::current_exe(), "data")
+            }
         }
     }
 
@@ -597,4 +654,80 @@ mod tests {
             let _ = std::fs::remove_dir_all(&dir1);
         }
     }
+
+    #[test]
+    fn test_exe_relative_dir_resolves_next_to_exe() {
+        let exe = PathBuf::from("/media/usb/myapp/myapp.exe");
+        assert_eq!(
+            exe_relative_dir(Ok(exe.clone()), "config").unwrap(),
+            PathBuf::from("/media/usb/myapp/config")
+        );
+        assert_eq!(
+            exe_relative_dir(Ok(exe), "data").unwrap(),
+            PathBuf::from("/media/usb/myapp/data")
+        );
+    }
+
+    #[test]
+    fn test_exe_relative_dir_errors_without_exe() {
+        let err = exe_relative_dir(
+            Err(std::io::Error::new(std::io::ErrorKind::NotFound, "gone")),
+            "config",
+        )
+        .unwrap_err();
+        assert!(matches!(err, StoreError::ExeDirNotFound(ref msg) if msg.contains("gone")));
+
+        let err = exe_relative_dir(Ok(PathBuf::from("/")), "config").unwrap_err();
+        assert!(matches!(err, StoreError::ExeDirNotFound(_)));
+    }
+
+    #[test]
+    fn test_portable_strategy_uses_current_exe_dir() {
+        let paths = AppPaths::portable("testapp");
+        let exe_dir = std::env::current_exe()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .to_path_buf();
+
+        assert_eq!(
+            paths.config_path("config.toml").unwrap(),
+            exe_dir.join("config").join("config.toml")
+        );
+        assert_eq!(paths.resolve_data_dir().unwrap(), exe_dir.join("data"));
+    }
+
+    #[test]
+    fn test_portable_config_with_custom_data() {
+        // Only the config side is portable; it ignores the app name.
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp")
+            .config_strategy(PathStrategy::PortableRelativeToExe)
+            .data_strategy(PathStrategy::CustomBase(temp_dir.path().to_path_buf()));
+        let exe_dir = std::env::current_exe()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .to_path_buf();
+
+        assert_eq!(
+            paths.config_path("settings.toml").unwrap(),
+            exe_dir.join("config").join("settings.toml")
+        );
+        assert_eq!(
+            paths.resolve_data_dir().unwrap(),
+            temp_dir.path().join("data").join("testapp")
+        );
+    }
+
+    #[test]
+    fn test_config_path_does_not_create_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp")
+            .config_strategy(PathStrategy::CustomBase(temp_dir.path().to_path_buf()));
+
+        let path = paths.config_path("config.toml").unwrap();
+        assert_eq!(path, temp_dir.path().join("testapp").join("config.toml"));
+        assert!(!temp_dir.path().join("testapp").exists());
+    }
 }
@@ -10,6 +10,7 @@ use serde_json::Value as JsonValue;
 use std::fs::{self, File};
 use std::io::Write as IoWrite;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 /// File format strategy for storage operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,11 +23,18 @@ pub enum FormatStrategy {
 
 /// Configuration for atomic write operations.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct AtomicWriteConfig {
     /// Number of times to retry rename operation (default: 3)
     pub retry_count: usize,
     /// Whether to clean up old temporary files (best effort)
     pub cleanup_tmp_files: bool,
+    /// Whether to fsync the parent directory after rename (default: false)
+    pub fsync_dir: bool,
+    /// Unix permission bits applied to the temporary file before the rename,
+    /// e.g. `Some(0o600)` (default: `None`, keeps the umask-derived mode).
+    /// Ignored on non-Unix platforms.
+    pub mode: Option<u32>,
 }
 
 impl Default for AtomicWriteConfig {
@@ -34,6 +42,8 @@ impl Default for AtomicWriteConfig {
         Self {
             retry_count: 3,
             cleanup_tmp_files: true,
+            fsync_dir: false,
+            mode: None,
         }
     }
 }
@@ -60,6 +70,10 @@ pub struct FileStorageStrategy {
     pub load_behavior: LoadBehavior,
     /// Default value used when `SaveIfMissing` is set (as JSON Value).
     pub default_value: Option<JsonValue>,
+    /// Keep running in memory when the file cannot be written, instead of
+    /// failing the save. Honoured by the `SaveIfMissing` write in
+    /// `FileStorage::new` and by higher-level stores.
+    pub in_memory_fallback: bool,
 }
 
 impl Default for FileStorageStrategy {
@@ -69,6 +83,7 @@ impl Default for FileStorageStrategy {
             atomic_write: AtomicWriteConfig::default(),
             load_behavior: LoadBehavior::CreateIfMissing,
             default_value: None,
+            in_memory_fallback: false,
         }
     }
 }
@@ -97,6 +112,18 @@ impl FileStorageStrategy {
         self
     }
 
+    /// Set whether to fsync the parent directory after each atomic rename.
+    pub fn with_fsync_dir(mut self, fsync_dir: bool) -> Self {
+        self.atomic_write.fsync_dir = fsync_dir;
+        self
+    }
+
+    /// Set the Unix permission bits for written files (e.g. `0o600`).
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.atomic_write.mode = Some(mode);
+        self
+    }
+
     /// Set the load behavior.
     pub fn with_load_behavior(mut self, behavior: LoadBehavior) -> Self {
         self.load_behavior = behavior;
@@ -108,6 +135,15 @@ impl FileStorageStrategy {
         self.default_value = Some(value);
         self
     }
+
+    /// Set whether write failures on save fall back to in-memory operation.
+    ///
+    /// Useful on read-only or sandboxed filesystems, where the application
+    /// should keep running with its in-memory config.
+    pub fn with_in_memory_fallback(mut self, enabled: bool) -> Self {
+        self.in_memory_fallback = enabled;
+        self
+    }
 }
 
 /// Raw file storage with ACID guarantees.
@@ -125,6 +161,7 @@ impl FileStorage {
     ///
     /// - `CreateIfMissing`: succeeds without writing when file is absent.
     /// - `SaveIfMissing`: serializes `strategy.default_value` (or `{}`) and writes it.
+    ///   With `strategy.in_memory_fallback`, a failed write is not an error.
     /// - `ErrorIfMissing`: returns `StoreError` when file is absent.
     pub fn new(path: PathBuf, strategy: FileStorageStrategy) -> Result<Self, StoreError> {
         let file_was_missing = !path.exists();
@@ -146,7 +183,12 @@ impl FileStorage {
                     // Serialize default_value (or "{}") and persist immediately.
                     let storage = Self { path, strategy };
                     let content = storage.default_value_as_string()?;
-                    storage.write_string(&content)?;
+                    match storage.write_string(&content) {
+                        Ok(()) => {}
+                        // Read-only filesystems must not prevent startup.
+                        Err(_) if storage.strategy.in_memory_fallback => {}
+                        Err(e) => return Err(e),
+                    }
                     return Ok(storage);
                 }
             }
@@ -193,6 +235,8 @@ impl FileStorage {
             error: e.to_string(),
         })?;
 
+        atomic_io::set_mode(&tmp_path, self.strategy.atomic_write.mode)?;
+
         tmp_file
             .write_all(content.as_bytes())
             .map_err(|e| StoreError::IoError {
@@ -217,6 +261,10 @@ impl FileStorage {
             self.strategy.atomic_write.retry_count,
         )?;
 
+        if self.strategy.atomic_write.fsync_dir {
+            atomic_io::sync_parent_dir(&self.path)?;
+        }
+
         if self.strategy.atomic_write.cleanup_tmp_files {
             let _ = atomic_io::cleanup_temp_files(&self.path);
         }
@@ -224,6 +272,61 @@ impl FileStorage {
         Ok(())
     }
 
+    /// Size and modification time of the file.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(None)` when the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Read, … }` if the metadata cannot be
+    /// read.
+    pub fn stamp(&self) -> Result<Option<(u64, SystemTime)>, StoreError> {
+        let metadata = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(StoreError::IoError {
+                    operation: IoOperationKind::Read,
+                    path: self.path.display().to_string(),
+                    context: Some("file metadata".to_string()),
+                    error: e.to_string(),
+                })
+            }
+        };
+
+        let modified = metadata.modified().map_err(|e| StoreError::IoError {
+            operation: IoOperationKind::Read,
+            path: self.path.display().to_string(),
+            context: Some("modification time".to_string()),
+            error: e.to_string(),
+        })?;
+        Ok(Some((metadata.len(), modified)))
+    }
+
+    /// Delete the file.
+    ///
+    /// This operation is **idempotent**: if the file does not exist, `Ok(())`
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// `StoreError::IoError { operation: Delete, … }` if the file exists but
+    /// cannot be removed.
+    pub fn delete(&self) -> Result<(), StoreError> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+
+        fs::remove_file(&self.path).map_err(|e| StoreError::IoError {
+            operation: IoOperationKind::Delete,
+            path: self.path.display().to_string(),
+            context: None,
+            error: e.to_string(),
+        })
+    }
+
     /// Returns a reference to the storage file path.
     pub fn path(&self) -> &Path {
         &self.path
@@ -297,6 +400,36 @@ mod tests {
         assert_eq!(storage.path(), path.as_path());
     }
 
+    #[test]
+    fn test_new_save_if_missing_with_in_memory_fallback() {
+        let dir = TempDir::new().unwrap();
+        let blocked = dir.path().join("blocked");
+        // A regular file where the directory should be makes the write fail,
+        // even when running as root.
+        fs::write(&blocked, "not a directory").unwrap();
+        let path = blocked.join("config.toml");
+
+        let strategy = FileStorageStrategy::new().with_load_behavior(LoadBehavior::SaveIfMissing);
+        assert!(FileStorage::new(path.clone(), strategy.clone()).is_err());
+
+        let storage =
+            FileStorage::new(path.clone(), strategy.with_in_memory_fallback(true)).unwrap();
+        assert_eq!(storage.path(), path.as_path());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_stamp_reports_size_and_missing_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.json");
+        let storage = FileStorage::new(path, FileStorageStrategy::new()).unwrap();
+
+        assert_eq!(storage.stamp().unwrap(), None);
+        storage.write_string("{}").unwrap();
+        let (len, _) = storage.stamp().unwrap().unwrap();
+        assert_eq!(len, 2);
+    }
+
     #[test]
     fn test_new_no_file_create_if_missing() {
         let dir = TempDir::new().unwrap();
@@ -363,6 +496,21 @@ mod tests {
         assert_eq!(back, r#"{"x":1}"#);
     }
 
+    #[test]
+    fn test_write_string_with_fsync_dir() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("durable.json");
+
+        let strategy = FileStorageStrategy::new()
+            .with_format(FormatStrategy::Json)
+            .with_fsync_dir(true);
+        assert!(strategy.atomic_write.fsync_dir);
+
+        let storage = FileStorage::new(path.clone(), strategy).unwrap();
+        storage.write_string(r#"{"x":1}"#).unwrap();
+        assert_eq!(storage.read_string().unwrap(), r#"{"x":1}"#);
+    }
+
     #[test]
     fn test_write_string_creates_parent_dirs() {
         let dir = TempDir::new().unwrap();
@@ -497,6 +645,7 @@ mod tests {
         let cfg = AtomicWriteConfig::default();
         assert_eq!(cfg.retry_count, 3);
         assert!(cfg.cleanup_tmp_files);
+        assert!(!cfg.fsync_dir);
     }
 
     #[test]
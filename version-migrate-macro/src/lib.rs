@@ -1,5 +1,5 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, DeriveInput, Meta, Type};
 
 /// Derives the `Versioned` trait for a struct.
@@ -12,10 +12,21 @@ use syn::{parse_macro_input, DeriveInput, Meta, Type};
 /// - `#[versioned(data_key = "...")]`: Customizes the data field key (optional, default: "data").
 /// - `#[versioned(auto_tag = true)]`: Auto-generates Serialize/Deserialize with version field (optional, default: false).
 ///   When enabled, the version field is automatically inserted during serialization and validated during deserialization.
+///   Fields marked `#[serde(flatten)]` are not supported and are rejected at compile time.
 /// - `#[versioned(queryable = true)]`: Auto-generates Queryable trait implementation (optional, default: false).
 ///   Enables use with ConfigMigrator for ORM-like queries.
 /// - `#[versioned(queryable_key = "...")]`: Customizes the entity name for Queryable (optional).
 ///   If not specified, uses the lowercased type name. Only used when `queryable = true`.
+/// - `#[versioned(queryable_plural = true)]`: Also implements `QueryableKey` with the
+///   pluralized entity name as the config key (optional, default: false), e.g. `task` → `tasks`,
+///   `category` → `categories`, `box` → `boxes`. Only used when `queryable = true`.
+/// - `#[versioned(frozen = true)]`: Marks the schema as frozen (optional, default: false).
+///   Registering a migration path with a step out of a frozen version fails.
+/// - `#[versioned(field_names = true)]`: Also emits an inherent
+///   `const FIELD_NAMES: &'static [&'static str]` listing the serialized field names
+///   (optional, default: false). Field `#[serde(rename = "...")]`, container
+///   `#[serde(rename_all = "...")]` and `#[serde(skip)]` are honored; `#[serde(flatten)]`
+///   is rejected at compile time.
 ///
 /// # Examples
 ///
@@ -72,7 +83,29 @@ use syn::{parse_macro_input, DeriveInput, Meta, Type};
 /// // Now TaskEntity implements Queryable automatically
 /// let tasks: Vec<TaskEntity> = config_migrator.query("tasks")?;
 /// ```
-#[proc_macro_derive(Versioned, attributes(versioned))]
+///
+/// Field names for schema tooling:
+/// ```ignore
+/// #[derive(Serialize, Deserialize, Versioned)]
+/// #[versioned(version = "1.0.0", field_names = true)]
+/// #[serde(rename_all = "camelCase")]
+/// pub struct TaskV1 {
+///     pub id: String,
+///     pub due_date: String,
+/// }
+///
+/// assert_eq!(TaskV1::FIELD_NAMES, &["id", "dueDate"]);
+/// ```
+///
+/// Plural config key:
+/// ```ignore
+/// #[derive(Serialize, Deserialize, Versioned)]
+/// #[versioned(version = "2.0.0", queryable = true, queryable_key = "task", queryable_plural = true)]
+/// pub struct TaskEntity { ... }
+///
+/// let tasks: Vec<TaskEntity> = config_migrator.query_default()?;  // reads "tasks"
+/// ```
+#[proc_macro_derive(Versioned, attributes(versioned, serde))]
 pub fn derive_versioned(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -85,18 +118,28 @@ pub fn derive_versioned(input: TokenStream) -> TokenStream {
     let version = &attrs.version;
     let version_key = &attrs.version_key;
     let data_key = &attrs.data_key;
+    let frozen = attrs.frozen;
 
     let versioned_impl = quote! {
         impl #impl_generics version_migrate::Versioned for #name #ty_generics #where_clause {
             const VERSION: &'static str = #version;
             const VERSION_KEY: &'static str = #version_key;
             const DATA_KEY: &'static str = #data_key;
+            const FROZEN: bool = #frozen;
         }
     };
 
     let mut impls = vec![versioned_impl];
 
     if attrs.auto_tag {
+        // The generated impls list named fields literally and cannot represent
+        // a flattened map, so reject it instead of emitting wrong code.
+        if let Some(field) = find_flattened_field(&input) {
+            return syn::Error::new_spanned(field, "auto_tag does not support serde(flatten)")
+                .to_compile_error()
+                .into();
+        }
+
         // Generate custom Serialize and Deserialize implementations
         let serialize_impl = generate_serialize_impl(&input, &attrs);
         let deserialize_impl = generate_deserialize_impl(&input, &attrs);
@@ -104,6 +147,19 @@ pub fn derive_versioned(input: TokenStream) -> TokenStream {
         impls.push(deserialize_impl);
     }
 
+    if attrs.field_names {
+        let names = match serialized_field_names(&input) {
+            Ok(names) => names,
+            Err(e) => return e.to_compile_error().into(),
+        };
+        impls.push(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Serialized field names of this version, in declaration order.
+                pub const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+            }
+        });
+    }
+
     if attrs.queryable {
         // Generate Queryable trait implementation
         let queryable_impl = generate_queryable_impl(&input, &attrs);
@@ -124,29 +180,30 @@ struct VersionedAttributes {
     auto_tag: bool,
     queryable: bool,
     queryable_key: Option<String>,
+    queryable_plural: bool,
+    frozen: bool,
+    field_names: bool,
 }
 
 fn extract_attributes(input: &DeriveInput) -> VersionedAttributes {
     let mut version = None;
-    let mut version_key = String::from("version");
-    let mut data_key = String::from("data");
-    let mut auto_tag = false;
-    let mut queryable = false;
-    let mut queryable_key = None;
+    let mut attrs = VersionedAttributes {
+        version: String::new(),
+        version_key: String::from("version"),
+        data_key: String::from("data"),
+        auto_tag: false,
+        queryable: false,
+        queryable_key: None,
+        queryable_plural: false,
+        frozen: false,
+        field_names: false,
+    };
 
     for attr in &input.attrs {
         if attr.path().is_ident("versioned") {
             if let Meta::List(meta_list) = &attr.meta {
                 let tokens = meta_list.tokens.to_string();
-                parse_versioned_attrs(
-                    &tokens,
-                    &mut version,
-                    &mut version_key,
-                    &mut data_key,
-                    &mut auto_tag,
-                    &mut queryable,
-                    &mut queryable_key,
-                );
+                parse_versioned_attrs(&tokens, &mut version, &mut attrs);
             }
         }
     }
@@ -160,24 +217,14 @@ fn extract_attributes(input: &DeriveInput) -> VersionedAttributes {
         panic!("Invalid semantic version '{}': {}", version, e);
     }
 
-    VersionedAttributes {
-        version,
-        version_key,
-        data_key,
-        auto_tag,
-        queryable,
-        queryable_key,
-    }
+    attrs.version = version;
+    attrs
 }
 
 fn parse_versioned_attrs(
     tokens: &str,
     version: &mut Option<String>,
-    version_key: &mut String,
-    data_key: &mut String,
-    auto_tag: &mut bool,
-    queryable: &mut bool,
-    queryable_key: &mut Option<String>,
+    attrs: &mut VersionedAttributes,
 ) {
     // Parse comma-separated key = "value" pairs
     for part in tokens.split(',') {
@@ -186,15 +233,21 @@ fn parse_versioned_attrs(
         if let Some(val) = parse_attr_value(part, "version") {
             *version = Some(val);
         } else if let Some(val) = parse_attr_value(part, "version_key") {
-            *version_key = val;
+            attrs.version_key = val;
         } else if let Some(val) = parse_attr_value(part, "data_key") {
-            *data_key = val;
+            attrs.data_key = val;
         } else if let Some(val) = parse_attr_bool_value(part, "auto_tag") {
-            *auto_tag = val;
+            attrs.auto_tag = val;
         } else if let Some(val) = parse_attr_bool_value(part, "queryable") {
-            *queryable = val;
+            attrs.queryable = val;
         } else if let Some(val) = parse_attr_value(part, "queryable_key") {
-            *queryable_key = Some(val);
+            attrs.queryable_key = Some(val);
+        } else if let Some(val) = parse_attr_bool_value(part, "queryable_plural") {
+            attrs.queryable_plural = val;
+        } else if let Some(val) = parse_attr_bool_value(part, "frozen") {
+            attrs.frozen = val;
+        } else if let Some(val) = parse_attr_bool_value(part, "field_names") {
+            attrs.field_names = val;
         }
     }
 }
@@ -243,11 +296,46 @@ fn generate_queryable_impl(
         name.to_string().to_lowercase()
     };
 
+    let key_impl = attrs.queryable_plural.then(|| {
+        let config_key = pluralize(&entity_name);
+        quote! {
+            impl version_migrate::QueryableKey for #name {
+                const CONFIG_KEY: &'static str = #config_key;
+            }
+        }
+    });
+
     quote! {
         impl version_migrate::Queryable for #name {
             const ENTITY_NAME: &'static str = #entity_name;
         }
+
+        #key_impl
+    }
+}
+
+/// Pluralizes an entity name with the regular English suffix rules.
+///
+/// `version-migrate` applies the same rule at runtime to map config keys
+/// back to entities; keep the two in sync.
+fn pluralize(name: &str) -> String {
+    const SIBILANT_ENDINGS: [&str; 5] = ["s", "x", "z", "ch", "sh"];
+
+    if SIBILANT_ENDINGS.iter().any(|ending| name.ends_with(ending)) {
+        return format!("{}es", name);
+    }
+
+    if let Some(stem) = name.strip_suffix('y') {
+        let after_consonant = stem
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphabetic() && !"aeiou".contains(c));
+        if after_consonant {
+            return format!("{}ies", stem);
+        }
     }
+
+    format!("{}s", name)
 }
 
 /// Derives the `Queryable` trait for a struct.
@@ -259,6 +347,9 @@ fn generate_queryable_impl(
 ///
 /// - `#[queryable(entity = "name")]`: Specifies the entity name (required).
 ///   This must match the entity name used when registering migration paths.
+/// - `#[queryable(key = "name")]`: Specifies the config key the records live
+///   under (optional). Also implements `QueryableKey`, so
+///   `ConfigMigrator::query_default` can be called without a key.
 ///
 /// # Examples
 ///
@@ -287,6 +378,15 @@ fn generate_queryable_impl(
 /// #[queryable(entity = "task")]  // ← Must match
 /// struct TaskEntity { ... }
 /// ```
+///
+/// With a default config key:
+/// ```ignore
+/// #[derive(Queryable)]
+/// #[queryable(entity = "task", key = "open_tasks")]
+/// struct TaskEntity { ... }
+///
+/// let tasks: Vec<TaskEntity> = config.query_default()?;  // reads "open_tasks"
+/// ```
 #[proc_macro_derive(Queryable, attributes(queryable))]
 pub fn derive_queryable(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -294,13 +394,15 @@ pub fn derive_queryable(input: TokenStream) -> TokenStream {
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let mut entity_name: Option<String> = None;
+    let mut config_key: Option<String> = None;
 
-    // Extract entity attribute
+    // Extract entity and key attributes
     for attr in &input.attrs {
         if attr.path().is_ident("queryable") {
             if let Meta::List(meta_list) = &attr.meta {
                 let tokens = meta_list.tokens.to_string();
                 entity_name = parse_entity_attr(&tokens);
+                config_key = parse_key_attr(&tokens);
             }
         }
     }
@@ -309,10 +411,20 @@ pub fn derive_queryable(input: TokenStream) -> TokenStream {
         panic!("Missing #[queryable(entity = \"name\")] attribute");
     });
 
+    let key_impl = config_key.map(|key| {
+        quote! {
+            impl #impl_generics version_migrate::QueryableKey for #name #ty_generics #where_clause {
+                const CONFIG_KEY: &'static str = #key;
+            }
+        }
+    });
+
     let expanded = quote! {
         impl #impl_generics version_migrate::Queryable for #name #ty_generics #where_clause {
             const ENTITY_NAME: &'static str = #entity_name;
         }
+
+        #key_impl
     };
 
     TokenStream::from(expanded)
@@ -328,6 +440,149 @@ fn parse_entity_attr(tokens: &str) -> Option<String> {
     None
 }
 
+fn parse_key_attr(tokens: &str) -> Option<String> {
+    for part in tokens.split(',') {
+        let part = part.trim();
+        if let Some(val) = parse_attr_value(part, "key") {
+            return Some(val);
+        }
+    }
+    None
+}
+
+/// Returns the comma-separated parts of every `#[serde(...)]` attribute.
+fn serde_attr_parts(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(meta_list) => Some(meta_list.tokens.to_string()),
+            _ => None,
+        })
+        .flat_map(|tokens| {
+            tokens
+                .split(',')
+                .map(|part| part.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Lists the names the fields of `input` are serialized under, in
+/// declaration order.
+fn serialized_field_names(input: &DeriveInput) -> syn::Result<Vec<String>> {
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "field_names only supports structs with named fields",
+            ))
+        }
+    };
+
+    if let Some(field) = find_flattened_field(input) {
+        return Err(syn::Error::new_spanned(
+            field,
+            "field_names does not support serde(flatten)",
+        ));
+    }
+
+    let rename_all = serde_attr_parts(&input.attrs)
+        .iter()
+        .find_map(|part| parse_attr_value(part, "rename_all"));
+
+    let mut names = Vec::new();
+    for field in fields {
+        let parts = serde_attr_parts(&field.attrs);
+        if parts
+            .iter()
+            .any(|part| part == "skip" || part == "skip_serializing")
+        {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().unwrap().to_string();
+        let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+        let name = match parts
+            .iter()
+            .find_map(|part| parse_attr_value(part, "rename"))
+        {
+            Some(renamed) => renamed,
+            None => match &rename_all {
+                Some(rule) => apply_rename_rule(rule, ident).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &input.ident,
+                        format!("unknown rename_all rule '{}'", rule),
+                    )
+                })?,
+                None => ident.to_string(),
+            },
+        };
+        names.push(name);
+    }
+    Ok(names)
+}
+
+/// Applies a serde `rename_all` rule to a snake_case field name.
+fn apply_rename_rule(rule: &str, field: &str) -> Option<String> {
+    let pascal = || {
+        field
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect::<String>()
+    };
+
+    Some(match rule {
+        "lowercase" | "snake_case" => field.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => field.to_ascii_uppercase(),
+        "PascalCase" => pascal(),
+        "camelCase" => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "kebab-case" => field.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => field.to_ascii_uppercase().replace('_', "-"),
+        _ => return None,
+    })
+}
+
+/// Returns the first field marked `#[serde(flatten)]`, if any.
+fn find_flattened_field(input: &DeriveInput) -> Option<&syn::Field> {
+    let syn::Data::Struct(data_struct) = &input.data else {
+        return None;
+    };
+
+    data_struct.fields.iter().find(|field| {
+        field.attrs.iter().any(|attr| {
+            if !attr.path().is_ident("serde") {
+                return false;
+            }
+            match &attr.meta {
+                Meta::List(meta_list) => meta_list
+                    .tokens
+                    .to_string()
+                    .split(',')
+                    .any(|part| part.trim() == "flatten"),
+                _ => false,
+            }
+        })
+    })
+}
+
 fn generate_serialize_impl(
     input: &DeriveInput,
     attrs: &VersionedAttributes,
@@ -669,3 +924,150 @@ fn parse_version_migrate_attrs(
         }
     }
 }
+
+/// Derives `FromDomain<Entity>` for a versioned DTO by copying fields by name.
+///
+/// Each DTO field is initialized from the domain field of the same name, so
+/// names and types must match. A DTO field without a domain counterpart is a
+/// compile error unless it is annotated.
+///
+/// # Attributes
+///
+/// - `#[from_domain(Entity)]`: The domain type to convert from (required).
+/// - `#[from_domain(default)]` on a field: Uses `Default::default()`.
+/// - `#[from_domain(value = "...")]` on a field: Uses the given expression.
+///   A string literal is converted with `From::from`, so
+///   `value = "\"pending\""` works for a `String` field.
+///
+/// # Examples
+///
+/// ```ignore
+/// use version_migrate::{FromDomain, Versioned};
+///
+/// #[derive(Serialize, Deserialize, Versioned, FromDomain)]
+/// #[versioned(version = "2.0.0")]
+/// #[from_domain(TaskEntity)]
+/// struct TaskV2 {
+///     id: String,
+///     title: String,
+///     #[from_domain(default)]
+///     tags: Vec<String>,
+///     #[from_domain(value = "\"open\"")]
+///     status: String,
+/// }
+///
+/// // Generates:
+/// // impl FromDomain<TaskEntity> for TaskV2 {
+/// //     fn from_domain(domain: TaskEntity) -> Self {
+/// //         TaskV2 {
+/// //             id: domain.id,
+/// //             title: domain.title,
+/// //             tags: Default::default(),
+/// //             status: From::from("open"),
+/// //         }
+/// //     }
+/// // }
+/// ```
+#[proc_macro_derive(FromDomain, attributes(from_domain))]
+pub fn derive_from_domain(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match generate_from_domain_impl(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn generate_from_domain_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut domain_type: Option<Type> = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("from_domain") {
+            domain_type = Some(attr.parse_args::<Type>()?);
+        }
+    }
+    let domain_type = domain_type.ok_or_else(|| {
+        syn::Error::new_spanned(
+            name,
+            "Missing #[from_domain(Entity)] attribute naming the domain type",
+        )
+    })?;
+
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "FromDomain can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut inits = Vec::with_capacity(fields.len());
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let init = match parse_from_domain_field_attr(field)? {
+            FieldSource::Domain => {
+                quote_spanned! {ident.span()=> #ident: domain.#ident }
+            }
+            FieldSource::Default => {
+                quote! { #ident: ::core::default::Default::default() }
+            }
+            FieldSource::Value(expr) => match &expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(_),
+                    ..
+                }) => quote! { #ident: ::core::convert::From::from(#expr) },
+                _ => quote! { #ident: #expr },
+            },
+        };
+        inits.push(init);
+    }
+
+    Ok(quote! {
+        impl #impl_generics version_migrate::FromDomain<#domain_type> for #name #ty_generics #where_clause {
+            fn from_domain(domain: #domain_type) -> Self {
+                Self {
+                    #(#inits),*
+                }
+            }
+        }
+    })
+}
+
+/// Where a field of a `#[derive(FromDomain)]` struct takes its value from.
+enum FieldSource {
+    /// The domain field of the same name.
+    Domain,
+    /// `Default::default()`.
+    Default,
+    /// A user-supplied expression.
+    Value(syn::Expr),
+}
+
+fn parse_from_domain_field_attr(field: &syn::Field) -> syn::Result<FieldSource> {
+    let mut source = FieldSource::Domain;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("from_domain") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                source = FieldSource::Default;
+                Ok(())
+            } else if meta.path.is_ident("value") {
+                let lit: syn::LitStr = meta.value()?.parse()?;
+                source = FieldSource::Value(lit.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `default` or `value = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(source)
+}
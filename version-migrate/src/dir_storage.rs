@@ -39,11 +39,78 @@
 //! ```
 
 use crate::{AppPaths, MigrationError, Migrator};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 // Re-export shared types from local_store.
 pub use local_store::{AtomicWriteConfig, DirStorageStrategy, FilenameEncoding, FormatStrategy};
 
+/// One entry of the version index (`_index.json`), as returned by
+/// [`DirStorage::list_with_versions`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IndexEntry {
+    /// Stored (unmigrated) version of the entity.
+    pub version: String,
+    /// Modification time of the entity file when the entry was recorded.
+    ///
+    /// `None` for entries of an index written before mtimes were recorded;
+    /// the next save of the entity or `rebuild_index` fills it in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mtime: Option<SystemTime>,
+}
+
+/// On-disk form of an index entry. Older indexes map IDs to bare version
+/// strings; they are read as entries without an mtime.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum StoredIndexEntry {
+    Legacy(String),
+    Entry(IndexEntry),
+}
+
+impl From<StoredIndexEntry> for IndexEntry {
+    fn from(stored: StoredIndexEntry) -> Self {
+        match stored {
+            StoredIndexEntry::Legacy(version) => IndexEntry {
+                version,
+                mtime: None,
+            },
+            StoredIndexEntry::Entry(entry) => entry,
+        }
+    }
+}
+
+/// Result of [`DirStorage::save_reporting`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveOutcome {
+    /// The file the entity was written to.
+    pub path: PathBuf,
+    /// Number of bytes written.
+    pub bytes: usize,
+    /// `true` if the file did not exist before this save.
+    pub created: bool,
+}
+
+/// Fingerprint of a stored entity file, returned by
+/// [`DirStorage::load_with_token`] and checked by [`DirStorage::save_if_token`].
+///
+/// Derived from the file content, so any change to the file, including one
+/// that keeps the same mtime, produces a different token. Tokens are only
+/// meaningful within the process that created them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FreshnessToken(u64);
+
+impl FreshnessToken {
+    fn of(content: &str) -> Self {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        content.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
 /// Directory-based entity storage with ACID guarantees and automatic migrations.
 ///
 /// Manages one file per entity. Raw IO (atomic rename, fsync, temp-file cleanup,
@@ -110,7 +177,8 @@ impl DirStorage {
     ///
     /// 1. Converts `entity` to its latest versioned DTO via `migrator.save_domain_flat`.
     /// 2. Serialises to the configured format (JSON or TOML).
-    /// 3. Delegates atomic write (tmp file + fsync + rename) to `inner.save_raw_string`.
+    /// 3. Delegates atomic write (tmp file + fsync + rename) to `inner`, holding
+    ///    the entry lock (with `strategy.locking`) until the index is updated.
     ///
     /// # Errors
     ///
@@ -120,29 +188,107 @@ impl DirStorage {
     /// - Serialisation or format conversion fails.
     /// - The underlying file write fails.
     pub fn save<T>(&self, entity_name: &str, id: &str, entity: T) -> Result<(), MigrationError>
+    where
+        T: serde::Serialize,
+    {
+        self.save_reporting(entity_name, id, entity)?;
+        Ok(())
+    }
+
+    /// Save an entity like [`save`](Self::save) and report what was written.
+    ///
+    /// # Returns
+    ///
+    /// A [`SaveOutcome`] with the file path, the number of bytes written and
+    /// whether the file was newly created rather than overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`save`](Self::save).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let outcome = storage.save_reporting("session", "session-123", session)?;
+    /// log::info!("wrote {} bytes to {}", outcome.bytes, outcome.path.display());
+    /// ```
+    pub fn save_reporting<T>(
+        &self,
+        entity_name: &str,
+        id: &str,
+        entity: T,
+    ) -> Result<SaveOutcome, MigrationError>
+    where
+        T: serde::Serialize,
+    {
+        let path = self.inner.path_for_id(id).map_err(store_err_to_migration)?;
+        let created = !path.exists();
+
+        let versioned_value = self.versioned_value(entity_name, entity)?;
+        let bytes = self.write_value(entity_name, id, &versioned_value)?;
+
+        Ok(SaveOutcome {
+            path,
+            bytes,
+            created,
+        })
+    }
+
+    /// Convert `entity` to the flat versioned value of its latest version.
+    fn versioned_value<T>(
+        &self,
+        entity_name: &str,
+        entity: T,
+    ) -> Result<serde_json::Value, MigrationError>
     where
         T: serde::Serialize,
     {
         let json_string = self.migrator.save_domain_flat(entity_name, entity)?;
+        serde_json::from_str(&json_string)
+            .map_err(|e| MigrationError::DeserializationError(e.to_string()))
+    }
 
-        let versioned_value: serde_json::Value = serde_json::from_str(&json_string)
-            .map_err(|e| MigrationError::DeserializationError(e.to_string()))?;
+    /// Serialise a flat versioned value, write it atomically and keep the
+    /// index up to date. Returns the number of bytes written.
+    fn write_value(
+        &self,
+        entity_name: &str,
+        id: &str,
+        versioned_value: &serde_json::Value,
+    ) -> Result<usize, MigrationError> {
+        let entry = self.inner.lock_entry(id).map_err(store_err_to_migration)?;
+        self.write_locked(&entry, entity_name, versioned_value)
+    }
 
-        let content = match self.strategy.format {
-            FormatStrategy::Json => serde_json::to_string_pretty(&versioned_value)
-                .map_err(|e| MigrationError::SerializationError(e.to_string()))?,
-            FormatStrategy::Toml => {
-                let tv = local_store::json_to_toml(&versioned_value).map_err(|e| {
-                    MigrationError::Store(local_store::StoreError::FormatConvert(e))
-                })?;
-                toml::to_string_pretty(&tv)
-                    .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))?
-            }
-        };
+    /// Like `write_value`, for an entry whose lock the caller already holds.
+    ///
+    /// The entry lock stays held while the index is updated, so the file and
+    /// its index entry change together.
+    fn write_locked(
+        &self,
+        entry: &local_store::EntryLock<'_>,
+        entity_name: &str,
+        versioned_value: &serde_json::Value,
+    ) -> Result<usize, MigrationError> {
+        let content = self.format_content(versioned_value)?;
+
+        entry
+            .save_raw_string(&content)
+            .map_err(store_err_to_migration)?;
 
-        self.inner
-            .save_raw_string(entity_name, id, &content)
-            .map_err(store_err_to_migration)
+        if self.strategy.index {
+            let index_entry = IndexEntry {
+                version: self.extract_version(entity_name, entry.id(), versioned_value)?,
+                mtime: self.modified(entry.id())?,
+            };
+            self.update_index(|index| {
+                let mut index = index.unwrap_or_default();
+                index.insert(entry.id().to_string(), index_entry);
+                Some(index)
+            })?;
+        }
+
+        Ok(content.len())
     }
 
     /// Load an entity from its file, applying schema migrations if needed.
@@ -157,12 +303,62 @@ impl DirStorage {
     /// 1. Reads raw string content via `inner.load_raw_string`.
     /// 2. Deserialises to `serde_json::Value` (converting from TOML if needed).
     /// 3. Applies schema migration via `migrator.load_flat_from`.
+    /// 4. With `strategy.rewrite_on_load`, atomically re-saves the file at the
+    ///    latest version if it was stored at an older one. The entry lock is
+    ///    held from the read through the rewrite, so with `strategy.locking`
+    ///    a concurrent save is not overwritten with the stale upgrade.
     ///
     /// # Errors
     ///
-    /// Returns `MigrationError` if the file is missing, parsing fails, or
-    /// migration fails.
+    /// Returns `MigrationError` if the file is missing, parsing fails,
+    /// migration fails, or the rewrite fails.
     pub fn load<D>(&self, entity_name: &str, id: &str) -> Result<D, MigrationError>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        if !self.strategy.rewrite_on_load {
+            let value = self.load_value(id)?;
+            return self.migrator.load_flat_from(entity_name, value);
+        }
+
+        let entry = self.inner.lock_entry(id).map_err(store_err_to_migration)?;
+        let value = self.load_locked(&entry)?;
+        let version = self.extract_version(entity_name, id, &value)?;
+        if self.migrator.get_latest_version(entity_name) == Some(version.as_str()) {
+            return self.migrator.load_flat_from(entity_name, value);
+        }
+
+        let latest = self.migrator.migrate_flat_to_latest(entity_name, value)?;
+        let domain = self.migrator.load_flat_from(entity_name, latest.clone())?;
+        self.write_locked(&entry, entity_name, &latest)?;
+        Ok(domain)
+    }
+
+    /// Load an entity together with a token identifying the file content it
+    /// was loaded from.
+    ///
+    /// Pass the token to [`save_if_token`](Self::save_if_token) to save only
+    /// if nobody changed the file in the meantime. Unlike `load`, the file is
+    /// never rewritten here, even with `strategy.rewrite_on_load`, so the
+    /// token always matches the file as read.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`load`](Self::load).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (mut session, token): (SessionEntity, _) =
+    ///     storage.load_with_token("session", "session-123")?;
+    /// session.user_id = "user-2".to_string();
+    /// storage.save_if_token("session", "session-123", session, token)?;
+    /// ```
+    pub fn load_with_token<D>(
+        &self,
+        entity_name: &str,
+        id: &str,
+    ) -> Result<(D, FreshnessToken), MigrationError>
     where
         D: serde::de::DeserializeOwned,
     {
@@ -170,18 +366,48 @@ impl DirStorage {
             .inner
             .load_raw_string(id)
             .map_err(store_err_to_migration)?;
+        let value = self.parse_content(&content)?;
+        let entity = self.migrator.load_flat_from(entity_name, value)?;
+        Ok((entity, FreshnessToken::of(&content)))
+    }
 
-        let value = match self.strategy.format {
-            FormatStrategy::Json => serde_json::from_str(&content)
-                .map_err(|e| MigrationError::DeserializationError(e.to_string()))?,
-            FormatStrategy::Toml => {
-                let tv: toml::Value = toml::from_str(&content)
-                    .map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
-                toml_to_json(tv)?
-            }
-        };
+    /// Save an entity only if its file still matches `token`.
+    ///
+    /// Optimistic locking for concurrent editors: the file is re-read and
+    /// compared against the token from [`load_with_token`](Self::load_with_token)
+    /// right before the atomic write. With `strategy.locking`, the entry lock
+    /// is held from the check through the write, so other writers using the
+    /// lock wait instead of slipping in between; without it, a writer racing
+    /// into that short window is not detected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::Conflict` if the file was changed or deleted
+    /// since the token was taken, plus the errors of [`save`](Self::save).
+    /// Failures to read the current file are returned as they are.
+    pub fn save_if_token<T>(
+        &self,
+        entity_name: &str,
+        id: &str,
+        entity: T,
+        token: FreshnessToken,
+    ) -> Result<(), MigrationError>
+    where
+        T: serde::Serialize,
+    {
+        let versioned_value = self.versioned_value(entity_name, entity)?;
+
+        let entry = self.inner.lock_entry(id).map_err(store_err_to_migration)?;
+        let current = entry
+            .load_raw_string()
+            .map_err(store_err_to_migration)?
+            .map(|content| FreshnessToken::of(&content));
+        if current != Some(token) {
+            return Err(MigrationError::Conflict { id: id.to_string() });
+        }
 
-        self.migrator.load_flat_from(entity_name, value)
+        self.write_locked(&entry, entity_name, &versioned_value)?;
+        Ok(())
     }
 
     /// List all entity IDs in the storage directory in lexicographic ascending order.
@@ -228,6 +454,51 @@ impl DirStorage {
         Ok(results)
     }
 
+    /// Count the stored entities and sum the sizes of their files.
+    ///
+    /// Uses the same scan as `list_ids`, so temporary and index files are
+    /// not counted.
+    ///
+    /// # Returns
+    ///
+    /// `(entity_count, total_bytes)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the directory cannot be read or a file's
+    /// metadata cannot be queried.
+    pub fn entity_count_and_size(&self) -> Result<(usize, u64), MigrationError> {
+        let ids = self.list_ids()?;
+        let mut total = 0;
+        for id in &ids {
+            total += self
+                .inner
+                .entity_size(id.as_str())
+                .map_err(store_err_to_migration)?;
+        }
+        Ok((ids.len(), total))
+    }
+
+    /// Total bytes used by the stored entity files.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`DirStorage::entity_count_and_size`].
+    pub fn total_size(&self) -> Result<u64, MigrationError> {
+        self.entity_count_and_size().map(|(_, bytes)| bytes)
+    }
+
+    /// Total bytes of every regular file in the storage directory, including
+    /// leftover temporary files and the version index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the directory cannot be read or a file's
+    /// metadata cannot be queried.
+    pub fn total_size_including_auxiliary(&self) -> Result<u64, MigrationError> {
+        self.inner.directory_size().map_err(store_err_to_migration)
+    }
+
     /// Check whether an entity file exists.
     ///
     /// # Arguments
@@ -260,7 +531,156 @@ impl DirStorage {
     ///
     /// Returns `MigrationError` if the underlying file deletion fails.
     pub fn delete(&self, id: &str) -> Result<(), MigrationError> {
-        self.inner.delete(id).map_err(store_err_to_migration)
+        let entry = self.inner.lock_entry(id).map_err(store_err_to_migration)?;
+        entry.delete().map_err(store_err_to_migration)?;
+
+        if self.strategy.index {
+            self.update_index(|index| {
+                let mut index = index?;
+                index.remove(id)?;
+                Some(index)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Delete every stored entity file.
+    ///
+    /// Only files that `list_ids` reports (those with the configured
+    /// extension) are removed; unrelated files in the directory are left
+    /// alone. The version index, if enabled, is emptied.
+    ///
+    /// # Returns
+    ///
+    /// The number of entity files deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the directory cannot be read or a file
+    /// cannot be deleted. Files deleted before the failure stay deleted.
+    pub fn clear_all(&self) -> Result<usize, MigrationError> {
+        let ids = self.list_ids()?;
+        for id in &ids {
+            self.inner.delete(id).map_err(store_err_to_migration)?;
+        }
+
+        if self.strategy.index {
+            self.update_index(|index| index.map(|_| BTreeMap::new()))?;
+        }
+
+        Ok(ids.len())
+    }
+
+    /// Write every stored entity into a single archive.
+    ///
+    /// The archive is written in the configured format and holds the entity
+    /// name and a map of ID → stored versioned value. Values are copied as
+    /// stored, without migrating them, so an archive restores exactly what
+    /// was on disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_name` - Entity name registered in the migrator; recorded in
+    ///   the archive for [`import_archive`](Self::import_archive).
+    /// * `writer` - Destination of the archive.
+    ///
+    /// # Returns
+    ///
+    /// The number of entities written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if `entity_name` is not registered, an entity
+    /// file cannot be read or parsed, or the archive cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let file = std::fs::File::create("sessions-backup.json")?;
+    /// let count = storage.export_archive("session", file)?;
+    /// ```
+    pub fn export_archive<W: std::io::Write>(
+        &self,
+        entity_name: &str,
+        mut writer: W,
+    ) -> Result<usize, MigrationError> {
+        self.migrator.version_key_of(entity_name)?;
+
+        let mut entities = serde_json::Map::new();
+        for id in self.list_ids()? {
+            let value = self.load_value(&id)?;
+            entities.insert(id, value);
+        }
+        let count = entities.len();
+
+        let archive = serde_json::json!({
+            "entity": entity_name,
+            "entities": entities,
+        });
+        let content = self.format_content(&archive)?;
+        writer.write_all(content.as_bytes()).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to write archive: {}", e))
+        })?;
+
+        Ok(count)
+    }
+
+    /// Restore the entities of an archive written by
+    /// [`export_archive`](Self::export_archive).
+    ///
+    /// The archive is parsed and every entry checked for a version field
+    /// before anything is written. Each entity file is then written
+    /// atomically, overwriting an existing file with the same ID, and the
+    /// index is kept up to date. Entities already stored but absent from the
+    /// archive are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source of the archive, in the configured format.
+    ///
+    /// # Returns
+    ///
+    /// The number of entities restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the archive cannot be read or parsed, its
+    /// entity is not registered, an entry lacks a version, or a file cannot
+    /// be written. Files written before a write failure stay written.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let file = std::fs::File::open("sessions-backup.json")?;
+    /// let count = storage.import_archive(file)?;
+    /// ```
+    pub fn import_archive<R: std::io::Read>(&self, mut reader: R) -> Result<usize, MigrationError> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content).map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to read archive: {}", e))
+        })?;
+
+        let archive = self.parse_content(&content)?;
+        let invalid =
+            |what: &str| MigrationError::DeserializationError(format!("Invalid archive: {}", what));
+        let entity_name = archive
+            .get("entity")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| invalid("missing 'entity' string"))?;
+        let entities = archive
+            .get("entities")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| invalid("missing 'entities' object"))?;
+
+        for (id, value) in entities {
+            self.extract_version(entity_name, id, value)?;
+        }
+        for (id, value) in entities {
+            self.write_value(entity_name, id, value)?;
+        }
+
+        Ok(entities.len())
     }
 
     /// Returns a reference to the base directory path.
@@ -271,6 +691,313 @@ impl DirStorage {
     pub fn base_path(&self) -> &Path {
         self.inner.base_path()
     }
+
+    /// Fsync the base directory so that entity files written so far survive
+    /// a crash.
+    ///
+    /// On Linux a rename is only durable once its directory is synced.
+    /// Saving many entities with `fsync_dir` enabled syncs the directory
+    /// after every file; leaving it off and calling this once at the end
+    /// gives the same guarantee for the whole batch. A no-op on macOS and
+    /// non-Unix platforms.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::Store` if the directory cannot be opened or
+    /// synced.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for session in sessions {
+    ///     storage.save("session", &session.id, session)?;
+    /// }
+    /// storage.fsync_directory()?;
+    /// ```
+    pub fn fsync_directory(&self) -> Result<(), MigrationError> {
+        self.inner.fsync_directory().map_err(store_err_to_migration)
+    }
+
+    /// Move the entire entity store to `new_base` atomically.
+    ///
+    /// Uses a single `std::fs::rename`, which is atomic when both paths are
+    /// on the same filesystem. Afterwards this storage reads and writes under
+    /// `new_base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_base` - Destination directory; its parent must exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::Store` wrapping a `StoreError::IoError` if the
+    /// rename fails (e.g. `new_base` is a non-empty directory or on another
+    /// filesystem).
+    pub fn atomic_rename_directory(&mut self, new_base: PathBuf) -> Result<(), MigrationError> {
+        self.inner
+            .rename_base(new_base)
+            .map_err(store_err_to_migration)
+    }
+
+    /// Swap the entity store with the directory at `other_path`.
+    ///
+    /// The two directories are exchanged by renaming through a temporary
+    /// sibling path, so this storage now sees the entities previously under
+    /// `other_path`, and `other_path` holds the old store. Each rename is
+    /// atomic, but the swap as a whole is not.
+    ///
+    /// # Arguments
+    ///
+    /// * `other_path` - Existing directory to swap with (same filesystem).
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::Store` wrapping a `StoreError::IoError` if a
+    /// rename fails; completed renames are rolled back on a best-effort basis.
+    pub fn swap_with_directory(&self, other_path: PathBuf) -> Result<(), MigrationError> {
+        self.inner
+            .swap_base_with(&other_path)
+            .map_err(store_err_to_migration)
+    }
+
+    /// Rebuild the ID → version index file from the stored entity files.
+    ///
+    /// Scans every entity file, reads its (unmigrated) version and atomically
+    /// writes `_index.json` in the base directory. Use this to recover from a
+    /// missing or corrupted index; it works whether or not the index is
+    /// enabled in the strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_name` - Entity name registered in the migrator (selects the version key).
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the entity is not registered, a file cannot
+    /// be read or parsed, a file has no version field, or the index cannot be
+    /// written.
+    pub fn rebuild_index(&self, entity_name: &str) -> Result<(), MigrationError> {
+        let index = self.scan_versions(entity_name)?;
+        self.update_index(|_| Some(index))
+    }
+
+    /// List every stored ID with its (unmigrated) version and file mtime.
+    ///
+    /// When the index is enabled and `_index.json` exists, the answer comes
+    /// from the index without opening any entity file. Otherwise every entity
+    /// file is scanned. Call `rebuild_index` if files were changed behind
+    /// this storage's back; comparing an entry's `mtime` with the file's
+    /// current one reveals such changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_name` - Entity name registered in the migrator (selects the version key).
+    ///
+    /// # Returns
+    ///
+    /// A map from ID to [`IndexEntry`], in lexicographic ID order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the index or an entity file cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let stale: Vec<String> = storage
+    ///     .list_with_versions("session")?
+    ///     .into_iter()
+    ///     .filter(|(_, entry)| entry.version != "2.0.0")
+    ///     .map(|(id, _)| id)
+    ///     .collect();
+    /// ```
+    pub fn list_with_versions(
+        &self,
+        entity_name: &str,
+    ) -> Result<BTreeMap<String, IndexEntry>, MigrationError> {
+        let indexed = if self.strategy.index {
+            self.read_index()?
+        } else {
+            None
+        };
+        match indexed {
+            Some(index) => Ok(index),
+            None => self.scan_versions(entity_name),
+        }
+    }
+
+    /// List the IDs of entities stored at `version` (before migration).
+    ///
+    /// Uses the index when available, like `list_with_versions`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity_name` - Entity name registered in the migrator.
+    /// * `version` - Stored version string to match (e.g. `"1.0.0"`).
+    ///
+    /// # Returns
+    ///
+    /// Matching IDs in lexicographic ascending order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the index or an entity file cannot be read.
+    pub fn list_ids_by_version(
+        &self,
+        entity_name: &str,
+        version: &str,
+    ) -> Result<Vec<String>, MigrationError> {
+        let index = self.list_with_versions(entity_name)?;
+
+        // BTreeMap iteration is already in ascending key order.
+        Ok(index
+            .into_iter()
+            .filter(|(_, entry)| entry.version == version)
+            .map(|(id, _)| id)
+            .collect())
+    }
+
+    /// Read and parse an entity file into a `serde_json::Value` without migrating it.
+    fn load_value(&self, id: &str) -> Result<serde_json::Value, MigrationError> {
+        let content = self
+            .inner
+            .load_raw_string(id)
+            .map_err(store_err_to_migration)?;
+        self.parse_content(&content)
+    }
+
+    /// Like `load_value`, for an entry whose lock the caller holds.
+    fn load_locked(
+        &self,
+        entry: &local_store::EntryLock<'_>,
+    ) -> Result<serde_json::Value, MigrationError> {
+        match entry.load_raw_string().map_err(store_err_to_migration)? {
+            Some(content) => self.parse_content(&content),
+            None => {
+                let path = self
+                    .inner
+                    .path_for_id(entry.id())
+                    .map_err(store_err_to_migration)?;
+                Err(store_err_to_migration(local_store::StoreError::IoError {
+                    operation: local_store::IoOperationKind::Read,
+                    path: path.display().to_string(),
+                    context: None,
+                    error: "File not found".to_string(),
+                }))
+            }
+        }
+    }
+
+    /// Serialise `value` in the configured format.
+    fn format_content(&self, value: &serde_json::Value) -> Result<String, MigrationError> {
+        match self.strategy.format {
+            FormatStrategy::Json => serde_json::to_string_pretty(value)
+                .map_err(|e| MigrationError::SerializationError(e.to_string())),
+            FormatStrategy::Toml => {
+                let tv = local_store::json_to_toml(value).map_err(MigrationError::from)?;
+                toml::to_string_pretty(&tv)
+                    .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))
+            }
+        }
+    }
+
+    /// Parse raw entity file content in the configured format.
+    fn parse_content(&self, content: &str) -> Result<serde_json::Value, MigrationError> {
+        match self.strategy.format {
+            FormatStrategy::Json => serde_json::from_str(content)
+                .map_err(|e| MigrationError::DeserializationError(e.to_string())),
+            FormatStrategy::Toml => {
+                let tv: toml::Value = toml::from_str(content)
+                    .map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
+                toml_to_json(tv)
+            }
+        }
+    }
+
+    /// Extract the stored version string of entity `id` from its flat value.
+    fn extract_version(
+        &self,
+        entity_name: &str,
+        id: &str,
+        value: &serde_json::Value,
+    ) -> Result<String, MigrationError> {
+        let version_key = self.migrator.version_key_of(entity_name)?;
+        let stored = value.get(version_key).ok_or_else(|| {
+            MigrationError::DeserializationError(format!(
+                "Missing '{}' field in entity '{}'",
+                version_key, id
+            ))
+        })?;
+        self.migrator.read_version_of(entity_name, stored)
+    }
+
+    /// Build an ID → index entry map by reading every entity file.
+    fn scan_versions(
+        &self,
+        entity_name: &str,
+    ) -> Result<BTreeMap<String, IndexEntry>, MigrationError> {
+        let mut index = BTreeMap::new();
+        for id in self.list_ids()? {
+            let value = self.load_value(&id)?;
+            let entry = IndexEntry {
+                version: self.extract_version(entity_name, &id, &value)?,
+                mtime: self.modified(&id)?,
+            };
+            index.insert(id, entry);
+        }
+        Ok(index)
+    }
+
+    /// Modification time of the file stored for `id`.
+    fn modified(&self, id: &str) -> Result<Option<SystemTime>, MigrationError> {
+        self.inner.modified(id).map_err(store_err_to_migration)
+    }
+
+    /// Read the index file, if present, upgrading entries of the older
+    /// ID → version format.
+    fn read_index(&self) -> Result<Option<BTreeMap<String, IndexEntry>>, MigrationError> {
+        let Some(content) = self.inner.read_index().map_err(store_err_to_migration)? else {
+            return Ok(None);
+        };
+        let stored: BTreeMap<String, StoredIndexEntry> =
+            serde_json::from_str(&content).map_err(|e| {
+                MigrationError::DeserializationError(format!("Invalid index file: {}", e))
+            })?;
+        Ok(Some(
+            stored
+                .into_iter()
+                .map(|(id, entry)| (id, entry.into()))
+                .collect(),
+        ))
+    }
+
+    /// Read-modify-write the index file under `local_store::DirStorage::lock_index`.
+    ///
+    /// `update` receives the current index (`None` if there is none) and
+    /// returns the index to write, or `None` to leave the file untouched.
+    /// Without `strategy.locking`, concurrent writers can lose each other's
+    /// updates; `rebuild_index` repairs the index.
+    fn update_index(
+        &self,
+        update: impl FnOnce(
+            Option<BTreeMap<String, IndexEntry>>,
+        ) -> Option<BTreeMap<String, IndexEntry>>,
+    ) -> Result<(), MigrationError> {
+        let _lock = self.inner.lock_index().map_err(store_err_to_migration)?;
+        match update(self.read_index()?) {
+            Some(index) => self.write_index(&index),
+            None => Ok(()),
+        }
+    }
+
+    /// Atomically write the index file.
+    fn write_index(&self, index: &BTreeMap<String, IndexEntry>) -> Result<(), MigrationError> {
+        let content = serde_json::to_string_pretty(index)
+            .map_err(|e| MigrationError::SerializationError(e.to_string()))?;
+        self.inner
+            .write_index(&content)
+            .map_err(store_err_to_migration)
+    }
 }
 
 /// Convert a `local_store::StoreError` to `MigrationError`, promoting
@@ -296,6 +1023,153 @@ fn toml_to_json(toml_value: toml::Value) -> Result<serde_json::Value, MigrationE
     Ok(json_value)
 }
 
+// ============================================================================
+// Watch support
+// ============================================================================
+
+#[cfg(feature = "watch")]
+pub use watch_impl::StorageEvent;
+
+#[cfg(feature = "watch")]
+mod watch_impl {
+    use super::{store_err_to_migration, DirStorage};
+    use crate::MigrationError;
+    use notify::event::{EventKind, ModifyKind, RenameMode};
+    use notify::{RecursiveMode, Watcher};
+    use std::collections::HashSet;
+    use std::path::Path;
+    use std::sync::mpsc::{self, Receiver, Sender};
+
+    /// A change to an entity file observed by [`DirStorage::watch`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum StorageEvent {
+        /// An entity file appeared (including via atomic rename).
+        Created(String),
+        /// An existing entity file was rewritten.
+        Modified(String),
+        /// An entity file was removed.
+        Deleted(String),
+    }
+
+    impl DirStorage {
+        /// Watch the storage directory for entity file changes.
+        ///
+        /// Events carry decoded entity IDs. Temporary files (`.*.tmp.*`) and
+        /// the index file are filtered out, and the rename performed by an
+        /// atomic write is reported as `Created` or `Modified` depending on
+        /// whether the ID was already present.
+        ///
+        /// The watcher runs on a background thread and stops on the first
+        /// file-system event after the returned `Receiver` is dropped.
+        ///
+        /// # Errors
+        ///
+        /// Returns `MigrationError::Store` if the directory cannot be listed
+        /// or the platform watcher cannot be started.
+        ///
+        /// # Example
+        ///
+        /// ```ignore
+        /// let events = storage.watch()?;
+        /// for event in events {
+        ///     if let StorageEvent::Modified(id) = event {
+        ///         reload(&id);
+        ///     }
+        /// }
+        /// ```
+        pub fn watch(&self) -> Result<Receiver<StorageEvent>, MigrationError> {
+            let inner = self.inner.clone();
+            let mut known: HashSet<String> = self.list_ids()?.into_iter().collect();
+
+            let watch_error = |e: notify::Error| {
+                store_err_to_migration(local_store::StoreError::IoError {
+                    operation: local_store::IoOperationKind::ReadDir,
+                    path: inner.base_path().display().to_string(),
+                    context: Some("watch".to_string()),
+                    error: e.to_string(),
+                })
+            };
+
+            let (raw_tx, raw_rx) = mpsc::channel();
+            let mut watcher = notify::recommended_watcher(raw_tx).map_err(watch_error)?;
+            watcher
+                .watch(inner.base_path(), RecursiveMode::NonRecursive)
+                .map_err(watch_error)?;
+
+            let (tx, rx) = mpsc::channel();
+            std::thread::spawn(move || {
+                // Keep the watcher alive for as long as this thread runs.
+                let _watcher = watcher;
+                for event in raw_rx.into_iter().flatten() {
+                    let sent = match event.kind {
+                        EventKind::Remove(_)
+                        | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => event
+                            .paths
+                            .iter()
+                            .all(|p| removed(&inner, &mut known, p, &tx)),
+                        EventKind::Modify(ModifyKind::Name(RenameMode::Both))
+                            if event.paths.len() == 2 =>
+                        {
+                            removed(&inner, &mut known, &event.paths[0], &tx)
+                                && upserted(&inner, &mut known, &event.paths[1], &tx)
+                        }
+                        EventKind::Modify(ModifyKind::Name(_)) => event.paths.iter().all(|p| {
+                            if p.exists() {
+                                upserted(&inner, &mut known, p, &tx)
+                            } else {
+                                removed(&inner, &mut known, p, &tx)
+                            }
+                        }),
+                        EventKind::Create(_) | EventKind::Modify(_) => event
+                            .paths
+                            .iter()
+                            .all(|p| upserted(&inner, &mut known, p, &tx)),
+                        _ => true,
+                    };
+                    if !sent {
+                        break;
+                    }
+                }
+            });
+
+            Ok(rx)
+        }
+    }
+
+    /// Emit `Created` or `Modified` for `path`. Returns `false` once the
+    /// receiver is gone.
+    fn upserted(
+        inner: &local_store::DirStorage,
+        known: &mut HashSet<String>,
+        path: &Path,
+        tx: &Sender<StorageEvent>,
+    ) -> bool {
+        let Some(id) = inner.id_for_path(path) else {
+            return true;
+        };
+        let event = if known.insert(id.clone()) {
+            StorageEvent::Created(id)
+        } else {
+            StorageEvent::Modified(id)
+        };
+        tx.send(event).is_ok()
+    }
+
+    /// Emit `Deleted` for `path`. Returns `false` once the receiver is gone.
+    fn removed(
+        inner: &local_store::DirStorage,
+        known: &mut HashSet<String>,
+        path: &Path,
+        tx: &Sender<StorageEvent>,
+    ) -> bool {
+        let Some(id) = inner.id_for_path(path) else {
+            return true;
+        };
+        known.remove(&id);
+        tx.send(StorageEvent::Deleted(id)).is_ok()
+    }
+}
+
 // ============================================================================
 // Async implementation
 // ============================================================================
@@ -385,7 +1259,8 @@ mod async_impl {
         /// Load an entity from a file (async).
         ///
         /// Reads the raw string, deserialises to `serde_json::Value`, and migrates
-        /// to the target domain type.
+        /// to the target domain type. With `strategy.rewrite_on_load`, a file
+        /// stored at an older version is re-saved at the latest version.
         ///
         /// # Errors
         ///
@@ -402,7 +1277,32 @@ mod async_impl {
                 .map_err(store_err_to_migration)?;
 
             let value = self.deserialize_content(&content)?;
-            self.migrator.load_flat_from(entity_name, value)
+            if !self.strategy.rewrite_on_load || !self.is_stale(entity_name, &value)? {
+                return self.migrator.load_flat_from(entity_name, value);
+            }
+
+            let latest = self.migrator.migrate_flat_to_latest(entity_name, value)?;
+            let domain = self.migrator.load_flat_from(entity_name, latest.clone())?;
+            let content = self.serialize_content(&latest)?;
+            self.inner
+                .save_raw_string(entity_name, id, &content)
+                .await
+                .map_err(store_err_to_migration)?;
+            Ok(domain)
+        }
+
+        /// Whether a flat stored value is older than the entity's latest version.
+        fn is_stale(
+            &self,
+            entity_name: &str,
+            value: &serde_json::Value,
+        ) -> Result<bool, MigrationError> {
+            let version_key = self.migrator.version_key_of(entity_name)?;
+            let version = match value.get(version_key) {
+                Some(stored) => Some(self.migrator.read_version_of(entity_name, stored)?),
+                None => None,
+            };
+            Ok(version.as_deref() != self.migrator.get_latest_version(entity_name))
         }
 
         /// List all entity IDs in the storage directory in lexicographic ascending order (async).
@@ -466,6 +1366,21 @@ mod async_impl {
             self.inner.base_path()
         }
 
+        /// Fsync the base directory once after a batch of saves.
+        ///
+        /// See `DirStorage::fsync_directory`.
+        ///
+        /// # Errors
+        ///
+        /// Returns `MigrationError::Store` if the directory cannot be opened
+        /// or synced.
+        pub async fn fsync_directory(&self) -> Result<(), MigrationError> {
+            self.inner
+                .fsync_directory()
+                .await
+                .map_err(store_err_to_migration)
+        }
+
         // ================================================================
         // Private format helpers
         // ================================================================
@@ -475,9 +1390,8 @@ mod async_impl {
                 FormatStrategy::Json => serde_json::to_string_pretty(value)
                     .map_err(|e| MigrationError::SerializationError(e.to_string())),
                 FormatStrategy::Toml => {
-                    let tv = local_store::format_convert::json_to_toml(value).map_err(|e| {
-                        MigrationError::Store(local_store::StoreError::FormatConvert(e))
-                    })?;
+                    let tv = local_store::format_convert::json_to_toml(value)
+                        .map_err(MigrationError::from)?;
                     toml::to_string_pretty(&tv)
                         .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))
                 }
@@ -501,7 +1415,7 @@ mod async_impl {
     #[cfg(all(test, feature = "async"))]
     mod async_tests {
         use super::*;
-        use crate::{FromDomain, IntoDomain, MigratesTo, Versioned};
+        use crate::{FilenameEncoding, FromDomain, IntoDomain, MigratesTo, Versioned};
         use base64::engine::general_purpose::URL_SAFE_NO_PAD;
         use base64::Engine;
         use serde::{Deserialize, Serialize};
@@ -1085,6 +1999,33 @@ mod tests {
         assert_eq!(toml["created_at"].as_str().unwrap(), "2024-01-15T10:30:00Z");
     }
 
+    #[test]
+    fn test_dir_storage_save_toml_none_field_reports_field() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let migrator = setup_session_migrator();
+        let strategy = DirStorageStrategy::default().with_format(FormatStrategy::Toml);
+        let storage = DirStorage::new(paths, "sessions", migrator, strategy).unwrap();
+
+        let session = SessionEntity {
+            id: "session-null".to_string(),
+            user_id: "user-101".to_string(),
+            created_at: None,
+        };
+
+        let err = storage
+            .save("session", "session-null", session)
+            .unwrap_err();
+        assert!(
+            matches!(&err, MigrationError::TomlNullField { field } if field == "created_at"),
+            "unexpected error: {err}"
+        );
+        assert!(!storage.base_path().join("session-null.toml").exists());
+    }
+
     #[test]
     fn test_dir_storage_save_with_invalid_id() {
         let temp_dir = TempDir::new().unwrap();
@@ -1112,68 +2053,388 @@ mod tests {
     }
 
     #[test]
-    fn test_dir_storage_save_with_custom_extension() {
+    fn test_dir_storage_save_with_custom_extension() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let migrator = setup_session_migrator();
+        let strategy = DirStorageStrategy::default()
+            .with_format(FormatStrategy::Json)
+            .with_extension("data");
+        let storage = DirStorage::new(paths, "sessions", migrator, strategy).unwrap();
+
+        let session = SessionEntity {
+            id: "session-custom".to_string(),
+            user_id: "user-999".to_string(),
+            created_at: None,
+        };
+
+        storage.save("session", "session-custom", session).unwrap();
+
+        // Verify custom extension is used
+        let file_path = storage.base_path().join("session-custom.data");
+        assert!(file_path.exists());
+    }
+
+    #[test]
+    fn test_dir_storage_save_overwrites_existing() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let migrator = setup_session_migrator();
+        let strategy = DirStorageStrategy::default();
+        let storage = DirStorage::new(paths, "sessions", migrator, strategy).unwrap();
+
+        // Save initial version
+        let session1 = SessionEntity {
+            id: "session-overwrite".to_string(),
+            user_id: "user-111".to_string(),
+            created_at: Some("2024-01-01".to_string()),
+        };
+        storage
+            .save("session", "session-overwrite", session1)
+            .unwrap();
+
+        // Save updated version
+        let session2 = SessionEntity {
+            id: "session-overwrite".to_string(),
+            user_id: "user-222".to_string(),
+            created_at: Some("2024-01-02".to_string()),
+        };
+        storage
+            .save("session", "session-overwrite", session2)
+            .unwrap();
+
+        // Verify file was overwritten
+        let file_path = storage.base_path().join("session-overwrite.json");
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["user_id"], "user-222");
+        assert_eq!(json["created_at"], "2024-01-02");
+    }
+
+    #[test]
+    fn test_dir_storage_save_reporting() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let migrator = setup_session_migrator();
+        let storage =
+            DirStorage::new(paths, "sessions", migrator, DirStorageStrategy::default()).unwrap();
+
+        let session = |user_id: &str| SessionEntity {
+            id: "session-report".to_string(),
+            user_id: user_id.to_string(),
+            created_at: None,
+        };
+
+        let first = storage
+            .save_reporting("session", "session-report", session("user-1"))
+            .unwrap();
+        assert!(first.created);
+        assert_eq!(first.path, storage.base_path().join("session-report.json"));
+        assert_eq!(first.bytes as u64, fs::metadata(&first.path).unwrap().len());
+
+        let second = storage
+            .save_reporting("session", "session-report", session("user-22"))
+            .unwrap();
+        assert!(!second.created);
+        assert_eq!(second.path, first.path);
+        assert_eq!(
+            second.bytes as u64,
+            fs::metadata(&second.path).unwrap().len()
+        );
+        assert_eq!(second.bytes, first.bytes + 1);
+    }
+
+    #[test]
+    fn test_dir_storage_fsync_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+
+        for i in 0..3 {
+            let id = format!("session-{}", i);
+            let session = SessionEntity {
+                id: id.clone(),
+                user_id: "user".to_string(),
+                created_at: None,
+            };
+            storage.save("session", &id, session).unwrap();
+        }
+        storage.fsync_directory().unwrap();
+        assert_eq!(storage.list_ids().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_dir_storage_locking_concurrent_saves_to_one_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let strategy = DirStorageStrategy::default().with_locking(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+
+        std::thread::scope(|scope| {
+            for writer in 0..8 {
+                let storage = &storage;
+                scope.spawn(move || {
+                    for round in 0..20 {
+                        let session = SessionEntity {
+                            id: "shared".to_string(),
+                            user_id: format!("writer-{}-{}", writer, round),
+                            created_at: None,
+                        };
+                        storage.save("session", "shared", session).unwrap();
+                    }
+                });
+            }
+        });
+
+        let loaded: SessionEntity = storage.load("session", "shared").unwrap();
+        assert_eq!(loaded.id, "shared");
+        assert!(loaded.user_id.starts_with("writer-"));
+        assert_eq!(storage.list_ids().unwrap(), vec!["shared"]);
+
+        let mut names: Vec<String> = fs::read_dir(storage.base_path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec![".shared.lock", "shared.json"]);
+    }
+
+    #[test]
+    fn test_dir_storage_locking_keeps_index_consistent() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let strategy = DirStorageStrategy::default()
+            .with_locking(true)
+            .with_index(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+
+        // Every writer adds its own IDs; each save rewrites the shared index
+        std::thread::scope(|scope| {
+            for writer in 0..8 {
+                let storage = &storage;
+                scope.spawn(move || {
+                    for round in 0..10 {
+                        let id = format!("session-{}-{}", writer, round);
+                        let session = SessionEntity {
+                            id: id.clone(),
+                            user_id: "user".to_string(),
+                            created_at: None,
+                        };
+                        storage.save("session", &id, session).unwrap();
+                        if round % 3 == 0 {
+                            storage.delete(&id).unwrap();
+                        }
+                    }
+                });
+            }
+        });
+
+        let indexed: Vec<String> = storage
+            .list_with_versions("session")
+            .unwrap()
+            .into_keys()
+            .collect();
+        assert_eq!(indexed.len(), 8 * 6);
+        assert_eq!(indexed, storage.list_ids().unwrap());
+    }
+
+    #[test]
+    fn test_dir_storage_entity_count_and_size() {
         let temp_dir = TempDir::new().unwrap();
         let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
             temp_dir.path().to_path_buf(),
         ));
 
         let migrator = setup_session_migrator();
+        let storage =
+            DirStorage::new(paths, "sessions", migrator, DirStorageStrategy::default()).unwrap();
+        assert_eq!(storage.entity_count_and_size().unwrap(), (0, 0));
+
+        let mut expected = 0;
+        for i in 0..3 {
+            let id = format!("session-{}", i);
+            let outcome = storage
+                .save_reporting(
+                    "session",
+                    &id,
+                    SessionEntity {
+                        id: id.clone(),
+                        user_id: "u".repeat(10 * (i + 1)),
+                        created_at: None,
+                    },
+                )
+                .unwrap();
+            expected += outcome.bytes as u64;
+        }
+
+        assert_eq!(storage.entity_count_and_size().unwrap(), (3, expected));
+        assert_eq!(storage.total_size().unwrap(), expected);
+        assert!(expected > 60 && expected < 1024);
+
+        // Stray files are only counted by the auxiliary total.
+        fs::write(storage.base_path().join(".session-0.json.tmp.123"), "xxxx").unwrap();
+        assert_eq!(storage.total_size().unwrap(), expected);
+        assert_eq!(
+            storage.total_size_including_auxiliary().unwrap(),
+            expected + 4
+        );
+    }
+
+    fn archive_storage(temp_dir: &TempDir, format: FormatStrategy) -> DirStorage {
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
         let strategy = DirStorageStrategy::default()
-            .with_format(FormatStrategy::Json)
-            .with_extension("data");
-        let storage = DirStorage::new(paths, "sessions", migrator, strategy).unwrap();
+            .with_format(format)
+            .with_index(true);
+        DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap()
+    }
 
-        let session = SessionEntity {
-            id: "session-custom".to_string(),
-            user_id: "user-999".to_string(),
-            created_at: None,
-        };
+    #[test]
+    fn test_dir_storage_archive_round_trip() {
+        for format in [FormatStrategy::Json, FormatStrategy::Toml] {
+            let temp_dir = TempDir::new().unwrap();
+            let storage = archive_storage(&temp_dir, format);
 
-        storage.save("session", "session-custom", session).unwrap();
+            for i in 0..3 {
+                let id = format!("session-{}", i);
+                let session = SessionEntity {
+                    id: id.clone(),
+                    user_id: format!("user-{}", i),
+                    created_at: Some("2024-01-01".to_string()),
+                };
+                storage.save("session", &id, session).unwrap();
+            }
+            let original: Vec<(String, SessionEntity)> = storage.load_all("session").unwrap();
 
-        // Verify custom extension is used
-        let file_path = storage.base_path().join("session-custom.data");
-        assert!(file_path.exists());
+            let mut archive = Vec::new();
+            assert_eq!(storage.export_archive("session", &mut archive).unwrap(), 3);
+
+            storage.clear_all().unwrap();
+            assert!(storage.list_ids().unwrap().is_empty());
+
+            assert_eq!(storage.import_archive(archive.as_slice()).unwrap(), 3);
+            let restored: Vec<(String, SessionEntity)> = storage.load_all("session").unwrap();
+            assert_eq!(restored, original);
+            assert_eq!(
+                storage.list_ids_by_version("session", "1.1.0").unwrap(),
+                vec!["session-0", "session-1", "session-2"]
+            );
+        }
     }
 
     #[test]
-    fn test_dir_storage_save_overwrites_existing() {
+    fn test_dir_storage_archive_keeps_stored_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = archive_storage(&temp_dir, FormatStrategy::Json);
+        fs::write(
+            storage.base_path().join("old.json"),
+            r#"{"version":"1.0.0","id":"old","user_id":"u"}"#,
+        )
+        .unwrap();
+
+        let mut archive = Vec::new();
+        storage.export_archive("session", &mut archive).unwrap();
+        let archive_json: serde_json::Value = serde_json::from_slice(&archive).unwrap();
+        assert_eq!(archive_json["entity"], "session");
+        assert_eq!(archive_json["entities"]["old"]["version"], "1.0.0");
+
+        let other_dir = TempDir::new().unwrap();
+        let other = archive_storage(&other_dir, FormatStrategy::Json);
+        other.import_archive(archive.as_slice()).unwrap();
+        assert_eq!(
+            other.list_ids_by_version("session", "1.0.0").unwrap(),
+            vec!["old"]
+        );
+    }
+
+    #[test]
+    fn test_dir_storage_import_rejects_invalid_archive_before_writing() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = archive_storage(&temp_dir, FormatStrategy::Json);
+
+        let archive = r#"{"entity":"session","entities":{
+            "good":{"version":"1.1.0","id":"good","user_id":"u"},
+            "bad":{"id":"bad","user_id":"u"}
+        }}"#;
+        assert!(matches!(
+            storage.import_archive(archive.as_bytes()),
+            Err(MigrationError::DeserializationError(_))
+        ));
+        assert!(storage.list_ids().unwrap().is_empty());
+
+        assert!(matches!(
+            storage.import_archive(r#"{"entities":{}}"#.as_bytes()),
+            Err(MigrationError::DeserializationError(msg)) if msg.contains("'entity'")
+        ));
+        assert!(matches!(
+            storage.export_archive("user", Vec::new()),
+            Err(MigrationError::EntityNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_dir_storage_clear_all_keeps_unrelated_files() {
         let temp_dir = TempDir::new().unwrap();
         let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
             temp_dir.path().to_path_buf(),
         ));
 
         let migrator = setup_session_migrator();
-        let strategy = DirStorageStrategy::default();
+        let strategy = DirStorageStrategy::default().with_index(true);
         let storage = DirStorage::new(paths, "sessions", migrator, strategy).unwrap();
 
-        // Save initial version
-        let session1 = SessionEntity {
-            id: "session-overwrite".to_string(),
-            user_id: "user-111".to_string(),
-            created_at: Some("2024-01-01".to_string()),
-        };
-        storage
-            .save("session", "session-overwrite", session1)
-            .unwrap();
+        for i in 0..3 {
+            let id = format!("session-{}", i);
+            storage
+                .save(
+                    "session",
+                    &id,
+                    SessionEntity {
+                        id: id.clone(),
+                        user_id: "user".to_string(),
+                        created_at: None,
+                    },
+                )
+                .unwrap();
+        }
+        fs::write(storage.base_path().join("README.txt"), "keep me").unwrap();
 
-        // Save updated version
-        let session2 = SessionEntity {
-            id: "session-overwrite".to_string(),
-            user_id: "user-222".to_string(),
-            created_at: Some("2024-01-02".to_string()),
-        };
-        storage
-            .save("session", "session-overwrite", session2)
-            .unwrap();
+        assert_eq!(storage.clear_all().unwrap(), 3);
+        assert!(storage.list_ids().unwrap().is_empty());
+        assert!(storage
+            .list_ids_by_version("session", "1.0.0")
+            .unwrap()
+            .is_empty());
+        assert!(storage.base_path().join("README.txt").exists());
 
-        // Verify file was overwritten
-        let file_path = storage.base_path().join("session-overwrite.json");
-        let content = std::fs::read_to_string(&file_path).unwrap();
-        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
-        assert_eq!(json["user_id"], "user-222");
-        assert_eq!(json["created_at"], "2024-01-02");
+        assert_eq!(storage.clear_all().unwrap(), 0);
     }
 
     #[test]
@@ -1775,4 +3036,511 @@ mod tests {
             Err(MigrationError::Store(StoreError::IoError { .. }))
         ));
     }
+
+    #[test]
+    fn test_dir_storage_save_updates_index() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let strategy = DirStorageStrategy::default().with_index(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+
+        let session = SessionEntity {
+            id: "s1".to_string(),
+            user_id: "u1".to_string(),
+            created_at: None,
+        };
+        storage.save("session", "s1", session).unwrap();
+
+        let index_path = storage.base_path().join(local_store::INDEX_FILE_NAME);
+        let index: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        assert_eq!(index["s1"]["version"], "1.1.0");
+        assert!(index["s1"]["mtime"].is_object());
+
+        // The index file is not an entity.
+        assert_eq!(storage.list_ids().unwrap(), vec!["s1"]);
+
+        storage.delete("s1").unwrap();
+        let index: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        assert_eq!(index, serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_dir_storage_rebuild_index_and_list_ids_by_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let strategy = DirStorageStrategy::default().with_index(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+
+        let base = storage.base_path().to_path_buf();
+        fs::write(
+            base.join("a.json"),
+            r#"{"version":"1.0.0","id":"a","user_id":"u"}"#,
+        )
+        .unwrap();
+        fs::write(
+            base.join("b.json"),
+            r#"{"version":"1.1.0","id":"b","user_id":"u"}"#,
+        )
+        .unwrap();
+        fs::write(
+            base.join("c.json"),
+            r#"{"version":"1.0.0","id":"c","user_id":"u"}"#,
+        )
+        .unwrap();
+
+        // Without an index file the directory is scanned.
+        assert_eq!(
+            storage.list_ids_by_version("session", "1.0.0").unwrap(),
+            vec!["a", "c"]
+        );
+
+        storage.rebuild_index("session").unwrap();
+        let index_path = base.join(local_store::INDEX_FILE_NAME);
+        let index: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        for (id, version) in [("a", "1.0.0"), ("b", "1.1.0"), ("c", "1.0.0")] {
+            assert_eq!(index[id]["version"], version);
+            assert!(index[id]["mtime"].is_object());
+        }
+
+        // Once present, the index is the source of truth. Entries of the
+        // older ID -> version format are still understood.
+        fs::write(&index_path, r#"{"a":"1.0.0","b":"1.0.0"}"#).unwrap();
+        assert_eq!(
+            storage.list_ids_by_version("session", "1.0.0").unwrap(),
+            vec!["a", "b"]
+        );
+        assert_eq!(
+            storage.list_with_versions("session").unwrap()["a"],
+            IndexEntry {
+                version: "1.0.0".to_string(),
+                mtime: None,
+            }
+        );
+
+        // The next update rewrites the index in the current format
+        storage.delete("b").unwrap();
+        let index: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        assert_eq!(index, serde_json::json!({"a": {"version": "1.0.0"}}));
+    }
+
+    #[test]
+    fn test_dir_storage_list_with_versions_matches_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let strategy = DirStorageStrategy::default().with_index(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+        let base = storage.base_path().to_path_buf();
+
+        fs::write(
+            base.join("old.json"),
+            r#"{"version":"1.0.0","id":"old","user_id":"u"}"#,
+        )
+        .unwrap();
+        storage.rebuild_index("session").unwrap();
+
+        for id in ["s1", "s2"] {
+            let session = SessionEntity {
+                id: id.to_string(),
+                user_id: "u".to_string(),
+                created_at: None,
+            };
+            storage.save("session", id, session).unwrap();
+        }
+        storage.delete("s1").unwrap();
+
+        let listed = storage.list_with_versions("session").unwrap();
+        assert_eq!(
+            listed
+                .iter()
+                .map(|(id, entry)| (id.as_str(), entry.version.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("old", "1.0.0"), ("s2", "1.1.0")]
+        );
+
+        // Every listed version and mtime is the one actually on disk.
+        for (id, entry) in &listed {
+            let path = base.join(format!("{}.json", id));
+            let value: serde_json::Value =
+                serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+            assert_eq!(value["version"], entry.version.as_str());
+            assert_eq!(
+                entry.mtime,
+                Some(fs::metadata(&path).unwrap().modified().unwrap())
+            );
+        }
+        assert_eq!(
+            listed.keys().cloned().collect::<Vec<_>>(),
+            storage.list_ids().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dir_storage_save_if_token_detects_concurrent_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+
+        let session = SessionEntity {
+            id: "s1".to_string(),
+            user_id: "u1".to_string(),
+            created_at: None,
+        };
+        storage.save("session", "s1", session).unwrap();
+
+        // Unchanged file: the save goes through and yields a new token
+        let (mut loaded, token): (SessionEntity, _) =
+            storage.load_with_token("session", "s1").unwrap();
+        loaded.user_id = "u2".to_string();
+        storage
+            .save_if_token("session", "s1", loaded, token)
+            .unwrap();
+        let (loaded, fresh): (SessionEntity, _) = storage.load_with_token("session", "s1").unwrap();
+        assert_eq!(loaded.user_id, "u2");
+        assert_ne!(fresh, token);
+
+        // Another editor rewrites the file between load and save
+        let file = storage.base_path().join("s1.json");
+        fs::write(&file, r#"{"version":"1.1.0","id":"s1","user_id":"other"}"#).unwrap();
+        let result = storage.save_if_token("session", "s1", loaded, fresh);
+        assert!(matches!(
+            result,
+            Err(MigrationError::Conflict { id }) if id == "s1"
+        ));
+        let on_disk: SessionEntity = storage.load("session", "s1").unwrap();
+        assert_eq!(on_disk.user_id, "other");
+
+        // A deleted file is a conflict too
+        let (loaded, token): (SessionEntity, _) = storage.load_with_token("session", "s1").unwrap();
+        storage.delete("s1").unwrap();
+        assert!(matches!(
+            storage.save_if_token("session", "s1", loaded, token),
+            Err(MigrationError::Conflict { .. })
+        ));
+        assert!(!storage.exists("s1").unwrap());
+    }
+
+    #[test]
+    fn test_dir_storage_save_if_token_propagates_read_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let strategy = DirStorageStrategy::default().with_locking(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+
+        let session = |id: &str| SessionEntity {
+            id: id.to_string(),
+            user_id: "u1".to_string(),
+            created_at: None,
+        };
+        storage.save("session", "s1", session("s1")).unwrap();
+        let (_, token): (SessionEntity, _) = storage.load_with_token("session", "s1").unwrap();
+
+        // An ID the encoding rejects is not a conflict
+        assert!(matches!(
+            storage.save_if_token("session", "bad id", session("bad id"), token),
+            Err(MigrationError::FilenameEncoding { .. })
+        ));
+
+        // Neither is a file that exists but cannot be read
+        fs::create_dir(storage.base_path().join("s2.json")).unwrap();
+        assert!(matches!(
+            storage.save_if_token("session", "s2", session("s2"), token),
+            Err(MigrationError::Store(_))
+        ));
+    }
+
+    #[test]
+    fn test_dir_storage_list_ids_by_version_ignores_index_when_disabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+
+        let base = storage.base_path().to_path_buf();
+        fs::write(
+            base.join("a.json"),
+            r#"{"version":"1.0.0","id":"a","user_id":"u"}"#,
+        )
+        .unwrap();
+        fs::write(base.join(local_store::INDEX_FILE_NAME), r#"{"a":"9.9.9"}"#).unwrap();
+
+        assert_eq!(
+            storage.list_ids_by_version("session", "1.0.0").unwrap(),
+            vec!["a"]
+        );
+    }
+
+    #[test]
+    fn test_dir_storage_rewrite_on_load_upgrades_stale_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let strategy = DirStorageStrategy::default().with_rewrite_on_load(true);
+        let storage =
+            DirStorage::new(paths, "sessions", setup_session_migrator(), strategy).unwrap();
+
+        let stale_path = storage.base_path().join("old.json");
+        fs::write(
+            &stale_path,
+            r#"{"version":"1.0.0","id":"old","user_id":"user-1"}"#,
+        )
+        .unwrap();
+        let current = r#"{"version":"1.1.0","id":"new","user_id":"user-2","created_at":null}"#;
+        let current_path = storage.base_path().join("new.json");
+        fs::write(&current_path, current).unwrap();
+
+        let session: SessionEntity = storage.load("session", "old").unwrap();
+        assert_eq!(session.user_id, "user-1");
+
+        let rewritten: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&stale_path).unwrap()).unwrap();
+        assert_eq!(rewritten["version"], "1.1.0");
+        assert_eq!(rewritten["user_id"], "user-1");
+
+        // Files already at the latest version are left byte-for-byte intact
+        let _: SessionEntity = storage.load("session", "new").unwrap();
+        assert_eq!(fs::read_to_string(&current_path).unwrap(), current);
+    }
+
+    #[test]
+    fn test_dir_storage_rewrite_on_load_accepts_numeric_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let mut migrator = setup_session_migrator();
+        migrator
+            .register_version_alias("session", "1", "1.0.0")
+            .unwrap();
+        let strategy = DirStorageStrategy::default().with_rewrite_on_load(true);
+        let storage = DirStorage::new(paths, "sessions", migrator, strategy).unwrap();
+
+        let stale_path = storage.base_path().join("old.json");
+        fs::write(
+            &stale_path,
+            r#"{"version":1,"id":"old","user_id":"user-1"}"#,
+        )
+        .unwrap();
+
+        let session: SessionEntity = storage.load("session", "old").unwrap();
+        assert_eq!(session.user_id, "user-1");
+
+        let rewritten: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&stale_path).unwrap()).unwrap();
+        assert_eq!(rewritten["version"], "1.1.0");
+    }
+
+    #[test]
+    fn test_dir_storage_load_without_rewrite_keeps_stale_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+
+        let stale = r#"{"version":"1.0.0","id":"old","user_id":"user-1"}"#;
+        let stale_path = storage.base_path().join("old.json");
+        fs::write(&stale_path, stale).unwrap();
+
+        let _: SessionEntity = storage.load("session", "old").unwrap();
+        assert_eq!(fs::read_to_string(&stale_path).unwrap(), stale);
+    }
+
+    fn session(id: &str) -> SessionEntity {
+        SessionEntity {
+            id: id.to_string(),
+            user_id: format!("user-{}", id),
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_dir_storage_atomic_rename_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let mut storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+        storage.save("session", "a", session("a")).unwrap();
+        storage.save("session", "b", session("b")).unwrap();
+
+        let old_base = storage.base_path().to_path_buf();
+        let new_base = old_base.with_file_name("sessions-v2");
+        storage.atomic_rename_directory(new_base.clone()).unwrap();
+
+        assert!(!old_base.exists());
+        assert_eq!(storage.base_path(), new_base);
+        assert_eq!(storage.list_ids().unwrap(), vec!["a", "b"]);
+        let loaded: SessionEntity = storage.load("session", "b").unwrap();
+        assert_eq!(loaded, session("b"));
+
+        // A fresh storage opened at the new location sees the same entities
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let reopened = DirStorage::new(
+            paths,
+            "sessions-v2",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+        assert_eq!(reopened.base_path(), new_base);
+        assert_eq!(reopened.list_ids().unwrap(), vec!["a", "b"]);
+        let loaded: SessionEntity = reopened.load("session", "a").unwrap();
+        assert_eq!(loaded, session("a"));
+
+        // while one reopened at the old location starts out empty
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let reopened = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+        assert!(reopened.list_ids().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dir_storage_swap_with_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+        storage.save("session", "live", session("live")).unwrap();
+
+        let staged = temp_dir.path().join("staged");
+        fs::create_dir(&staged).unwrap();
+        fs::write(
+            staged.join("fresh.json"),
+            r#"{"version":"1.0.0","id":"fresh","user_id":"user-fresh"}"#,
+        )
+        .unwrap();
+
+        storage.swap_with_directory(staged.clone()).unwrap();
+
+        assert_eq!(storage.list_ids().unwrap(), vec!["fresh"]);
+        let loaded: SessionEntity = storage.load("session", "fresh").unwrap();
+        assert_eq!(loaded.user_id, "user-fresh");
+        assert!(staged.join("live.json").exists());
+        assert!(!staged.join("fresh.json").exists());
+    }
+
+    #[test]
+    fn test_dir_storage_swap_with_missing_directory_keeps_store() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default(),
+        )
+        .unwrap();
+        storage.save("session", "live", session("live")).unwrap();
+
+        let result = storage.swap_with_directory(temp_dir.path().join("missing"));
+        assert!(matches!(result, Err(MigrationError::Store(_))));
+        assert_eq!(storage.list_ids().unwrap(), vec!["live"]);
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn test_dir_storage_watch_reports_created_and_deleted() {
+        use std::time::Duration;
+
+        let temp_dir = TempDir::new().unwrap();
+        let paths = AppPaths::new("testapp").data_strategy(crate::PathStrategy::CustomBase(
+            temp_dir.path().to_path_buf(),
+        ));
+        let storage = DirStorage::new(
+            paths,
+            "sessions",
+            setup_session_migrator(),
+            DirStorageStrategy::default().with_filename_encoding(FilenameEncoding::UrlEncode),
+        )
+        .unwrap();
+
+        let events = storage.watch().unwrap();
+
+        let session = SessionEntity {
+            id: "user@example".to_string(),
+            user_id: "user-1".to_string(),
+            created_at: None,
+        };
+        storage.save("session", "user@example", session).unwrap();
+
+        // Events may arrive in bursts; wait for the one we care about.
+        let wait_for = |expected: StorageEvent| loop {
+            match events.recv_timeout(Duration::from_secs(5)) {
+                Ok(event) if event == expected => break,
+                Ok(_) => continue,
+                Err(e) => panic!("did not receive {:?}: {}", expected, e),
+            }
+        };
+
+        wait_for(StorageEvent::Created("user@example".to_string()));
+
+        storage.delete("user@example").unwrap();
+        wait_for(StorageEvent::Deleted("user@example".to_string()));
+    }
 }
@@ -2,6 +2,7 @@
 
 use thiserror::Error;
 
+use local_store::FormatConvertError;
 pub use local_store::{IoOperationKind, StoreError};
 
 /// Error types that can occur during migration operations.
@@ -90,11 +91,198 @@ pub enum MigrationError {
         reason: String,
     },
 
+    /// The input exceeds the configured maximum size.
+    #[error("Input of {size} bytes exceeds the limit of {max_bytes} bytes")]
+    InputTooLarge {
+        /// The input size in bytes.
+        size: usize,
+        /// The configured limit in bytes.
+        max_bytes: usize,
+    },
+
+    /// The input is nested deeper than the configured maximum depth.
+    #[error("Input exceeds the maximum nesting depth of {max_depth}")]
+    TooDeep {
+        /// The configured depth limit.
+        max_depth: usize,
+    },
+
+    /// Loading a single element of an array failed.
+    #[error("Failed to load element at index {index}: {source}")]
+    ElementFailed {
+        /// The position of the failing element in the input array.
+        index: usize,
+        /// The underlying error.
+        source: Box<MigrationError>,
+    },
+
+    /// A migration step did not finish within the registered timeout.
+    #[error(
+        "Migration of entity '{entity}' from version '{version}' timed out after {elapsed_ms}ms"
+    )]
+    Timeout {
+        /// The entity name.
+        entity: String,
+        /// The version whose migration step timed out.
+        version: String,
+        /// Time spent waiting before giving up, in milliseconds.
+        elapsed_ms: u64,
+    },
+
+    /// A field is `null`, which TOML cannot represent.
+    #[error("Field '{field}' is null, which TOML cannot represent (mark Option fields with #[serde(skip_serializing_if = \"Option::is_none\")] or use JSON)")]
+    TomlNullField {
+        /// Path of the null field, e.g. `"description"` or `"data.tags[0]"`.
+        field: String,
+    },
+
+    /// The data's version is not part of the entity's migration chain.
+    #[error("Version '{version}' is not part of the migration chain of entity '{entity}'")]
+    UnknownVersion {
+        /// The entity name.
+        entity: String,
+        /// The version found in the data.
+        version: String,
+    },
+
+    /// Serialized data does not match the JSON Schema of its version.
+    #[error("Entity '{entity}' version '{version}' failed schema validation: {details}")]
+    SchemaValidationFailed {
+        /// The entity name.
+        entity: String,
+        /// The version whose schema was violated.
+        version: String,
+        /// The validation errors, each prefixed with the offending JSON path.
+        details: String,
+    },
+
+    /// A loaded domain value was rejected by the entity's validator.
+    #[error("Validation failed for entity '{entity}': {reason}")]
+    ValidationFailed {
+        /// The entity name.
+        entity: String,
+        /// The reason returned by the validator.
+        reason: String,
+    },
+
+    /// A saved domain value did not load back unchanged.
+    #[error("Entity '{entity}' does not round-trip through save and load: {details}")]
+    RoundTripMismatch {
+        /// The entity name.
+        entity: String,
+        /// The fields that changed, with their saved and loaded values.
+        details: String,
+    },
+
+    /// An entity alias uses a name that is already a registered entity.
+    #[error("Entity '{0}' is already registered")]
+    EntityAlreadyRegistered(String),
+
+    /// A version marked frozen has an outgoing migration step.
+    #[error("Version '{version}' of entity '{entity}' is frozen and cannot migrate further")]
+    FrozenVersion {
+        /// The entity name.
+        entity: String,
+        /// The frozen version.
+        version: String,
+    },
+
+    /// A config key that was the target of a move is already occupied.
+    #[error("Config key '{0}' already exists")]
+    KeyAlreadyExists(String),
+
+    /// `load_default` or `save_default` was called before `set_default_entity`.
+    #[error("No default entity is set")]
+    NoDefaultEntity,
+
+    /// A stored entity changed between loading and saving it.
+    #[error("Entity '{id}' was modified since it was loaded")]
+    Conflict {
+        /// The ID of the stored entity.
+        id: String,
+    },
+
+    /// An entity name is empty or whitespace-only.
+    #[error("Invalid entity name '{0}': must not be empty or whitespace")]
+    InvalidEntityName(String),
+
+    /// A migration path or entity setting is misconfigured, such as an
+    /// invalid semver range or JSON pointer.
+    #[error("Invalid configuration for entity '{entity}': {reason}")]
+    InvalidPathConfig {
+        /// The entity name.
+        entity: String,
+        /// What is wrong with the configuration.
+        reason: String,
+    },
+
+    /// A registered version chain differs from the one declared in a
+    /// registration config.
+    #[error(
+        "Version chain of entity '{entity}' is {registered:?} but the config declares {declared:?}"
+    )]
+    VersionChainMismatch {
+        /// The entity name.
+        entity: String,
+        /// The chain registered by the code, oldest first.
+        registered: Vec<String>,
+        /// The chain declared in the config, oldest first.
+        declared: Vec<String>,
+    },
+
+    /// An HTTP response could not be used as input: its status is not a
+    /// success or its body could not be read.
+    #[error("HTTP response from '{url}' is unusable: {error}")]
+    Http {
+        /// The URL of the response.
+        url: String,
+        /// The response status, if the failure is about the status.
+        status: Option<u16>,
+        /// The error message.
+        error: String,
+    },
+
     /// Store / path-related error (delegated to local-store crate).
     #[error(transparent)]
     Store(#[from] StoreError),
 }
 
+impl From<FormatConvertError> for MigrationError {
+    fn from(e: FormatConvertError) -> Self {
+        match e {
+            FormatConvertError::NullField(field) => MigrationError::TomlNullField { field },
+            other => MigrationError::Store(StoreError::FormatConvert(other)),
+        }
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl MigrationError {
+    /// Converts this error into an `anyhow::Error`.
+    ///
+    /// The `source()` chain is preserved. `?` performs the same conversion
+    /// through anyhow's blanket `From` impl.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        anyhow::Error::new(self)
+    }
+
+    /// Converts this error into an `anyhow::Error` wrapped with `context`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let task: TaskEntity = migrator
+    ///     .load("task", &json)
+    ///     .map_err(|e| e.context("loading tasks.json"))?;
+    /// ```
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: std::fmt::Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +377,145 @@ mod tests {
         assert!(display.contains("must increase"));
     }
 
+    #[test]
+    fn test_error_display_element_failed() {
+        let err = MigrationError::ElementFailed {
+            index: 3,
+            source: Box::new(MigrationError::DeserializationError("bad item".to_string())),
+        };
+        let display = format!("{}", err);
+        assert!(display.contains("index 3"));
+        assert!(display.contains("bad item"));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn test_error_display_timeout() {
+        let err = MigrationError::Timeout {
+            entity: "task".to_string(),
+            version: "1.0.0".to_string(),
+            elapsed_ms: 100,
+        };
+        let display = format!("{}", err);
+        assert!(display.contains("task"));
+        assert!(display.contains("1.0.0"));
+        assert!(display.contains("100ms"));
+    }
+
+    #[test]
+    fn test_error_display_unknown_version() {
+        let err = MigrationError::UnknownVersion {
+            entity: "task".to_string(),
+            version: "0.9.0".to_string(),
+        };
+        let display = format!("{}", err);
+        assert!(display.contains("'0.9.0'"));
+        assert!(display.contains("'task'"));
+        assert!(display.contains("not part of the migration chain"));
+    }
+
+    #[test]
+    fn test_error_display_validation_failed() {
+        let err = MigrationError::ValidationFailed {
+            entity: "task".to_string(),
+            reason: "title must not be empty".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Validation failed for entity 'task': title must not be empty"
+        );
+    }
+
+    #[test]
+    fn test_error_display_round_trip_mismatch() {
+        let err = MigrationError::RoundTripMismatch {
+            entity: "task".to_string(),
+            details: "'title': saved \"a\" but loaded \"\"".to_string(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("'task'"));
+        assert!(display.contains("round-trip"));
+        assert!(display.contains("'title'"));
+    }
+
+    #[test]
+    fn test_error_display_entity_already_registered() {
+        let err = MigrationError::EntityAlreadyRegistered("todo".to_string());
+        assert_eq!(err.to_string(), "Entity 'todo' is already registered");
+    }
+
+    #[test]
+    fn test_error_display_frozen_version() {
+        let err = MigrationError::FrozenVersion {
+            entity: "task".to_string(),
+            version: "2.0.0".to_string(),
+        };
+        let display = format!("{}", err);
+        assert!(display.contains("'2.0.0'"));
+        assert!(display.contains("'task'"));
+        assert!(display.contains("frozen"));
+    }
+
+    #[test]
+    fn test_error_display_key_already_exists() {
+        let err = MigrationError::KeyAlreadyExists("settings".to_string());
+        assert_eq!(err.to_string(), "Config key 'settings' already exists");
+    }
+
+    #[test]
+    fn test_error_display_no_default_entity() {
+        let err = MigrationError::NoDefaultEntity;
+        assert_eq!(err.to_string(), "No default entity is set");
+    }
+
+    #[test]
+    fn test_error_display_conflict() {
+        let err = MigrationError::Conflict {
+            id: "session-1".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Entity 'session-1' was modified since it was loaded"
+        );
+    }
+
+    #[test]
+    fn test_error_display_invalid_entity_name() {
+        let err = MigrationError::InvalidEntityName(" ".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Invalid entity name ' ': must not be empty or whitespace"
+        );
+    }
+
+    #[test]
+    fn test_error_display_invalid_path_config() {
+        let err = MigrationError::InvalidPathConfig {
+            entity: "task".to_string(),
+            reason: "invalid semver range 'x'".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Invalid configuration for entity 'task': invalid semver range 'x'"
+        );
+    }
+
+    #[test]
+    fn test_format_convert_null_field_maps_to_toml_null_field() {
+        let err = MigrationError::from(FormatConvertError::NullField("data.note".to_string()));
+        assert!(matches!(
+            &err,
+            MigrationError::TomlNullField { field } if field == "data.note"
+        ));
+        assert!(format!("{}", err).contains("'data.note' is null"));
+
+        let err = MigrationError::from(FormatConvertError::Serialize("x".to_string()));
+        assert!(matches!(
+            err,
+            MigrationError::Store(StoreError::FormatConvert(_))
+        ));
+    }
+
     #[test]
     fn test_error_display_io_error_without_context() {
         let err = MigrationError::Store(StoreError::IoError {
@@ -232,4 +559,45 @@ mod tests {
         assert!(display.contains("/path/to/file.toml"));
         assert!(display.contains("Resource temporarily unavailable"));
     }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    fn test_into_anyhow_preserves_source_chain() {
+        let err = MigrationError::ElementFailed {
+            index: 2,
+            source: Box::new(MigrationError::EntityNotFound("task".to_string())),
+        };
+        let display = format!("{:#}", err.into_anyhow());
+        assert!(display.contains("index 2"));
+        assert!(display.contains("Entity 'task' not found"));
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    fn test_context_wraps_error() {
+        let err = MigrationError::MigrationPathNotDefined {
+            entity: "task".to_string(),
+            version: "9.0.0".to_string(),
+        };
+        let anyhow_err = err.context("loading tasks.json");
+
+        assert_eq!(anyhow_err.to_string(), "loading tasks.json");
+        let chain: Vec<String> = anyhow_err.chain().map(|e| e.to_string()).collect();
+        assert_eq!(chain.len(), 2);
+        assert!(chain[1].contains("task"));
+        assert!(chain[1].contains("9.0.0"));
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    fn test_question_mark_converts_to_anyhow() {
+        fn load() -> anyhow::Result<()> {
+            Err(MigrationError::EntityNotFound("user".to_string()))?;
+            Ok(())
+        }
+
+        let err = load().unwrap_err();
+        assert!(err.downcast_ref::<MigrationError>().is_some());
+        assert!(err.to_string().contains("user"));
+    }
 }
@@ -144,7 +144,11 @@ use serde::{Deserialize, Serialize};
 pub mod dir_storage;
 pub mod errors;
 pub mod forward;
+#[cfg(feature = "cache")]
+mod load_cache;
 mod migrator;
+#[cfg(feature = "registry")]
+pub mod registry;
 pub mod storage;
 pub mod versioned_dir;
 pub mod versioned_file;
@@ -159,6 +163,10 @@ pub use version_migrate_macro::Queryable as DeriveQueryable;
 // Re-export VersionMigrate derive macro
 #[doc(inline)]
 pub use version_migrate_macro::VersionMigrate;
+
+// Re-export FromDomain derive macro (same name as trait is OK in Rust)
+#[doc(inline)]
+pub use version_migrate_macro::FromDomain;
 /// Creates a migration path with simplified syntax.
 ///
 /// This macro provides a concise way to define migration paths between versioned types.
@@ -228,6 +236,63 @@ macro_rules! migrate_path {
     };
 }
 
+/// Creates a migration path for an entity with a single version.
+///
+/// For entities that start at their latest version with no migration
+/// history. `Migrator::register_passthrough` registers the same path directly.
+///
+/// # Example
+///
+/// ```ignore
+/// use version_migrate::{passthrough, Migrator};
+///
+/// let mut migrator = Migrator::new();
+/// migrator.register(passthrough!("settings", SettingsV1, Settings))?;
+///
+/// // expands to:
+/// // Migrator::define("settings")
+/// //     .from::<SettingsV1>()
+/// //     .into::<Settings>()
+/// ```
+#[macro_export]
+macro_rules! passthrough {
+    ($entity:expr, $version:ty, $domain:ty $(,)?) => {
+        $crate::Migrator::define($entity)
+            .from::<$version>()
+            .into::<$domain>()
+    };
+}
+
+/// Generates identity `IntoDomain<Self>` implementations.
+///
+/// Useful when a versioned type doubles as its own domain model, e.g. when
+/// loading a path straight into its latest version.
+///
+/// # Example
+///
+/// ```ignore
+/// use version_migrate::identity_into_domain;
+///
+/// identity_into_domain!(TaskV1, TaskV2, TaskV3);
+///
+/// // expands to, for each type:
+/// // impl IntoDomain<TaskV1> for TaskV1 {
+/// //     fn into_domain(self) -> TaskV1 { self }
+/// // }
+/// ```
+#[macro_export]
+macro_rules! identity_into_domain {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::IntoDomain<$ty> for $ty {
+                fn into_domain(self) -> $ty {
+                    self
+                }
+            }
+        )+
+    };
+}
+
 /// Helper macro for Vec notation without custom keys
 #[doc(hidden)]
 #[macro_export]
@@ -519,19 +584,46 @@ macro_rules! migrator {
 pub use errors::{IoOperationKind, MigrationError, StoreError};
 
 // Re-export migrator types
-pub use migrator::{ConfigMigrator, MigrationPath, Migrator};
+pub use migrator::{
+    AuditRecord, AuditSink, BidirectionalPath, ChainedMigrator, CoercionRule, ConfigDiff,
+    ConfigMigrator, EntityDiff, EntityInspection, ExtractVersionFn, KeyCase, LoadLimits,
+    MigrationPath, Migrator, NullHandlingMode, VersionDiff, VersionExtractor,
+};
+
+#[cfg(feature = "compression")]
+pub use migrator::EncodingHint;
+
+#[cfg(feature = "registry")]
+pub use registry::{EntityRegistration, MigratorRegistrationPlan, PlannedEntity};
+
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+pub use inventory;
+
+/// Re-exported so `#[derive(JsonSchema)]` matches the version used by
+/// `Migrator::save_validated`.
+#[cfg(feature = "schema")]
+pub use schemars;
+
+/// Re-exported so callers build requests and responses with the version used
+/// by `Migrator::load_from_http_response`.
+#[cfg(feature = "reqwest")]
+pub use reqwest;
 
 // Re-export storage types
 pub use local_store::{AtomicWriteConfig, FileStorageStrategy, FormatStrategy, LoadBehavior};
-pub use storage::FileStorage;
+pub use storage::{CompactByKeysReport, FileStorage, SaveStatus, ShardedFileStorage};
 
 // Re-export dir_storage types
-pub use dir_storage::DirStorage;
+pub use dir_storage::{DirStorage, FreshnessToken, IndexEntry, SaveOutcome};
 pub use local_store::{DirStorageStrategy, FilenameEncoding};
 
 #[cfg(feature = "async")]
 pub use dir_storage::AsyncDirStorage;
 
+#[cfg(feature = "watch")]
+pub use dir_storage::StorageEvent;
+
 // Re-export versioned wrappers (raw IO delegated to local_store)
 pub use versioned_dir::VersionedDirStorage;
 pub use versioned_file::VersionedFileStorage;
@@ -579,6 +671,10 @@ pub trait Versioned {
     /// The key name for the data field in serialized data.
     /// Defaults to "data".
     const DATA_KEY: &'static str = "data";
+
+    /// Whether this schema is frozen: it must be the last version of any
+    /// migration path it appears in. Defaults to `false`.
+    const FROZEN: bool = false;
 }
 
 /// Defines explicit migration logic from one version to another.
@@ -599,6 +695,34 @@ pub trait IntoDomain<D>: Versioned {
     fn into_domain(self) -> D;
 }
 
+/// Fallible variant of `IntoDomain` for conversions that validate invariants.
+///
+/// Register with `MigrationPathBuilder::try_into`; an error returned here is
+/// surfaced unchanged by `Migrator::load`.
+///
+/// # Example
+///
+/// ```ignore
+/// impl TryIntoDomain<TaskEntity> for TaskV2 {
+///     fn try_into_domain(self) -> Result<TaskEntity, MigrationError> {
+///         if self.title.is_empty() {
+///             return Err(MigrationError::DeserializationError(
+///                 "title must not be empty".to_string(),
+///             ));
+///         }
+///         Ok(TaskEntity { id: self.id, title: self.title })
+///     }
+/// }
+/// ```
+pub trait TryIntoDomain<D>: Versioned {
+    /// Converts this versioned data into the domain model, or fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError` if the data violates a domain invariant.
+    fn try_into_domain(self) -> Result<D, MigrationError>;
+}
+
 /// Converts a domain model back into a versioned DTO.
 ///
 /// This trait should be implemented on versioned DTOs to enable conversion
@@ -658,6 +782,15 @@ pub trait LatestVersioned: Sized {
     fn to_latest(self) -> Self::Latest {
         Self::Latest::from_domain(self)
     }
+
+    /// Converts a batch of domain entities into their latest versioned format.
+    ///
+    /// Used by `Migrator::save_entity_vec` and `save_entity_vec_flat`. The
+    /// default maps each entity through `to_latest`; override it to add a
+    /// step that needs the whole batch, such as assigning sequential IDs.
+    fn batch_to_latest(entities: Vec<Self>) -> Vec<Self::Latest> {
+        entities.into_iter().map(Self::to_latest).collect()
+    }
 }
 
 /// Marks a domain type as queryable, associating it with an entity name.
@@ -679,6 +812,25 @@ pub trait Queryable {
     const ENTITY_NAME: &'static str;
 }
 
+/// Associates a queryable type with the config key its records live under.
+///
+/// Lets `ConfigMigrator::query_default` and `update_default` omit the key.
+/// Derived with `#[queryable(entity = "...", key = "...")]`.
+///
+/// # Example
+///
+/// ```ignore
+/// impl QueryableKey for TaskEntity {
+///     const CONFIG_KEY: &'static str = "open_tasks";
+/// }
+///
+/// let tasks: Vec<TaskEntity> = config.query_default()?;
+/// ```
+pub trait QueryableKey: Queryable {
+    /// The top-level config key holding this type's records.
+    const CONFIG_KEY: &'static str;
+}
+
 /// Async version of `MigratesTo` for migrations requiring I/O operations.
 ///
 /// Use this trait when migrations need to perform asynchronous operations
@@ -713,28 +865,66 @@ pub trait AsyncIntoDomain<D>: Versioned + Send {
 ///
 /// This struct is used for persistence to ensure that the version of the data
 /// is always stored alongside the data itself.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// Serializes as `{"version": ..., "data": ...}` unless the wrapper was built
+/// by `Migrator::wrap_entity`, in which case it uses the wrapped type's
+/// `VERSION_KEY` and `DATA_KEY`.
+#[derive(Deserialize, Debug, Clone)]
 pub struct VersionedWrapper<T> {
     /// The semantic version of the data.
     pub version: String,
     /// The actual data.
     pub data: T,
+    #[serde(skip)]
+    keys: Option<(&'static str, &'static str)>,
 }
 
 impl<T> VersionedWrapper<T> {
     /// Creates a new versioned wrapper with the specified version and data.
     pub fn new(version: String, data: T) -> Self {
-        Self { version, data }
+        Self {
+            version,
+            data,
+            keys: None,
+        }
+    }
+
+    /// Creates a wrapper that serializes under the given version and data keys.
+    pub(crate) fn with_keys(
+        version: String,
+        data: T,
+        version_key: &'static str,
+        data_key: &'static str,
+    ) -> Self {
+        Self {
+            version,
+            data,
+            keys: Some((version_key, data_key)),
+        }
+    }
+
+    /// Returns the version and data keys this wrapper was built with, if any.
+    pub fn keys(&self) -> Option<(&'static str, &'static str)> {
+        self.keys
     }
 }
 
 impl<T: Versioned> VersionedWrapper<T> {
     /// Creates a wrapper from a versioned value, automatically extracting its version.
     pub fn from_versioned(data: T) -> Self {
-        Self {
-            version: T::VERSION.to_string(),
-            data,
-        }
+        Self::new(T::VERSION.to_string(), data)
+    }
+}
+
+impl<T: Serialize> Serialize for VersionedWrapper<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let (version_key, data_key) = self.keys.unwrap_or(("version", "data"));
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry(version_key, &self.version)?;
+        map.serialize_entry(data_key, &self.data)?;
+        map.end()
     }
 }
 
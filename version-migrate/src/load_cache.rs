@@ -0,0 +1,137 @@
+//! Bounded LRU cache of finalized load results, used by `Migrator::load`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+/// Entity name and the exact input string.
+type CacheKey = (String, String);
+
+/// Least-recently-used cache from `(entity, input)` to the finalized domain value.
+///
+/// Keys hold the full input rather than a digest, so two inputs share an
+/// entry only if they are byte-for-byte equal.
+pub(crate) struct LoadCache {
+    capacity: usize,
+    inner: Mutex<LruState>,
+}
+
+#[derive(Default)]
+struct LruState {
+    /// Key -> (last use tick, cached value)
+    entries: HashMap<CacheKey, (u64, serde_json::Value)>,
+    /// Last use tick -> key, oldest first
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+}
+
+impl LoadCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Returns the cached value for `entity` and `input`, marking it as recently used.
+    pub(crate) fn get(&self, entity: &str, input: &str) -> Option<serde_json::Value> {
+        let mut state = self.lock();
+        let key = (entity.to_string(), input.to_string());
+        let tick = state.next_tick();
+
+        let (used, value) = state.entries.get_mut(&key)?;
+        let previous = std::mem::replace(used, tick);
+        let value = value.clone();
+
+        state.recency.remove(&previous);
+        state.recency.insert(tick, key);
+        Some(value)
+    }
+
+    /// Stores `value`, evicting the least recently used entry when full.
+    pub(crate) fn insert(&self, entity: &str, input: &str, value: serde_json::Value) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.lock();
+        let key = (entity.to_string(), input.to_string());
+        let tick = state.next_tick();
+
+        if let Some((previous, _)) = state.entries.remove(&key) {
+            state.recency.remove(&previous);
+        } else if state.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = state.recency.pop_first() {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.recency.insert(tick, key.clone());
+        state.entries.insert(key, (tick, value));
+    }
+
+    /// Drops every entry.
+    pub(crate) fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    /// Number of cached entries.
+    pub(crate) fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        // A panic while holding the lock cannot leave the maps inconsistent
+        // in a way that matters for a cache, so recover from poisoning.
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl LruState {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoadCache;
+    use serde_json::json;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LoadCache::new(2);
+        cache.insert("task", "a", json!(1));
+        cache.insert("task", "b", json!(2));
+
+        // Touch "a" so "b" becomes the oldest
+        assert_eq!(cache.get("task", "a"), Some(json!(1)));
+        cache.insert("task", "c", json!(3));
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("task", "b"), None);
+        assert_eq!(cache.get("task", "a"), Some(json!(1)));
+        assert_eq!(cache.get("task", "c"), Some(json!(3)));
+    }
+
+    #[test]
+    fn test_keys_include_entity_and_exact_input() {
+        let cache = LoadCache::new(4);
+        cache.insert("task", "{}", json!("task"));
+
+        assert_eq!(cache.get("user", "{}"), None);
+        assert_eq!(cache.get("task", "{ }"), None);
+
+        cache.clear();
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_zero_capacity_stores_nothing() {
+        let cache = LoadCache::new(0);
+        cache.insert("task", "{}", json!(1));
+        assert_eq!(cache.get("task", "{}"), None);
+    }
+}
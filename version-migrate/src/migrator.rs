@@ -2,642 +2,5156 @@
 
 use crate::errors::MigrationError;
 use crate::forward::{ForwardContext, Forwardable};
-use crate::{IntoDomain, MigratesTo, Versioned};
+use crate::{IntoDomain, MigratesTo, TryIntoDomain, Versioned, VersionedWrapper};
+use local_store::{AppPaths, PathStrategy};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 type MigrationFn =
+    Arc<dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync>;
+
+/// Type-erased conversion of the latest version's value into the domain value
+type FinalizeFn =
     Box<dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync>;
 
-/// Type-erased function for saving domain entities
-type DomainSaveFn =
-    Box<dyn Fn(serde_json::Value, &str, &str) -> Result<String, MigrationError> + Send + Sync>;
+/// Type-erased function listing the top-level fields a version's DTO ignores
+/// when deserializing a value
+type UnknownFieldsFn = Arc<dyn Fn(&serde_json::Value) -> Vec<String> + Send + Sync>;
+
+/// Type-erased function for converting domain entities to their latest versioned JSON
+type DomainSaveFn = Box<
+    dyn Fn(serde_json::Value, &str, &str) -> Result<serde_json::Value, MigrationError>
+        + Send
+        + Sync,
+>;
 type DomainSaveFlatFn =
-    Box<dyn Fn(serde_json::Value, &str) -> Result<String, MigrationError> + Send + Sync>;
+    Box<dyn Fn(serde_json::Value, &str) -> Result<serde_json::Value, MigrationError> + Send + Sync>;
+
+/// Type-erased function converting a finalized domain value into the boxed domain type
+type DynamicDomainFn =
+    Box<dyn Fn(serde_json::Value) -> Result<Box<dyn std::any::Any>, MigrationError> + Send + Sync>;
 
 /// A registered migration path for a specific entity type.
 struct EntityMigrationPath {
     /// Maps version -> migration function to next version
     steps: HashMap<String, MigrationFn>,
     /// The final conversion to domain model
-    finalize:
-        Box<dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync>,
+    finalize: FinalizeFn,
     /// Ordered list of versions in the migration path
     versions: Vec<String>,
     /// The key name for version field in serialized data
     version_key: String,
     /// The key name for data field in serialized data
     data_key: String,
+    /// Semver range -> registered version whose step handles matching versions,
+    /// parsed when the path is registered
+    ranges: Vec<(semver::VersionReq, String)>,
+    /// Maps version -> function listing the fields its DTO does not consume
+    unknown_fields: HashMap<String, UnknownFieldsFn>,
+    /// Settings registered for the entity after its path
+    options: EntityOptions,
 }
 
-/// Type-erased functions for saving domain entities by entity name
-struct DomainSavers {
-    save_fn: DomainSaveFn,
-    save_flat_fn: DomainSaveFlatFn,
+/// Audit sink of a registered path, with the entity name its records carry.
+struct EntityAudit {
+    entity: String,
+    sink: AuditSink,
 }
 
-/// The migration manager that orchestrates all migrations.
-pub struct Migrator {
-    paths: HashMap<String, EntityMigrationPath>,
-    default_version_key: Option<String>,
-    default_data_key: Option<String>,
-    domain_savers: HashMap<String, DomainSavers>,
+/// Per-entity settings registered through `Migrator` after the path itself.
+///
+/// Held apart from the builder-produced path so that re-registering an
+/// entity keeps them.
+#[derive(Default)]
+struct EntityOptions {
+    /// Maps version -> field -> coercion applied before that version is migrated
+    coercions: HashMap<String, HashMap<String, CoercionRule>>,
+    /// Maps version -> default top-level fields filled in where the data omits them
+    defaults: HashMap<String, serde_json::Map<String, serde_json::Value>>,
+    /// Maps version -> target version -> step of a branch off the linear chain;
+    /// together with `steps` these form the path's migration graph
+    branches: HashMap<String, BTreeMap<String, MigrationFn>>,
+    /// Per-step time limit applied by `Migrator::load_async`
+    timeout: Option<std::time::Duration>,
+    /// Version marking logically deleted records, skipped by `ConfigMigrator::query_live`
+    tombstone: Option<String>,
+    /// Normalization of empty values applied before the first migration step
+    null_handling: NullHandlingMode,
+    /// Object key rewrite applied before each version is deserialized
+    key_case: Option<KeyCase>,
+    /// Collection fields whose items are migrated by another entity's steps
+    children: Vec<ChildCollection>,
+    /// Stored version (string or stringified number) -> registered version
+    version_aliases: HashMap<String, String>,
+    /// Overrides the format each loading method reads, if set
+    extractor: Option<VersionExtractor>,
+    /// Receives a record for every step applied while loading
+    audit: Option<EntityAudit>,
 }
 
-impl Migrator {
-    /// Creates a new, empty migrator.
-    pub fn new() -> Self {
-        Self {
-            paths: HashMap::new(),
-            default_version_key: None,
-            default_data_key: None,
-            domain_savers: HashMap::new(),
-        }
-    }
+impl EntityMigrationPath {
+    /// Splits wrapped-format data (`{"version": ..., "data": ...}`) into its
+    /// version string and data value.
+    fn split_wrapped(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        let obj = value.as_object().ok_or_else(|| {
+            MigrationError::DeserializationError(
+                "Expected object with version and data fields".to_string(),
+            )
+        })?;
 
-    /// Gets the latest version for a given entity.
-    ///
-    /// # Returns
-    ///
-    /// The latest version string if the entity is registered, `None` otherwise.
-    pub fn get_latest_version(&self, entity: &str) -> Option<&str> {
-        self.paths
-            .get(entity)
-            .and_then(|path| path.versions.last())
-            .map(|v| v.as_str())
-    }
+        let version = obj
+            .get(&self.version_key)
+            .ok_or_else(|| {
+                MigrationError::DeserializationError(format!(
+                    "Missing '{}' field",
+                    self.version_key
+                ))
+            })
+            .and_then(|v| self.read_version(v, &self.version_key))?;
 
-    /// Creates a builder for configuring the migrator.
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// let migrator = Migrator::builder()
-    ///     .default_version_key("schema_version")
-    ///     .default_data_key("payload")
-    ///     .build();
-    /// ```
-    pub fn builder() -> MigratorBuilder {
-        MigratorBuilder::new()
-    }
+        let data = obj
+            .get(&self.data_key)
+            .ok_or_else(|| {
+                MigrationError::DeserializationError(format!("Missing '{}' field", self.data_key))
+            })?
+            .clone();
 
-    /// Starts defining a migration path for an entity.
-    pub fn define(entity: &str) -> MigrationPathBuilder<Start> {
-        MigrationPathBuilder::new(entity.to_string())
+        Ok((version, data))
     }
 
-    /// Registers a migration path with validation.
-    ///
-    /// This method validates the migration path before registering it:
-    /// - Checks for circular migration paths
-    /// - Validates version ordering follows semver rules
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if validation fails.
-    pub fn register<D>(&mut self, path: MigrationPath<D>) -> Result<(), MigrationError> {
-        Self::validate_migration_path(&path.entity, &path.versions)?;
-
-        // Resolve key priority: Path custom > Migrator default > EntityPath (trait constants)
-        let version_key = path
-            .custom_version_key
-            .or_else(|| self.default_version_key.clone())
-            .unwrap_or_else(|| path.inner.version_key.clone());
+    /// Splits flat-format data (version next to the data fields) into its
+    /// version string and the remaining data fields.
+    fn split_flat(
+        &self,
+        mut value: serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        let obj = value.as_object_mut().ok_or_else(|| {
+            MigrationError::DeserializationError(
+                "Expected object with version field at top level".to_string(),
+            )
+        })?;
 
-        let data_key = path
-            .custom_data_key
-            .or_else(|| self.default_data_key.clone())
-            .unwrap_or_else(|| path.inner.data_key.clone());
+        let version = obj.remove(&self.version_key).ok_or_else(|| {
+            MigrationError::DeserializationError(format!(
+                "Missing '{}' field in flat format",
+                self.version_key
+            ))
+        })?;
+        let version = self.read_version(&version, &self.version_key)?;
 
-        let entity_name = path.entity.clone();
-        let final_path = EntityMigrationPath {
-            steps: path.inner.steps,
-            finalize: path.inner.finalize,
-            versions: path.versions,
-            version_key,
-            data_key,
-        };
+        Ok((version, value))
+    }
 
-        self.paths.insert(path.entity, final_path);
+    /// Splits input read as wrapped data, unless a `VersionExtractor` is set.
+    fn extract_wrapped(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        match &self.options.extractor {
+            Some(extractor) => self.extract_with(extractor, value.clone()),
+            None => self.split_wrapped(value),
+        }
+    }
 
-        // Register domain savers if available
-        if let (Some(save_fn), Some(save_flat_fn)) = (path.save_fn, path.save_flat_fn) {
-            self.domain_savers.insert(
-                entity_name,
-                DomainSavers {
-                    save_fn,
-                    save_flat_fn,
-                },
-            );
+    /// Splits input read as flat data, unless a `VersionExtractor` is set.
+    fn extract_flat(
+        &self,
+        value: serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        match &self.options.extractor {
+            Some(extractor) => self.extract_with(extractor, value),
+            None => self.split_flat(value),
         }
+    }
 
-        Ok(())
+    /// Splits `value` with `extractor`.
+    fn extract_with(
+        &self,
+        extractor: &VersionExtractor,
+        value: serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        match extractor {
+            VersionExtractor::Wrapped => self.split_wrapped(&value),
+            VersionExtractor::Flat => self.split_flat(value),
+            VersionExtractor::JsonPointer { version, data } => {
+                let missing = |pointer: &str| {
+                    MigrationError::DeserializationError(format!("Missing '{}' field", pointer))
+                };
+                let stored = value.pointer(version).ok_or_else(|| missing(version))?;
+                let stored = self.read_version(stored, version)?;
+                let data = value.pointer(data).ok_or_else(|| missing(data))?.clone();
+                Ok((stored, data))
+            }
+            VersionExtractor::Custom(extract) => {
+                let (stored, data) = extract(&value)?;
+                let stored = self
+                    .options
+                    .version_aliases
+                    .get(&stored)
+                    .cloned()
+                    .unwrap_or(stored);
+                Ok((stored, data))
+            }
+        }
     }
 
-    /// Validates a migration path for correctness.
-    fn validate_migration_path(entity: &str, versions: &[String]) -> Result<(), MigrationError> {
-        // Check for circular paths
-        Self::check_circular_path(entity, versions)?;
+    /// Reads the stored version from `value`, resolving registered aliases.
+    ///
+    /// Numbers are accepted when their string form is an alias or a
+    /// registered version; otherwise the error says the field is numeric.
+    fn read_version(
+        &self,
+        value: &serde_json::Value,
+        field: &str,
+    ) -> Result<String, MigrationError> {
+        use serde_json::Value;
+
+        let raw = match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => {
+                let raw = n.to_string();
+                if !self.options.version_aliases.contains_key(&raw) && !self.knows_version(&raw) {
+                    return Err(MigrationError::DeserializationError(format!(
+                        "'{}' field is a number ({}), expected a version string (map it with Migrator::register_version_alias)",
+                        field, n
+                    )));
+                }
+                raw
+            }
+            _ => {
+                return Err(MigrationError::DeserializationError(format!(
+                    "Invalid '{}' field type: expected a version string",
+                    field
+                )))
+            }
+        };
 
-        // Check version ordering
-        Self::check_version_ordering(entity, versions)?;
+        Ok(self
+            .options
+            .version_aliases
+            .get(&raw)
+            .cloned()
+            .unwrap_or(raw))
+    }
 
-        Ok(())
+    /// Applies migration steps starting at `version` until no further step is
+    /// registered, returning the reached version and the migrated data.
+    fn migrate_steps(
+        &self,
+        version: String,
+        data: serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        self.migrate_steps_until(version, data, None)
     }
 
-    /// Checks if there are any circular dependencies in the migration path.
-    fn check_circular_path(entity: &str, versions: &[String]) -> Result<(), MigrationError> {
-        let mut seen = std::collections::HashSet::new();
+    /// Like `migrate_steps`, but stops once `stop_at` is reached.
+    fn migrate_steps_until(
+        &self,
+        version: String,
+        data: serde_json::Value,
+        stop_at: Option<&str>,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        let mut current_version = self.resolve_version(version);
+        let mut current_data = data;
+        self.options.null_handling.apply(&mut current_data);
+        let id = self
+            .options
+            .audit
+            .as_ref()
+            .and_then(|_| record_id(&current_data));
+
+        for (next, migrate_fn) in self.plan_steps(&current_version, stop_at) {
+            self.rewrite_keys(&mut current_data)?;
+            self.coerce(&current_version, &mut current_data);
+            self.fill_defaults(&current_version, &mut current_data);
+            let before = (!self.options.children.is_empty()).then(|| current_data.clone());
 
-        for version in versions {
-            if !seen.insert(version) {
-                // Found a duplicate - circular path detected
-                let path = versions.join(" -> ");
-                return Err(MigrationError::CircularMigrationPath {
-                    entity: entity.to_string(),
-                    path,
-                });
+            // Migration function returns raw value, no wrapping
+            current_data = migrate_fn(current_data)?;
+
+            if let Some(before) = &before {
+                self.migrate_children(&current_version, &next, before, &mut current_data)?;
             }
+            self.audit_step(id.as_deref(), &current_version, &next);
+            current_version = next;
         }
 
-        Ok(())
+        self.rewrite_keys(&mut current_data)?;
+        self.coerce(&current_version, &mut current_data);
+        self.fill_defaults(&current_version, &mut current_data);
+
+        Ok((current_version, current_data))
     }
 
-    /// Checks if versions are ordered according to semver rules.
-    fn check_version_ordering(entity: &str, versions: &[String]) -> Result<(), MigrationError> {
-        for i in 0..versions.len().saturating_sub(1) {
-            let current = &versions[i];
-            let next = &versions[i + 1];
+    /// Like `migrate_steps`, but for several values stored at the same
+    /// version: the route is planned once and each step is applied to every
+    /// value before moving on to the next. Stops at the first failing value
+    /// and returns its position in `items` along with the error.
+    fn migrate_steps_batch(
+        &self,
+        version: String,
+        mut items: Vec<serde_json::Value>,
+    ) -> Result<(String, Vec<serde_json::Value>), (usize, MigrationError)> {
+        let mut current_version = self.resolve_version(version);
+        for data in &mut items {
+            self.options.null_handling.apply(data);
+        }
+        let ids: Vec<Option<String>> = match &self.options.audit {
+            Some(_) => items.iter().map(record_id).collect(),
+            None => vec![None; items.len()],
+        };
 
-            // Parse versions
-            let current_ver = semver::Version::parse(current).map_err(|e| {
-                MigrationError::DeserializationError(format!("Invalid semver '{}': {}", current, e))
-            })?;
+        for (next, migrate_fn) in self.plan_steps(&current_version, None) {
+            for (pos, data) in items.iter_mut().enumerate() {
+                self.rewrite_keys(data).map_err(|e| (pos, e))?;
+                self.coerce(&current_version, data);
+                self.fill_defaults(&current_version, data);
+                let before = (!self.options.children.is_empty()).then(|| data.clone());
 
-            let next_ver = semver::Version::parse(next).map_err(|e| {
-                MigrationError::DeserializationError(format!("Invalid semver '{}': {}", next, e))
-            })?;
+                *data = migrate_fn(std::mem::take(data)).map_err(|e| (pos, e))?;
 
-            // Check that next version is greater than current
-            if next_ver <= current_ver {
-                return Err(MigrationError::InvalidVersionOrder {
-                    entity: entity.to_string(),
-                    from: current.clone(),
-                    to: next.clone(),
-                });
+                if let Some(before) = &before {
+                    self.migrate_children(&current_version, &next, before, data)
+                        .map_err(|e| (pos, e))?;
+                }
+                self.audit_step(ids[pos].as_deref(), &current_version, &next);
             }
+            current_version = next;
         }
 
-        Ok(())
+        for (pos, data) in items.iter_mut().enumerate() {
+            self.rewrite_keys(data).map_err(|e| (pos, e))?;
+            self.coerce(&current_version, data);
+            self.fill_defaults(&current_version, data);
+        }
+
+        Ok((current_version, items))
     }
 
-    /// Loads and migrates data from any serde-compatible format.
-    ///
-    /// This is the generic version that accepts any type implementing `Serialize`.
-    /// For JSON strings, use the convenience method `load` instead.
-    ///
-    /// # Arguments
-    ///
-    /// * `entity` - The entity name used when registering the migration path
-    /// * `data` - Versioned data in any serde-compatible format (e.g., `toml::Value`, `serde_json::Value`)
-    ///
-    /// # Returns
-    ///
-    /// The migrated data as the domain model type
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The data cannot be converted to the internal format
-    /// - The entity is not registered
-    /// - A migration step fails
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Load from TOML
-    /// let toml_data: toml::Value = toml::from_str(toml_str)?;
-    /// let domain: TaskEntity = migrator.load_from("task", toml_data)?;
-    ///
-    /// // Load from JSON Value
-    /// let json_data: serde_json::Value = serde_json::from_str(json_str)?;
-    /// let domain: TaskEntity = migrator.load_from("task", json_data)?;
-    /// ```
-    pub fn load_from<D, T>(&self, entity: &str, data: T) -> Result<D, MigrationError>
-    where
-        D: DeserializeOwned,
-        T: Serialize,
-    {
-        // Convert the input data to serde_json::Value for internal processing
-        let value = serde_json::to_value(data).map_err(|e| {
-            MigrationError::DeserializationError(format!(
-                "Failed to convert input data to internal format: {}",
-                e
-            ))
-        })?;
+    /// Like `migrate_steps`, but runs each step on the blocking thread pool
+    /// under the path's timeout, if one is registered.
+    #[cfg(feature = "async")]
+    async fn migrate_steps_async(
+        &self,
+        entity: &str,
+        version: String,
+        data: serde_json::Value,
+    ) -> Result<(String, serde_json::Value), MigrationError> {
+        let Some(limit) = self.options.timeout else {
+            return self.migrate_steps(version, data);
+        };
 
-        // Get the migration path for this entity
-        let path = self
-            .paths
-            .get(entity)
-            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+        let mut current_version = self.resolve_version(version);
+        let mut current_data = data;
+        self.options.null_handling.apply(&mut current_data);
+        let id = self
+            .options
+            .audit
+            .as_ref()
+            .and_then(|_| record_id(&current_data));
+
+        for (next, migrate_fn) in self.plan_steps(&current_version, None) {
+            self.rewrite_keys(&mut current_data)?;
+            self.coerce(&current_version, &mut current_data);
+            self.fill_defaults(&current_version, &mut current_data);
+            let before = (!self.options.children.is_empty()).then(|| current_data.clone());
+
+            let started = std::time::Instant::now();
+            let task = tokio::task::spawn_blocking(move || migrate_fn(current_data));
+
+            current_data = match tokio::time::timeout(limit, task).await {
+                Ok(joined) => joined.map_err(|e| MigrationError::MigrationStepFailed {
+                    from: current_version.clone(),
+                    to: next.clone(),
+                    error: e.to_string(),
+                })??,
+                Err(_) => {
+                    return Err(MigrationError::Timeout {
+                        entity: entity.to_string(),
+                        version: current_version,
+                        elapsed_ms: started.elapsed().as_millis() as u64,
+                    })
+                }
+            };
 
-        let version_key = &path.version_key;
-        let data_key = &path.data_key;
+            if let Some(before) = &before {
+                self.migrate_children(&current_version, &next, before, &mut current_data)?;
+            }
+            self.audit_step(id.as_deref(), &current_version, &next);
+            current_version = next;
+        }
 
-        // Extract version and data using custom keys
-        let obj = value.as_object().ok_or_else(|| {
-            MigrationError::DeserializationError(
-                "Expected object with version and data fields".to_string(),
-            )
-        })?;
+        self.rewrite_keys(&mut current_data)?;
+        self.coerce(&current_version, &mut current_data);
+        self.fill_defaults(&current_version, &mut current_data);
 
-        let current_version = obj
-            .get(version_key)
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                MigrationError::DeserializationError(format!(
-                    "Missing or invalid '{}' field",
-                    version_key
-                ))
-            })?
-            .to_string();
+        Ok((current_version, current_data))
+    }
 
-        let mut current_data = obj
-            .get(data_key)
-            .ok_or_else(|| {
-                MigrationError::DeserializationError(format!("Missing '{}' field", data_key))
-            })?
-            .clone();
+    /// Sends the record of an applied `from` -> `to` step to the audit sink, if any.
+    fn audit_step(&self, id: Option<&str>, from: &str, to: &str) {
+        let Some(audit) = &self.options.audit else {
+            return;
+        };
 
-        let mut current_version = current_version;
+        let timestamp_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
 
-        // Apply migration steps until we reach a version with no further steps
-        while let Some(migrate_fn) = path.steps.get(&current_version) {
-            // Migration function returns raw value, no wrapping
-            current_data = migrate_fn(current_data.clone())?;
+        (audit.sink)(AuditRecord {
+            entity: audit.entity.clone(),
+            id: id.map(str::to_string),
+            from: from.to_string(),
+            to: to.to_string(),
+            timestamp_ms,
+        });
+    }
 
-            // Update version to the next step
-            // Find the next version in the path
-            match path.versions.iter().position(|v| v == &current_version) {
-                Some(idx) if idx + 1 < path.versions.len() => {
-                    current_version = path.versions[idx + 1].clone();
-                }
-                _ => break,
+    /// Maps an unregistered version onto the registered version whose range
+    /// step covers it. Registered and unmatched versions are returned as is.
+    fn resolve_version(&self, version: String) -> String {
+        if self.ranges.is_empty() || self.knows_version(&version) {
+            return version;
+        }
+        let Ok(parsed) = semver::Version::parse(&version) else {
+            return version;
+        };
+
+        self.ranges
+            .iter()
+            .find(|(req, _)| req.matches(&parsed))
+            .map(|(_, source)| source.clone())
+            .unwrap_or(version)
+    }
+
+    /// Returns the version following `version` in the chain, if any.
+    fn next_version(&self, version: &str) -> Option<String> {
+        let idx = self.versions.iter().position(|v| v == version)?;
+        self.versions.get(idx + 1).cloned()
+    }
+
+    /// Returns the steps leading from `version` to `stop_at` (or the latest
+    /// version), each paired with the version it reaches.
+    ///
+    /// Without branches this walks the linear chain. Once branches are
+    /// registered the shortest route through the migration graph is taken,
+    /// falling back to the chain if the target is unreachable. The route is
+    /// computed once, before the first step runs.
+    fn plan_steps(&self, version: &str, stop_at: Option<&str>) -> Vec<(String, MigrationFn)> {
+        let Some(target) = stop_at.or(self.versions.last().map(String::as_str)) else {
+            return Vec::new();
+        };
+
+        if !self.options.branches.is_empty() {
+            if let Some(route) = self.route(version, target) {
+                return route
+                    .windows(2)
+                    .filter_map(|pair| {
+                        let step = self.step_between(&pair[0], &pair[1])?;
+                        Some((pair[1].clone(), step))
+                    })
+                    .collect();
+            }
+        }
+
+        let mut plan = Vec::new();
+        let mut current = version.to_string();
+        while current != target {
+            let (Some(step), Some(next)) = (self.steps.get(&current), self.next_version(&current))
+            else {
+                break;
+            };
+            plan.push((next.clone(), Arc::clone(step)));
+            current = next;
+        }
+        plan
+    }
+
+    /// Returns the step migrating `from` directly to `to`, preferring a
+    /// branch over the linear chain.
+    fn step_between(&self, from: &str, to: &str) -> Option<MigrationFn> {
+        if let Some(step) = self.options.branches.get(from).and_then(|t| t.get(to)) {
+            return Some(Arc::clone(step));
+        }
+        let step = self.steps.get(from)?;
+        (self.next_version(from).as_deref() == Some(to)).then(|| Arc::clone(step))
+    }
+
+    /// Versions reachable from `version` in a single step, the linear chain's
+    /// next version first.
+    fn successors(&self, version: &str) -> Vec<String> {
+        let linear = self
+            .steps
+            .contains_key(version)
+            .then(|| self.next_version(version))
+            .flatten();
+        let branched = self
+            .options
+            .branches
+            .get(version)
+            .into_iter()
+            .flat_map(|t| t.keys());
+        linear.into_iter().chain(branched.cloned()).collect()
+    }
+
+    /// Finds the shortest route from `from` to `to` through the migration
+    /// graph, both ends included.
+    fn route(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let mut previous: HashMap<String, String> = HashMap::new();
+        let mut queue = VecDeque::from([from.to_string()]);
+
+        while let Some(version) = queue.pop_front() {
+            if version == to {
+                let mut route = vec![version];
+                while let Some(prev) = route.last().and_then(|v| previous.get(v)) {
+                    route.push(prev.clone());
+                }
+                route.reverse();
+                return Some(route);
+            }
+            for next in self.successors(&version) {
+                if next != from && !previous.contains_key(&next) {
+                    previous.insert(next.clone(), version.clone());
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns true if `version` is on the linear chain or an end of a branch.
+    fn knows_version(&self, version: &str) -> bool {
+        self.versions.iter().any(|v| v == version)
+            || self.options.branches.contains_key(version)
+            || self
+                .options
+                .branches
+                .values()
+                .any(|t| t.contains_key(version))
+    }
+
+    /// Applies the type coercions registered for `version` to top-level fields
+    /// of `data`.
+    fn coerce(&self, version: &str, data: &mut serde_json::Value) {
+        let (Some(rules), Some(obj)) = (self.options.coercions.get(version), data.as_object_mut())
+        else {
+            return;
+        };
+        for (field, rule) in rules {
+            if let Some(value) = obj.get_mut(field) {
+                if let Some(coerced) = rule.apply(value) {
+                    *value = coerced;
+                }
+            }
+        }
+    }
+
+    /// Adds the default fields registered for `version` that `data` lacks.
+    fn fill_defaults(&self, version: &str, data: &mut serde_json::Value) {
+        let (Some(defaults), Some(obj)) =
+            (self.options.defaults.get(version), data.as_object_mut())
+        else {
+            return;
+        };
+        for (field, value) in defaults {
+            obj.entry(field.as_str()).or_insert_with(|| value.clone());
+        }
+    }
+
+    /// Rewrites the object keys of `data` according to the migrator's
+    /// `KeyCase`, if one is set.
+    fn rewrite_keys(&self, data: &mut serde_json::Value) -> Result<(), MigrationError> {
+        match self.options.key_case {
+            Some(case) => case.apply(data),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the top-level fields of `data` that the DTO of `version` does
+    /// not consume, after the same normalization `migrate_steps` applies.
+    fn extras(
+        &self,
+        version: String,
+        data: &serde_json::Value,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, MigrationError> {
+        let version = self.resolve_version(version);
+        let Some(unknown_fields) = self.unknown_fields.get(&version) else {
+            return Ok(serde_json::Map::new());
+        };
+
+        let mut probe = data.clone();
+        self.options.null_handling.apply(&mut probe);
+        self.rewrite_keys(&mut probe)?;
+        self.coerce(&version, &mut probe);
+
+        Ok(unknown_fields(&probe)
+            .into_iter()
+            .filter_map(|key| {
+                let value = probe.get(&key)?.clone();
+                Some((key, value))
+            })
+            .collect())
+    }
+
+    /// Migrates the registered child collections of a step from `from` to
+    /// `to`, reading items from the step's input `before`.
+    fn migrate_children(
+        &self,
+        from: &str,
+        to: &str,
+        before: &serde_json::Value,
+        after: &mut serde_json::Value,
+    ) -> Result<(), MigrationError> {
+        for child in &self.options.children {
+            child.migrate(from, to, before, after)?;
+        }
+        Ok(())
+    }
+}
+
+/// A collection field of a parent entity whose items are migrated by the
+/// steps of a child entity, registered with
+/// [`Migrator::register_nested_collection`].
+struct ChildCollection {
+    /// The field holding the array of child items
+    field: String,
+    /// The child entity name, for error reporting
+    entity: String,
+    /// Parent version -> child version its items are stored at
+    versions: HashMap<String, String>,
+    /// The child entity's migration steps
+    steps: HashMap<String, MigrationFn>,
+    /// The child entity's version chain
+    chain: Vec<String>,
+}
+
+impl ChildCollection {
+    /// Replaces the field in `after` (the output of the parent step from
+    /// `from` to `to`) with the items of `before`, migrated to the child
+    /// version of `to`. Does nothing if either parent version is unmapped
+    /// or `before` has no array under the field.
+    fn migrate(
+        &self,
+        from: &str,
+        to: &str,
+        before: &serde_json::Value,
+        after: &mut serde_json::Value,
+    ) -> Result<(), MigrationError> {
+        let (Some(from), Some(to)) = (self.versions.get(from), self.versions.get(to)) else {
+            return Ok(());
+        };
+        let (Some(items), Some(obj)) = (
+            before.get(&self.field).and_then(|v| v.as_array()),
+            after.as_object_mut(),
+        ) else {
+            return Ok(());
+        };
+
+        let migrated = items
+            .iter()
+            .map(|item| self.migrate_item(from, to, item.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        obj.insert(self.field.clone(), serde_json::Value::Array(migrated));
+        Ok(())
+    }
+
+    /// Runs the child steps on one item from version `from` up to `to`.
+    fn migrate_item(
+        &self,
+        from: &str,
+        to: &str,
+        mut item: serde_json::Value,
+    ) -> Result<serde_json::Value, MigrationError> {
+        let mut current = from;
+        while current != to {
+            let idx = self.chain.iter().position(|v| v == current);
+            let (Some(step), Some(next)) = (
+                self.steps.get(current),
+                idx.and_then(|i| self.chain.get(i + 1)),
+            ) else {
+                return Err(MigrationError::MigrationPathNotDefined {
+                    entity: self.entity.clone(),
+                    version: current.to_string(),
+                });
+            };
+            item = step(item)?;
+            current = next;
+        }
+        Ok(item)
+    }
+}
+
+/// Returns the entry of the object `value` stored under `key`.
+fn map_entry<'a>(
+    value: &'a serde_json::Value,
+    key: &str,
+) -> Result<&'a serde_json::Value, MigrationError> {
+    value
+        .as_object()
+        .ok_or_else(|| MigrationError::DeserializationError("Expected a JSON object".to_string()))?
+        .get(key)
+        .ok_or_else(|| MigrationError::DeserializationError(format!("Missing '{}' key", key)))
+}
+
+/// Builds the `UnknownFieldsFn` of `V`, reporting the top-level keys its
+/// deserializer skips. Values that fail to deserialize report nothing.
+fn unknown_fields_fn<V: DeserializeOwned>() -> UnknownFieldsFn {
+    Arc::new(|value| {
+        let mut keys = Vec::new();
+        let _ = serde_ignored::deserialize::<_, _, V>(value, |path| {
+            if let serde_ignored::Path::Map {
+                parent: serde_ignored::Path::Root,
+                key,
+            } = path
+            {
+                keys.push(key.clone());
+            }
+        });
+        keys
+    })
+}
+
+/// Builds the finalizer deserializing the latest version `V` and converting
+/// it into the domain model `D`.
+fn into_domain_finalizer<V, D>(entity: String) -> FinalizeFn
+where
+    V: Versioned + DeserializeOwned + IntoDomain<D>,
+    D: Serialize,
+{
+    Box::new(move |value| {
+        let versioned: V = serde_json::from_value(value).map_err(|e| {
+            MigrationError::DeserializationError(format!(
+                "Failed to deserialize final version of entity '{}': {}",
+                entity, e
+            ))
+        })?;
+
+        let domain = versioned.into_domain();
+
+        serde_json::to_value(domain).map_err(|e| MigrationError::MigrationStepFailed {
+            from: V::VERSION.to_string(),
+            to: "domain".to_string(),
+            error: e.to_string(),
+        })
+    })
+}
+
+/// Like `into_domain_finalizer`, but for a fallible conversion whose errors
+/// are propagated unchanged.
+fn try_into_domain_finalizer<V, D>(entity: String) -> FinalizeFn
+where
+    V: Versioned + DeserializeOwned + TryIntoDomain<D>,
+    D: Serialize,
+{
+    Box::new(move |value| {
+        let versioned: V = serde_json::from_value(value).map_err(|e| {
+            MigrationError::DeserializationError(format!(
+                "Failed to deserialize final version of entity '{}': {}",
+                entity, e
+            ))
+        })?;
+
+        let domain = versioned.try_into_domain()?;
+
+        serde_json::to_value(domain).map_err(|e| MigrationError::MigrationStepFailed {
+            from: V::VERSION.to_string(),
+            to: "domain".to_string(),
+            error: e.to_string(),
+        })
+    })
+}
+
+/// Builds the functions saving domain entities `D` as the latest version `V`,
+/// in the wrapped and the flat format.
+fn domain_savers<V, D>() -> DomainSavers
+where
+    V: Versioned + Serialize + crate::FromDomain<D>,
+    D: DeserializeOwned,
+{
+    let version = V::VERSION;
+
+    let save_fn: DomainSaveFn = Box::new(move |domain_value, vkey, dkey| {
+        let domain: D = serde_json::from_value(domain_value).map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
+        })?;
+
+        let latest = V::from_domain(domain);
+        let data_value = serde_json::to_value(&latest).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize latest: {}", e))
+        })?;
+
+        let mut map = serde_json::Map::new();
+        map.insert(
+            vkey.to_string(),
+            serde_json::Value::String(version.to_string()),
+        );
+        map.insert(dkey.to_string(), data_value);
+
+        Ok(serde_json::Value::Object(map))
+    });
+
+    let save_flat_fn: DomainSaveFlatFn = Box::new(move |domain_value, vkey| {
+        let domain: D = serde_json::from_value(domain_value).map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
+        })?;
+
+        let latest = V::from_domain(domain);
+        let mut data_value = serde_json::to_value(&latest).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize latest: {}", e))
+        })?;
+
+        let obj = data_value.as_object_mut().ok_or_else(|| {
+            MigrationError::SerializationError(
+                "Data must serialize to a JSON object for flat format".to_string(),
+            )
+        })?;
+
+        obj.insert(
+            vkey.to_string(),
+            serde_json::Value::String(version.to_string()),
+        );
+
+        Ok(data_value)
+    });
+
+    DomainSavers {
+        save_fn,
+        save_flat_fn,
+    }
+}
+
+/// JSON pointer to the top-level `key` of an object.
+fn key_pointer(key: &str) -> String {
+    format!("/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Entity name under which the backward path of `entity` is registered.
+fn backward_entity(entity: &str) -> String {
+    format!("{}_backward", entity)
+}
+
+/// Replaces every scalar in `value` with its zero value, keeping the shape.
+fn zero_value(value: &serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => Value::Null,
+        Value::Bool(_) => Value::Bool(false),
+        Value::Number(_) => Value::from(0),
+        Value::String(_) => Value::String(String::new()),
+        Value::Array(_) => Value::Array(Vec::new()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, v)| (key.clone(), zero_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Converts the finalized domain value of `entity` into `D`.
+fn domain_from_value<D: DeserializeOwned>(
+    entity: &str,
+    value: serde_json::Value,
+) -> Result<D, MigrationError> {
+    serde_json::from_value(value).map_err(|e| {
+        MigrationError::DeserializationError(format!(
+            "Failed to convert entity '{}' to domain: {}",
+            entity, e
+        ))
+    })
+}
+
+/// Reads the top-level `id` of a record for its `AuditRecord`.
+fn record_id(data: &serde_json::Value) -> Option<String> {
+    match data.get("id")? {
+        serde_json::Value::String(id) => Some(id.clone()),
+        serde_json::Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+/// Pluralizes an entity name with the regular English suffix rules.
+///
+/// Must match `pluralize` in `version-migrate-macro`, which generates
+/// `QueryableKey::CONFIG_KEY` for `queryable_plural` entities.
+fn pluralize(name: &str) -> String {
+    const SIBILANT_ENDINGS: [&str; 5] = ["s", "x", "z", "ch", "sh"];
+
+    if SIBILANT_ENDINGS.iter().any(|ending| name.ends_with(ending)) {
+        return format!("{}es", name);
+    }
+
+    if let Some(stem) = name.strip_suffix('y') {
+        let after_consonant = stem
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphabetic() && !"aeiou".contains(c));
+        if after_consonant {
+            return format!("{}ies", stem);
+        }
+    }
+
+    format!("{}s", name)
+}
+
+/// Strips a leading UTF-8 byte order mark and leading whitespace.
+///
+/// Some Windows tools prefix exported JSON with a BOM, which `serde_json`
+/// rejects.
+fn strip_bom(input: &str) -> &str {
+    input.strip_prefix('\u{FEFF}').unwrap_or(input).trim_start()
+}
+
+/// Wraps a per-element failure with its array index.
+///
+/// `EntityNotFound` is not element-specific and is passed through unchanged.
+fn element_error(index: usize, error: MigrationError) -> MigrationError {
+    match error {
+        MigrationError::EntityNotFound(_) => error,
+        other => MigrationError::ElementFailed {
+            index,
+            source: Box::new(other),
+        },
+    }
+}
+
+/// Visits a JSON array one element at a time, so the array is never held
+/// in memory as a whole.
+struct RecordSeqVisitor<'a, F> {
+    /// Called with each element's index and value.
+    on_record: F,
+    /// The error that stopped the visit; serde only sees its message.
+    failure: &'a mut Option<MigrationError>,
+}
+
+impl<'de, F> serde::de::Visitor<'de> for RecordSeqVisitor<'_, F>
+where
+    F: FnMut(usize, serde_json::Value) -> Result<(), MigrationError>,
+{
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an array of versioned records")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(mut self, mut seq: A) -> Result<usize, A::Error> {
+        let mut count = 0;
+        while let Some(record) = seq.next_element::<serde_json::Value>()? {
+            if let Err(e) = (self.on_record)(count, record) {
+                let message = e.to_string();
+                *self.failure = Some(element_error(count, e));
+                return Err(serde::de::Error::custom(message));
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Serializes `value` as compact JSON straight into `writer`.
+fn write_json<W: std::io::Write>(
+    writer: W,
+    value: &serde_json::Value,
+) -> Result<(), MigrationError> {
+    serde_json::to_writer(writer, value)
+        .map_err(|e| MigrationError::SerializationError(format!("Failed to write JSON: {}", e)))
+}
+
+/// Serializes `value` as compact JSON and writes it to an async writer.
+#[cfg(feature = "async")]
+async fn write_json_async<W: tokio::io::AsyncWrite + Unpin>(
+    mut writer: W,
+    value: &serde_json::Value,
+) -> Result<(), MigrationError> {
+    use tokio::io::AsyncWriteExt;
+
+    let bytes = serde_json::to_vec(value)
+        .map_err(|e| MigrationError::SerializationError(format!("Failed to write JSON: {}", e)))?;
+    writer
+        .write_all(&bytes)
+        .await
+        .map_err(|e| MigrationError::SerializationError(format!("Failed to write JSON: {}", e)))?;
+    writer
+        .flush()
+        .await
+        .map_err(|e| MigrationError::SerializationError(format!("Failed to write JSON: {}", e)))
+}
+
+/// Type-erased functions for saving domain entities by entity name
+struct DomainSavers {
+    save_fn: DomainSaveFn,
+    save_flat_fn: DomainSaveFlatFn,
+}
+
+/// The migration manager that orchestrates all migrations.
+pub struct Migrator {
+    paths: HashMap<String, EntityMigrationPath>,
+    default_version_key: Option<String>,
+    default_data_key: Option<String>,
+    domain_savers: HashMap<String, DomainSavers>,
+    /// Entity name -> domain constructor used by `load_dynamic`
+    dynamic_domains: HashMap<String, DynamicDomainFn>,
+    limits: Option<LoadLimits>,
+    key_case: Option<KeyCase>,
+    /// Old entity name -> registered entity name
+    entity_aliases: HashMap<String, String>,
+    /// Entity used by `load_default` and `save_default`
+    default_entity: Option<String>,
+    /// Receives a record for every step applied while loading
+    audit_sink: Option<AuditSink>,
+    /// Finalized results of `load`, keyed by entity and exact input
+    #[cfg(feature = "cache")]
+    load_cache: Option<crate::load_cache::LoadCache>,
+}
+
+/// Size and nesting limits applied to JSON strings before they are parsed.
+///
+/// Set with [`Migrator::with_limits`] when loading input from untrusted sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadLimits {
+    /// Maximum input length in bytes.
+    pub max_bytes: usize,
+    /// Maximum nesting depth of objects and arrays (the top-level value is depth 1).
+    pub max_depth: usize,
+}
+
+impl Default for LoadLimits {
+    /// No byte limit and serde_json's own recursion limit (128).
+    fn default() -> Self {
+        Self {
+            max_bytes: usize::MAX,
+            max_depth: 128,
+        }
+    }
+}
+
+/// Receives the [`AuditRecord`]s set up with [`Migrator::with_audit_sink`].
+pub type AuditSink = Arc<dyn Fn(AuditRecord) + Send + Sync>;
+
+/// One migration step applied to a record while it was loaded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
+pub struct AuditRecord {
+    /// Registered entity name.
+    pub entity: String,
+    /// The record's top-level `id` field, if it has a string or number one.
+    pub id: Option<String>,
+    /// Version the step migrated from.
+    pub from: String,
+    /// Version the step migrated to.
+    pub to: String,
+    /// When the step was applied, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u64,
+}
+
+/// A field type conversion applied to raw JSON before a version is deserialized.
+///
+/// Register with [`Migrator::register_type_coercion`]. Values that are not of
+/// the rule's source type, or that cannot be converted (e.g. `"abc"` with
+/// `StringToInt`), are left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CoercionRule {
+    /// `true` → `"true"`
+    BoolToString,
+    /// `"true"` / `"false"` → `true` / `false`
+    StringToBool,
+    /// `42` → `"42"`
+    IntToString,
+    /// `"42"` → `42`
+    StringToInt,
+    /// `"1.5"` → `1.5`
+    StringToFloat,
+    /// `42` → `42.0`
+    IntToFloat,
+}
+
+impl CoercionRule {
+    /// Returns the converted value, or `None` if the rule does not apply.
+    fn apply(self, value: &serde_json::Value) -> Option<serde_json::Value> {
+        use serde_json::Value;
+
+        match (self, value) {
+            (CoercionRule::BoolToString, Value::Bool(b)) => Some(Value::String(b.to_string())),
+            (CoercionRule::StringToBool, Value::String(s)) => match s.trim() {
+                "true" => Some(Value::Bool(true)),
+                "false" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            (CoercionRule::IntToString, Value::Number(n)) if !n.is_f64() => {
+                Some(Value::String(n.to_string()))
+            }
+            (CoercionRule::StringToInt, Value::String(s)) => {
+                let s = s.trim();
+                s.parse::<i64>()
+                    .map(Value::from)
+                    .or_else(|_| s.parse::<u64>().map(Value::from))
+                    .ok()
+            }
+            (CoercionRule::StringToFloat, Value::String(s)) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number),
+            (CoercionRule::IntToFloat, Value::Number(n)) if !n.is_f64() => n
+                .as_f64()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number),
+            _ => None,
+        }
+    }
+}
+
+/// Type-erased version extraction used by [`VersionExtractor::Custom`].
+pub type ExtractVersionFn = Arc<
+    dyn Fn(&serde_json::Value) -> Result<(String, serde_json::Value), MigrationError> + Send + Sync,
+>;
+
+/// Where the stored version and the data are read from when loading.
+///
+/// Register per entity with [`Migrator::set_version_extractor`]. Once set,
+/// `load`, `load_flat` and the other loading methods of the entity all use
+/// it, whatever shape their name implies. Without one, each method reads its
+/// own format.
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum VersionExtractor {
+    /// `{"<version_key>": "...", "<data_key>": {...}}`.
+    Wrapped,
+    /// `{"<version_key>": "...", ...fields}`.
+    Flat,
+    /// Version and data at JSON pointers (RFC 6901); `""` points to the whole
+    /// input. Also what `MigrationPathBuilder::with_version_pointer` and
+    /// `with_data_pointer` register.
+    JsonPointer {
+        /// Pointer to the version string, e.g. `"/meta/schema"`.
+        version: String,
+        /// Pointer to the data, e.g. `"/payload"`.
+        data: String,
+    },
+    /// A closure returning the version string and the data. Registered
+    /// version aliases still apply to the returned version.
+    Custom(ExtractVersionFn),
+}
+
+impl std::fmt::Debug for VersionExtractor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionExtractor::Wrapped => f.write_str("Wrapped"),
+            VersionExtractor::Flat => f.write_str("Flat"),
+            VersionExtractor::JsonPointer { version, data } => f
+                .debug_struct("JsonPointer")
+                .field("version", version)
+                .field("data", data)
+                .finish(),
+            VersionExtractor::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// How "no value" markers in raw JSON are normalized before migration.
+///
+/// Register per entity with [`Migrator::set_null_handling`]. Only top-level
+/// fields of the data are inspected; nested objects are left unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NullHandlingMode {
+    /// Values are passed through unchanged.
+    #[default]
+    StrictNull,
+    /// `""` → `null`
+    EmptyStringAsNone,
+    /// `0` / `0.0` → `null`
+    ZeroAsNone,
+    /// Fields holding `null` or `""` are removed, so they deserialize as if
+    /// they were missing (`None`, or the `#[serde(default)]` value).
+    NullOrEmptyStringAsNone,
+}
+
+impl NullHandlingMode {
+    /// Applies the mode to the top-level fields of `data`.
+    fn apply(self, data: &mut serde_json::Value) {
+        use serde_json::Value;
+
+        let Some(obj) = data.as_object_mut() else {
+            return;
+        };
+        match self {
+            NullHandlingMode::StrictNull => {}
+            NullHandlingMode::EmptyStringAsNone => {
+                for value in obj.values_mut() {
+                    if value.as_str() == Some("") {
+                        *value = Value::Null;
+                    }
+                }
+            }
+            NullHandlingMode::ZeroAsNone => {
+                for value in obj.values_mut() {
+                    if value.as_f64() == Some(0.0) {
+                        *value = Value::Null;
+                    }
+                }
+            }
+            NullHandlingMode::NullOrEmptyStringAsNone => {
+                obj.retain(|_, value| !value.is_null() && value.as_str() != Some(""));
+            }
+        }
+    }
+}
+
+/// A rewrite of JSON object keys applied before each version is deserialized.
+///
+/// Set with [`Migrator::with_key_case`]. Keys are rewritten recursively,
+/// including objects nested in arrays and the keys of map fields (such as a
+/// `HashMap<String, _>`), not only struct field names. Two keys of one object
+/// that convert to the same key (`userId` and `user_id`) are rejected with
+/// `DeserializationError` rather than one overwriting the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyCase {
+    /// `userId` → `user_id`
+    CamelToSnake,
+    /// `user_id` → `userId`
+    SnakeToCamel,
+}
+
+impl KeyCase {
+    /// Rewrites every object key in `value`, failing if two keys of one
+    /// object convert to the same key.
+    fn apply(self, value: &mut serde_json::Value) -> Result<(), MigrationError> {
+        use serde_json::Value;
+
+        match value {
+            Value::Object(map) => {
+                let entries = std::mem::take(map);
+                let mut sources: HashMap<String, String> = HashMap::with_capacity(entries.len());
+                for (key, mut child) in entries {
+                    self.apply(&mut child)?;
+                    let converted = self.convert(&key);
+                    if let Some(other) = sources.insert(converted.clone(), key.clone()) {
+                        return Err(MigrationError::DeserializationError(format!(
+                            "Keys '{}' and '{}' both map to '{}' under {:?}",
+                            other, key, converted, self
+                        )));
+                    }
+                    map.insert(converted, child);
+                }
+                Ok(())
+            }
+            Value::Array(items) => items.iter_mut().try_for_each(|item| self.apply(item)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Converts a single key.
+    fn convert(self, key: &str) -> String {
+        match self {
+            KeyCase::CamelToSnake => camel_to_snake(key),
+            KeyCase::SnakeToCamel => snake_to_camel(key),
+        }
+    }
+}
+
+/// `userId` → `user_id`, `HTTPServer` → `http_server`.
+fn camel_to_snake(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    let mut out = String::with_capacity(key.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+                _ => false,
+            };
+            if boundary {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// `user_id` → `userId`. Leading underscores are kept.
+fn snake_to_camel(key: &str) -> String {
+    let trimmed = key.trim_start_matches('_');
+    let mut out = String::with_capacity(key.len());
+    out.push_str(&key[..key.len() - trimmed.len()]);
+    let mut upper = false;
+    for c in trimmed.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Key under which `save_with_encoding_hint` records how the payload is encoded.
+#[cfg(feature = "compression")]
+const ENCODING_KEY: &str = "_encoding";
+
+/// How `save_with_encoding_hint` encodes the data payload.
+///
+/// Compressed payloads are stored base64-encoded inside the JSON wrapper, so
+/// the output stays valid JSON and the version remains readable without
+/// decompressing.
+///
+/// Requires the `compression` feature.
+#[cfg(feature = "compression")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingHint {
+    /// The data is stored as plain JSON.
+    PlainJson,
+    /// The data is gzip-compressed JSON.
+    GzipJson,
+    /// The data is zstd-compressed JSON.
+    ZstdJson,
+}
+
+#[cfg(feature = "compression")]
+impl EncodingHint {
+    /// The value written under the `_encoding` key.
+    fn as_str(self) -> &'static str {
+        match self {
+            EncodingHint::PlainJson => "json",
+            EncodingHint::GzipJson => "gzip",
+            EncodingHint::ZstdJson => "zstd",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "json" => Some(EncodingHint::PlainJson),
+            "gzip" => Some(EncodingHint::GzipJson),
+            "zstd" => Some(EncodingHint::ZstdJson),
+            _ => None,
+        }
+    }
+
+    fn compress(self, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+        use std::io::Write;
+
+        match self {
+            EncodingHint::PlainJson => Ok(bytes.to_vec()),
+            EncodingHint::GzipJson => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(bytes)?;
+                encoder.finish()
+            }
+            EncodingHint::ZstdJson => zstd::encode_all(bytes, 0),
+        }
+    }
+
+    /// Decompresses `bytes`, reading at most `limit` bytes of output.
+    fn decompress(self, bytes: &[u8], limit: u64) -> std::io::Result<Vec<u8>> {
+        use std::io::Read;
+
+        let mut out = Vec::new();
+        match self {
+            EncodingHint::PlainJson => out.extend_from_slice(bytes),
+            EncodingHint::GzipJson => {
+                flate2::read::GzDecoder::new(bytes)
+                    .take(limit)
+                    .read_to_end(&mut out)?;
+            }
+            EncodingHint::ZstdJson => {
+                zstd::stream::read::Decoder::new(bytes)?
+                    .take(limit)
+                    .read_to_end(&mut out)?;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Migrator {
+    /// Creates a new, empty migrator.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new, empty migrator with room for `n` entities.
+    ///
+    /// Avoids rehashing when many entities are registered at startup.
+    pub fn with_capacity(n: usize) -> Self {
+        Self {
+            paths: HashMap::with_capacity(n),
+            default_version_key: None,
+            default_data_key: None,
+            domain_savers: HashMap::with_capacity(n),
+            dynamic_domains: HashMap::new(),
+            limits: None,
+            key_case: None,
+            entity_aliases: HashMap::new(),
+            default_entity: None,
+            audit_sink: None,
+            #[cfg(feature = "cache")]
+            load_cache: None,
+        }
+    }
+
+    /// Returns the number of registered entities.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns `true` if no entity is registered.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Applies size and nesting limits to every JSON string passed to the
+    /// string-based `load*` methods.
+    ///
+    /// Inputs are checked before parsing, so oversized or over-nested payloads
+    /// are rejected without being deserialized.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let migrator = migrator.with_limits(LoadLimits {
+    ///     max_bytes: 64 * 1024,
+    ///     max_depth: 32,
+    /// });
+    /// let task: TaskEntity = migrator.load("task", untrusted_json)?;
+    /// ```
+    pub fn with_limits(mut self, limits: LoadLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Caches the results of `load` in a bounded LRU cache.
+    ///
+    /// Entries are keyed by the entity name and the exact input string and
+    /// hold the finalized domain value, so loading an identical blob again
+    /// skips migration entirely. At most `capacity` entries are kept; the
+    /// least recently used one is evicted first. Registering paths or changing
+    /// per-entity settings clears the cache.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let migrator = migrator.with_load_cache(1024);
+    /// let first: TaskEntity = migrator.load("task", &json)?;  // migrates
+    /// let second: TaskEntity = migrator.load("task", &json)?; // cache hit
+    /// ```
+    #[cfg(feature = "cache")]
+    pub fn with_load_cache(mut self, capacity: usize) -> Self {
+        self.load_cache = Some(crate::load_cache::LoadCache::new(capacity));
+        self
+    }
+
+    /// Drops every cached `load` result.
+    #[cfg(feature = "cache")]
+    pub fn clear_load_cache(&self) {
+        self.invalidate_load_cache();
+    }
+
+    /// Number of `load` results currently cached; `0` without a cache.
+    #[cfg(feature = "cache")]
+    pub fn load_cache_len(&self) -> usize {
+        self.load_cache.as_ref().map_or(0, |cache| cache.len())
+    }
+
+    /// Rewrites object keys of loaded data before each version is
+    /// deserialized.
+    ///
+    /// Lets DTOs keep Rust field names while the stored data uses another
+    /// naming convention, without `#[serde(rename_all)]` on every version.
+    /// Applies to entities registered before and after this call. Off by
+    /// default. Keys of map fields are rewritten too, and loading fails with
+    /// `DeserializationError` when two keys of one object collide after the
+    /// rewrite; see [`KeyCase`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let migrator = migrator.with_key_case(KeyCase::CamelToSnake);
+    /// // {"data": {"userId": "u1"}} loads into `TaskV1 { user_id }`
+    /// let task: TaskEntity = migrator.load("task", &json)?;
+    /// ```
+    pub fn with_key_case(mut self, case: KeyCase) -> Self {
+        self.key_case = Some(case);
+        self.invalidate_load_cache();
+        for path in self.paths.values_mut() {
+            path.options.key_case = Some(case);
+        }
+        self
+    }
+
+    /// Sends an `AuditRecord` to `sink` for every migration step applied
+    /// while loading.
+    ///
+    /// Records are structured and serializable, so they can be persisted as
+    /// an audit trail of which records were upgraded and when. Applies to
+    /// entities registered before and after this call. Loads answered from
+    /// the `load` cache apply no steps and produce no records.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let log = audit_log.clone();
+    /// let migrator = migrator.with_audit_sink(Arc::new(move |record: AuditRecord| {
+    ///     log.append(&serde_json::to_string(&record).unwrap());
+    /// }));
+    /// let task: TaskEntity = migrator.load("task", &json)?;
+    /// ```
+    pub fn with_audit_sink(mut self, sink: AuditSink) -> Self {
+        for (entity, path) in self.paths.iter_mut() {
+            path.options.audit = Some(EntityAudit {
+                entity: entity.clone(),
+                sink: Arc::clone(&sink),
+            });
+        }
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    /// Parses string input after stripping a leading BOM and applying the
+    /// load limits. `what` names the expected input in the error message.
+    fn parse_json<T: DeserializeOwned>(&self, json: &str, what: &str) -> Result<T, MigrationError> {
+        let json = strip_bom(json);
+        self.check_limits(json)?;
+        serde_json::from_str(json).map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to parse {}: {}", what, e))
+        })
+    }
+
+    /// Like `parse_json`, for JSON5 text.
+    #[cfg(feature = "json5")]
+    fn parse_json5<T: DeserializeOwned>(&self, text: &str) -> Result<T, MigrationError> {
+        let text = strip_bom(text);
+        self.check_limits(text)?;
+        json5::from_str(text).map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to parse JSON5: {}", e))
+        })
+    }
+
+    /// Checks `json` against the configured `LoadLimits`, if any.
+    fn check_limits(&self, json: &str) -> Result<(), MigrationError> {
+        let Some(limits) = self.limits else {
+            return Ok(());
+        };
+
+        if json.len() > limits.max_bytes {
+            return Err(MigrationError::InputTooLarge {
+                size: json.len(),
+                max_bytes: limits.max_bytes,
+            });
+        }
+
+        // Track object/array nesting, ignoring brackets inside strings.
+        let mut depth = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        for byte in json.bytes() {
+            if in_string {
+                match byte {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match byte {
+                b'"' => in_string = true,
+                b'{' | b'[' => {
+                    depth += 1;
+                    if depth > limits.max_depth {
+                        return Err(MigrationError::TooDeep {
+                            max_depth: limits.max_depth,
+                        });
+                    }
+                }
+                b'}' | b']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers field type coercions for one version of an entity.
+    ///
+    /// Before data at `version` is migrated (or finalized, for the latest
+    /// version), each listed top-level field is converted according to its
+    /// rule. This lets files whose field type disagrees with the version's
+    /// struct (e.g. `"active": "true"` for a `bool` field) still load.
+    /// Registering again for the same version replaces the previous rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered, or
+    /// `MigrationPathNotDefined` if `version` is not part of its chain.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_type_coercion(
+    ///     "task",
+    ///     "1.0.0",
+    ///     HashMap::from([("done".to_string(), CoercionRule::StringToBool)]),
+    /// )?;
+    /// ```
+    pub fn register_type_coercion(
+        &mut self,
+        entity: &str,
+        version: &str,
+        coercions: HashMap<String, CoercionRule>,
+    ) -> Result<(), MigrationError> {
+        let path = self.path_mut(entity)?;
+
+        if !path.knows_version(version) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: version.to_string(),
+            });
+        }
+
+        path.options
+            .coercions
+            .insert(version.to_string(), coercions);
+        Ok(())
+    }
+
+    /// Fills fields missing from stored data of version `V` with the values
+    /// of `V::default()`.
+    ///
+    /// Before data at `V::VERSION` is migrated (or finalized, for the latest
+    /// version), each top-level field of the serialized default that the data
+    /// lacks is added, so records written by an older producer that omitted a
+    /// required, non-`Option` field still deserialize. Fields present in the
+    /// data, including explicit `null`s, are kept. Registering again for the
+    /// same version replaces the previous defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered,
+    /// `MigrationPathNotDefined` if `V::VERSION` is not part of its chain, or
+    /// `SerializationError` if `V::default()` does not serialize to a JSON
+    /// object.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // TaskV1 derives Default; stored V1 data without "priority" now loads
+    /// migrator.register_default_fill::<TaskV1>("task")?;
+    /// let task: TaskEntity = migrator.load("task", &json)?;
+    /// ```
+    pub fn register_default_fill<V>(&mut self, entity: &str) -> Result<(), MigrationError>
+    where
+        V: Versioned + Default + Serialize,
+    {
+        let defaults = match serde_json::to_value(V::default()) {
+            Ok(serde_json::Value::Object(defaults)) => defaults,
+            Ok(_) => {
+                return Err(MigrationError::SerializationError(format!(
+                    "Default of version {} of entity '{}' is not a JSON object",
+                    V::VERSION,
+                    entity
+                )))
+            }
+            Err(e) => {
+                return Err(MigrationError::SerializationError(format!(
+                    "Failed to serialize default of version {} of entity '{}': {}",
+                    V::VERSION,
+                    entity,
+                    e
+                )))
+            }
+        };
+
+        let path = self.path_mut(entity)?;
+        if !path.knows_version(V::VERSION) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: V::VERSION.to_string(),
+            });
+        }
+
+        path.options
+            .defaults
+            .insert(V::VERSION.to_string(), defaults);
+        Ok(())
+    }
+
+    /// Adds a migration step from version `V` to `Next` alongside the linear
+    /// chain of `entity`, turning its path into a directed graph of versions.
+    ///
+    /// Branches let versions diverge and converge again: with a chain
+    /// `1.0.0 -> 1.1.0 -> 3.0.0`, registering `1.0.0 -> 2.0.0` and
+    /// `2.0.0 -> 3.0.0` lets data stored at `2.0.0` load as well. Loading
+    /// follows the shortest route from the stored version to the latest one,
+    /// preferring the linear chain when routes are equally long. A branch to
+    /// the chain's next version replaces that step. Registering the same edge
+    /// again replaces the previous step.
+    ///
+    /// Branch versions are accepted wherever a stored version is checked
+    /// (`can_load`, `load_forward`, coercions, aliases, ...). Nested
+    /// collections and `save_all_versions` only follow the linear chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered,
+    /// `FrozenVersion` if `V` is frozen, `CircularMigrationPath` if `Next`
+    /// already leads back to `V`, or `InvalidVersionOrder` if `Next` is not
+    /// newer than `V`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_branch::<TaskV1_0_0, TaskV2_0_0>("task")?;
+    /// migrator.register_branch::<TaskV2_0_0, TaskV3_0_0>("task")?;
+    /// let task: TaskEntity = migrator.load("task", &v2_json)?;
+    /// ```
+    pub fn register_branch<V, Next>(&mut self, entity: &str) -> Result<(), MigrationError>
+    where
+        V: Versioned + DeserializeOwned + MigratesTo<Next>,
+        Next: Versioned + Serialize,
+    {
+        let path = self.path_mut(entity)?;
+        let edge = [V::VERSION.to_string(), Next::VERSION.to_string()];
+        let frozen: Vec<String> = V::FROZEN.then(|| edge[0].clone()).into_iter().collect();
+        Self::check_frozen(entity, &edge, &frozen)?;
+
+        let cycle = if V::VERSION == Next::VERSION {
+            Some(vec![Next::VERSION.to_string()])
+        } else {
+            path.route(Next::VERSION, V::VERSION)
+        };
+        if let Some(route) = cycle {
+            return Err(MigrationError::CircularMigrationPath {
+                entity: entity.to_string(),
+                path: format!("{} -> {}", V::VERSION, route.join(" -> ")),
+            });
+        }
+        Self::check_version_ordering(entity, &edge)?;
+
+        let owner = entity.to_string();
+        let step: MigrationFn = Arc::new(move |value| {
+            let from_value: V = serde_json::from_value(value).map_err(|e| {
+                MigrationError::DeserializationError(format!(
+                    "Failed to deserialize version {} of entity '{}': {}",
+                    V::VERSION,
+                    owner,
+                    e
+                ))
+            })?;
+
+            serde_json::to_value(from_value.migrate()).map_err(|e| {
+                MigrationError::MigrationStepFailed {
+                    from: V::VERSION.to_string(),
+                    to: Next::VERSION.to_string(),
+                    error: e.to_string(),
+                }
+            })
+        });
+
+        path.options
+            .branches
+            .entry(V::VERSION.to_string())
+            .or_default()
+            .insert(Next::VERSION.to_string(), step);
+        Ok(())
+    }
+
+    /// Limits how long each migration step of `entity` may run in `load_async`.
+    ///
+    /// Steps exceeding the limit fail with `MigrationError::Timeout`. The
+    /// synchronous `load` family is not affected. Registering again replaces
+    /// the previous limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_migration_timeout("task", Duration::from_secs(5))?;
+    /// let task: TaskEntity = migrator.load_async("task", &json).await?;
+    /// ```
+    pub fn register_migration_timeout(
+        &mut self,
+        entity: &str,
+        timeout: std::time::Duration,
+    ) -> Result<(), MigrationError> {
+        let path = self.path_mut(entity)?;
+
+        path.options.timeout = Some(timeout);
+        Ok(())
+    }
+
+    /// Marks `version` as the tombstone of `entity`.
+    ///
+    /// Records stored with this version are logically deleted: they are
+    /// skipped by `ConfigMigrator::query_live`. The tombstone does not need to
+    /// be part of the migration chain, and other loaders treat it like any
+    /// other unknown version. Registering again replaces the previous
+    /// tombstone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_tombstone_version("task", "deleted")?;
+    /// let live: Vec<TaskEntity> = config.query_live("tasks")?;
+    /// ```
+    pub fn register_tombstone_version(
+        &mut self,
+        entity: &str,
+        version: impl Into<String>,
+    ) -> Result<(), MigrationError> {
+        let path = self.path_mut(entity)?;
+
+        path.options.tombstone = Some(version.into());
+        Ok(())
+    }
+
+    /// Sets how empty values in raw data of `entity` are normalized before
+    /// migration (see [`NullHandlingMode`]). The default is `StrictNull`.
+    /// Setting again replaces the previous mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.set_null_handling("task", NullHandlingMode::EmptyStringAsNone)?;
+    /// // {"description": ""} now loads as `description: None`
+    /// let task: TaskEntity = migrator.load("task", &json)?;
+    /// ```
+    pub fn set_null_handling(
+        &mut self,
+        entity: &str,
+        mode: NullHandlingMode,
+    ) -> Result<(), MigrationError> {
+        let path = self.path_mut(entity)?;
+
+        path.options.null_handling = mode;
+        Ok(())
+    }
+
+    /// Sets where the version and data of `entity` are read from when
+    /// loading (see [`VersionExtractor`]). Setting again replaces the
+    /// previous extractor, including one installed by the path's
+    /// `with_version_pointer` / `with_data_pointer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered, or
+    /// `InvalidPathConfig` if a `JsonPointer` pointer is neither empty nor
+    /// starts with `/`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.set_version_extractor(
+    ///     "task",
+    ///     VersionExtractor::JsonPointer {
+    ///         version: "/meta/schema".to_string(),
+    ///         data: "/payload".to_string(),
+    ///     },
+    /// )?;
+    /// // `load` and `load_flat` now both read {"meta": {"schema": ...}, "payload": {...}}
+    /// let task: TaskEntity = migrator.load("task", &json)?;
+    /// ```
+    pub fn set_version_extractor(
+        &mut self,
+        entity: &str,
+        extractor: VersionExtractor,
+    ) -> Result<(), MigrationError> {
+        let path = self.path_mut(entity)?;
+        if let VersionExtractor::JsonPointer { version, data } = &extractor {
+            Self::check_pointers(entity, [version, data])?;
+        }
+
+        path.options.extractor = Some(extractor);
+        Ok(())
+    }
+
+    /// Maps a stored version of `entity` onto a registered version.
+    ///
+    /// Applies to string versions and to numeric ones, which are matched by
+    /// their JSON string form (`2` → `"2"`, `2.5` → `"2.5"`). Without an
+    /// alias, numeric versions fail to load with an error naming the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is not registered, or
+    /// `MigrationPathNotDefined` if `version` is not part of its chain.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_version_alias("task", "2", "2.0.0")?;
+    /// // {"version": 2, "data": {...}} now loads as version 2.0.0
+    /// let task: TaskEntity = migrator.load("task", &json)?;
+    /// ```
+    pub fn register_version_alias(
+        &mut self,
+        entity: &str,
+        alias: impl Into<String>,
+        version: &str,
+    ) -> Result<(), MigrationError> {
+        let path = self.path_mut(entity)?;
+        if !path.knows_version(version) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: version.to_string(),
+            });
+        }
+
+        path.options
+            .version_aliases
+            .insert(alias.into(), version.to_string());
+        Ok(())
+    }
+
+    /// Routes a former entity name to a registered entity.
+    ///
+    /// After an entity is renamed, data and call sites still using
+    /// `old_name` load through `new_name`'s migration path. Every lookup by
+    /// entity name (`load`, `load_flat`, `save_domain`, ...) follows the
+    /// alias; registration methods such as `set_null_handling` take the
+    /// registered name only.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidEntityName` if `old_name` is empty or whitespace-only,
+    /// `EntityNotFound` if `new_name` is not registered, or
+    /// `EntityAlreadyRegistered` if `old_name` is itself a registered entity.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register(Migrator::define("work_item").from::<TaskV1>().into::<WorkItem>())?;
+    /// migrator.register_entity_alias("task", "work_item")?;
+    /// let item: WorkItem = migrator.load("task", &json)?;
+    /// ```
+    pub fn register_entity_alias(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<(), MigrationError> {
+        Self::check_entity_name(old_name)?;
+        if !self.paths.contains_key(new_name) {
+            return Err(MigrationError::EntityNotFound(new_name.to_string()));
+        }
+        if self.paths.contains_key(old_name) {
+            return Err(MigrationError::EntityAlreadyRegistered(
+                old_name.to_string(),
+            ));
+        }
+
+        self.entity_aliases
+            .insert(old_name.to_string(), new_name.to_string());
+        Ok(())
+    }
+
+    /// Sets the entity used by `load_default` and `save_default`.
+    ///
+    /// Meant for single-entity applications, where naming the entity on
+    /// every call is noise. Setting it again replaces the previous default.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if `entity` is neither registered nor an alias.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.set_default_entity("task")?;
+    /// let task: TaskEntity = migrator.load_default(&json)?;
+    /// ```
+    pub fn set_default_entity(&mut self, entity: &str) -> Result<(), MigrationError> {
+        if self.entity_path(entity).is_none() {
+            return Err(MigrationError::EntityNotFound(entity.to_string()));
+        }
+
+        self.default_entity = Some(entity.to_string());
+        Ok(())
+    }
+
+    /// Returns the entity set by `set_default_entity`.
+    fn default_entity(&self) -> Result<&str, MigrationError> {
+        self.default_entity
+            .as_deref()
+            .ok_or(MigrationError::NoDefaultEntity)
+    }
+
+    /// Looks up the registered migration path of `entity` for modification.
+    ///
+    /// Cached load results are dropped, since they may no longer match.
+    fn path_mut(&mut self, entity: &str) -> Result<&mut EntityMigrationPath, MigrationError> {
+        self.invalidate_load_cache();
+        self.paths
+            .get_mut(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))
+    }
+
+    /// Drops cached `load` results after the registered paths change.
+    fn invalidate_load_cache(&self) {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.load_cache {
+            cache.clear();
+        }
+    }
+
+    /// Looks up the migration path of `entity`, following entity aliases.
+    fn entity_path(&self, entity: &str) -> Option<&EntityMigrationPath> {
+        self.paths.get(entity).or_else(|| {
+            self.entity_aliases
+                .get(entity)
+                .and_then(|name| self.paths.get(name))
+        })
+    }
+
+    /// Delegates migration of a collection field of `parent` to the
+    /// registered path of `child`.
+    ///
+    /// `versions` maps parent versions to the child version their `field`
+    /// items are stored at. After each parent step between two mapped
+    /// versions, `field` in the step's output is replaced with the input's
+    /// items migrated through the child's steps, so the parent's
+    /// `MigratesTo` impl can leave the field empty. Items are plain child
+    /// data, not wrapped with a version. Registering the same `field` of
+    /// `parent` again replaces the earlier delegation.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The entity holding the collection
+    /// * `field` - The top-level field holding the array of child items
+    /// * `child` - The entity whose steps migrate each item
+    /// * `versions` - `(parent_version, child_version)` pairs
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `parent` or `child` is not registered (`EntityNotFound`)
+    /// - A version is not part of its entity's chain (`MigrationPathNotDefined`)
+    /// - The child versions decrease along the parent chain (`InvalidVersionOrder`)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // ConfigV1 { items: Vec<ItemV1> } -> ConfigV2 { items: Vec<ItemV2> }
+    /// impl MigratesTo<ConfigV2> for ConfigV1 {
+    ///     fn migrate(self) -> ConfigV2 {
+    ///         ConfigV2 { name: self.name, items: Vec::new() } // filled by the migrator
+    ///     }
+    /// }
+    ///
+    /// migrator.register_nested_collection(
+    ///     "config",
+    ///     "items",
+    ///     "item",
+    ///     &[("1.0.0", "1.0.0"), ("2.0.0", "2.0.0")],
+    /// )?;
+    /// ```
+    pub fn register_nested_collection(
+        &mut self,
+        parent: &str,
+        field: &str,
+        child: &str,
+        versions: &[(&str, &str)],
+    ) -> Result<(), MigrationError> {
+        let child_path = self
+            .paths
+            .get(child)
+            .ok_or_else(|| MigrationError::EntityNotFound(child.to_string()))?;
+        let parent_path = self
+            .paths
+            .get(parent)
+            .ok_or_else(|| MigrationError::EntityNotFound(parent.to_string()))?;
+
+        let position = |chain: &[String], entity: &str, version: &str| {
+            chain.iter().position(|v| v == version).ok_or_else(|| {
+                MigrationError::MigrationPathNotDefined {
+                    entity: entity.to_string(),
+                    version: version.to_string(),
+                }
+            })
+        };
+        let mut mapped = Vec::with_capacity(versions.len());
+        for &(parent_version, child_version) in versions {
+            mapped.push((
+                position(&parent_path.versions, parent, parent_version)?,
+                position(&child_path.versions, child, child_version)?,
+            ));
+        }
+        mapped.sort_unstable();
+        for pair in mapped.windows(2) {
+            let ((_, from), (_, to)) = (pair[0], pair[1]);
+            if to < from {
+                return Err(MigrationError::InvalidVersionOrder {
+                    entity: child.to_string(),
+                    from: child_path.versions[from].clone(),
+                    to: child_path.versions[to].clone(),
+                });
+            }
+        }
+
+        let collection = ChildCollection {
+            field: field.to_string(),
+            entity: child.to_string(),
+            versions: versions
+                .iter()
+                .map(|(p, c)| (p.to_string(), c.to_string()))
+                .collect(),
+            steps: child_path.steps.clone(),
+            chain: child_path.versions.clone(),
+        };
+        self.invalidate_load_cache();
+        if let Some(path) = self.paths.get_mut(parent) {
+            let children = &mut path.options.children;
+            match children.iter_mut().find(|c| c.field == collection.field) {
+                Some(existing) => *existing = collection,
+                None => children.push(collection),
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the latest version for a given entity.
+    ///
+    /// # Returns
+    ///
+    /// The latest version string if the entity is registered, `None` otherwise.
+    pub fn get_latest_version(&self, entity: &str) -> Option<&str> {
+        self.entity_path(entity)
+            .and_then(|path| path.versions.last())
+            .map(|v| v.as_str())
+    }
+
+    /// Describes every registered entity, sorted by entity name.
+    ///
+    /// The result is plain data, suitable for logging or JSON export.
+    /// Backward paths registered with `register_bidirectional` appear under
+    /// their `"<entity>_backward"` name.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for entity in migrator.inspect_registered_entities() {
+    ///     tracing::info!(?entity, "registered migration path");
+    /// }
+    /// ```
+    pub fn inspect_registered_entities(&self) -> Vec<EntityInspection> {
+        let mut inspections: Vec<EntityInspection> = self
+            .paths
+            .iter()
+            .map(|(name, path)| EntityInspection {
+                entity_name: name.clone(),
+                version_chain: path.versions.clone(),
+                version_key: path.version_key.clone(),
+                data_key: path.data_key.clone(),
+                has_domain_saver: self.domain_savers.contains_key(name),
+                step_count: path.steps.len(),
+            })
+            .collect();
+        inspections.sort_by(|a, b| a.entity_name.cmp(&b.entity_name));
+        inspections
+    }
+
+    /// Lists the registered entities whose version chain contains the
+    /// version stored in `json`, sorted by entity name.
+    ///
+    /// Meant for input that carries no entity tag: each entity reads the
+    /// version with its own keys or extractor (wrapped format first, then
+    /// flat), and version aliases and semver ranges are resolved as when
+    /// loading. Backward paths registered with `register_bidirectional`
+    /// appear under their `"<entity>_backward"` name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeserializationError` if `json` cannot be parsed, or the
+    /// `LoadLimits` error if it exceeds the configured limits.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// match migrator.candidate_entities(&blob)?.as_slice() {
+    ///     [entity] => import(migrator.load::<serde_json::Value>(entity, &blob)?),
+    ///     candidates => ask_user(candidates),
+    /// }
+    /// ```
+    pub fn candidate_entities(&self, json: &str) -> Result<Vec<&str>, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let mut candidates: Vec<&str> = self
+            .paths
+            .iter()
+            .filter(|(_, path)| {
+                path.extract_wrapped(&value)
+                    .or_else(|_| path.extract_flat(value.clone()))
+                    .is_ok_and(|(version, _)| path.knows_version(&path.resolve_version(version)))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+        candidates.sort_unstable();
+        Ok(candidates)
+    }
+
+    /// Asserts that `T` is the latest registered version of `entity`.
+    ///
+    /// Intended for test setup, to catch a migrator whose chain was not
+    /// extended after adding a new version type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` is not registered or its latest version is not
+    /// `T::VERSION`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.assert_latest_version_matches::<TaskV1_1_0>("task");
+    /// ```
+    pub fn assert_latest_version_matches<T: Versioned>(&self, entity: &str) {
+        match self.get_latest_version(entity) {
+            Some(latest) => assert_eq!(
+                latest,
+                T::VERSION,
+                "latest version of entity '{}' is '{}', expected '{}'",
+                entity,
+                latest,
+                T::VERSION
+            ),
+            None => panic!("entity '{}' is not registered", entity),
+        }
+    }
+
+    /// Asserts that the registered chain of `entity` is strictly ascending.
+    ///
+    /// Intended for test setup.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` is not registered, a version is not valid semver,
+    /// or the versions are not in strictly increasing order.
+    pub fn assert_migration_chain_order(&self, entity: &str) {
+        let path = self
+            .entity_path(entity)
+            .unwrap_or_else(|| panic!("entity '{}' is not registered", entity));
+
+        if let Err(e) = Self::check_version_ordering(entity, &path.versions) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Creates a builder for configuring the migrator.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let migrator = Migrator::builder()
+    ///     .default_version_key("schema_version")
+    ///     .default_data_key("payload")
+    ///     .build();
+    /// ```
+    pub fn builder() -> MigratorBuilder {
+        MigratorBuilder::new()
+    }
+
+    /// Starts defining a migration path for an entity.
+    ///
+    /// The entity name may be a `&str`, a `String` or a `&String`, so names
+    /// built at runtime can be passed without borrowing.
+    /// Empty or whitespace-only names are rejected when the path is
+    /// registered.
+    pub fn define(entity: impl Into<String>) -> MigrationPathBuilder<Start> {
+        MigrationPathBuilder::new(entity.into())
+    }
+
+    /// Composes two migrators into a two-stage pipeline.
+    ///
+    /// `first` migrates input to its domain type, which must be the first
+    /// version of the same entity's chain in `second` (so it implements
+    /// `Versioned + Serialize`). See [`ChainedMigrator::load`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let pipeline = Migrator::chain_migrators(ingest, storage);
+    /// let record: StoredRecord = pipeline.load("record", raw_json)?;
+    /// ```
+    pub fn chain_migrators(first: Migrator, second: Migrator) -> ChainedMigrator {
+        ChainedMigrator { first, second }
+    }
+
+    /// Bundles a forward path and a backward (downgrade) path for
+    /// [`register_bidirectional`](Self::register_bidirectional).
+    ///
+    /// `backward` should start at the forward path's latest version and step
+    /// down to `Old`. Its entity name is ignored.
+    pub fn bidirectional<D, Old>(
+        forward: MigrationPath<D>,
+        backward: MigrationPath<Old>,
+    ) -> BidirectionalPath<D, Old>
+    where
+        D: DeserializeOwned + Serialize,
+        Old: Versioned + DeserializeOwned + IntoDomain<D>,
+    {
+        BidirectionalPath { forward, backward }
+    }
+
+    /// Registers a migration path with validation.
+    ///
+    /// This method validates the migration path before registering it:
+    /// - Checks for circular migration paths
+    /// - Validates version ordering follows semver rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails.
+    pub fn register<D>(&mut self, mut path: MigrationPath<D>) -> Result<(), MigrationError> {
+        Self::validate_path(&mut path)?;
+        self.insert_path(path);
+        Ok(())
+    }
+
+    /// Registers an entity that has a single version and no migration history.
+    ///
+    /// Shorthand for `register(Migrator::define(entity).from::<V>().into::<D>())`.
+    /// See also the [`passthrough!`](crate::passthrough) macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails, as with `register`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_passthrough::<SettingsV1, Settings>("settings")?;
+    /// let settings: Settings = migrator.load("settings", &json)?;
+    /// ```
+    pub fn register_passthrough<V, D>(&mut self, entity: &str) -> Result<(), MigrationError>
+    where
+        V: Versioned + DeserializeOwned + IntoDomain<D>,
+        D: DeserializeOwned + Serialize,
+    {
+        self.register(Self::define(entity).from::<V>().into::<D>())
+    }
+
+    /// Registers a migration path whose domain type can be loaded without
+    /// naming it, via [`load_dynamic`](Self::load_dynamic).
+    ///
+    /// Same as `register`, but also keeps a type-erased constructor for `D`,
+    /// for plugin hosts where the entity → domain type mapping is only known
+    /// at runtime. Registering the entity again with `register` drops it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails, as with `register`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_dynamic(Migrator::define("task").from::<TaskV1>().into::<TaskEntity>())?;
+    /// let task = migrator.load_dynamic("task", &json)?;
+    /// let task: Box<TaskEntity> = task.downcast().unwrap();
+    /// ```
+    pub fn register_dynamic<D>(&mut self, path: MigrationPath<D>) -> Result<(), MigrationError>
+    where
+        D: DeserializeOwned + 'static,
+    {
+        let entity = path.entity.clone();
+        self.register(path)?;
+
+        let name = entity.clone();
+        self.dynamic_domains.insert(
+            entity,
+            Box::new(move |value| {
+                domain_from_value::<D>(&name, value)
+                    .map(|domain| Box::new(domain) as Box<dyn std::any::Any>)
+            }),
+        );
+        Ok(())
+    }
+
+    /// Registers a migration path after checking that `sample` migrates
+    /// through it.
+    ///
+    /// In debug builds, `sample` (data of the path's first version, without a
+    /// wrapper) is run through every migration step and the final domain
+    /// conversion. In release builds the smoke test is skipped and this is
+    /// equivalent to [`register`](Self::register).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails, as with `register`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics with the entity, first version and underlying
+    /// error if `sample` fails to reach the domain model.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_with_smoke_test(path, json!({"id": "t1", "title": "Task"}))?;
+    /// ```
+    pub fn register_with_smoke_test<D>(
+        &mut self,
+        mut path: MigrationPath<D>,
+        sample: serde_json::Value,
+    ) -> Result<(), MigrationError> {
+        Self::validate_path(&mut path)?;
+
+        if cfg!(debug_assertions) {
+            Self::smoke_test(&path, sample);
+        }
+
+        self.insert_path(path);
+        Ok(())
+    }
+
+    /// Migrates `sample` from the first version of `path` to the domain
+    /// model, panicking on failure.
+    fn smoke_test<D>(path: &MigrationPath<D>, sample: serde_json::Value) {
+        let Some(first) = path.versions.first() else {
+            return;
+        };
+
+        let result = path
+            .inner
+            .migrate_steps(first.clone(), sample)
+            .and_then(|(_, data)| (path.inner.finalize)(data));
+
+        if let Err(e) = result {
+            panic!(
+                "Smoke test failed for entity '{}': sample at version {} did not migrate to the domain model: {}",
+                path.entity, first, e
+            );
+        }
+    }
+
+    /// Registers a forward and a backward migration path in one call.
+    ///
+    /// The forward path is registered under its own entity name and validated
+    /// like [`register`](Self::register). The backward path is registered as
+    /// `"<entity>_backward"`; its versions are expected to descend, so only
+    /// circular paths are rejected. Use [`load_downgraded`](Self::load_downgraded)
+    /// to load through it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either path fails validation. Nothing is
+    /// registered in that case.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let forward = Migrator::define("task")
+    ///     .from::<TaskV1>()
+    ///     .step::<TaskV2>()
+    ///     .into::<TaskEntity>();
+    /// let backward = Migrator::define("task")
+    ///     .from::<TaskV2>()
+    ///     .step::<TaskV1>()
+    ///     .into::<TaskV1>();
+    ///
+    /// migrator.register_bidirectional(Migrator::bidirectional(forward, backward))?;
+    /// ```
+    pub fn register_bidirectional<D, Old>(
+        &mut self,
+        path: BidirectionalPath<D, Old>,
+    ) -> Result<(), MigrationError> {
+        let BidirectionalPath {
+            mut forward,
+            mut backward,
+        } = path;
+
+        backward.entity = backward_entity(&forward.entity);
+        Self::validate_backward_path(&mut backward)?;
+        Self::validate_path(&mut forward)?;
+
+        self.insert_path(forward);
+        self.insert_path(backward);
+        Ok(())
+    }
+
+    /// Stores a validated migration path and its domain savers.
+    fn insert_path<D>(&mut self, path: MigrationPath<D>) {
+        // Resolve key priority: Path custom > Migrator default > EntityPath (trait constants)
+        let version_key = path
+            .custom_version_key
+            .or_else(|| self.default_version_key.clone())
+            .unwrap_or_else(|| path.inner.version_key.clone());
+
+        let data_key = path
+            .custom_data_key
+            .or_else(|| self.default_data_key.clone())
+            .unwrap_or_else(|| path.inner.data_key.clone());
+
+        let entity_name = path.entity.clone();
+        let mut options = self
+            .paths
+            .remove(&entity_name)
+            .map(|previous| previous.options)
+            .unwrap_or_default();
+        options.key_case = self.key_case;
+        options.audit = self.audit_sink.clone().map(|sink| EntityAudit {
+            entity: entity_name.clone(),
+            sink,
+        });
+        if path.version_pointer.is_some() || path.data_pointer.is_some() {
+            options.extractor = Some(VersionExtractor::JsonPointer {
+                version: path
+                    .version_pointer
+                    .unwrap_or_else(|| key_pointer(&version_key)),
+                data: path.data_pointer.unwrap_or_else(|| key_pointer(&data_key)),
+            });
+        }
+        let final_path = EntityMigrationPath {
+            version_key,
+            data_key,
+            options,
+            ..path.inner
+        };
+
+        self.invalidate_load_cache();
+        self.dynamic_domains.remove(&entity_name);
+        self.paths.insert(path.entity, final_path);
+
+        // Register domain savers if available
+        if let (Some(save_fn), Some(save_flat_fn)) = (path.save_fn, path.save_flat_fn) {
+            self.domain_savers.insert(
+                entity_name,
+                DomainSavers {
+                    save_fn,
+                    save_flat_fn,
+                },
+            );
+        }
+    }
+
+    /// Runs every check `register` applies to `path`, parsing its semver
+    /// ranges.
+    fn validate_path<D>(path: &mut MigrationPath<D>) -> Result<(), MigrationError> {
+        Self::validate_migration_path(&path.entity, &path.versions)?;
+        Self::check_frozen(&path.entity, &path.versions, &path.frozen)?;
+        Self::check_path_contents(path)
+    }
+
+    /// Like `validate_path`, but for a backward path, whose versions descend.
+    fn validate_backward_path<D>(path: &mut MigrationPath<D>) -> Result<(), MigrationError> {
+        Self::check_entity_name(&path.entity)?;
+        Self::check_circular_path(&path.entity, &path.versions)?;
+        Self::check_path_contents(path)
+    }
+
+    /// Checks of a path's steps, ranges and pointers, independent of the
+    /// direction of its versions.
+    fn check_path_contents<D>(path: &mut MigrationPath<D>) -> Result<(), MigrationError> {
+        Self::check_steps(&path.entity, &path.versions, &path.inner.steps)?;
+        path.inner.ranges = Self::parse_ranges(&path.entity, &path.ranges)?;
+        Self::check_pointers(
+            &path.entity,
+            [&path.version_pointer, &path.data_pointer]
+                .into_iter()
+                .flatten(),
+        )
+    }
+
+    /// Validates a migration path for correctness.
+    fn validate_migration_path(entity: &str, versions: &[String]) -> Result<(), MigrationError> {
+        Self::check_entity_name(entity)?;
+
+        // Check for circular paths
+        Self::check_circular_path(entity, versions)?;
+
+        // Check version ordering
+        Self::check_version_ordering(entity, versions)?;
+
+        Ok(())
+    }
+
+    /// Checks that an entity name is not empty or whitespace-only.
+    fn check_entity_name(entity: &str) -> Result<(), MigrationError> {
+        if entity.trim().is_empty() {
+            return Err(MigrationError::InvalidEntityName(entity.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Checks if there are any circular dependencies in the migration path.
+    fn check_circular_path(entity: &str, versions: &[String]) -> Result<(), MigrationError> {
+        let mut seen = std::collections::HashSet::new();
+
+        for version in versions {
+            if !seen.insert(version) {
+                // Found a duplicate - circular path detected
+                let path = versions.join(" -> ");
+                return Err(MigrationError::CircularMigrationPath {
+                    entity: entity.to_string(),
+                    path,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every version but the last has a step to the next one.
+    fn check_steps(
+        entity: &str,
+        versions: &[String],
+        steps: &HashMap<String, MigrationFn>,
+    ) -> Result<(), MigrationError> {
+        let Some((_, earlier)) = versions.split_last() else {
+            return Ok(());
+        };
+        if let Some(version) = earlier.iter().find(|v| !steps.contains_key(*v)) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: version.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that no version marked `FROZEN` has an outgoing migration step.
+    fn check_frozen(
+        entity: &str,
+        versions: &[String],
+        frozen: &[String],
+    ) -> Result<(), MigrationError> {
+        let Some((_, earlier)) = versions.split_last() else {
+            return Ok(());
+        };
+        if let Some(version) = frozen.iter().find(|v| earlier.contains(v)) {
+            return Err(MigrationError::FrozenVersion {
+                entity: entity.to_string(),
+                version: version.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Parses every range registered with `step_range` as a semver requirement.
+    fn parse_ranges(
+        entity: &str,
+        ranges: &[(String, String)],
+    ) -> Result<Vec<(semver::VersionReq, String)>, MigrationError> {
+        ranges
+            .iter()
+            .map(|(range, source)| {
+                let req = semver::VersionReq::parse(range).map_err(|e| {
+                    MigrationError::InvalidPathConfig {
+                        entity: entity.to_string(),
+                        reason: format!("invalid semver range '{}': {}", range, e),
+                    }
+                })?;
+                Ok((req, source.clone()))
+            })
+            .collect()
+    }
+
+    /// Checks that every pointer is a valid JSON pointer (RFC 6901).
+    fn check_pointers<'a>(
+        entity: &str,
+        pointers: impl IntoIterator<Item = &'a String>,
+    ) -> Result<(), MigrationError> {
+        for pointer in pointers {
+            if !pointer.is_empty() && !pointer.starts_with('/') {
+                return Err(MigrationError::InvalidPathConfig {
+                    entity: entity.to_string(),
+                    reason: format!(
+                        "invalid JSON pointer '{}': must be empty or start with '/'",
+                        pointer
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks if versions are ordered according to semver rules.
+    fn check_version_ordering(entity: &str, versions: &[String]) -> Result<(), MigrationError> {
+        for i in 0..versions.len().saturating_sub(1) {
+            let current = &versions[i];
+            let next = &versions[i + 1];
+
+            // Parse versions
+            let current_ver = semver::Version::parse(current).map_err(|e| {
+                MigrationError::DeserializationError(format!("Invalid semver '{}': {}", current, e))
+            })?;
+
+            let next_ver = semver::Version::parse(next).map_err(|e| {
+                MigrationError::DeserializationError(format!("Invalid semver '{}': {}", next, e))
+            })?;
+
+            // Check that next version is greater than current
+            if next_ver <= current_ver {
+                return Err(MigrationError::InvalidVersionOrder {
+                    entity: entity.to_string(),
+                    from: current.clone(),
+                    to: next.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Loads and migrates data from any serde-compatible format.
+    ///
+    /// This is the generic version that accepts any type implementing `Serialize`.
+    /// For JSON strings, use the convenience method `load` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - Versioned data in any serde-compatible format (e.g., `toml::Value`, `serde_json::Value`)
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The data cannot be converted to the internal format
+    /// - The entity is not registered
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Load from TOML
+    /// let toml_data: toml::Value = toml::from_str(toml_str)?;
+    /// let domain: TaskEntity = migrator.load_from("task", toml_data)?;
+    ///
+    /// // Load from JSON Value
+    /// let json_data: serde_json::Value = serde_json::from_str(json_str)?;
+    /// let domain: TaskEntity = migrator.load_from("task", json_data)?;
+    /// ```
+    pub fn load_from<D, T>(&self, entity: &str, data: T) -> Result<D, MigrationError>
+    where
+        D: DeserializeOwned,
+        T: Serialize,
+    {
+        // Convert the input data to serde_json::Value for internal processing
+        let value = serde_json::to_value(data).map_err(|e| {
+            MigrationError::DeserializationError(format!(
+                "Failed to convert input data to internal format: {}",
+                e
+            ))
+        })?;
+
+        let domain_value = self.finalize_value(entity, value)?;
+
+        domain_from_value(entity, domain_value)
+    }
+
+    /// Migrates wrapped `value` of `entity` and returns its domain value as JSON.
+    fn finalize_value(
+        &self,
+        entity: &str,
+        value: serde_json::Value,
+    ) -> Result<serde_json::Value, MigrationError> {
+        // Get the migration path for this entity
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        // Extract version and data using custom keys
+        let (current_version, current_data) = path.extract_wrapped(&value)?;
+
+        // Apply migration steps until we reach a version with no further steps
+        let (_, current_data) = path.migrate_steps(current_version, current_data)?;
+
+        // Finalize into domain model
+        (path.finalize)(current_data)
+    }
+
+    /// Loads data from a JSON string using the default entity.
+    ///
+    /// Same as `load` with the entity set by `set_default_entity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoDefaultEntity` if no default entity is set, plus the errors
+    /// of `load`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.set_default_entity("task")?;
+    /// let task: TaskEntity = migrator.load_default(&json)?;
+    /// ```
+    pub fn load_default<D: DeserializeOwned>(&self, json: &str) -> Result<D, MigrationError> {
+        self.load(self.default_entity()?, json)
+    }
+
+    /// Loads data from a JSON string into the domain type registered with
+    /// `register_dynamic`, without naming it.
+    ///
+    /// Migrates like `load`; the caller downcasts the result to the concrete
+    /// domain type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if the entity is not registered or was not
+    /// registered with `register_dynamic`, plus the errors of `load`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let domain = migrator.load_dynamic(plugin.entity(), &json)?;
+    /// if let Some(task) = domain.downcast_ref::<TaskEntity>() {
+    ///     println!("{}", task.title);
+    /// }
+    /// ```
+    pub fn load_dynamic(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<Box<dyn std::any::Any>, MigrationError> {
+        if self.entity_path(entity).is_none() {
+            return Err(MigrationError::EntityNotFound(entity.to_string()));
+        }
+        let construct = self
+            .dynamic_domains
+            .get(entity)
+            .or_else(|| {
+                self.entity_aliases
+                    .get(entity)
+                    .and_then(|name| self.dynamic_domains.get(name))
+            })
+            .ok_or_else(|| {
+                MigrationError::EntityNotFound(format!(
+                    "Entity '{}' is not registered with a dynamic domain type. Use register_dynamic() when registering the migration path.",
+                    entity
+                ))
+            })?;
+
+        let domain_value: serde_json::Value = self.load(entity, json)?;
+        construct(domain_value)
+    }
+
+    /// Loads data from a JSON string and reports the fields its version's DTO
+    /// does not know.
+    ///
+    /// Like `load`, but also returns the top-level data fields that the DTO of
+    /// the stored version ignores while deserializing. A non-empty map means
+    /// the data was written by a newer schema than this reader knows; those
+    /// fields do not reach the domain value.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data (wrapped format)
+    ///
+    /// # Returns
+    ///
+    /// The migrated domain model and the unknown fields with their values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `load`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (task, extras): (TaskEntity, _) = migrator.load_with_extras("task", &json)?;
+    /// if !extras.is_empty() {
+    ///     log::warn!("reader is behind writer: {:?}", extras.keys());
+    /// }
+    /// ```
+    pub fn load_with_extras<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<(D, serde_json::Map<String, serde_json::Value>), MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let (current_version, current_data) = path.extract_wrapped(&value)?;
+        let extras = path.extras(current_version.clone(), &current_data)?;
+        let (_, current_data) = path.migrate_steps(current_version, current_data)?;
+
+        let domain_value = (path.finalize)(current_data)?;
+        let domain = domain_from_value(entity, domain_value)?;
+
+        Ok((domain, extras))
+    }
+
+    /// Loads and migrates data from a JSON string.
+    ///
+    /// This is a convenience method for the common case of loading from JSON.
+    /// For other formats, use `load_from` instead. A leading UTF-8 byte order
+    /// mark is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#;
+    /// let domain: TaskEntity = migrator.load("task", json)?;
+    /// ```
+    pub fn load<D: DeserializeOwned>(&self, entity: &str, json: &str) -> Result<D, MigrationError> {
+        let json = strip_bom(json);
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.load_cache {
+            let domain_value = match cache.get(entity, json) {
+                Some(hit) => hit,
+                None => {
+                    let data = self.parse_json(json, "JSON")?;
+                    let domain_value = self.finalize_value(entity, data)?;
+                    cache.insert(entity, json, domain_value.clone());
+                    domain_value
+                }
+            };
+            return domain_from_value(entity, domain_value);
+        }
+
+        let data: serde_json::Value = self.parse_json(json, "JSON")?;
+        self.load_from(entity, data)
+    }
+
+    /// Loads and migrates data from a JSON5 string.
+    ///
+    /// Like `load`, but accepts hand-edited input: comments, trailing commas,
+    /// unquoted keys and single-quoted strings. Configured `LoadLimits` apply
+    /// to the JSON5 text; brackets inside comments count towards the depth.
+    ///
+    /// Requires the `json5` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeserializationError` if the text is not valid JSON5, plus
+    /// the errors of `load`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let text = r#"{
+    ///     // written by hand
+    ///     version: "1.0.0",
+    ///     data: { id: "task-1", title: "My Task", },
+    /// }"#;
+    /// let task: TaskEntity = migrator.load_json5("task", text)?;
+    /// ```
+    #[cfg(feature = "json5")]
+    pub fn load_json5<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        text: &str,
+    ) -> Result<D, MigrationError> {
+        let data: serde_json::Value = self.parse_json5(text)?;
+        self.load_from(entity, data)
+    }
+
+    /// Loads data stored under `canonical_entity` or any of its former names.
+    ///
+    /// `canonical_entity` is tried first; if it is not a registered entity,
+    /// it is looked up among the former names added with
+    /// `register_entity_alias`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` naming `canonical_entity` if neither it nor
+    /// an alias resolves, plus the errors of `load`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_entity_alias("todo", "task")?;
+    /// let task: TaskEntity = migrator.load_with_entity_rename("todo", &json)?;
+    /// ```
+    pub fn load_with_entity_rename<D: DeserializeOwned>(
+        &self,
+        canonical_entity: &str,
+        json: &str,
+    ) -> Result<D, MigrationError> {
+        let entity = if self.paths.contains_key(canonical_entity) {
+            canonical_entity
+        } else {
+            self.entity_aliases
+                .get(canonical_entity)
+                .map(String::as_str)
+                .ok_or_else(|| MigrationError::EntityNotFound(canonical_entity.to_string()))?
+        };
+        self.load(entity, json)
+    }
+
+    /// Loads data from a JSON string, rejecting versions outside the chain.
+    ///
+    /// Like `load`, but checks the data's version against the registered
+    /// chain before migrating. The latest version, which has no step, is
+    /// accepted; so are versions covered by a range step. Any other version
+    /// fails with `UnknownVersion` instead of being treated as already
+    /// migrated.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data (wrapped format)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - The data's version is not in the chain (`UnknownVersion`)
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = r#"{"version":"0.9.0","data":{"id":"task-1"}}"#;
+    /// let err = migrator.load_with_strict_version_check::<TaskEntity>("task", json);
+    /// // Err(MigrationError::UnknownVersion { version: "0.9.0", .. })
+    /// ```
+    pub fn load_with_strict_version_check<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<D, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+        let (version, _) = path.extract_wrapped(&value)?;
+        if !path.knows_version(&path.resolve_version(version.clone())) {
+            return Err(MigrationError::UnknownVersion {
+                entity: entity.to_string(),
+                version,
+            });
+        }
+
+        self.load_from(entity, value)
+    }
+
+    /// Loads data from a JSON string, migrating it only up to `target_version`.
+    ///
+    /// Steps run until the data reaches `target_version`, and the result is
+    /// deserialized into the DTO for that version. The final conversion to the
+    /// domain model is skipped. Data already at `target_version` is
+    /// deserialized as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data (wrapped format)
+    /// * `target_version` - A version in the registered chain
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - `target_version` or the data's version is not in the chain
+    ///   (`MigrationPathNotDefined`)
+    /// - The data is newer than `target_version` (`InvalidVersionOrder`)
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Chain: TaskV1 -> TaskV2 -> TaskV3
+    /// let json = r#"{"version":"1.0.0","data":{"id":"task-1"}}"#;
+    /// let v2: TaskV2 = migrator.load_upgrading_to_version("task", json, "2.0.0")?;
+    /// ```
+    pub fn load_upgrading_to_version<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+        target_version: &str,
+    ) -> Result<D, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let (version, data) = path.extract_wrapped(&value)?;
+        let version = path.resolve_version(version);
+        for known in [version.as_str(), target_version] {
+            if !path.knows_version(known) {
+                return Err(MigrationError::MigrationPathNotDefined {
+                    entity: entity.to_string(),
+                    version: known.to_string(),
+                });
+            }
+        }
+        if path.route(&version, target_version).is_none() {
+            return Err(MigrationError::InvalidVersionOrder {
+                entity: entity.to_string(),
+                from: version,
+                to: target_version.to_string(),
+            });
+        }
+
+        let (_, data) = path.migrate_steps_until(version, data, Some(target_version))?;
+
+        serde_json::from_value(data).map_err(|e| {
+            MigrationError::DeserializationError(format!(
+                "Failed to convert to version {}: {}",
+                target_version, e
+            ))
+        })
+    }
+
+    /// Loads and migrates data from a JSON string, enforcing the timeout
+    /// registered with `register_migration_timeout`.
+    ///
+    /// With a timeout registered, each migration step runs on tokio's blocking
+    /// thread pool and is abandoned once the limit passes; the step itself
+    /// keeps running in the background until it returns. Without one, this
+    /// behaves exactly like `load`.
+    ///
+    /// Requires the `async` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `load`, plus `Timeout` when a step exceeds
+    /// the registered limit.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// migrator.register_migration_timeout("task", Duration::from_millis(500))?;
+    /// let task: TaskEntity = migrator.load_async("task", &json).await?;
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn load_async<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<D, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let (current_version, current_data) = path.extract_wrapped(&value)?;
+        let (_, current_data) = path
+            .migrate_steps_async(entity, current_version, current_data)
+            .await?;
+
+        let domain_value = (path.finalize)(current_data)?;
+
+        domain_from_value(entity, domain_value)
+    }
+
+    /// Checks whether a JSON string could be loaded, without migrating it.
+    ///
+    /// Only the version field is inspected: no migration step or domain
+    /// conversion runs, so a `true` result does not guarantee that the data
+    /// itself deserializes.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data
+    ///
+    /// # Returns
+    ///
+    /// `true` if the entity is registered and the data's version is part of its
+    /// chain, `false` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON cannot be parsed or lacks the version or
+    /// data field.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if migrator.can_load("task", &json)? {
+    ///     let task: TaskEntity = migrator.load("task", &json)?;
+    /// }
+    /// ```
+    pub fn can_load(&self, entity: &str, json: &str) -> Result<bool, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let Some(path) = self.entity_path(entity) else {
+            return Ok(false);
+        };
+
+        let (version, _) = path.extract_wrapped(&value)?;
+        Ok(path.knows_version(&path.resolve_version(version)))
+    }
+
+    /// Loads and migrates data from a JSON string, rewriting the string to the
+    /// latest version.
+    ///
+    /// The input is read as `load` reads it, honoring a configured
+    /// `VersionExtractor`, version aliases and numeric versions. After a
+    /// successful call `*json` holds the same string that `save` would
+    /// produce for the latest version. On error `*json` is left untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data; replaced in place
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#.to_string();
+    /// let domain: TaskEntity = migrator.load_upgrading_in_place("task", &mut json)?;
+    /// // json: {"data":{"description":null,"id":"task-1","title":"My Task"},"version":"1.1.0"}
+    /// ```
+    pub fn load_upgrading_in_place<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &mut String,
+    ) -> Result<D, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let (version, data) = path.extract_wrapped(&value)?;
+        let (latest_version, latest_data) = path.migrate_steps(version, data)?;
+
+        let mut map = serde_json::Map::new();
+        map.insert(
+            path.version_key.clone(),
+            serde_json::Value::String(latest_version),
+        );
+        map.insert(path.data_key.clone(), latest_data.clone());
+        let upgraded = serde_json::to_string(&serde_json::Value::Object(map)).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
+        })?;
+
+        let domain_value = (path.finalize)(latest_data)?;
+        let domain = domain_from_value(entity, domain_value)?;
+
+        *json = upgraded;
+        Ok(domain)
+    }
+
+    /// Downgrades latest-version data through a backward path.
+    ///
+    /// Uses the path registered by
+    /// [`register_bidirectional`](Self::register_bidirectional). `domain_json`
+    /// may be versioned (e.g. the output of `save`) or, when the domain shape
+    /// matches the latest version, plain serialized domain data, which is
+    /// treated as the latest version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityNotFound` if no backward path is registered for
+    /// `entity`, or an error if parsing or a downgrade step fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = serde_json::to_string(&task_entity)?;
+    /// let old: TaskV1 = migrator.load_downgraded("task", &json)?;
+    /// ```
+    pub fn load_downgraded<Old: DeserializeOwned>(
+        &self,
+        entity: &str,
+        domain_json: &str,
+    ) -> Result<Old, MigrationError> {
+        let backward = backward_entity(entity);
+        if !self.paths.contains_key(&backward) {
+            return Err(MigrationError::EntityNotFound(backward));
+        }
+        self.load_with_fallback(&backward, domain_json)
+    }
+
+    /// Loads and migrates data from any serde-compatible format with fallback for legacy data.
+    ///
+    /// This method attempts to load data as versioned first. If version field is missing,
+    /// it treats the data as version 0 (the first version in the migration chain).
+    /// The version field is read as `load` reads it, so a numeric version
+    /// mapped with `register_version_alias` counts as versioned data.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - Data that may or may not have version information
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The data cannot be converted to the internal format
+    /// - The entity is not registered
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Works with versioned data
+    /// let versioned_json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#;
+    /// let domain: TaskEntity = migrator.load_with_fallback("task", versioned_json)?;
+    ///
+    /// // Works with legacy data (no version field) - treats as V1
+    /// let legacy_json = r#"{"id":"task-1","title":"My Task"}"#;
+    /// let domain: TaskEntity = migrator.load_with_fallback("task", legacy_json)?;
+    /// ```
+    pub fn load_from_with_fallback<D, T>(&self, entity: &str, data: T) -> Result<D, MigrationError>
+    where
+        D: DeserializeOwned,
+        T: Serialize,
+    {
+        // Convert the input data to serde_json::Value for internal processing
+        let value = serde_json::to_value(data).map_err(|e| {
+            MigrationError::DeserializationError(format!(
+                "Failed to convert input data to internal format: {}",
+                e
+            ))
+        })?;
+
+        // Get the migration path for this entity
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let version_key = &path.version_key;
+        let data_key = &path.data_key;
+
+        // Try to extract version and data using custom keys
+        let (current_version, current_data) = if let Some(obj) = value.as_object() {
+            if let Some(version_value) = obj.get(version_key) {
+                if let Ok(version_str) = path.read_version(version_value, version_key) {
+                    // Versioned data format
+                    let data = obj
+                        .get(data_key)
+                        .ok_or_else(|| {
+                            MigrationError::DeserializationError(format!(
+                                "Missing '{}' field",
+                                data_key
+                            ))
+                        })?
+                        .clone();
+                    (version_str, data)
+                } else {
+                    // Version field exists but is not a readable version - fallback to first version
+                    if path.versions.is_empty() {
+                        return Err(MigrationError::DeserializationError(
+                            "No migration versions defined for fallback".to_string(),
+                        ));
+                    }
+                    (path.versions[0].clone(), value)
+                }
+            } else {
+                // No version field - fallback to first version
+                if path.versions.is_empty() {
+                    return Err(MigrationError::DeserializationError(
+                        "No migration versions defined for fallback".to_string(),
+                    ));
+                }
+                (path.versions[0].clone(), value)
+            }
+        } else {
+            return Err(MigrationError::DeserializationError(
+                "Expected object format for versioned data".to_string(),
+            ));
+        };
+
+        // Apply migration steps until we reach a version with no further steps
+        let (_, current_data) = path.migrate_steps(current_version, current_data)?;
+
+        // Finalize into domain model
+        let domain_value = (path.finalize)(current_data)?;
+
+        domain_from_value(entity, domain_value)
+    }
+
+    /// Loads and migrates data from a JSON string with fallback for legacy data.
+    ///
+    /// This is a convenience method for `load_from_with_fallback` that accepts JSON strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string that may or may not contain version information
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Works with versioned data
+    /// let versioned_json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#;
+    /// let domain: TaskEntity = migrator.load_with_fallback("task", versioned_json)?;
+    ///
+    /// // Works with legacy data (no version field) - treats as V1
+    /// let legacy_json = r#"{"id":"task-1","title":"My Task"}"#;
+    /// let domain: TaskEntity = migrator.load_with_fallback("task", legacy_json)?;
+    /// ```
+    pub fn load_with_fallback<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<D, MigrationError> {
+        let data: serde_json::Value = self.parse_json(json, "JSON")?;
+        self.load_from_with_fallback(entity, data)
+    }
+
+    /// Loads and migrates data from a flat format JSON string.
+    ///
+    /// This is a convenience method for loading from flat format JSON where the version
+    /// field is at the same level as the data fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data in flat format
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = r#"{"version":"1.0.0","id":"task-1","title":"My Task"}"#;
+    /// let domain: TaskEntity = migrator.load_flat("task", json)?;
+    /// ```
+    pub fn load_flat<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<D, MigrationError> {
+        let data: serde_json::Value = self.parse_json(json, "JSON")?;
+        self.load_flat_from(entity, data)
+    }
+
+    /// Loads and migrates data from any serde-compatible format in flat format.
+    ///
+    /// This method expects the version field to be at the same level as the data fields.
+    /// It uses the registered migration path's runtime-configured keys (respecting the
+    /// Path > Migrator > Trait priority).
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `value` - A serde-compatible value containing versioned data in flat format
+    ///
+    /// # Returns
+    ///
+    /// The migrated data as the domain model type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered
+    /// - The data format is invalid
+    /// - A migration step fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let toml_value: toml::Value = toml::from_str(toml_str)?;
+    /// let domain: TaskEntity = migrator.load_flat_from("task", toml_value)?;
+    /// ```
+    pub fn load_flat_from<D, T>(&self, entity: &str, value: T) -> Result<D, MigrationError>
+    where
+        D: DeserializeOwned,
+        T: Serialize,
+    {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        // Convert to serde_json::Value for manipulation
+        let value = serde_json::to_value(value).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to convert input: {}", e))
+        })?;
+
+        // Extract version from the flat structure; the remainder holds only data fields
+        let (current_version, current_data) = path.extract_flat(value)?;
+
+        // Apply migration steps until we reach a version with no further steps
+        let (_, current_data) = path.migrate_steps(current_version, current_data)?;
+
+        // Finalize into domain model
+        let domain_value = (path.finalize)(current_data)?;
+
+        domain_from_value(entity, domain_value)
+    }
+
+    /// Loads and migrates the wrapped-format entry stored under `key` of an
+    /// already-parsed object.
+    ///
+    /// Lets several entities be extracted from one parsed payload without
+    /// re-parsing the JSON string for each.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `value` - The parsed outer object
+    /// * `key` - The key holding the versioned data
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeserializationError` if `value` is not an object or has no
+    /// `key`, plus the errors of `load_from`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let payload: serde_json::Value = serde_json::from_str(body)?;
+    /// let task: TaskEntity = migrator.load_from_map_entry("task", &payload, "task")?;
+    /// let user: UserEntity = migrator.load_from_map_entry("user", &payload, "author")?;
+    /// ```
+    pub fn load_from_map_entry<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        value: &serde_json::Value,
+        key: &str,
+    ) -> Result<D, MigrationError> {
+        self.load_from(entity, map_entry(value, key)?)
+    }
+
+    /// Like `load_from_map_entry`, but the entry is in flat format (version
+    /// key next to the data fields).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeserializationError` if `value` is not an object or has no
+    /// `key`, plus the errors of `load_flat_from`.
+    pub fn load_flat_from_map_entry<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        value: &serde_json::Value,
+        key: &str,
+    ) -> Result<D, MigrationError> {
+        self.load_flat_from(entity, map_entry(value, key)?)
+    }
+
+    /// Discovers and loads a config file from the standard locations.
+    ///
+    /// Searches, in order:
+    /// 1. `$XDG_CONFIG_HOME/<app_name>/<filename>` (if the variable is set)
+    /// 2. `~/.config/<app_name>/<filename>`
+    /// 3. `/etc/<app_name>/<filename>`
+    ///
+    /// See [`load_from_config_paths`](Self::load_from_config_paths) for how
+    /// each candidate is loaded.
+    ///
+    /// # Returns
+    ///
+    /// The migrated domain model and the path it was loaded from.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first load error if every existing candidate failed, or
+    /// `MigrationError::Store` listing the searched paths if none exists.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (config, path): (AppConfig, _) =
+    ///     migrator.load_from_environment_config("config", "myapp", "config.toml")?;
+    /// ```
+    pub fn load_from_environment_config<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        app_name: &str,
+        filename: &str,
+    ) -> Result<(D, std::path::PathBuf), MigrationError> {
+        let mut candidates = Vec::new();
+        if let Some(xdg) = std::env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+            candidates.push(
+                AppPaths::new(app_name).config_strategy(PathStrategy::CustomBase(xdg.into())),
+            );
+        }
+        candidates.push(AppPaths::new(app_name).config_strategy(PathStrategy::Xdg));
+        candidates
+            .push(AppPaths::new(app_name).config_strategy(PathStrategy::CustomBase("/etc".into())));
+
+        self.load_from_config_paths(entity, &candidates, filename)
+    }
+
+    /// Loads `filename` from the first of `candidates` whose config directory
+    /// holds a loadable copy.
+    ///
+    /// The file holds one entity in flat format (version key next to the
+    /// fields), as TOML when the extension is `.toml` and JSON otherwise.
+    /// Missing files are skipped without creating any directory; files that
+    /// fail to load are skipped in favour of later candidates.
+    ///
+    /// # Returns
+    ///
+    /// The migrated domain model and the path it was loaded from.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first load error if every existing candidate failed, or
+    /// `MigrationError::Store` listing the searched paths if none exists.
+    pub fn load_from_config_paths<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        candidates: &[AppPaths],
+        filename: &str,
+    ) -> Result<(D, std::path::PathBuf), MigrationError> {
+        let mut searched = Vec::new();
+        let mut first_error = None;
+
+        for paths in candidates {
+            // A candidate whose base cannot be resolved (no home dir) is skipped
+            let Ok(path) = paths.config_path(filename) else {
+                continue;
+            };
+
+            if path.is_file() {
+                match self.load_config_file(entity, &path) {
+                    Ok(domain) => return Ok((domain, path)),
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+            searched.push(path.display().to_string());
+        }
+
+        Err(first_error.unwrap_or_else(|| {
+            MigrationError::Store(local_store::StoreError::IoError {
+                operation: local_store::IoOperationKind::Read,
+                path: searched.join(", "),
+                context: Some("config search".to_string()),
+                error: "File not found".to_string(),
+            })
+        }))
+    }
+
+    /// Reads a flat-format config file, parsing it as TOML or JSON by extension.
+    fn load_config_file<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        path: &std::path::Path,
+    ) -> Result<D, MigrationError> {
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            MigrationError::Store(local_store::StoreError::IoError {
+                operation: local_store::IoOperationKind::Read,
+                path: path.display().to_string(),
+                context: None,
+                error: e.to_string(),
+            })
+        })?;
+
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            let value: toml::Value =
+                toml::from_str(&raw).map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
+            self.load_flat_from(entity, value)
+        } else {
+            self.load_flat(entity, &raw)
+        }
+    }
+
+    /// Loads wrapped data from raw bytes, parsing them as TOML or JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeserializationError` if JSON bytes are not valid UTF-8 and
+    /// `TomlParseError` if TOML bytes cannot be parsed, plus the errors of
+    /// `load`.
+    pub fn load_from_bytes<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        bytes: &[u8],
+        format: local_store::FormatStrategy,
+    ) -> Result<D, MigrationError> {
+        let text = std::str::from_utf8(bytes).map_err(|e| {
+            MigrationError::DeserializationError(format!("Body is not valid UTF-8: {}", e))
+        })?;
+
+        match format {
+            local_store::FormatStrategy::Json => self.load(entity, text),
+            local_store::FormatStrategy::Toml => {
+                let value: toml::Value = toml::from_str(text)
+                    .map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
+                self.load_from(entity, value)
+            }
+        }
+    }
+
+    /// Migrates a JSON array of versioned records read from `reader` and
+    /// writes the domain values to `writer` in `out_format`, one record at a
+    /// time.
+    ///
+    /// Only the record being migrated is held in memory, so this suits
+    /// converting large files. Each domain value is transcoded into the
+    /// output format with `serde_transcode`:
+    ///
+    /// - JSON output is an array of the domain values.
+    /// - TOML output is an array of tables named after the entity, e.g.
+    ///   `[[task]]`, so it parses back as `{ task = [...] }`.
+    ///
+    /// Wrap `reader` and `writer` in `BufReader` / `BufWriter` for files.
+    /// `LoadLimits` are not applied, since the input is never held whole.
+    ///
+    /// # Returns
+    ///
+    /// The number of records written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered
+    /// - The input is not a JSON array
+    /// - A record fails to migrate (reported as `ElementFailed` with its index)
+    /// - A domain value cannot be represented in `out_format` (such as a null
+    ///   field in TOML) or the writer fails
+    ///
+    /// Records before a failing one have already been written by then.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let reader = BufReader::new(File::open("tasks.json")?);
+    /// let writer = BufWriter::new(File::create("tasks.toml")?);
+    /// let count = migrator.transcode_stream("task", reader, writer, FormatStrategy::Toml)?;
+    /// ```
+    pub fn transcode_stream<R: std::io::Read, W: std::io::Write>(
+        &self,
+        entity: &str,
+        reader: R,
+        mut writer: W,
+        out_format: local_store::FormatStrategy,
+    ) -> Result<usize, MigrationError> {
+        use local_store::FormatStrategy;
+        use serde::Deserializer as _;
+
+        if self.entity_path(entity).is_none() {
+            return Err(MigrationError::EntityNotFound(entity.to_string()));
+        }
+
+        let write_error = |e: std::io::Error| {
+            MigrationError::SerializationError(format!("Failed to write output: {}", e))
+        };
+
+        if out_format == FormatStrategy::Json {
+            writer.write_all(b"[").map_err(write_error)?;
+        }
+
+        let mut failure = None;
+        let visitor = RecordSeqVisitor {
+            failure: &mut failure,
+            on_record: |index, record| {
+                let domain = self.finalize_value(entity, record)?;
+
+                match out_format {
+                    FormatStrategy::Json => {
+                        if index > 0 {
+                            writer.write_all(b",").map_err(write_error)?;
+                        }
+                        let mut serializer = serde_json::Serializer::new(&mut writer);
+                        serde_transcode::transcode(domain, &mut serializer).map_err(|e| {
+                            MigrationError::SerializationError(format!(
+                                "Failed to write JSON: {}",
+                                e
+                            ))
+                        })
+                    }
+                    FormatStrategy::Toml => {
+                        // A one-element array of tables per record; written
+                        // back to back they form a single array of tables.
+                        let mut table = serde_json::Map::new();
+                        table.insert(entity.to_string(), serde_json::Value::Array(vec![domain]));
+
+                        let mut out = String::new();
+                        serde_transcode::transcode(
+                            serde_json::Value::Object(table),
+                            toml::Serializer::new(&mut out),
+                        )
+                        .map_err(|e| {
+                            MigrationError::SerializationError(format!(
+                                "Failed to write TOML: {}",
+                                e
+                            ))
+                        })?;
+
+                        if index > 0 {
+                            writer.write_all(b"\n").map_err(write_error)?;
+                        }
+                        writer.write_all(out.as_bytes()).map_err(write_error)
+                    }
+                }
+            },
+        };
+
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let count = match deserializer.deserialize_seq(visitor) {
+            Ok(count) => count,
+            Err(e) => {
+                return Err(failure.unwrap_or_else(|| {
+                    MigrationError::DeserializationError(format!(
+                        "Failed to parse JSON array: {}",
+                        e
+                    ))
+                }))
+            }
+        };
+        deserializer.end().map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to parse JSON array: {}", e))
+        })?;
+
+        if out_format == FormatStrategy::Json {
+            writer.write_all(b"]").map_err(write_error)?;
+        }
+        writer.flush().map_err(write_error)?;
+
+        Ok(count)
+    }
+
+    /// Loads wrapped data from the body of an HTTP response.
+    ///
+    /// The format is taken from the `Content-Type` header: TOML for
+    /// `application/toml`, JSON for JSON media types or when the header is
+    /// missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Http` if the status is not a success (2xx) or the body cannot
+    /// be read, `DeserializationError` for any other content type, plus the
+    /// errors of `load_from_bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let response = client.get(url).send().await?;
+    /// let task: TaskEntity = migrator.load_from_http_response("task", response).await?;
+    /// ```
+    #[cfg(feature = "reqwest")]
+    pub async fn load_from_http_response<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        response: reqwest::Response,
+    ) -> Result<D, MigrationError> {
+        let url = response.url().to_string();
+        let status = response.status();
+        if !status.is_success() {
+            return Err(MigrationError::Http {
+                url,
+                status: Some(status.as_u16()),
+                error: format!("unexpected status {}", status),
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| {
+                v.split(';')
+                    .next()
+                    .unwrap_or("")
+                    .trim()
+                    .to_ascii_lowercase()
+            });
+
+        let format = match content_type.as_deref() {
+            None => local_store::FormatStrategy::Json,
+            Some(ct) if ct.ends_with("toml") => local_store::FormatStrategy::Toml,
+            Some(ct) if ct.ends_with("json") => local_store::FormatStrategy::Json,
+            Some(ct) => {
+                return Err(MigrationError::DeserializationError(format!(
+                    "Unsupported content type '{}'",
+                    ct
+                )))
+            }
+        };
+
+        let bytes = response.bytes().await.map_err(|e| MigrationError::Http {
+            url,
+            status: None,
+            error: format!("failed to read body: {}", e),
+        })?;
+
+        self.load_from_bytes(entity, &bytes, format)
+    }
+
+    /// Attaches `data` as a versioned JSON body to an HTTP request.
+    ///
+    /// The body has the same shape as `save` and the `Content-Type` header is
+    /// set to `application/json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `save`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let request = migrator.save_to_http_request(task, client.post(url))?;
+    /// request.send().await?;
+    /// ```
+    #[cfg(feature = "reqwest")]
+    pub fn save_to_http_request<T: Versioned + Serialize>(
+        &self,
+        data: T,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::RequestBuilder, MigrationError> {
+        let json = self.save(data)?;
+        Ok(builder
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(json))
+    }
+
+    /// Saves versioned data to a JSON string.
+    ///
+    /// This method wraps the provided data with its version information and serializes
+    /// it to JSON format. The resulting JSON can later be loaded and migrated using
+    /// the `load` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The versioned data to save
+    ///
+    /// # Returns
+    ///
+    /// A JSON string with the format: `{"version":"x.y.z","data":{...}}`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SerializationError` if the data cannot be serialized to JSON.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let task = TaskV1_0_0 {
+    ///     id: "task-1".to_string(),
+    ///     title: "My Task".to_string(),
+    /// };
+    ///
+    /// let migrator = Migrator::new();
+    /// let json = migrator.save(task)?;
+    /// // json: {"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}
+    /// ```
+    pub fn save<T: Versioned + Serialize>(&self, data: T) -> Result<String, MigrationError> {
+        let wrapper = Self::wrapped_value(&data)?;
+
+        serde_json::to_string(&wrapper).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
+        })
+    }
+
+    /// Wraps versioned data in a `VersionedWrapper` without serializing it
+    /// to a string.
+    ///
+    /// The in-memory counterpart of `save`: the wrapper holds `T::VERSION`
+    /// and the data as a `serde_json::Value`, and remembers `T::VERSION_KEY`
+    /// and `T::DATA_KEY` so it serializes exactly like `save`'s output. Use
+    /// `unwrap_entity` to load it back.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SerializationError` if `data` cannot be converted to JSON.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let wrapper = migrator.wrap_entity(task_v1)?;
+    /// assert_eq!(wrapper.version, "1.0.0");
+    /// let task: TaskEntity = migrator.unwrap_entity("task", wrapper)?;
+    /// ```
+    pub fn wrap_entity<T: Versioned + Serialize>(
+        &self,
+        data: T,
+    ) -> Result<VersionedWrapper<serde_json::Value>, MigrationError> {
+        let data = serde_json::to_value(data).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
+        })?;
+        Ok(VersionedWrapper::with_keys(
+            T::VERSION.to_string(),
+            data,
+            T::VERSION_KEY,
+            T::DATA_KEY,
+        ))
+    }
+
+    /// Loads and migrates an in-memory `VersionedWrapper` into the domain model.
+    ///
+    /// The wrapper is laid out with the keys it was built with, falling back
+    /// to the entity's version and data keys, so it behaves exactly like
+    /// `load` on the equivalent JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entity is not registered, a migration step
+    /// fails, or the result cannot be deserialized into `D`.
+    pub fn unwrap_entity<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        wrapper: VersionedWrapper<serde_json::Value>,
+    ) -> Result<D, MigrationError> {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let (version_key, data_key) = match wrapper.keys() {
+            Some((version_key, data_key)) => (version_key.to_string(), data_key.to_string()),
+            None => (path.version_key.clone(), path.data_key.clone()),
+        };
+
+        let mut map = serde_json::Map::new();
+        map.insert(version_key, serde_json::Value::String(wrapper.version));
+        map.insert(data_key, wrapper.data);
+
+        self.load_from(entity, serde_json::Value::Object(map))
+    }
+
+    /// Saves versioned data after checking it belongs to `entity`'s migration path.
+    ///
+    /// Produces the same output as `save`, but rejects types whose version is
+    /// not part of the registered chain, catching data saved under the wrong
+    /// entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - The versioned data to save
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered (`EntityNotFound`)
+    /// - `T::VERSION` is not in the entity's chain (`MigrationPathNotDefined`)
+    /// - The data cannot be serialized
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = migrator.save_checked("task", task_v1)?;
+    /// // Errors: UserV1 is not part of the "task" chain
+    /// assert!(migrator.save_checked("task", user_v1).is_err());
+    /// ```
+    pub fn save_checked<T: Versioned + Serialize>(
+        &self,
+        entity: &str,
+        data: T,
+    ) -> Result<String, MigrationError> {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        if !path.knows_version(T::VERSION) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: T::VERSION.to_string(),
+            });
+        }
+
+        self.save(data)
+    }
+
+    /// Saves versioned data after validating it against its JSON Schema.
+    ///
+    /// The schema is generated from `T`'s `JsonSchema` impl, so a custom
+    /// `Serialize` impl that drifts from the declared fields (e.g. omits a
+    /// required one) is caught before the output is written anywhere. Like
+    /// `save_checked`, `T::VERSION` must be part of `entity`'s chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - The versioned data to save
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered (`EntityNotFound`)
+    /// - `T::VERSION` is not in the entity's chain (`MigrationPathNotDefined`)
+    /// - The serialized data does not match the schema (`SchemaValidationFailed`)
+    /// - The data cannot be serialized
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Serialize, JsonSchema)]
+    /// struct TaskV2 { id: String, done: bool }
+    ///
+    /// let json = migrator.save_validated("task", task_v2)?;
+    /// ```
+    #[cfg(feature = "schema")]
+    pub fn save_validated<T>(&self, entity: &str, data: T) -> Result<String, MigrationError>
+    where
+        T: Versioned + Serialize + schemars::JsonSchema,
+    {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        if !path.knows_version(T::VERSION) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: T::VERSION.to_string(),
+            });
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(T)).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize schema: {}", e))
+        })?;
+        let validator = jsonschema::validator_for(&schema)
+            .map_err(|e| MigrationError::SerializationError(format!("Invalid schema: {}", e)))?;
+
+        let wrapper = Self::wrapped_value(&data)?;
+        let details: Vec<String> = validator
+            .iter_errors(&wrapper[T::DATA_KEY])
+            .map(|e| format!("{}: {}", e.instance_path, e))
+            .collect();
+        if !details.is_empty() {
+            return Err(MigrationError::SchemaValidationFailed {
+                entity: entity.to_string(),
+                version: T::VERSION.to_string(),
+                details: details.join("; "),
+            });
+        }
+
+        serde_json::to_string(&wrapper).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
+        })
+    }
+
+    /// Builds the wrapped JSON value (`{"version": ..., "data": ...}`) for `data`.
+    fn wrapped_value<T: Versioned + Serialize>(
+        data: &T,
+    ) -> Result<serde_json::Value, MigrationError> {
+        // Use custom keys from the type's Versioned trait
+        let version_key = T::VERSION_KEY;
+        let data_key = T::DATA_KEY;
+
+        // Serialize the data
+        let data_value = serde_json::to_value(data).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
+        })?;
+
+        // Build the wrapper with custom keys
+        let mut map = serde_json::Map::new();
+        map.insert(
+            version_key.to_string(),
+            serde_json::Value::String(T::VERSION.to_string()),
+        );
+        map.insert(data_key.to_string(), data_value);
+
+        Ok(serde_json::Value::Object(map))
+    }
+
+    /// Saves versioned data with its payload encoded according to `encoding`.
+    ///
+    /// The wrapper is JSON carrying an `"_encoding"` marker next to the version
+    /// (e.g. `"_encoding":"gzip"`). For compressed encodings the data key holds
+    /// the base64-encoded compressed JSON. Read it back with
+    /// `load_detecting_encoding`.
+    ///
+    /// Requires the `compression` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SerializationError` if the data cannot be serialized or compressed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let bytes = migrator.save_with_encoding_hint(task_v2, EncodingHint::GzipJson)?;
+    /// std::fs::write("task.json", &bytes)?;
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn save_with_encoding_hint<T: Versioned + Serialize>(
+        &self,
+        data: T,
+        encoding: EncodingHint,
+    ) -> Result<Vec<u8>, MigrationError> {
+        use base64::Engine;
+
+        let mut wrapper = Self::wrapped_value(&data)?;
+        let map = wrapper
+            .as_object_mut()
+            .expect("wrapped_value always returns an object");
+
+        if encoding != EncodingHint::PlainJson {
+            let data_value = map.remove(T::DATA_KEY).unwrap_or(serde_json::Value::Null);
+            let raw = serde_json::to_vec(&data_value).map_err(|e| {
+                MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
+            })?;
+            let compressed = encoding.compress(&raw).map_err(|e| {
+                MigrationError::SerializationError(format!("Failed to compress data: {}", e))
+            })?;
+            map.insert(
+                T::DATA_KEY.to_string(),
+                serde_json::Value::String(
+                    base64::engine::general_purpose::STANDARD.encode(compressed),
+                ),
+            );
+        }
+        map.insert(
+            ENCODING_KEY.to_string(),
+            serde_json::Value::String(encoding.as_str().to_string()),
+        );
+
+        serde_json::to_vec(&wrapper).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
+        })
+    }
+
+    /// Loads bytes written by `save_with_encoding_hint`, detecting and undoing
+    /// the payload encoding before migrating to the domain model.
+    ///
+    /// Input without an `"_encoding"` marker is treated as plain wrapped JSON.
+    /// Configured `LoadLimits` apply to both the input and the decompressed
+    /// payload.
+    ///
+    /// Requires the `compression` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The input is not a JSON object or exceeds the configured limits
+    /// - The encoding is unknown or the payload cannot be decompressed
+    /// - The entity is not registered or migration fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let bytes = std::fs::read("task.json")?;
+    /// let task: TaskEntity = migrator.load_detecting_encoding("task", &bytes)?;
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn load_detecting_encoding<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        bytes: &[u8],
+    ) -> Result<D, MigrationError> {
+        use base64::Engine;
+
+        let json = std::str::from_utf8(bytes).map_err(|e| {
+            MigrationError::DeserializationError(format!("Input is not valid UTF-8: {}", e))
+        })?;
+        let mut map = self.parse_object(json)?;
+
+        let encoding = match map.remove(ENCODING_KEY) {
+            None => EncodingHint::PlainJson,
+            Some(serde_json::Value::String(s)) => EncodingHint::from_str(&s).ok_or_else(|| {
+                MigrationError::DeserializationError(format!("Unknown encoding '{}'", s))
+            })?,
+            Some(other) => {
+                return Err(MigrationError::DeserializationError(format!(
+                    "Expected '{}' to be a string, found {}",
+                    ENCODING_KEY, other
+                )))
             }
+        };
+
+        if encoding != EncodingHint::PlainJson {
+            let path = self
+                .entity_path(entity)
+                .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+            let encoded = map
+                .get(&path.data_key)
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    MigrationError::DeserializationError(format!(
+                        "Expected '{}' to hold encoded data",
+                        path.data_key
+                    ))
+                })?;
+            let compressed = base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(|e| {
+                    MigrationError::DeserializationError(format!("Invalid base64 payload: {}", e))
+                })?;
+            // Stop one byte past the size limit so oversized payloads are
+            // rejected by `check_limits` without being fully inflated.
+            let limit = self
+                .limits
+                .map_or(u64::MAX, |limits| limits.max_bytes as u64 + 1);
+            let raw = encoding.decompress(&compressed, limit).map_err(|e| {
+                MigrationError::DeserializationError(format!("Failed to decompress data: {}", e))
+            })?;
+            let raw = String::from_utf8(raw).map_err(|e| {
+                MigrationError::DeserializationError(format!("Payload is not valid UTF-8: {}", e))
+            })?;
+            let data: serde_json::Value = self.parse_json(&raw, "payload")?;
+            map.insert(path.data_key.clone(), data);
+        }
+
+        self.load_from(entity, serde_json::Value::Object(map))
+    }
+
+    /// Loads gzip-compressed versioned JSON and migrates it to the domain model.
+    ///
+    /// The bytes are decompressed and then handled exactly like `load()`.
+    /// Configured `LoadLimits` apply to the decompressed JSON, and
+    /// decompression stops as soon as the size limit is exceeded.
+    ///
+    /// Requires the `compression` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The input is not valid gzip or does not decompress to UTF-8
+    /// - The decompressed JSON exceeds the configured limits
+    /// - The entity is not registered or migration fails
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let bytes = std::fs::read("task.json.gz")?;
+    /// let task: TaskEntity = migrator.load_gzip("task", &bytes)?;
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn load_gzip<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        bytes: &[u8],
+    ) -> Result<D, MigrationError> {
+        // Stop one byte past the size limit so oversized payloads are
+        // rejected by `load` without being fully inflated.
+        let limit = self
+            .limits
+            .map_or(u64::MAX, |limits| limits.max_bytes as u64 + 1);
+        let raw = EncodingHint::GzipJson
+            .decompress(bytes, limit)
+            .map_err(|e| {
+                MigrationError::DeserializationError(format!("Failed to decompress data: {}", e))
+            })?;
+        let json = String::from_utf8(raw).map_err(|e| {
+            MigrationError::DeserializationError(format!("Payload is not valid UTF-8: {}", e))
+        })?;
+
+        self.load(entity, &json)
+    }
+
+    /// Saves versioned data to a JSON string in flat format.
+    ///
+    /// Unlike `save()`, this method produces a flat JSON structure where the version
+    /// field is at the same level as the data fields, not wrapped in a separate object.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The versioned data to save
+    ///
+    /// # Returns
+    ///
+    /// A JSON string with the format: `{"version":"x.y.z","field1":"value1",...}`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SerializationError` if the data cannot be serialized to JSON.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let task = TaskV1_0_0 {
+    ///     id: "task-1".to_string(),
+    ///     title: "My Task".to_string(),
+    /// };
+    ///
+    /// let migrator = Migrator::new();
+    /// let json = migrator.save_flat(task)?;
+    /// // json: {"version":"1.0.0","id":"task-1","title":"My Task"}
+    /// ```
+    pub fn save_flat<T: Versioned + Serialize>(&self, data: T) -> Result<String, MigrationError> {
+        let flat = Self::flat_value(&data)?;
+
+        serde_json::to_string(&flat).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize flat format: {}", e))
+        })
+    }
+
+    /// Builds the flat JSON value (version next to the data fields) for `data`.
+    fn flat_value<T: Versioned + Serialize>(data: &T) -> Result<serde_json::Value, MigrationError> {
+        let version_key = T::VERSION_KEY;
+
+        // Serialize the data to a JSON object
+        let mut data_value = serde_json::to_value(data).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
+        })?;
+
+        // Ensure it's an object so we can add the version field
+        let obj = data_value.as_object_mut().ok_or_else(|| {
+            MigrationError::SerializationError(
+                "Data must serialize to a JSON object for flat format".to_string(),
+            )
+        })?;
+
+        // Add the version field to the same level as data fields
+        obj.insert(
+            version_key.to_string(),
+            serde_json::Value::String(T::VERSION.to_string()),
+        );
+
+        Ok(data_value)
+    }
+
+    /// Loads and migrates multiple entities from any serde-compatible format.
+    ///
+    /// This is the generic version that accepts any type implementing `Serialize`.
+    /// For JSON arrays, use the convenience method `load_vec` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - Array of versioned data in any serde-compatible format
+    ///
+    /// # Returns
+    ///
+    /// A vector of migrated data as domain model types
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The data cannot be converted to the internal format
+    /// - The entity is not registered
+    /// - Any migration step fails (reported as `ElementFailed` with the element index)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Load from TOML array
+    /// let toml_array: Vec<toml::Value> = /* ... */;
+    /// let domains: Vec<TaskEntity> = migrator.load_vec_from("task", toml_array)?;
+    ///
+    /// // Load from JSON Value array
+    /// let json_array: Vec<serde_json::Value> = /* ... */;
+    /// let domains: Vec<TaskEntity> = migrator.load_vec_from("task", json_array)?;
+    /// ```
+    pub fn load_vec_from<D, T>(&self, entity: &str, data: Vec<T>) -> Result<Vec<D>, MigrationError>
+    where
+        D: DeserializeOwned,
+        T: Serialize,
+    {
+        data.into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                self.load_from(entity, item)
+                    .map_err(|e| element_error(index, e))
+            })
+            .collect()
+    }
+
+    /// Loads and migrates a mixed-version array, migrating elements grouped by
+    /// their stored version.
+    ///
+    /// Produces the same output as `load_vec_from`, in the original order, but
+    /// plans the route once per stored version and applies each migration
+    /// step to the whole bucket before moving on to the next step. Versions
+    /// are resolved (aliases, numeric versions, range steps) before grouping,
+    /// so every spelling of one registered version shares a bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - Array of versioned data in any serde-compatible format
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `load_vec_from`, stopping at the first
+    /// failure. Elements whose version cannot be read are reported first, by
+    /// lowest index; after that buckets are migrated in ascending semver
+    /// order, so with several failing elements the reported one is the first
+    /// to fail in that order rather than necessarily the lowest index.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json_array: Vec<serde_json::Value> = /* ... */;
+    /// let domains: Vec<TaskEntity> = migrator.load_vec_grouped("task", json_array)?;
+    /// ```
+    pub fn load_vec_grouped<D, T>(
+        &self,
+        entity: &str,
+        data: Vec<T>,
+    ) -> Result<Vec<D>, MigrationError>
+    where
+        D: DeserializeOwned,
+        T: Serialize,
+    {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        // Keyed by the parsed version so "10.0.0" sorts after "9.0.0"; the
+        // string breaks ties and keeps unparsable versions apart.
+        type Bucket = (Vec<usize>, Vec<serde_json::Value>);
+        let len = data.len();
+        let mut buckets: std::collections::BTreeMap<(Option<semver::Version>, String), Bucket> =
+            std::collections::BTreeMap::new();
+
+        for (index, item) in data.into_iter().enumerate() {
+            let (version, data) = serde_json::to_value(item)
+                .map_err(|e| {
+                    MigrationError::DeserializationError(format!(
+                        "Failed to convert input data to internal format: {}",
+                        e
+                    ))
+                })
+                .and_then(|value| path.extract_wrapped(&value))
+                .map_err(|e| element_error(index, e))?;
+
+            let version = path.resolve_version(version);
+            let key = (semver::Version::parse(&version).ok(), version);
+            let (indices, values) = buckets.entry(key).or_default();
+            indices.push(index);
+            values.push(data);
         }
 
-        // Finalize into domain model
-        let domain_value = (path.finalize)(current_data)?;
+        let mut results: Vec<Option<D>> = std::iter::repeat_with(|| None).take(len).collect();
+        for ((_, version), (indices, values)) in buckets {
+            let (_, values) = path
+                .migrate_steps_batch(version, values)
+                .map_err(|(pos, e)| element_error(indices[pos], e))?;
+
+            for (index, data) in indices.into_iter().zip(values) {
+                let domain = (path.finalize)(data)
+                    .and_then(|value| domain_from_value(entity, value))
+                    .map_err(|e| element_error(index, e))?;
+                results[index] = Some(domain);
+            }
+        }
 
-        serde_json::from_value(domain_value).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to convert to domain: {}", e))
-        })
+        Ok(results.into_iter().flatten().collect())
     }
 
-    /// Loads and migrates data from a JSON string.
+    /// Loads and migrates multiple entities from a JSON array string.
     ///
-    /// This is a convenience method for the common case of loading from JSON.
-    /// For other formats, use `load_from` instead.
+    /// This is a convenience method for the common case of loading from a JSON array.
+    /// For other formats, use `load_vec_from` instead.
+    /// A leading UTF-8 byte order mark is ignored.
     ///
     /// # Arguments
     ///
     /// * `entity` - The entity name used when registering the migration path
-    /// * `json` - A JSON string containing versioned data
+    /// * `json` - A JSON array string containing versioned data
     ///
     /// # Returns
     ///
-    /// The migrated data as the domain model type
+    /// A vector of migrated data as domain model types
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The JSON cannot be parsed
     /// - The entity is not registered
-    /// - A migration step fails
+    /// - Any migration step fails (reported as `ElementFailed` with the element index)
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#;
-    /// let domain: TaskEntity = migrator.load("task", json)?;
+    /// let json = r#"[
+    ///     {"version":"1.0.0","data":{"id":"task-1","title":"Task 1"}},
+    ///     {"version":"1.0.0","data":{"id":"task-2","title":"Task 2"}}
+    /// ]"#;
+    /// let domains: Vec<TaskEntity> = migrator.load_vec("task", json)?;
     /// ```
-    pub fn load<D: DeserializeOwned>(&self, entity: &str, json: &str) -> Result<D, MigrationError> {
-        let data: serde_json::Value = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON: {}", e))
-        })?;
-        self.load_from(entity, data)
+    pub fn load_vec<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<Vec<D>, MigrationError> {
+        let data: Vec<serde_json::Value> = self.parse_json(json, "JSON array")?;
+        self.load_vec_from(entity, data)
     }
 
-    /// Loads and migrates data from any serde-compatible format with fallback for legacy data.
+    /// Loads and migrates multiple entities from an array wrapped in an object
+    /// envelope, such as a paginated API response.
     ///
-    /// This method attempts to load data as versioned first. If version field is missing,
-    /// it treats the data as version 0 (the first version in the migration chain).
+    /// The array at `items_key` is loaded like `load_vec`; the other envelope
+    /// fields (cursors, counts, ...) are ignored.
     ///
     /// # Arguments
     ///
     /// * `entity` - The entity name used when registering the migration path
-    /// * `data` - Data that may or may not have version information
+    /// * `json` - A JSON object string holding the versioned items
+    /// * `items_key` - The key of the array inside the envelope
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed or is not an object
+    /// - `items_key` is missing or does not hold an array
+    /// - The entity is not registered
+    /// - Any migration step fails (reported as `ElementFailed` with the element index)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = r#"{
+    ///     "items": [{"version":"1.0.0","data":{"id":"task-1","title":"Task 1"}}],
+    ///     "next_cursor": "abc"
+    /// }"#;
+    /// let tasks: Vec<TaskEntity> = migrator.load_vec_enveloped("task", json, "items")?;
+    /// ```
+    pub fn load_vec_enveloped<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+        items_key: &str,
+    ) -> Result<Vec<D>, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+        let serde_json::Value::Object(mut envelope) = value else {
+            return Err(MigrationError::DeserializationError(
+                "Expected a JSON object envelope".to_string(),
+            ));
+        };
+
+        match envelope.remove(items_key) {
+            Some(serde_json::Value::Array(items)) => self.load_vec_from(entity, items),
+            Some(_) => Err(MigrationError::DeserializationError(format!(
+                "Envelope key '{}' does not contain an array",
+                items_key
+            ))),
+            None => Err(MigrationError::DeserializationError(format!(
+                "Missing '{}' field in envelope",
+                items_key
+            ))),
+        }
+    }
+
+    /// Loads and migrates multiple entities from a flat format JSON array string.
+    ///
+    /// This is a convenience method for loading from a JSON array where each element
+    /// has the version field at the same level as the data fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON array string containing versioned data in flat format
     ///
     /// # Returns
     ///
-    /// The migrated data as the domain model type
+    /// A vector of migrated data as domain model types
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The data cannot be converted to the internal format
+    /// - The JSON cannot be parsed
     /// - The entity is not registered
-    /// - A migration step fails
+    /// - Any migration step fails (reported as `ElementFailed` with the element index)
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Works with versioned data
-    /// let versioned_json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#;
-    /// let domain: TaskEntity = migrator.load_with_fallback("task", versioned_json)?;
+    /// let json = r#"[
+    ///     {"version":"1.0.0","id":"task-1","title":"Task 1"},
+    ///     {"version":"1.0.0","id":"task-2","title":"Task 2"}
+    /// ]"#;
+    /// let domains: Vec<TaskEntity> = migrator.load_vec_flat("task", json)?;
+    /// ```
+    pub fn load_vec_flat<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<Vec<D>, MigrationError> {
+        let data: Vec<serde_json::Value> = self.parse_json(json, "JSON array")?;
+        self.load_vec_flat_from(entity, data)
+    }
+
+    /// Loads and migrates multiple entities from any serde-compatible format in flat format.
     ///
-    /// // Works with legacy data (no version field) - treats as V1
-    /// let legacy_json = r#"{"id":"task-1","title":"My Task"}"#;
-    /// let domain: TaskEntity = migrator.load_with_fallback("task", legacy_json)?;
+    /// This method expects each element to have the version field at the same level
+    /// as the data fields. It uses the registered migration path's runtime-configured
+    /// keys (respecting the Path > Migrator > Trait priority).
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - Vector of serde-compatible values in flat format
+    ///
+    /// # Returns
+    ///
+    /// A vector of migrated data as domain model types
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered
+    /// - The data format is invalid
+    /// - Any migration step fails (reported as `ElementFailed` with the element index)
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let toml_array: Vec<toml::Value> = /* ... */;
+    /// let domains: Vec<TaskEntity> = migrator.load_vec_flat_from("task", toml_array)?;
     /// ```
-    pub fn load_from_with_fallback<D, T>(&self, entity: &str, data: T) -> Result<D, MigrationError>
+    pub fn load_vec_flat_from<D, T>(
+        &self,
+        entity: &str,
+        data: Vec<T>,
+    ) -> Result<Vec<D>, MigrationError>
     where
         D: DeserializeOwned,
         T: Serialize,
     {
-        // Convert the input data to serde_json::Value for internal processing
-        let value = serde_json::to_value(data).map_err(|e| {
-            MigrationError::DeserializationError(format!(
-                "Failed to convert input data to internal format: {}",
-                e
-            ))
-        })?;
+        data.into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                self.load_flat_from(entity, item)
+                    .map_err(|e| element_error(index, e))
+            })
+            .collect()
+    }
+
+    /// Extracts a single field from every item of a JSON array, after
+    /// migrating each item to the latest version.
+    ///
+    /// Items are migrated but not converted to the domain model, so the field
+    /// is looked up in the latest versioned data. Useful when only one field of
+    /// a large collection is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON array string containing versioned data
+    /// * `field_path` - A dot-separated path into the item data (e.g. `"user.name"` or `"tags.0"`)
+    ///
+    /// # Returns
+    ///
+    /// The extracted field of each item, in array order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The JSON cannot be parsed
+    /// - The entity is not registered
+    /// - Any migration step fails (reported as `ElementFailed` with the element index)
+    /// - The field is missing or cannot be deserialized as `T`
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let titles: Vec<String> = migrator.load_field_vec("task", json, "title")?;
+    /// ```
+    pub fn load_field_vec<T: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+        field_path: &str,
+    ) -> Result<Vec<T>, MigrationError> {
+        let data: Vec<serde_json::Value> = self.parse_json(json, "JSON array")?;
 
-        // Get the migration path for this entity
         let path = self
-            .paths
-            .get(entity)
+            .entity_path(entity)
             .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
 
-        let version_key = &path.version_key;
-        let data_key = &path.data_key;
-
-        // Try to extract version and data using custom keys
-        let (current_version, current_data) = if let Some(obj) = value.as_object() {
-            if let Some(version_value) = obj.get(version_key) {
-                if let Some(version_str) = version_value.as_str() {
-                    // Versioned data format
-                    let data = obj
-                        .get(data_key)
-                        .ok_or_else(|| {
-                            MigrationError::DeserializationError(format!(
-                                "Missing '{}' field",
-                                data_key
-                            ))
-                        })?
-                        .clone();
-                    (version_str.to_string(), data)
-                } else {
-                    // Version field exists but is not a string - fallback to first version
-                    if path.versions.is_empty() {
-                        return Err(MigrationError::DeserializationError(
-                            "No migration versions defined for fallback".to_string(),
-                        ));
-                    }
-                    (path.versions[0].clone(), value)
-                }
-            } else {
-                // No version field - fallback to first version
-                if path.versions.is_empty() {
-                    return Err(MigrationError::DeserializationError(
-                        "No migration versions defined for fallback".to_string(),
-                    ));
-                }
-                (path.versions[0].clone(), value)
-            }
-        } else {
-            return Err(MigrationError::DeserializationError(
-                "Expected object format for versioned data".to_string(),
-            ));
-        };
-
-        let mut current_version = current_version;
-        let mut current_data = current_data;
-
-        // Apply migration steps until we reach a version with no further steps
-        while let Some(migrate_fn) = path.steps.get(&current_version) {
-            // Migration function returns raw value, no wrapping
-            current_data = migrate_fn(current_data.clone())?;
-
-            // Update version to the next step
-            // Find the next version in the path
-            match path.versions.iter().position(|v| v == &current_version) {
-                Some(idx) if idx + 1 < path.versions.len() => {
-                    current_version = path.versions[idx + 1].clone();
-                }
-                _ => break,
-            }
-        }
-
-        // Finalize into domain model
-        let domain_value = (path.finalize)(current_data)?;
+        let pointer: String = field_path
+            .split('.')
+            .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+            .collect();
 
-        serde_json::from_value(domain_value).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to convert to domain: {}", e))
-        })
+        data.iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let extract = || {
+                    let (version, data) = path.extract_wrapped(item)?;
+                    let (_, data) = path.migrate_steps(version, data)?;
+
+                    let field = data.pointer(&pointer).ok_or_else(|| {
+                        MigrationError::DeserializationError(format!(
+                            "Missing field '{}'",
+                            field_path
+                        ))
+                    })?;
+
+                    T::deserialize(field).map_err(|e| {
+                        MigrationError::DeserializationError(format!(
+                            "Failed to convert field '{}': {}",
+                            field_path, e
+                        ))
+                    })
+                };
+                extract().map_err(|e| element_error(index, e))
+            })
+            .collect()
     }
 
-    /// Loads and migrates data from a JSON string with fallback for legacy data.
+    /// Loads one value of a JSON object as a flat-format versioned entity.
     ///
-    /// This is a convenience method for `load_from_with_fallback` that accepts JSON strings.
+    /// Treats `root[key]` as a flat object whose version field sits next to
+    /// its data fields, and migrates it like `load_flat`.
     ///
     /// # Arguments
     ///
     /// * `entity` - The entity name used when registering the migration path
-    /// * `json` - A JSON string that may or may not contain version information
-    ///
-    /// # Returns
-    ///
-    /// The migrated data as the domain model type
+    /// * `json` - A JSON object string
+    /// * `key` - The top-level key holding the entity
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The JSON cannot be parsed
-    /// - The entity is not registered
-    /// - A migration step fails
+    /// - The JSON cannot be parsed or is not an object
+    /// - `key` is missing
+    /// - The value cannot be loaded as a flat versioned entity
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Works with versioned data
-    /// let versioned_json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}"#;
-    /// let domain: TaskEntity = migrator.load_with_fallback("task", versioned_json)?;
-    ///
-    /// // Works with legacy data (no version field) - treats as V1
-    /// let legacy_json = r#"{"id":"task-1","title":"My Task"}"#;
-    /// let domain: TaskEntity = migrator.load_with_fallback("task", legacy_json)?;
+    /// let json = r#"{"db":{"version":"1.0.0","host":"localhost"}}"#;
+    /// let db: DbConfig = migrator.load_flat_scalar("db_config", json, "db")?;
     /// ```
-    pub fn load_with_fallback<D: DeserializeOwned>(
+    pub fn load_flat_scalar<D: DeserializeOwned>(
         &self,
         entity: &str,
         json: &str,
+        key: &str,
     ) -> Result<D, MigrationError> {
-        let data: serde_json::Value = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON: {}", e))
+        let mut root = self.parse_object(json)?;
+        let value = root.remove(key).ok_or_else(|| {
+            MigrationError::DeserializationError(format!("Missing '{}' field", key))
         })?;
-        self.load_from_with_fallback(entity, data)
+        self.load_flat_from(entity, value)
     }
 
-    /// Loads and migrates data from a flat format JSON string.
+    /// Loads every value of a JSON object as a flat-format versioned entity.
     ///
-    /// This is a convenience method for loading from flat format JSON where the version
-    /// field is at the same level as the data fields.
+    /// Each top-level value is loaded as in
+    /// [`load_flat_scalar`](Self::load_flat_scalar), keyed by its key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON cannot be parsed or is not an object, or
+    /// if any value fails to load.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let json = r#"{
+    ///     "primary": {"version":"1.0.0","host":"db1"},
+    ///     "replica": {"version":"2.0.0","host":"db2","port":5433}
+    /// }"#;
+    /// let dbs: HashMap<String, DbConfig> = migrator.load_all_flat_scalars("db_config", json)?;
+    /// ```
+    pub fn load_all_flat_scalars<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<HashMap<String, D>, MigrationError> {
+        self.parse_object(json)?
+            .into_iter()
+            .map(|(key, value)| Ok((key, self.load_flat_from(entity, value)?)))
+            .collect()
+    }
+
+    /// Parses `json` as a JSON object after applying the load limits.
+    fn parse_object(
+        &self,
+        json: &str,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, MigrationError> {
+        match self.parse_json(json, "JSON")? {
+            serde_json::Value::Object(map) => Ok(map),
+            _ => Err(MigrationError::DeserializationError(
+                "Expected a JSON object".to_string(),
+            )),
+        }
+    }
+
+    /// Migrates a stored value and a candidate update to the domain model and
+    /// compares them field by field.
+    ///
+    /// Both inputs are loaded independently (as with `load`), so they may be
+    /// stored at different versions.
     ///
     /// # Arguments
     ///
     /// * `entity` - The entity name used when registering the migration path
-    /// * `json` - A JSON string containing versioned data in flat format
+    /// * `stored_json` - The currently stored versioned JSON
+    /// * `candidate_json` - The proposed versioned JSON
     ///
     /// # Returns
     ///
-    /// The migrated data as the domain model type
+    /// An `EntityDiff` listing each top-level domain field that differs
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The JSON cannot be parsed
-    /// - The entity is not registered
-    /// - A migration step fails
+    /// - Either JSON cannot be parsed or migrated
+    /// - The domain model does not serialize to a JSON object
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let json = r#"{"version":"1.0.0","id":"task-1","title":"My Task"}"#;
-    /// let domain: TaskEntity = migrator.load_flat("task", json)?;
+    /// let diff = migrator.load_and_diff::<TaskEntity>("task", &stored, &candidate)?;
+    /// for (field, (old, new)) in &diff.changed_fields {
+    ///     println!("{field}: {old} -> {new}");
+    /// }
     /// ```
-    pub fn load_flat<D: DeserializeOwned>(
+    pub fn load_and_diff<D: DeserializeOwned + Serialize>(
         &self,
         entity: &str,
-        json: &str,
-    ) -> Result<D, MigrationError> {
-        let data: serde_json::Value = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON: {}", e))
-        })?;
-        self.load_flat_from(entity, data)
+        stored_json: &str,
+        candidate_json: &str,
+    ) -> Result<EntityDiff, MigrationError> {
+        let to_fields =
+            |json: &str| -> Result<serde_json::Map<String, serde_json::Value>, MigrationError> {
+                let domain: D = self.load(entity, json)?;
+                match serde_json::to_value(domain) {
+                    Ok(serde_json::Value::Object(map)) => Ok(map),
+                    Ok(_) => Err(MigrationError::SerializationError(
+                        "Domain must serialize to a JSON object to be diffed".to_string(),
+                    )),
+                    Err(e) => Err(MigrationError::SerializationError(format!(
+                        "Failed to serialize domain: {}",
+                        e
+                    ))),
+                }
+            };
+
+        let mut old = to_fields(stored_json)?;
+        let mut new = to_fields(candidate_json)?;
+
+        let mut changed_fields = HashMap::new();
+        for (field, old_value) in old.iter_mut() {
+            let new_value = new.remove(field).unwrap_or(serde_json::Value::Null);
+            if *old_value != new_value {
+                changed_fields.insert(field.clone(), (old_value.take(), new_value));
+            }
+        }
+        for (field, new_value) in new {
+            changed_fields.insert(field, (serde_json::Value::Null, new_value));
+        }
+
+        Ok(EntityDiff { changed_fields })
     }
 
-    /// Loads and migrates data from any serde-compatible format in flat format.
+    /// Compares the raw data of two versions of `entity` field by field.
     ///
-    /// This method expects the version field to be at the same level as the data fields.
-    /// It uses the registered migration path's runtime-configured keys (respecting the
-    /// Path > Migrator > Trait priority).
+    /// Both inputs are versioned JSON, read as `load` reads it (wrapped as
+    /// produced by `save`, or the shape of a configured `VersionExtractor`),
+    /// stored at `V1::VERSION` and `V2::VERSION` respectively. Nothing is migrated:
+    /// the data objects are compared as they are, which shows how the shape
+    /// changed between the two versions.
     ///
     /// # Arguments
     ///
     /// * `entity` - The entity name used when registering the migration path
-    /// * `value` - A serde-compatible value containing versioned data in flat format
-    ///
-    /// # Returns
-    ///
-    /// The migrated data as the domain model type
+    /// * `v1_json` - Versioned JSON stored at `V1::VERSION`
+    /// * `v2_json` - Versioned JSON stored at `V2::VERSION`
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The entity is not registered
-    /// - The data format is invalid
-    /// - A migration step fails
+    /// - The entity is not registered (`EntityNotFound`)
+    /// - `V1::VERSION` or `V2::VERSION` is not in the chain (`MigrationPathNotDefined`)
+    /// - Either JSON cannot be parsed, is stored at another version, or its
+    ///   data is not an object (`DeserializationError`)
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let toml_value: toml::Value = toml::from_str(toml_str)?;
-    /// let domain: TaskEntity = migrator.load_flat_from("task", toml_value)?;
+    /// let v1 = migrator.save(task_v1)?;
+    /// let v2 = migrator.save(task_v2)?;
+    /// let diff = migrator.diff_entity_versions::<TaskV1, TaskV2>("task", &v1, &v2)?;
+    /// assert_eq!(diff.added_fields, vec!["tags"]);
     /// ```
-    pub fn load_flat_from<D, T>(&self, entity: &str, value: T) -> Result<D, MigrationError>
+    pub fn diff_entity_versions<V1, V2>(
+        &self,
+        entity: &str,
+        v1_json: &str,
+        v2_json: &str,
+    ) -> Result<VersionDiff, MigrationError>
     where
-        D: DeserializeOwned,
-        T: Serialize,
+        V1: Versioned + Serialize,
+        V2: Versioned + Serialize,
     {
         let path = self
-            .paths
-            .get(entity)
+            .entity_path(entity)
             .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
 
-        let version_key = &path.version_key;
+        let to_fields =
+            |json: &str,
+             expected: &str|
+             -> Result<serde_json::Map<String, serde_json::Value>, MigrationError> {
+                if !path.knows_version(expected) {
+                    return Err(MigrationError::MigrationPathNotDefined {
+                        entity: entity.to_string(),
+                        version: expected.to_string(),
+                    });
+                }
+                let value: serde_json::Value = self.parse_json(json, "JSON")?;
+                let (version, data) = path.extract_wrapped(&value)?;
+                if version != expected {
+                    return Err(MigrationError::DeserializationError(format!(
+                        "Expected data stored at version '{}', found '{}'",
+                        expected, version
+                    )));
+                }
+                match data {
+                    serde_json::Value::Object(map) => Ok(map),
+                    _ => Err(MigrationError::DeserializationError(
+                        "Data must be a JSON object to be diffed".to_string(),
+                    )),
+                }
+            };
 
-        // Convert to serde_json::Value for manipulation
-        let mut value = serde_json::to_value(value).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to convert input: {}", e))
-        })?;
+        let old = to_fields(v1_json, V1::VERSION)?;
+        let mut new = to_fields(v2_json, V2::VERSION)?;
 
-        // Extract version from the flat structure
-        let obj = value.as_object_mut().ok_or_else(|| {
-            MigrationError::DeserializationError(
-                "Expected object with version field at top level".to_string(),
-            )
-        })?;
+        let mut diff = VersionDiff::default();
+        for (field, old_value) in old {
+            match new.remove(&field) {
+                None => diff.removed_fields.push(field),
+                Some(new_value) if new_value != old_value => {
+                    diff.changed_fields.push((field, old_value, new_value))
+                }
+                Some(_) => diff.unchanged_fields.push(field),
+            }
+        }
+        diff.added_fields = new.into_iter().map(|(field, _)| field).collect();
 
-        let current_version = obj
-            .remove(version_key)
-            .ok_or_else(|| {
-                MigrationError::DeserializationError(format!(
-                    "Missing '{}' field in flat format",
-                    version_key
-                ))
-            })?
-            .as_str()
-            .ok_or_else(|| {
-                MigrationError::DeserializationError(format!(
-                    "Invalid '{}' field type",
-                    version_key
-                ))
-            })?
-            .to_string();
+        diff.added_fields.sort();
+        diff.removed_fields.sort();
+        diff.unchanged_fields.sort();
+        diff.changed_fields.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(diff)
+    }
 
-        // Now obj contains only data fields (version has been removed)
-        let mut current_data = serde_json::Value::Object(obj.clone());
-        let mut current_version = current_version;
+    /// Saves multiple versioned entities to a JSON array string.
+    ///
+    /// This method wraps each item with its version information and serializes
+    /// them as a JSON array. The resulting JSON can later be loaded and migrated
+    /// using the `load_vec` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Vector of versioned data to save
+    ///
+    /// # Returns
+    ///
+    /// A JSON array string where each element has the format: `{"version":"x.y.z","data":{...}}`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SerializationError` if the data cannot be serialized to JSON.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let tasks = vec![
+    ///     TaskV1_0_0 {
+    ///         id: "task-1".to_string(),
+    ///         title: "Task 1".to_string(),
+    ///     },
+    ///     TaskV1_0_0 {
+    ///         id: "task-2".to_string(),
+    ///         title: "Task 2".to_string(),
+    ///     },
+    /// ];
+    ///
+    /// let migrator = Migrator::new();
+    /// let json = migrator.save_vec(tasks)?;
+    /// // json: [{"version":"1.0.0","data":{"id":"task-1",...}}, ...]
+    /// ```
+    pub fn save_vec<T: Versioned + Serialize>(
+        &self,
+        data: Vec<T>,
+    ) -> Result<String, MigrationError> {
+        let version_key = T::VERSION_KEY;
+        let data_key = T::DATA_KEY;
 
-        // Apply migration steps until we reach a version with no further steps
-        while let Some(migrate_fn) = path.steps.get(&current_version) {
-            // Migration function returns raw value, no wrapping
-            current_data = migrate_fn(current_data.clone())?;
+        let wrappers: Result<Vec<serde_json::Value>, MigrationError> = data
+            .into_iter()
+            .map(|item| {
+                let data_value = serde_json::to_value(&item).map_err(|e| {
+                    MigrationError::SerializationError(format!("Failed to serialize item: {}", e))
+                })?;
 
-            // Update version to the next step
-            match path.versions.iter().position(|v| v == &current_version) {
-                Some(idx) if idx + 1 < path.versions.len() => {
-                    current_version = path.versions[idx + 1].clone();
-                }
-                _ => break,
-            }
-        }
+                let mut map = serde_json::Map::new();
+                map.insert(
+                    version_key.to_string(),
+                    serde_json::Value::String(T::VERSION.to_string()),
+                );
+                map.insert(data_key.to_string(), data_value);
 
-        // Finalize into domain model
-        let domain_value = (path.finalize)(current_data)?;
+                Ok(serde_json::Value::Object(map))
+            })
+            .collect();
 
-        serde_json::from_value(domain_value).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to convert to domain: {}", e))
+        serde_json::to_string(&wrappers?).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize data array: {}", e))
         })
     }
 
-    /// Saves versioned data to a JSON string.
+    /// Saves multiple versioned entities to a JSON array string in flat format.
     ///
-    /// This method wraps the provided data with its version information and serializes
-    /// it to JSON format. The resulting JSON can later be loaded and migrated using
-    /// the `load` method.
+    /// This method serializes each item with the version field at the same level
+    /// as the data fields, not wrapped in a separate object.
     ///
     /// # Arguments
     ///
-    /// * `data` - The versioned data to save
+    /// * `data` - Vector of versioned data to save
     ///
     /// # Returns
     ///
-    /// A JSON string with the format: `{"version":"x.y.z","data":{...}}`
+    /// A JSON array string where each element has the format: `{"version":"x.y.z","field1":"value1",...}`
     ///
     /// # Errors
     ///
@@ -646,939 +5160,1239 @@ impl Migrator {
     /// # Example
     ///
     /// ```ignore
-    /// let task = TaskV1_0_0 {
-    ///     id: "task-1".to_string(),
-    ///     title: "My Task".to_string(),
-    /// };
+    /// let tasks = vec![
+    ///     TaskV1_0_0 {
+    ///         id: "task-1".to_string(),
+    ///         title: "Task 1".to_string(),
+    ///     },
+    ///     TaskV1_0_0 {
+    ///         id: "task-2".to_string(),
+    ///         title: "Task 2".to_string(),
+    ///     },
+    /// ];
     ///
     /// let migrator = Migrator::new();
-    /// let json = migrator.save(task)?;
-    /// // json: {"version":"1.0.0","data":{"id":"task-1","title":"My Task"}}
+    /// let json = migrator.save_vec_flat(tasks)?;
+    /// // json: [{"version":"1.0.0","id":"task-1",...}, ...]
     /// ```
-    pub fn save<T: Versioned + Serialize>(&self, data: T) -> Result<String, MigrationError> {
-        // Use custom keys from the type's Versioned trait
+    pub fn save_vec_flat<T: Versioned + Serialize>(
+        &self,
+        data: Vec<T>,
+    ) -> Result<String, MigrationError> {
         let version_key = T::VERSION_KEY;
-        let data_key = T::DATA_KEY;
 
-        // Serialize the data
-        let data_value = serde_json::to_value(&data).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
-        })?;
+        let flat_items: Result<Vec<serde_json::Value>, MigrationError> = data
+            .into_iter()
+            .map(|item| {
+                let mut data_value = serde_json::to_value(&item).map_err(|e| {
+                    MigrationError::SerializationError(format!("Failed to serialize item: {}", e))
+                })?;
 
-        // Build the wrapper with custom keys
-        let mut map = serde_json::Map::new();
-        map.insert(
-            version_key.to_string(),
-            serde_json::Value::String(T::VERSION.to_string()),
-        );
-        map.insert(data_key.to_string(), data_value);
+                let obj = data_value.as_object_mut().ok_or_else(|| {
+                    MigrationError::SerializationError(
+                        "Data must serialize to a JSON object for flat format".to_string(),
+                    )
+                })?;
 
-        serde_json::to_string(&map).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
+                // Add version field at the same level
+                obj.insert(
+                    version_key.to_string(),
+                    serde_json::Value::String(T::VERSION.to_string()),
+                );
+
+                Ok(serde_json::Value::Object(obj.clone()))
+            })
+            .collect();
+
+        serde_json::to_string(&flat_items?).map_err(|e| {
+            MigrationError::SerializationError(format!(
+                "Failed to serialize flat data array: {}",
+                e
+            ))
         })
     }
 
-    /// Saves versioned data to a JSON string in flat format.
+    // =========================================================================
+    // Forward Compatibility API
+    // =========================================================================
+
+    /// Loads data with forward compatibility support.
     ///
-    /// Unlike `save()`, this method produces a flat JSON structure where the version
-    /// field is at the same level as the data fields, not wrapped in a separate object.
+    /// This method allows loading data from versions that don't exist in the current
+    /// codebase yet. Unknown versions are deserialized using the latest known schema,
+    /// and unknown fields are preserved for later saving.
+    ///
+    /// # ⚠️ Requirements
+    ///
+    /// Forward compatibility assumes **additive-only schema changes**:
+    ///
+    /// - ✅ Field additions (future version has new fields) → OK, unknown fields preserved
+    /// - ❌ Field deletions → Deserialization will fail
+    /// - ❌ Field type changes → Data corruption
+    /// - ❌ Field semantic changes → Logic bugs
     ///
     /// # Arguments
     ///
-    /// * `data` - The versioned data to save
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `json` - A JSON string containing versioned data
     ///
     /// # Returns
     ///
-    /// A JSON string with the format: `{"version":"x.y.z","field1":"value1",...}`
+    /// A `Forwardable<D>` wrapper containing the domain model and context for preserving
+    /// unknown fields when saving.
     ///
-    /// # Errors
+    /// # Example
     ///
-    /// Returns `SerializationError` if the data cannot be serialized to JSON.
+    /// ```ignore
+    /// // Data from a future version (2.0.0) that doesn't exist in code yet
+    /// let json = r#"{"version":"2.0.0","data":{"id":"1","title":"Task","new_field":"value"}}"#;
+    ///
+    /// // Load with forward compatibility (V1 is the latest known version)
+    /// let mut task: Forwardable<TaskEntity> = migrator.load_forward("task", json)?;
+    ///
+    /// // Check if it was a lossy load
+    /// if task.was_lossy() {
+    ///     eprintln!("Warning: Loaded from unknown version {}", task.original_version());
+    /// }
+    ///
+    /// // Modify the data
+    /// task.title = "Updated".to_string();
+    ///
+    /// // Save preserving unknown fields and original version
+    /// let saved = migrator.save_forward(&task)?;
+    /// // → {"version":"2.0.0","data":{"id":"1","title":"Updated","new_field":"value"}}
+    /// ```
+    pub fn load_forward<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<Forwardable<D>, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+        self.load_forward_from(entity, value, false)
+    }
+
+    /// Loads data with forward compatibility support from flat format.
+    ///
+    /// Same as `load_forward` but expects flat format where version is at the same
+    /// level as data fields.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let task = TaskV1_0_0 {
-    ///     id: "task-1".to_string(),
-    ///     title: "My Task".to_string(),
-    /// };
-    ///
-    /// let migrator = Migrator::new();
-    /// let json = migrator.save_flat(task)?;
-    /// // json: {"version":"1.0.0","id":"task-1","title":"My Task"}
+    /// let json = r#"{"version":"2.0.0","id":"1","title":"Task","new_field":"value"}"#;
+    /// let task: Forwardable<TaskEntity> = migrator.load_forward_flat("task", json)?;
     /// ```
-    pub fn save_flat<T: Versioned + Serialize>(&self, data: T) -> Result<String, MigrationError> {
-        let version_key = T::VERSION_KEY;
+    pub fn load_forward_flat<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        json: &str,
+    ) -> Result<Forwardable<D>, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+        self.load_forward_from(entity, value, true)
+    }
 
-        // Serialize the data to a JSON object
-        let mut data_value = serde_json::to_value(&data).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
+    /// Internal implementation for forward-compatible loading.
+    fn load_forward_from<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        value: serde_json::Value,
+        is_flat: bool,
+    ) -> Result<Forwardable<D>, MigrationError> {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let version_key = &path.version_key;
+        let data_key = &path.data_key;
+
+        let obj = value.as_object().ok_or_else(|| {
+            MigrationError::DeserializationError("Expected JSON object".to_string())
         })?;
 
-        // Ensure it's an object so we can add the version field
-        let obj = data_value.as_object_mut().ok_or_else(|| {
-            MigrationError::SerializationError(
-                "Data must serialize to a JSON object for flat format".to_string(),
-            )
+        // Extract version
+        let original_version = obj
+            .get(version_key)
+            .ok_or_else(|| {
+                MigrationError::DeserializationError(format!("Missing '{}' field", version_key))
+            })
+            .and_then(|v| path.read_version(v, version_key))?;
+
+        // Extract data (handling both wrapped and flat formats)
+        let (data_value, all_fields) = if is_flat {
+            // Flat format: data fields are at the same level as version
+            let mut fields = obj.clone();
+            fields.remove(version_key);
+            (serde_json::Value::Object(fields.clone()), fields)
+        } else {
+            // Wrapped format: data is in a separate field
+            let data = obj
+                .get(data_key)
+                .ok_or_else(|| {
+                    MigrationError::DeserializationError(format!("Missing '{}' field", data_key))
+                })?
+                .clone();
+            let fields = data
+                .as_object()
+                .cloned()
+                .unwrap_or_else(serde_json::Map::new);
+            (data, fields)
+        };
+
+        // Check if version is known
+        let is_known_version = path.knows_version(&original_version);
+        let was_lossy = !is_known_version;
+
+        // Determine which version to use for deserialization
+        let target_version = if is_known_version {
+            original_version.clone()
+        } else {
+            // Use the latest known version for unknown versions
+            path.versions.last().cloned().ok_or_else(|| {
+                MigrationError::DeserializationError(
+                    "No versions registered for entity".to_string(),
+                )
+            })?
+        };
+
+        // Apply migrations if needed (from known version to latest)
+        let current_data = if is_known_version {
+            path.migrate_steps(target_version, data_value)?.1
+        } else {
+            // For unknown versions, skip migration and deserialize directly
+            data_value
+        };
+
+        // Finalize into domain model
+        let domain_value = (path.finalize)(current_data)?;
+
+        // Deserialize to domain type
+        let domain: D = serde_json::from_value(domain_value.clone()).map_err(|e| {
+            MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
         })?;
 
-        // Add the version field to the same level as data fields
-        obj.insert(
-            version_key.to_string(),
-            serde_json::Value::String(T::VERSION.to_string()),
+        // Calculate unknown fields (fields in original data but not in domain)
+        let domain_obj = domain_value
+            .as_object()
+            .cloned()
+            .unwrap_or_else(serde_json::Map::new);
+        let mut unknown_fields = serde_json::Map::new();
+        for (key, value) in all_fields {
+            if !domain_obj.contains_key(&key) {
+                unknown_fields.insert(key, value);
+            }
+        }
+
+        let ctx = ForwardContext::new(
+            original_version,
+            unknown_fields,
+            was_lossy,
+            version_key.clone(),
+            data_key.clone(),
+            is_flat,
         );
 
-        serde_json::to_string(&obj).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize flat format: {}", e))
-        })
+        Ok(Forwardable::new(domain, ctx))
     }
 
-    /// Loads and migrates multiple entities from any serde-compatible format.
+    /// Saves data preserving forward compatibility information.
     ///
-    /// This is the generic version that accepts any type implementing `Serialize`.
-    /// For JSON arrays, use the convenience method `load_vec` instead.
+    /// This method saves the domain data while preserving unknown fields from the
+    /// original data and maintaining the original version number.
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity name used when registering the migration path
-    /// * `data` - Array of versioned data in any serde-compatible format
+    /// * `data` - A `Forwardable<D>` wrapper containing domain data and context
     ///
     /// # Returns
     ///
-    /// A vector of migrated data as domain model types
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The data cannot be converted to the internal format
-    /// - The entity is not registered
-    /// - Any migration step fails
+    /// A JSON string with preserved unknown fields and original version.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Load from TOML array
-    /// let toml_array: Vec<toml::Value> = /* ... */;
-    /// let domains: Vec<TaskEntity> = migrator.load_vec_from("task", toml_array)?;
-    ///
-    /// // Load from JSON Value array
-    /// let json_array: Vec<serde_json::Value> = /* ... */;
-    /// let domains: Vec<TaskEntity> = migrator.load_vec_from("task", json_array)?;
+    /// let mut task: Forwardable<TaskEntity> = migrator.load_forward("task", json)?;
+    /// task.title = "Updated".to_string();
+    /// let saved = migrator.save_forward(&task)?;
     /// ```
-    pub fn load_vec_from<D, T>(&self, entity: &str, data: Vec<T>) -> Result<Vec<D>, MigrationError>
-    where
-        D: DeserializeOwned,
-        T: Serialize,
-    {
-        data.into_iter()
-            .map(|item| self.load_from(entity, item))
-            .collect()
+    pub fn save_forward<D: Serialize>(
+        &self,
+        data: &Forwardable<D>,
+    ) -> Result<String, MigrationError> {
+        let ctx = data.context();
+
+        // Serialize the domain data
+        let mut domain_value = serde_json::to_value(&data.inner).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize domain: {}", e))
+        })?;
+
+        // Merge unknown fields back
+        if let Some(obj) = domain_value.as_object_mut() {
+            for (key, value) in ctx.unknown_fields.iter() {
+                if !obj.contains_key(key) {
+                    obj.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        // Build output based on original format
+        if ctx.was_flat {
+            // Flat format
+            let obj = domain_value.as_object_mut().ok_or_else(|| {
+                MigrationError::SerializationError(
+                    "Domain must serialize to object for flat format".to_string(),
+                )
+            })?;
+            obj.insert(
+                ctx.version_key.clone(),
+                serde_json::Value::String(ctx.original_version.clone()),
+            );
+            serde_json::to_string(&obj).map_err(|e| {
+                MigrationError::SerializationError(format!("Failed to serialize: {}", e))
+            })
+        } else {
+            // Wrapped format
+            let mut wrapper = serde_json::Map::new();
+            wrapper.insert(
+                ctx.version_key.clone(),
+                serde_json::Value::String(ctx.original_version.clone()),
+            );
+            wrapper.insert(ctx.data_key.clone(), domain_value);
+            serde_json::to_string(&wrapper).map_err(|e| {
+                MigrationError::SerializationError(format!("Failed to serialize: {}", e))
+            })
+        }
     }
 
-    /// Loads and migrates multiple entities from a JSON array string.
+    /// Saves a domain entity to a JSON string using its latest versioned format.
     ///
-    /// This is a convenience method for the common case of loading from a JSON array.
-    /// For other formats, use `load_vec_from` instead.
+    /// This method automatically converts the domain entity to its latest version
+    /// and saves it with version information.
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity name used when registering the migration path
-    /// * `json` - A JSON array string containing versioned data
+    /// * `entity` - The domain entity to save (must implement `LatestVersioned`)
     ///
     /// # Returns
     ///
-    /// A vector of migrated data as domain model types
+    /// A JSON string with the format: `{"version":"x.y.z","data":{...}}`
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The JSON cannot be parsed
-    /// - The entity is not registered
-    /// - Any migration step fails
+    /// Returns `SerializationError` if the entity cannot be serialized to JSON.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let json = r#"[
-    ///     {"version":"1.0.0","data":{"id":"task-1","title":"Task 1"}},
-    ///     {"version":"1.0.0","data":{"id":"task-2","title":"Task 2"}}
-    /// ]"#;
-    /// let domains: Vec<TaskEntity> = migrator.load_vec("task", json)?;
+    /// #[version_migrate(entity = "task", latest = TaskV1_1_0)]
+    /// struct TaskEntity {
+    ///     id: String,
+    ///     title: String,
+    ///     description: Option<String>,
+    /// }
+    ///
+    /// let entity = TaskEntity {
+    ///     id: "task-1".to_string(),
+    ///     title: "My Task".to_string(),
+    ///     description: Some("Description".to_string()),
+    /// };
+    ///
+    /// let migrator = Migrator::new();
+    /// let json = migrator.save_entity(entity)?;
+    /// // Automatically saved with latest version (1.1.0)
     /// ```
-    pub fn load_vec<D: DeserializeOwned>(
+    pub fn save_entity<E: crate::LatestVersioned>(
         &self,
-        entity: &str,
-        json: &str,
-    ) -> Result<Vec<D>, MigrationError> {
-        let data: Vec<serde_json::Value> = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON array: {}", e))
-        })?;
-        self.load_vec_from(entity, data)
+        entity: E,
+    ) -> Result<String, MigrationError> {
+        let latest = entity.to_latest();
+        self.save(latest)
     }
 
-    /// Loads and migrates multiple entities from a flat format JSON array string.
+    /// Saves a domain entity to a JSON string in flat format using its latest versioned format.
     ///
-    /// This is a convenience method for loading from a JSON array where each element
-    /// has the version field at the same level as the data fields.
+    /// This method automatically converts the domain entity to its latest version
+    /// and saves it with the version field at the same level as data fields.
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity name used when registering the migration path
-    /// * `json` - A JSON array string containing versioned data in flat format
+    /// * `entity` - The domain entity to save (must implement `LatestVersioned`)
     ///
     /// # Returns
     ///
-    /// A vector of migrated data as domain model types
+    /// A JSON string with the format: `{"version":"x.y.z","field1":"value1",...}`
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The JSON cannot be parsed
-    /// - The entity is not registered
-    /// - Any migration step fails
+    /// Returns `SerializationError` if the entity cannot be serialized to JSON.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let json = r#"[
-    ///     {"version":"1.0.0","id":"task-1","title":"Task 1"},
-    ///     {"version":"1.0.0","id":"task-2","title":"Task 2"}
-    /// ]"#;
-    /// let domains: Vec<TaskEntity> = migrator.load_vec_flat("task", json)?;
+    /// #[version_migrate(entity = "task", latest = TaskV1_1_0)]
+    /// struct TaskEntity {
+    ///     id: String,
+    ///     title: String,
+    ///     description: Option<String>,
+    /// }
+    ///
+    /// let entity = TaskEntity {
+    ///     id: "task-1".to_string(),
+    ///     title: "My Task".to_string(),
+    ///     description: Some("Description".to_string()),
+    /// };
+    ///
+    /// let migrator = Migrator::new();
+    /// let json = migrator.save_entity_flat(entity)?;
+    /// // json: {"version":"1.1.0","id":"task-1","title":"My Task",...}
     /// ```
-    pub fn load_vec_flat<D: DeserializeOwned>(
+    pub fn save_entity_flat<E: crate::LatestVersioned>(
         &self,
-        entity: &str,
-        json: &str,
-    ) -> Result<Vec<D>, MigrationError> {
-        let data: Vec<serde_json::Value> = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON array: {}", e))
-        })?;
-        self.load_vec_flat_from(entity, data)
+        entity: E,
+    ) -> Result<String, MigrationError> {
+        let latest = entity.to_latest();
+        self.save_flat(latest)
     }
 
-    /// Loads and migrates multiple entities from any serde-compatible format in flat format.
+    /// Saves multiple domain entities to a JSON array string using their latest versioned format.
     ///
-    /// This method expects each element to have the version field at the same level
-    /// as the data fields. It uses the registered migration path's runtime-configured
-    /// keys (respecting the Path > Migrator > Trait priority).
+    /// This method automatically converts each domain entity to its latest version
+    /// and saves them as a JSON array.
     ///
     /// # Arguments
     ///
-    /// * `entity` - The entity name used when registering the migration path
-    /// * `data` - Vector of serde-compatible values in flat format
+    /// * `entities` - Vector of domain entities to save
     ///
     /// # Returns
     ///
-    /// A vector of migrated data as domain model types
+    /// A JSON array string where each element has the format: `{"version":"x.y.z","data":{...}}`
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The entity is not registered
-    /// - The data format is invalid
-    /// - Any migration step fails
+    /// Returns `SerializationError` if the entities cannot be serialized to JSON.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let toml_array: Vec<toml::Value> = /* ... */;
-    /// let domains: Vec<TaskEntity> = migrator.load_vec_flat_from("task", toml_array)?;
+    /// let entities = vec![
+    ///     TaskEntity { id: "1".into(), title: "Task 1".into(), description: None },
+    ///     TaskEntity { id: "2".into(), title: "Task 2".into(), description: None },
+    /// ];
+    ///
+    /// let json = migrator.save_entity_vec(entities)?;
     /// ```
-    pub fn load_vec_flat_from<D, T>(
+    pub fn save_entity_vec<E: crate::LatestVersioned>(
         &self,
-        entity: &str,
-        data: Vec<T>,
-    ) -> Result<Vec<D>, MigrationError>
-    where
-        D: DeserializeOwned,
-        T: Serialize,
-    {
-        data.into_iter()
-            .map(|item| self.load_flat_from(entity, item))
-            .collect()
+        entities: Vec<E>,
+    ) -> Result<String, MigrationError> {
+        let versioned = E::batch_to_latest(entities);
+        self.save_vec(versioned)
     }
 
-    /// Saves multiple versioned entities to a JSON array string.
+    /// Saves multiple domain entities to a JSON array string in flat format using their latest versioned format.
     ///
-    /// This method wraps each item with its version information and serializes
-    /// them as a JSON array. The resulting JSON can later be loaded and migrated
-    /// using the `load_vec` method.
+    /// This method automatically converts each domain entity to its latest version
+    /// and saves them with version fields at the same level as data fields.
     ///
     /// # Arguments
     ///
-    /// * `data` - Vector of versioned data to save
+    /// * `entities` - Vector of domain entities to save
     ///
     /// # Returns
     ///
-    /// A JSON array string where each element has the format: `{"version":"x.y.z","data":{...}}`
+    /// A JSON array string where each element has the format: `{"version":"x.y.z","field1":"value1",...}`
     ///
     /// # Errors
     ///
-    /// Returns `SerializationError` if the data cannot be serialized to JSON.
+    /// Returns `SerializationError` if the entities cannot be serialized to JSON.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let tasks = vec![
-    ///     TaskV1_0_0 {
-    ///         id: "task-1".to_string(),
-    ///         title: "Task 1".to_string(),
-    ///     },
-    ///     TaskV1_0_0 {
-    ///         id: "task-2".to_string(),
-    ///         title: "Task 2".to_string(),
-    ///     },
+    /// let entities = vec![
+    ///     TaskEntity { id: "1".into(), title: "Task 1".into(), description: None },
+    ///     TaskEntity { id: "2".into(), title: "Task 2".into(), description: None },
     /// ];
     ///
-    /// let migrator = Migrator::new();
-    /// let json = migrator.save_vec(tasks)?;
-    /// // json: [{"version":"1.0.0","data":{"id":"task-1",...}}, ...]
+    /// let json = migrator.save_entity_vec_flat(entities)?;
     /// ```
-    pub fn save_vec<T: Versioned + Serialize>(
+    pub fn save_entity_vec_flat<E: crate::LatestVersioned>(
         &self,
-        data: Vec<T>,
+        entities: Vec<E>,
     ) -> Result<String, MigrationError> {
-        let version_key = T::VERSION_KEY;
-        let data_key = T::DATA_KEY;
-
-        let wrappers: Result<Vec<serde_json::Value>, MigrationError> = data
-            .into_iter()
-            .map(|item| {
-                let data_value = serde_json::to_value(&item).map_err(|e| {
-                    MigrationError::SerializationError(format!("Failed to serialize item: {}", e))
-                })?;
-
-                let mut map = serde_json::Map::new();
-                map.insert(
-                    version_key.to_string(),
-                    serde_json::Value::String(T::VERSION.to_string()),
-                );
-                map.insert(data_key.to_string(), data_value);
-
-                Ok(serde_json::Value::Object(map))
-            })
-            .collect();
-
-        serde_json::to_string(&wrappers?).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize data array: {}", e))
-        })
+        let versioned = E::batch_to_latest(entities);
+        self.save_vec_flat(versioned)
     }
 
-    /// Saves multiple versioned entities to a JSON array string in flat format.
+    /// Saves a domain entity to a JSON string using its latest versioned format, by entity name.
     ///
-    /// This method serializes each item with the version field at the same level
-    /// as the data fields, not wrapped in a separate object.
+    /// This method works without requiring the `VersionMigrate` macro on the entity type.
+    /// Instead, it uses the save function registered during `register()` via `into_with_save()`.
     ///
     /// # Arguments
     ///
-    /// * `data` - Vector of versioned data to save
+    /// * `entity_name` - The entity name used when registering the migration path
+    /// * `entity` - The domain entity to save (must be Serialize)
     ///
     /// # Returns
     ///
-    /// A JSON array string where each element has the format: `{"version":"x.y.z","field1":"value1",...}`
+    /// A JSON string with the format: `{"version":"x.y.z","data":{...}}`
     ///
     /// # Errors
     ///
-    /// Returns `SerializationError` if the data cannot be serialized to JSON.
+    /// Returns `MigrationPathNotDefined` if the entity is not registered with save support.
+    /// Returns `SerializationError` if the entity cannot be serialized.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let tasks = vec![
-    ///     TaskV1_0_0 {
-    ///         id: "task-1".to_string(),
-    ///         title: "Task 1".to_string(),
-    ///     },
-    ///     TaskV1_0_0 {
-    ///         id: "task-2".to_string(),
-    ///         title: "Task 2".to_string(),
-    ///     },
-    /// ];
+    /// impl FromDomain<TaskEntity> for TaskV1_1_0 {
+    ///     fn from_domain(entity: TaskEntity) -> Self { ... }
+    /// }
     ///
-    /// let migrator = Migrator::new();
-    /// let json = migrator.save_vec_flat(tasks)?;
-    /// // json: [{"version":"1.0.0","id":"task-1",...}, ...]
+    /// let path = Migrator::define("task")
+    ///     .from::<TaskV1_0_0>()
+    ///     .step::<TaskV1_1_0>()
+    ///     .into_with_save::<TaskEntity>();
+    ///
+    /// migrator.register(path)?;
+    ///
+    /// let entity = TaskEntity { ... };
+    /// let json = migrator.save_domain("task", entity)?;
+    /// // → {"version":"1.1.0","data":{"id":"1","title":"My Task",...}}
     /// ```
-    pub fn save_vec_flat<T: Versioned + Serialize>(
+    pub fn save_domain<T: Serialize>(
         &self,
-        data: Vec<T>,
+        entity_name: &str,
+        entity: T,
     ) -> Result<String, MigrationError> {
-        let version_key = T::VERSION_KEY;
-
-        let flat_items: Result<Vec<serde_json::Value>, MigrationError> = data
-            .into_iter()
-            .map(|item| {
-                let mut data_value = serde_json::to_value(&item).map_err(|e| {
-                    MigrationError::SerializationError(format!("Failed to serialize item: {}", e))
-                })?;
-
-                let obj = data_value.as_object_mut().ok_or_else(|| {
-                    MigrationError::SerializationError(
-                        "Data must serialize to a JSON object for flat format".to_string(),
-                    )
-                })?;
-
-                // Add version field at the same level
-                obj.insert(
-                    version_key.to_string(),
-                    serde_json::Value::String(T::VERSION.to_string()),
-                );
-
-                Ok(serde_json::Value::Object(obj.clone()))
-            })
-            .collect();
-
-        serde_json::to_string(&flat_items?).map_err(|e| {
-            MigrationError::SerializationError(format!(
-                "Failed to serialize flat data array: {}",
-                e
-            ))
+        let wrapper = self.domain_wrapped_value(entity_name, entity)?;
+
+        serde_json::to_string(&wrapper).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
         })
     }
 
-    // =========================================================================
-    // Forward Compatibility API
-    // =========================================================================
-
-    /// Loads data with forward compatibility support.
+    /// Saves a domain entity under the default entity.
     ///
-    /// This method allows loading data from versions that don't exist in the current
-    /// codebase yet. Unknown versions are deserialized using the latest known schema,
-    /// and unknown fields are preserved for later saving.
+    /// Same as `save_domain` with the entity set by `set_default_entity`.
     ///
-    /// # ⚠️ Requirements
+    /// # Errors
     ///
-    /// Forward compatibility assumes **additive-only schema changes**:
+    /// Returns `NoDefaultEntity` if no default entity is set, plus the errors
+    /// of `save_domain`.
     ///
-    /// - ✅ Field additions (future version has new fields) → OK, unknown fields preserved
-    /// - ❌ Field deletions → Deserialization will fail
-    /// - ❌ Field type changes → Data corruption
-    /// - ❌ Field semantic changes → Logic bugs
+    /// # Example
     ///
-    /// # Arguments
+    /// ```ignore
+    /// migrator.set_default_entity("task")?;
+    /// let json = migrator.save_default(task)?;
+    /// ```
+    pub fn save_default<T: Serialize>(&self, entity: T) -> Result<String, MigrationError> {
+        self.save_domain(self.default_entity()?, entity)
+    }
+
+    /// Saves a domain entity like `save_domain`, then loads the output back
+    /// and checks it equals `entity`.
     ///
-    /// * `entity` - The entity name used when registering the migration path
-    /// * `json` - A JSON string containing versioned data
+    /// Catches `FromDomain` / `IntoDomain` pairs that are not inverses of each
+    /// other before the lossy output is written anywhere. The check costs a
+    /// full load, so reserve it for tests, debug builds or rarely saved data.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A `Forwardable<D>` wrapper containing the domain model and context for preserving
-    /// unknown fields when saving.
+    /// Returns `RoundTripMismatch` listing the differing top-level fields if
+    /// the reloaded value differs, plus the errors of `save_domain` and `load`.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Data from a future version (2.0.0) that doesn't exist in code yet
-    /// let json = r#"{"version":"2.0.0","data":{"id":"1","title":"Task","new_field":"value"}}"#;
+    /// let json = migrator.save_domain_checked("task", &entity)?;
+    /// ```
+    pub fn save_domain_checked<T>(
+        &self,
+        entity_name: &str,
+        entity: &T,
+    ) -> Result<String, MigrationError>
+    where
+        T: Serialize + DeserializeOwned + PartialEq,
+    {
+        let json = self.save_domain(entity_name, entity)?;
+        let reloaded: T = self.load(entity_name, &json)?;
+        if &reloaded == entity {
+            return Ok(json);
+        }
+
+        let to_value = |value: &T| {
+            serde_json::to_value(value)
+                .map_err(|e| MigrationError::SerializationError(e.to_string()))
+        };
+        let (expected, actual) = (to_value(entity)?, to_value(&reloaded)?);
+        let mut fields: Vec<String> = match (expected.as_object(), actual.as_object()) {
+            (Some(expected), Some(actual)) => expected
+                .keys()
+                .chain(actual.keys())
+                .filter(|key| expected.get(*key) != actual.get(*key))
+                .map(|key| {
+                    format!(
+                        "'{}': saved {} but loaded {}",
+                        key,
+                        expected.get(key).unwrap_or(&serde_json::Value::Null),
+                        actual.get(key).unwrap_or(&serde_json::Value::Null)
+                    )
+                })
+                .collect(),
+            _ => vec![format!("saved {} but loaded {}", expected, actual)],
+        };
+        fields.sort();
+        fields.dedup();
+
+        Err(MigrationError::RoundTripMismatch {
+            entity: entity_name.to_string(),
+            details: fields.join("; "),
+        })
+    }
+
+    /// Saves a domain entity to a `serde_json::Value` using its latest versioned
+    /// format, by entity name.
     ///
-    /// // Load with forward compatibility (V1 is the latest known version)
-    /// let mut task: Forwardable<TaskEntity> = migrator.load_forward("task", json)?;
+    /// Same as `save_domain`, but returns the wrapper as a value so it can be
+    /// embedded in a larger document without serializing twice.
     ///
-    /// // Check if it was a lossy load
-    /// if task.was_lossy() {
-    ///     eprintln!("Warning: Loaded from unknown version {}", task.original_version());
-    /// }
+    /// # Errors
     ///
-    /// // Modify the data
-    /// task.title = "Updated".to_string();
+    /// Same as `save_domain`.
     ///
-    /// // Save preserving unknown fields and original version
-    /// let saved = migrator.save_forward(&task)?;
-    /// // → {"version":"2.0.0","data":{"id":"1","title":"Updated","new_field":"value"}}
+    /// # Example
+    ///
+    /// ```ignore
+    /// let response = serde_json::json!({
+    ///     "task": migrator.save_domain_to_value("task", entity)?,
+    /// });
     /// ```
-    pub fn load_forward<D: DeserializeOwned>(
+    pub fn save_domain_to_value<T: Serialize>(
         &self,
-        entity: &str,
-        json: &str,
-    ) -> Result<Forwardable<D>, MigrationError> {
-        let value: serde_json::Value = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON: {}", e))
-        })?;
-        self.load_forward_from(entity, value, false)
+        entity_name: &str,
+        entity: T,
+    ) -> Result<serde_json::Value, MigrationError> {
+        self.domain_wrapped_value(entity_name, entity)
     }
 
-    /// Loads data with forward compatibility support from flat format.
+    /// Generates a minimal JSON fixture for `entity` at `version`.
     ///
-    /// Same as `load_forward` but expects flat format where version is at the same
-    /// level as data fields.
+    /// Starts from `D::default()`, converts it to the latest DTO via the
+    /// path's `FromDomain` saver, then downgrades it to `version` through the
+    /// backward path registered with `register_bidirectional`. Without a
+    /// backward path covering `version`, the latest DTO is reused with every
+    /// value zeroed (`""`, `0`, `false`, `[]`), which loads as long as the
+    /// older version's fields are a subset of the latest's.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `version` - The version to generate the fixture for
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered with `into_with_save` (`EntityNotFound`)
+    /// - `version` is not part of the entity's chain (`MigrationPathNotDefined`)
+    /// - A backward migration step fails
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let json = r#"{"version":"2.0.0","id":"1","title":"Task","new_field":"value"}"#;
-    /// let task: Forwardable<TaskEntity> = migrator.load_forward_flat("task", json)?;
+    /// let json = migrator.generate_test_fixture::<TaskEntity>("task", "1.0.0")?;
+    /// let task: TaskEntity = migrator.load("task", &json)?;
     /// ```
-    pub fn load_forward_flat<D: DeserializeOwned>(
-        &self,
-        entity: &str,
-        json: &str,
-    ) -> Result<Forwardable<D>, MigrationError> {
-        let value: serde_json::Value = serde_json::from_str(json).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to parse JSON: {}", e))
-        })?;
-        self.load_forward_from(entity, value, true)
-    }
-
-    /// Internal implementation for forward-compatible loading.
-    fn load_forward_from<D: DeserializeOwned>(
+    pub fn generate_test_fixture<D: Default + Serialize>(
         &self,
         entity: &str,
-        value: serde_json::Value,
-        is_flat: bool,
-    ) -> Result<Forwardable<D>, MigrationError> {
+        version: &str,
+    ) -> Result<String, MigrationError> {
         let path = self
-            .paths
-            .get(entity)
+            .entity_path(entity)
             .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
 
-        let version_key = &path.version_key;
-        let data_key = &path.data_key;
-
-        let obj = value.as_object().ok_or_else(|| {
-            MigrationError::DeserializationError("Expected JSON object".to_string())
-        })?;
-
-        // Extract version
-        let original_version = obj
-            .get(version_key)
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                MigrationError::DeserializationError(format!(
-                    "Missing or invalid '{}' field",
-                    version_key
-                ))
-            })?
-            .to_string();
-
-        // Extract data (handling both wrapped and flat formats)
-        let (data_value, all_fields) = if is_flat {
-            // Flat format: data fields are at the same level as version
-            let mut fields = obj.clone();
-            fields.remove(version_key);
-            (serde_json::Value::Object(fields.clone()), fields)
-        } else {
-            // Wrapped format: data is in a separate field
-            let data = obj
-                .get(data_key)
-                .ok_or_else(|| {
-                    MigrationError::DeserializationError(format!("Missing '{}' field", data_key))
-                })?
-                .clone();
-            let fields = data
-                .as_object()
-                .cloned()
-                .unwrap_or_else(serde_json::Map::new);
-            (data, fields)
-        };
-
-        // Check if version is known
-        let is_known_version = path.versions.contains(&original_version);
-        let was_lossy = !is_known_version;
-
-        // Determine which version to use for deserialization
-        let target_version = if is_known_version {
-            original_version.clone()
-        } else {
-            // Use the latest known version for unknown versions
-            path.versions.last().cloned().ok_or_else(|| {
-                MigrationError::DeserializationError(
-                    "No versions registered for entity".to_string(),
-                )
-            })?
-        };
-
-        // Apply migrations if needed (from known version to latest)
-        let mut current_data = data_value.clone();
-        let mut current_version = if is_known_version {
-            original_version.clone()
-        } else {
-            // For unknown versions, skip migration and deserialize directly
-            target_version.clone()
-        };
-
-        if is_known_version {
-            // Apply migration steps
-            while let Some(migrate_fn) = path.steps.get(&current_version) {
-                current_data = migrate_fn(current_data)?;
-                match path.versions.iter().position(|v| v == &current_version) {
-                    Some(idx) if idx + 1 < path.versions.len() => {
-                        current_version = path.versions[idx + 1].clone();
-                    }
-                    _ => break,
-                }
-            }
+        if !path.knows_version(version) {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: version.to_string(),
+            });
         }
 
-        // Finalize into domain model
-        let domain_value = (path.finalize)(current_data)?;
-
-        // Deserialize to domain type
-        let domain: D = serde_json::from_value(domain_value.clone()).map_err(|e| {
-            MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
-        })?;
+        let wrapper = self.domain_wrapped_value(entity, D::default())?;
+        let (latest, mut data) = path.split_wrapped(&wrapper)?;
 
-        // Calculate unknown fields (fields in original data but not in domain)
-        let domain_obj = domain_value
-            .as_object()
-            .cloned()
-            .unwrap_or_else(serde_json::Map::new);
-        let mut unknown_fields = serde_json::Map::new();
-        for (key, value) in all_fields {
-            if !domain_obj.contains_key(&key) {
-                unknown_fields.insert(key, value);
-            }
+        if latest != version {
+            data = match self.downgrade_value(entity, &latest, data.clone(), version)? {
+                Some(downgraded) => downgraded,
+                None => zero_value(&data),
+            };
         }
 
-        let ctx = ForwardContext::new(
-            original_version,
-            unknown_fields,
-            was_lossy,
-            version_key.clone(),
-            data_key.clone(),
-            is_flat,
+        let mut map = serde_json::Map::new();
+        map.insert(
+            path.version_key.clone(),
+            serde_json::Value::String(version.to_string()),
         );
+        map.insert(path.data_key.clone(), data);
 
-        Ok(Forwardable::new(domain, ctx))
+        serde_json::to_string(&serde_json::Value::Object(map)).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize fixture: {}", e))
+        })
     }
 
-    /// Saves data preserving forward compatibility information.
+    /// Serializes `data` at every version of `entity`'s chain up to `T::VERSION`.
     ///
-    /// This method saves the domain data while preserving unknown fields from the
-    /// original data and maintaining the original version number.
+    /// `T::VERSION` itself is always included. Earlier versions are produced
+    /// through the backward path registered with `register_bidirectional`
+    /// and are omitted when no backward path connects them, so a
+    /// forward-only chain yields just the current version.
     ///
     /// # Arguments
     ///
-    /// * `data` - A `Forwardable<D>` wrapper containing domain data and context
+    /// * `entity` - The entity name used when registering the migration path
+    /// * `data` - The versioned data to serialize
     ///
     /// # Returns
     ///
-    /// A JSON string with preserved unknown fields and original version.
+    /// A map of version → wrapped JSON string (`{"version": ..., "data": ...}`
+    /// using the path's keys).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The entity is not registered (`EntityNotFound`)
+    /// - `T::VERSION` is not in the entity's chain (`MigrationPathNotDefined`)
+    /// - Serialization or a backward migration step fails
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let mut task: Forwardable<TaskEntity> = migrator.load_forward("task", json)?;
-    /// task.title = "Updated".to_string();
-    /// let saved = migrator.save_forward(&task)?;
+    /// let fixtures = migrator.save_all_versions("task", task_v3)?;
+    /// for (version, json) in &fixtures {
+    ///     let task: TaskEntity = migrator.load("task", json)?;
+    /// }
     /// ```
-    pub fn save_forward<D: Serialize>(
+    pub fn save_all_versions<T: Versioned + Serialize>(
         &self,
-        data: &Forwardable<D>,
-    ) -> Result<String, MigrationError> {
-        let ctx = data.context();
+        entity: &str,
+        data: T,
+    ) -> Result<HashMap<String, String>, MigrationError> {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+        let Some(current) = path.versions.iter().position(|v| v == T::VERSION) else {
+            return Err(MigrationError::MigrationPathNotDefined {
+                entity: entity.to_string(),
+                version: T::VERSION.to_string(),
+            });
+        };
 
-        // Serialize the domain data
-        let mut domain_value = serde_json::to_value(&data.inner).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize domain: {}", e))
+        let data = serde_json::to_value(&data).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize data: {}", e))
         })?;
 
-        // Merge unknown fields back
-        if let Some(obj) = domain_value.as_object_mut() {
-            for (key, value) in ctx.unknown_fields.iter() {
-                if !obj.contains_key(key) {
-                    obj.insert(key.clone(), value.clone());
+        let mut out = HashMap::new();
+        for version in &path.versions[..=current] {
+            let versioned = if version == T::VERSION {
+                data.clone()
+            } else {
+                match self.downgrade_value(entity, T::VERSION, data.clone(), version)? {
+                    Some(downgraded) => downgraded,
+                    None => continue,
                 }
-            }
-        }
+            };
 
-        // Build output based on original format
-        if ctx.was_flat {
-            // Flat format
-            let obj = domain_value.as_object_mut().ok_or_else(|| {
-                MigrationError::SerializationError(
-                    "Domain must serialize to object for flat format".to_string(),
-                )
-            })?;
-            obj.insert(
-                ctx.version_key.clone(),
-                serde_json::Value::String(ctx.original_version.clone()),
-            );
-            serde_json::to_string(&obj).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize: {}", e))
-            })
-        } else {
-            // Wrapped format
-            let mut wrapper = serde_json::Map::new();
-            wrapper.insert(
-                ctx.version_key.clone(),
-                serde_json::Value::String(ctx.original_version.clone()),
+            let mut map = serde_json::Map::new();
+            map.insert(
+                path.version_key.clone(),
+                serde_json::Value::String(version.clone()),
             );
-            wrapper.insert(ctx.data_key.clone(), domain_value);
-            serde_json::to_string(&wrapper).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize: {}", e))
-            })
+            map.insert(path.data_key.clone(), versioned);
+
+            let json = serde_json::to_string(&serde_json::Value::Object(map)).map_err(|e| {
+                MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
+            })?;
+            out.insert(version.clone(), json);
+        }
+
+        Ok(out)
+    }
+
+    /// Runs the backward path of `entity` from version `from` down to `to`.
+    ///
+    /// Returns `None` if no backward path is registered or it does not
+    /// connect the two versions.
+    fn downgrade_value(
+        &self,
+        entity: &str,
+        from: &str,
+        data: serde_json::Value,
+        to: &str,
+    ) -> Result<Option<serde_json::Value>, MigrationError> {
+        let Some(backward) = self.paths.get(&backward_entity(entity)) else {
+            return Ok(None);
+        };
+
+        let mut current_version = from.to_string();
+        let mut current_data = data;
+        while current_version != to {
+            let (Some(step), Some(next)) = (
+                backward.steps.get(&current_version),
+                backward.next_version(&current_version),
+            ) else {
+                return Ok(None);
+            };
+            current_data = step(current_data)?;
+            current_version = next;
         }
+
+        Ok(Some(current_data))
     }
 
-    /// Saves a domain entity to a JSON string using its latest versioned format.
+    /// Converts a domain entity to the wrapped JSON value of its latest version.
+    fn domain_wrapped_value<T: Serialize>(
+        &self,
+        entity_name: &str,
+        entity: T,
+    ) -> Result<serde_json::Value, MigrationError> {
+        let saver = self.domain_savers.get(entity_name).ok_or_else(|| {
+            MigrationError::EntityNotFound(format!(
+                "Entity '{}' is not registered with domain save support. Use into_with_save() when defining the migration path.",
+                entity_name
+            ))
+        })?;
+
+        // Get version/data keys from registered path
+        let path = self.entity_path(entity_name).ok_or_else(|| {
+            MigrationError::EntityNotFound(format!("Entity '{}' is not registered", entity_name))
+        })?;
+
+        let domain_value = serde_json::to_value(entity).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize entity: {}", e))
+        })?;
+
+        (saver.save_fn)(domain_value, &path.version_key, &path.data_key)
+    }
+
+    /// Saves a domain entity to a JSON string in flat format using its latest versioned format, by entity name.
     ///
-    /// This method automatically converts the domain entity to its latest version
-    /// and saves it with version information.
+    /// This method works without requiring the `VersionMigrate` macro on the entity type.
+    /// The version field is placed at the same level as data fields.
     ///
     /// # Arguments
     ///
-    /// * `entity` - The domain entity to save (must implement `LatestVersioned`)
+    /// * `entity_name` - The entity name used when registering the migration path
+    /// * `entity` - The domain entity to save (must be Serialize)
     ///
     /// # Returns
     ///
-    /// A JSON string with the format: `{"version":"x.y.z","data":{...}}`
+    /// A JSON string with the format: `{"version":"x.y.z","field1":"value1",...}`
     ///
     /// # Errors
     ///
-    /// Returns `SerializationError` if the entity cannot be serialized to JSON.
+    /// Returns `MigrationPathNotDefined` if the entity is not registered with save support.
+    /// Returns `SerializationError` if the entity cannot be serialized.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// #[version_migrate(entity = "task", latest = TaskV1_1_0)]
-    /// struct TaskEntity {
-    ///     id: String,
-    ///     title: String,
-    ///     description: Option<String>,
-    /// }
-    ///
-    /// let entity = TaskEntity {
-    ///     id: "task-1".to_string(),
-    ///     title: "My Task".to_string(),
-    ///     description: Some("Description".to_string()),
-    /// };
-    ///
-    /// let migrator = Migrator::new();
-    /// let json = migrator.save_entity(entity)?;
-    /// // Automatically saved with latest version (1.1.0)
+    /// let json = migrator.save_domain_flat("task", entity)?;
+    /// // → {"version":"1.1.0","id":"1","title":"My Task",...}
     /// ```
-    pub fn save_entity<E: crate::LatestVersioned>(
+    pub fn save_domain_flat<T: Serialize>(
         &self,
-        entity: E,
+        entity_name: &str,
+        entity: T,
     ) -> Result<String, MigrationError> {
-        let latest = entity.to_latest();
-        self.save(latest)
+        let flat = self.save_domain_flat_to_value(entity_name, entity)?;
+
+        serde_json::to_string(&flat).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize flat format: {}", e))
+        })
     }
 
-    /// Saves a domain entity to a JSON string in flat format using its latest versioned format.
-    ///
-    /// This method automatically converts the domain entity to its latest version
-    /// and saves it with the version field at the same level as data fields.
-    ///
-    /// # Arguments
-    ///
-    /// * `entity` - The domain entity to save (must implement `LatestVersioned`)
-    ///
-    /// # Returns
+    /// Saves a domain entity to a `serde_json::Value` in flat format using its
+    /// latest versioned format, by entity name.
     ///
-    /// A JSON string with the format: `{"version":"x.y.z","field1":"value1",...}`
+    /// Same as `save_domain_flat`, but returns the value so it can be embedded
+    /// in a larger document without serializing twice.
     ///
     /// # Errors
     ///
-    /// Returns `SerializationError` if the entity cannot be serialized to JSON.
+    /// Same as `save_domain_flat`.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// #[version_migrate(entity = "task", latest = TaskV1_1_0)]
-    /// struct TaskEntity {
-    ///     id: String,
-    ///     title: String,
-    ///     description: Option<String>,
-    /// }
-    ///
-    /// let entity = TaskEntity {
-    ///     id: "task-1".to_string(),
-    ///     title: "My Task".to_string(),
-    ///     description: Some("Description".to_string()),
-    /// };
-    ///
-    /// let migrator = Migrator::new();
-    /// let json = migrator.save_entity_flat(entity)?;
-    /// // json: {"version":"1.1.0","id":"task-1","title":"My Task",...}
+    /// let items: Vec<serde_json::Value> = tasks
+    ///     .into_iter()
+    ///     .map(|t| migrator.save_domain_flat_to_value("task", t))
+    ///     .collect::<Result<_, _>>()?;
     /// ```
-    pub fn save_entity_flat<E: crate::LatestVersioned>(
+    pub fn save_domain_flat_to_value<T: Serialize>(
         &self,
-        entity: E,
-    ) -> Result<String, MigrationError> {
-        let latest = entity.to_latest();
-        self.save_flat(latest)
+        entity_name: &str,
+        entity: T,
+    ) -> Result<serde_json::Value, MigrationError> {
+        let saver = self.domain_savers.get(entity_name).ok_or_else(|| {
+            MigrationError::EntityNotFound(format!(
+                "Entity '{}' is not registered with domain save support. Use into_with_save() when defining the migration path.",
+                entity_name
+            ))
+        })?;
+
+        // Get version key from registered path
+        let path = self.entity_path(entity_name).ok_or_else(|| {
+            MigrationError::EntityNotFound(format!("Entity '{}' is not registered", entity_name))
+        })?;
+
+        let domain_value = serde_json::to_value(entity).map_err(|e| {
+            MigrationError::SerializationError(format!("Failed to serialize entity: {}", e))
+        })?;
+
+        (saver.save_flat_fn)(domain_value, &path.version_key)
     }
 
-    /// Saves multiple domain entities to a JSON array string using their latest versioned format.
-    ///
-    /// This method automatically converts each domain entity to its latest version
-    /// and saves them as a JSON array.
-    ///
-    /// # Arguments
-    ///
-    /// * `entities` - Vector of domain entities to save
-    ///
-    /// # Returns
+    /// Streams a domain entity, in its latest versioned format, to a writer.
     ///
-    /// A JSON array string where each element has the format: `{"version":"x.y.z","data":{...}}`
+    /// Produces the same JSON as `save_entity` without building an
+    /// intermediate `String`.
     ///
     /// # Errors
     ///
-    /// Returns `SerializationError` if the entities cannot be serialized to JSON.
+    /// Returns `SerializationError` if the entity cannot be serialized or the
+    /// writer fails.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let entities = vec![
-    ///     TaskEntity { id: "1".into(), title: "Task 1".into(), description: None },
-    ///     TaskEntity { id: "2".into(), title: "Task 2".into(), description: None },
-    /// ];
-    ///
-    /// let json = migrator.save_entity_vec(entities)?;
+    /// let file = std::fs::File::create("task.json")?;
+    /// migrator.save_entity_to_writer(entity, std::io::BufWriter::new(file))?;
     /// ```
-    pub fn save_entity_vec<E: crate::LatestVersioned>(
+    pub fn save_entity_to_writer<E: crate::LatestVersioned, W: std::io::Write>(
         &self,
-        entities: Vec<E>,
-    ) -> Result<String, MigrationError> {
-        let versioned: Vec<E::Latest> = entities.into_iter().map(|e| e.to_latest()).collect();
-        self.save_vec(versioned)
+        entity: E,
+        writer: W,
+    ) -> Result<(), MigrationError> {
+        let wrapper = Self::wrapped_value(&entity.to_latest())?;
+        write_json(writer, &wrapper)
     }
 
-    /// Saves multiple domain entities to a JSON array string in flat format using their latest versioned format.
-    ///
-    /// This method automatically converts each domain entity to its latest version
-    /// and saves them with version fields at the same level as data fields.
+    /// Streams versioned data in flat format to a writer.
     ///
-    /// # Arguments
+    /// Produces the same JSON as `save_flat` without building an
+    /// intermediate `String`.
     ///
-    /// * `entities` - Vector of domain entities to save
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns `SerializationError` if the data does not serialize to a JSON
+    /// object or the writer fails.
+    pub fn save_flat_to_writer<T: Versioned + Serialize, W: std::io::Write>(
+        &self,
+        data: T,
+        writer: W,
+    ) -> Result<(), MigrationError> {
+        let flat = Self::flat_value(&data)?;
+        write_json(writer, &flat)
+    }
+
+    /// Streams a domain entity, converted via its registered save path, to a writer.
     ///
-    /// A JSON array string where each element has the format: `{"version":"x.y.z","field1":"value1",...}`
+    /// Produces the same JSON as `save_domain` without building an
+    /// intermediate `String`.
     ///
     /// # Errors
     ///
-    /// Returns `SerializationError` if the entities cannot be serialized to JSON.
-    ///
-    /// # Example
+    /// Returns `EntityNotFound` if the entity is not registered with save
+    /// support, or `SerializationError` if serialization or the writer fails.
+    pub fn save_domain_to_writer<T: Serialize, W: std::io::Write>(
+        &self,
+        entity_name: &str,
+        entity: T,
+        writer: W,
+    ) -> Result<(), MigrationError> {
+        let wrapper = self.domain_wrapped_value(entity_name, entity)?;
+        write_json(writer, &wrapper)
+    }
+
+    /// Async version of `save_entity_to_writer` for `tokio::io::AsyncWrite`.
     ///
-    /// ```ignore
-    /// let entities = vec![
-    ///     TaskEntity { id: "1".into(), title: "Task 1".into(), description: None },
-    ///     TaskEntity { id: "2".into(), title: "Task 2".into(), description: None },
-    /// ];
+    /// # Errors
     ///
-    /// let json = migrator.save_entity_vec_flat(entities)?;
-    /// ```
-    pub fn save_entity_vec_flat<E: crate::LatestVersioned>(
+    /// Returns `SerializationError` if the entity cannot be serialized or the
+    /// writer fails.
+    #[cfg(feature = "async")]
+    pub async fn save_entity_to_async_writer<E, W>(
         &self,
-        entities: Vec<E>,
-    ) -> Result<String, MigrationError> {
-        let versioned: Vec<E::Latest> = entities.into_iter().map(|e| e.to_latest()).collect();
-        self.save_vec_flat(versioned)
+        entity: E,
+        writer: W,
+    ) -> Result<(), MigrationError>
+    where
+        E: crate::LatestVersioned,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let wrapper = Self::wrapped_value(&entity.to_latest())?;
+        write_json_async(writer, &wrapper).await
     }
 
-    /// Saves a domain entity to a JSON string using its latest versioned format, by entity name.
+    /// Async version of `save_flat_to_writer` for `tokio::io::AsyncWrite`.
     ///
-    /// This method works without requiring the `VersionMigrate` macro on the entity type.
-    /// Instead, it uses the save function registered during `register()` via `into_with_save()`.
+    /// # Errors
     ///
-    /// # Arguments
+    /// Returns `SerializationError` if the data does not serialize to a JSON
+    /// object or the writer fails.
+    #[cfg(feature = "async")]
+    pub async fn save_flat_to_async_writer<T, W>(
+        &self,
+        data: T,
+        writer: W,
+    ) -> Result<(), MigrationError>
+    where
+        T: Versioned + Serialize,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let flat = Self::flat_value(&data)?;
+        write_json_async(writer, &flat).await
+    }
+
+    /// Async version of `save_domain_to_writer` for `tokio::io::AsyncWrite`.
     ///
-    /// * `entity_name` - The entity name used when registering the migration path
-    /// * `entity` - The domain entity to save (must be Serialize)
+    /// # Errors
     ///
-    /// # Returns
+    /// Returns `EntityNotFound` if the entity is not registered with save
+    /// support, or `SerializationError` if serialization or the writer fails.
+    #[cfg(feature = "async")]
+    pub async fn save_domain_to_async_writer<T, W>(
+        &self,
+        entity_name: &str,
+        entity: T,
+        writer: W,
+    ) -> Result<(), MigrationError>
+    where
+        T: Serialize,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        let wrapper = self.domain_wrapped_value(entity_name, entity)?;
+        write_json_async(writer, &wrapper).await
+    }
+
+    /// Migrates every registered entity collection in a backup-style document
+    /// to its latest version.
     ///
-    /// A JSON string with the format: `{"version":"x.y.z","data":{...}}`
+    /// Each top-level key is matched against the registered entities, first
+    /// as is and then in singular form (`"tasks"` → `"task"`). Items of a
+    /// matched key are flat-format records and come back migrated to the
+    /// latest version, still carrying the version field; they are not
+    /// converted to the domain model. Unmatched keys are copied unchanged.
+    /// A value that is not an array is treated as a single-item list.
     ///
     /// # Errors
     ///
-    /// Returns `MigrationPathNotDefined` if the entity is not registered with save support.
-    /// Returns `SerializationError` if the entity cannot be serialized.
+    /// Returns an error if:
+    /// - The JSON cannot be parsed or is not an object
+    /// - An item of a matched key fails to migrate (`ElementFailed`)
     ///
     /// # Example
     ///
     /// ```ignore
-    /// impl FromDomain<TaskEntity> for TaskV1_1_0 {
-    ///     fn from_domain(entity: TaskEntity) -> Self { ... }
+    /// // {"tasks": [{"version": "1.0.0", ...}], "users": [...], "exported_at": "..."}
+    /// let restored = migrator.load_all_to_json_map(&backup_json)?;
+    /// for task in &restored["tasks"] {
+    ///     assert_eq!(task["version"], "2.0.0");
     /// }
-    ///
-    /// let path = Migrator::define("task")
-    ///     .from::<TaskV1_0_0>()
-    ///     .step::<TaskV1_1_0>()
-    ///     .into_with_save::<TaskEntity>();
-    ///
-    /// migrator.register(path)?;
-    ///
-    /// let entity = TaskEntity { ... };
-    /// let json = migrator.save_domain("task", entity)?;
-    /// // → {"version":"1.1.0","data":{"id":"1","title":"My Task",...}}
     /// ```
-    pub fn save_domain<T: Serialize>(
+    pub fn load_all_to_json_map(
         &self,
-        entity_name: &str,
-        entity: T,
+        json: &str,
+    ) -> Result<HashMap<String, Vec<serde_json::Value>>, MigrationError> {
+        let value: serde_json::Value = self.parse_json(json, "JSON")?;
+        let serde_json::Value::Object(root) = value else {
+            return Err(MigrationError::DeserializationError(
+                "Expected a JSON object at the top level".to_string(),
+            ));
+        };
+
+        let mut result = HashMap::with_capacity(root.len());
+        for (key, value) in root {
+            let items = match value {
+                serde_json::Value::Array(items) => items,
+                other => vec![other],
+            };
+
+            let items = match self.entity_for_key(&key) {
+                Some(entity) => items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        self.migrate_flat_to_latest(entity, item)
+                            .map_err(|e| element_error(index, e))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+                None => items,
+            };
+            result.insert(key, items);
+        }
+        Ok(result)
+    }
+
+    /// Resolves the registered entity whose items are stored under `key`.
+    ///
+    /// Matches the key itself first, then the plural of each entity name
+    /// (`"tasks"` → `"task"`, `"categories"` → `"category"`).
+    pub(crate) fn entity_for_key(&self, key: &str) -> Option<&str> {
+        if let Some((name, _)) = self.paths.get_key_value(key) {
+            return Some(name.as_str());
+        }
+        self.paths
+            .keys()
+            .find(|name| pluralize(name) == key)
+            .map(String::as_str)
+    }
+
+    /// Returns the ordered version chain of `entity`'s migration path.
+    #[cfg(feature = "registry")]
+    pub(crate) fn versions_of(&self, entity: &str) -> Option<&[String]> {
+        self.entity_path(entity)
+            .map(|path| path.versions.as_slice())
+    }
+
+    /// Returns the version key configured for `entity`'s migration path.
+    pub(crate) fn version_key_of(&self, entity: &str) -> Result<&str, MigrationError> {
+        self.entity_path(entity)
+            .map(|path| path.version_key.as_str())
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))
+    }
+
+    /// Reads a stored version value of `entity` the way its loaders do,
+    /// accepting numeric versions and resolving aliases.
+    pub(crate) fn read_version_of(
+        &self,
+        entity: &str,
+        value: &serde_json::Value,
     ) -> Result<String, MigrationError> {
-        let saver = self.domain_savers.get(entity_name).ok_or_else(|| {
-            MigrationError::EntityNotFound(format!(
-                "Entity '{}' is not registered with domain save support. Use into_with_save() when defining the migration path.",
-                entity_name
-            ))
-        })?;
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+        path.read_version(value, &path.version_key)
+    }
 
-        // Get version/data keys from registered path
-        let path = self.paths.get(entity_name).ok_or_else(|| {
-            MigrationError::EntityNotFound(format!("Entity '{}' is not registered", entity_name))
-        })?;
+    /// Migrates flat-format data to the latest registered version without
+    /// converting it into the domain model.
+    ///
+    /// The returned object carries the reached version under the path's version key.
+    pub(crate) fn migrate_flat_to_latest(
+        &self,
+        entity: &str,
+        value: serde_json::Value,
+    ) -> Result<serde_json::Value, MigrationError> {
+        let path = self
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+
+        let (current_version, current_data) = path.split_flat(value)?;
+        let (current_version, mut current_data) =
+            path.migrate_steps(current_version, current_data)?;
 
-        let domain_value = serde_json::to_value(entity).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize entity: {}", e))
+        let obj = current_data.as_object_mut().ok_or_else(|| {
+            MigrationError::SerializationError(
+                "Data must serialize to a JSON object for flat format".to_string(),
+            )
         })?;
+        obj.insert(
+            path.version_key.clone(),
+            serde_json::Value::String(current_version),
+        );
 
-        (saver.save_fn)(domain_value, &path.version_key, &path.data_key)
+        Ok(current_data)
     }
 
-    /// Saves a domain entity to a JSON string in flat format using its latest versioned format, by entity name.
-    ///
-    /// This method works without requiring the `VersionMigrate` macro on the entity type.
-    /// The version field is placed at the same level as data fields.
-    ///
-    /// # Arguments
-    ///
-    /// * `entity_name` - The entity name used when registering the migration path
-    /// * `entity` - The domain entity to save (must be Serialize)
+    /// Upgrades entity collections in a TOML document to their latest version,
+    /// leaving everything else as written.
     ///
-    /// # Returns
+    /// Each `(table_key, entity)` mapping names a top-level array of tables
+    /// (e.g. `[[tasks]]`) holding flat-format items of `entity`. Other tables,
+    /// top-level scalars and comments are kept byte-for-byte; upgraded
+    /// collections are re-rendered in place.
     ///
-    /// A JSON string with the format: `{"version":"x.y.z","field1":"value1",...}`
+    /// Requires the `toml-preserve` feature.
     ///
     /// # Errors
     ///
-    /// Returns `MigrationPathNotDefined` if the entity is not registered with save support.
-    /// Returns `SerializationError` if the entity cannot be serialized.
+    /// Returns an error if:
+    /// - The TOML cannot be parsed
+    /// - A mapped key is not an array
+    /// - An entity is not registered or an item fails to migrate
     ///
     /// # Example
     ///
     /// ```ignore
-    /// let json = migrator.save_domain_flat("task", entity)?;
-    /// // → {"version":"1.1.0","id":"1","title":"My Task",...}
+    /// let upgraded = migrator.upgrade_toml(&raw, &[("tasks", "task")])?;
+    /// std::fs::write("config.toml", upgraded)?;
     /// ```
-    pub fn save_domain_flat<T: Serialize>(
+    #[cfg(feature = "toml-preserve")]
+    pub fn upgrade_toml(
         &self,
-        entity_name: &str,
-        entity: T,
+        toml_str: &str,
+        mappings: &[(&str, &str)],
     ) -> Result<String, MigrationError> {
-        let saver = self.domain_savers.get(entity_name).ok_or_else(|| {
-            MigrationError::EntityNotFound(format!(
-                "Entity '{}' is not registered with domain save support. Use into_with_save() when defining the migration path.",
-                entity_name
-            ))
-        })?;
+        let toml_value: toml::Value =
+            toml::from_str(toml_str).map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
+        let mut root = serde_json::to_value(toml_value)
+            .map_err(|e| MigrationError::DeserializationError(e.to_string()))?;
 
-        // Get version key from registered path
-        let path = self.paths.get(entity_name).ok_or_else(|| {
-            MigrationError::EntityNotFound(format!("Entity '{}' is not registered", entity_name))
-        })?;
+        for (key, entity) in mappings {
+            let Some(items) = root.get_mut(*key) else {
+                continue;
+            };
+            let serde_json::Value::Array(items) = items else {
+                return Err(MigrationError::DeserializationError(format!(
+                    "Expected '{}' to be an array",
+                    key
+                )));
+            };
+            for item in items.iter_mut() {
+                *item = self.migrate_flat_to_latest(entity, item.take())?;
+            }
+        }
 
-        let domain_value = serde_json::to_value(entity).map_err(|e| {
-            MigrationError::SerializationError(format!("Failed to serialize entity: {}", e))
-        })?;
+        crate::storage::toml_preserve::render(toml_str, &root)
+    }
 
-        (saver.save_flat_fn)(domain_value, &path.version_key)
+    /// Consumes the migrator into a `ConfigMigrator` holding an empty JSON object.
+    ///
+    /// Equivalent to `ConfigMigrator::from("{}", migrator)`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut config = migrator.into_empty_config();
+    /// config.update("tasks", tasks)?;
+    /// ```
+    pub fn into_empty_config(self) -> ConfigMigrator {
+        ConfigMigrator {
+            root: serde_json::Value::Object(serde_json::Map::new()),
+            migrator: self,
+        }
+    }
+
+    /// Consumes the migrator into a `ConfigMigrator` built from a JSON string.
+    ///
+    /// Equivalent to `ConfigMigrator::from(json, migrator)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::DeserializationError` if the JSON is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let config = migrator.into_config_from_str(&config_json)?;
+    /// let tasks: Vec<TaskEntity> = config.query("tasks")?;
+    /// ```
+    pub fn into_config_from_str(self, json: &str) -> Result<ConfigMigrator, MigrationError> {
+        ConfigMigrator::from(json, self)
     }
 }
 
@@ -1592,6 +6406,7 @@ impl Default for Migrator {
 pub struct MigratorBuilder {
     default_version_key: Option<String>,
     default_data_key: Option<String>,
+    expected_entities: usize,
 }
 
 impl MigratorBuilder {
@@ -1599,9 +6414,17 @@ impl MigratorBuilder {
         Self {
             default_version_key: None,
             default_data_key: None,
+            expected_entities: 0,
         }
     }
 
+    /// Hints how many entities will be registered, so the migrator can
+    /// preallocate its maps. See [`Migrator::with_capacity`].
+    pub fn expected_entities(mut self, n: usize) -> Self {
+        self.expected_entities = n;
+        self
+    }
+
     /// Sets the default version key for all entities.
     ///
     /// This key will be used unless overridden by:
@@ -1625,10 +6448,9 @@ impl MigratorBuilder {
     /// Builds the `Migrator` with the configured defaults.
     pub fn build(self) -> Migrator {
         Migrator {
-            paths: HashMap::new(),
             default_version_key: self.default_version_key,
             default_data_key: self.default_data_key,
-            domain_savers: HashMap::new(),
+            ..Migrator::with_capacity(self.expected_entities)
         }
     }
 }
@@ -1651,9 +6473,49 @@ pub struct MigrationPathBuilder<State> {
     data_key: String,
     custom_version_key: Option<String>,
     custom_data_key: Option<String>,
+    ranges: Vec<(String, String)>,
+    version_pointer: Option<String>,
+    data_pointer: Option<String>,
+    frozen: Vec<String>,
+    unknown_fields: HashMap<String, UnknownFieldsFn>,
     _state: PhantomData<State>,
 }
 
+impl<State> MigrationPathBuilder<State> {
+    /// Assembles the migration path from the registered steps, the final
+    /// conversion to the domain model and, if available, its savers.
+    fn finish<D>(self, finalize: FinalizeFn, savers: Option<DomainSavers>) -> MigrationPath<D> {
+        let (save_fn, save_flat_fn) = match savers {
+            Some(savers) => (Some(savers.save_fn), Some(savers.save_flat_fn)),
+            None => (None, None),
+        };
+
+        MigrationPath {
+            entity: self.entity,
+            inner: EntityMigrationPath {
+                steps: self.steps,
+                finalize,
+                versions: self.versions.clone(),
+                version_key: self.version_key,
+                data_key: self.data_key,
+                ranges: Vec::new(),
+                unknown_fields: self.unknown_fields,
+                options: EntityOptions::default(),
+            },
+            versions: self.versions,
+            ranges: self.ranges,
+            version_pointer: self.version_pointer,
+            data_pointer: self.data_pointer,
+            custom_version_key: self.custom_version_key,
+            custom_data_key: self.custom_data_key,
+            save_fn,
+            save_flat_fn,
+            frozen: self.frozen,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl MigrationPathBuilder<Start> {
     fn new(entity: String) -> Self {
         Self {
@@ -1664,6 +6526,11 @@ impl MigrationPathBuilder<Start> {
             data_key: String::from("data"),
             custom_version_key: None,
             custom_data_key: None,
+            ranges: Vec::new(),
+            version_pointer: None,
+            data_pointer: None,
+            frozen: Vec::new(),
+            unknown_fields: HashMap::new(),
             _state: PhantomData,
         }
     }
@@ -1690,10 +6557,105 @@ impl MigrationPathBuilder<Start> {
         self
     }
 
+    /// Reads the version from a nested location given as a JSON pointer
+    /// (RFC 6901), e.g. `"/meta/schema"`.
+    ///
+    /// Shorthand for registering a [`VersionExtractor::JsonPointer`] with the
+    /// path: every loading method of the entity reads through it, and the
+    /// data is read from the data key unless `with_data_pointer` is also set.
+    /// A later `Migrator::set_version_extractor` replaces it. Saving keeps
+    /// using the version key. An invalid pointer fails `register` with
+    /// `InvalidPathConfig`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // {"meta": {"schema": "1.0.0"}, "data": {...}}
+    /// Migrator::define("task")
+    ///     .with_version_pointer("/meta/schema")
+    ///     .with_data_pointer("/data")
+    ///     .from::<TaskV1>()
+    ///     .into::<TaskDomain>();
+    /// ```
+    pub fn with_version_pointer(mut self, pointer: impl Into<String>) -> Self {
+        self.version_pointer = Some(pointer.into());
+        self
+    }
+
+    /// Reads the data from a nested location given as a JSON pointer
+    /// (RFC 6901), e.g. `"/payload/body"`.
+    ///
+    /// Saving keeps using the data key. See
+    /// [`with_version_pointer`](Self::with_version_pointer).
+    pub fn with_data_pointer(mut self, pointer: impl Into<String>) -> Self {
+        self.data_pointer = Some(pointer.into());
+        self
+    }
+
+    /// Adds a step from `from_version` to `to_version` that only renames
+    /// object keys, for versions that have no Rust type.
+    ///
+    /// Each `(old_key, new_key)` pair moves the top-level field `old_key` to
+    /// `new_key`; fields absent from the data are skipped. Rename steps are
+    /// chained before `from`, whose type must be the version the last rename
+    /// step leads to. A gap in the chain is reported by `register`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // 1.0.0 data stored `name`; TaskV2 (2.0.0) calls it `title`
+    /// let path = Migrator::define("task")
+    ///     .rename_step("1.0.0", "2.0.0", &[("name", "title")])
+    ///     .from::<TaskV2>()
+    ///     .into::<TaskEntity>();
+    /// ```
+    pub fn rename_step(
+        mut self,
+        from_version: &str,
+        to_version: &str,
+        renames: &[(&str, &str)],
+    ) -> Self {
+        let renames: Vec<(String, String)> = renames
+            .iter()
+            .map(|(old, new)| (old.to_string(), new.to_string()))
+            .collect();
+        let (from, to) = (from_version.to_string(), to_version.to_string());
+        let migration_fn: MigrationFn = Arc::new(move |mut value| {
+            let obj = value
+                .as_object_mut()
+                .ok_or_else(|| MigrationError::MigrationStepFailed {
+                    from: from.clone(),
+                    to: to.clone(),
+                    error: "expected a JSON object".to_string(),
+                })?;
+            for (old, new) in &renames {
+                if let Some(field) = obj.remove(old) {
+                    obj.insert(new.clone(), field);
+                }
+            }
+            Ok(value)
+        });
+
+        if self.versions.last().map(String::as_str) != Some(from_version) {
+            self.versions.push(from_version.to_string());
+        }
+        self.versions.push(to_version.to_string());
+        self.steps.insert(from_version.to_string(), migration_fn);
+        self
+    }
+
     /// Sets the starting version for migrations.
     pub fn from<V: Versioned + DeserializeOwned>(self) -> MigrationPathBuilder<HasFrom<V>> {
         let mut versions = self.versions;
-        versions.push(V::VERSION.to_string());
+        if versions.last().map(String::as_str) != Some(V::VERSION) {
+            versions.push(V::VERSION.to_string());
+        }
+        let mut frozen = self.frozen;
+        if V::FROZEN {
+            frozen.push(V::VERSION.to_string());
+        }
+        let mut unknown_fields = self.unknown_fields;
+        unknown_fields.insert(V::VERSION.to_string(), unknown_fields_fn::<V>());
 
         MigrationPathBuilder {
             entity: self.entity,
@@ -1703,6 +6665,11 @@ impl MigrationPathBuilder<Start> {
             data_key: V::DATA_KEY.to_string(),
             custom_version_key: self.custom_version_key,
             custom_data_key: self.custom_data_key,
+            ranges: self.ranges,
+            version_pointer: self.version_pointer,
+            data_pointer: self.data_pointer,
+            frozen,
+            unknown_fields,
             _state: PhantomData,
         }
     }
@@ -1719,11 +6686,13 @@ where
         Next: Versioned + DeserializeOwned + Serialize,
     {
         let from_version = V::VERSION.to_string();
-        let migration_fn: MigrationFn = Box::new(move |value| {
+        let entity = self.entity.clone();
+        let migration_fn: MigrationFn = Arc::new(move |value| {
             let from_value: V = serde_json::from_value(value).map_err(|e| {
                 MigrationError::DeserializationError(format!(
-                    "Failed to deserialize version {}: {}",
+                    "Failed to deserialize version {} of entity '{}': {}",
                     V::VERSION,
+                    entity,
                     e
                 ))
             })?;
@@ -1740,6 +6709,11 @@ where
 
         self.steps.insert(from_version, migration_fn);
         self.versions.push(Next::VERSION.to_string());
+        if Next::FROZEN {
+            self.frozen.push(Next::VERSION.to_string());
+        }
+        self.unknown_fields
+            .insert(Next::VERSION.to_string(), unknown_fields_fn::<Next>());
 
         MigrationPathBuilder {
             entity: self.entity,
@@ -1749,179 +6723,112 @@ where
             data_key: self.data_key,
             custom_version_key: self.custom_version_key,
             custom_data_key: self.custom_data_key,
+            ranges: self.ranges,
+            version_pointer: self.version_pointer,
+            data_pointer: self.data_pointer,
+            frozen: self.frozen,
+            unknown_fields: self.unknown_fields,
             _state: PhantomData,
         }
     }
 
+    /// Adds a migration step to the next version that also handles every
+    /// incoming version matching the semver `range`.
+    ///
+    /// Versions registered exactly still take precedence; an unregistered
+    /// version such as `1.0.5` is deserialized as the current version and
+    /// migrated through this step. An invalid range fails `register` with
+    /// `InvalidPathConfig`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // 1.0.0, 1.0.1, ... 1.0.99 all migrate to 2.0.0 via TaskV1_0_0
+    /// let path = Migrator::define("task")
+    ///     .from::<TaskV1_0_0>()
+    ///     .step_range::<TaskV2_0_0>("^1.0")
+    ///     .into::<TaskEntity>();
+    /// ```
+    pub fn step_range<Next>(mut self, range: &str) -> MigrationPathBuilder<HasSteps<Next>>
+    where
+        V: MigratesTo<Next>,
+        Next: Versioned + DeserializeOwned + Serialize,
+    {
+        self.ranges
+            .push((range.to_string(), V::VERSION.to_string()));
+        self.step::<Next>()
+    }
+
     /// Finalizes the migration path with conversion to domain model.
     pub fn into<D: DeserializeOwned + Serialize>(self) -> MigrationPath<D>
     where
         V: IntoDomain<D>,
     {
-        let finalize: Box<
-            dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync,
-        > = Box::new(move |value| {
-            let versioned: V = serde_json::from_value(value).map_err(|e| {
-                MigrationError::DeserializationError(format!(
-                    "Failed to deserialize final version: {}",
-                    e
-                ))
-            })?;
-
-            let domain = versioned.into_domain();
-
-            serde_json::to_value(domain).map_err(|e| MigrationError::MigrationStepFailed {
-                from: V::VERSION.to_string(),
-                to: "domain".to_string(),
-                error: e.to_string(),
-            })
-        });
-
-        MigrationPath {
-            entity: self.entity,
-            inner: EntityMigrationPath {
-                steps: self.steps,
-                finalize,
-                versions: self.versions.clone(),
-                version_key: self.version_key,
-                data_key: self.data_key,
-            },
-            versions: self.versions,
-            custom_version_key: self.custom_version_key,
-            custom_data_key: self.custom_data_key,
-            save_fn: None,
-            save_flat_fn: None,
-            _phantom: PhantomData,
-        }
+        let finalize = into_domain_finalizer::<V, D>(self.entity.clone());
+        self.finish(finalize, None)
     }
 
-    /// Finalizes the migration path with conversion to domain model and enables domain entity saving.
-    ///
-    /// This variant registers save functions that allow saving domain entities directly by entity name,
-    /// without needing the `VersionMigrate` macro on the entity type.
+    /// Finalizes the migration path with a fallible conversion to domain model.
     ///
-    /// # Requirements
-    ///
-    /// The latest versioned type (V) must implement `FromDomain<D>` to convert domain entities
-    /// back to the versioned format for saving.
+    /// Errors returned by `TryIntoDomain::try_into_domain` are propagated
+    /// unchanged from `load`.
     ///
     /// # Example
     ///
     /// ```ignore
-    /// impl FromDomain<TaskEntity> for TaskV1_1_0 {
-    ///     fn from_domain(entity: TaskEntity) -> Self {
-    ///         TaskV1_1_0 {
-    ///             id: entity.id,
-    ///             title: entity.title,
-    ///             description: entity.description,
-    ///         }
-    ///     }
-    /// }
-    ///
     /// let path = Migrator::define("task")
-    ///     .from::<TaskV1_0_0>()
-    ///     .step::<TaskV1_1_0>()
-    ///     .into_with_save::<TaskEntity>();
-    ///
-    /// migrator.register(path)?;
-    ///
-    /// // Now you can save by entity name
-    /// let entity = TaskEntity { ... };
-    /// let json = migrator.save_domain("task", entity)?;
+    ///     .from::<TaskV1>()
+    ///     .step::<TaskV2>()
+    ///     .try_into::<TaskEntity>();
     /// ```
-    pub fn into_with_save<D: DeserializeOwned + Serialize>(self) -> MigrationPath<D>
+    pub fn try_into<D: DeserializeOwned + Serialize>(self) -> MigrationPath<D>
     where
-        V: IntoDomain<D> + crate::FromDomain<D>,
+        V: TryIntoDomain<D>,
     {
-        let finalize: Box<
-            dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync,
-        > = Box::new(move |value| {
-            let versioned: V = serde_json::from_value(value).map_err(|e| {
-                MigrationError::DeserializationError(format!(
-                    "Failed to deserialize final version: {}",
-                    e
-                ))
-            })?;
-
-            let domain = versioned.into_domain();
-
-            serde_json::to_value(domain).map_err(|e| MigrationError::MigrationStepFailed {
-                from: V::VERSION.to_string(),
-                to: "domain".to_string(),
-                error: e.to_string(),
-            })
-        });
-
-        // Create save function for domain entities
-        let version = V::VERSION;
-
-        let save_fn: DomainSaveFn = Box::new(move |domain_value, vkey, dkey| {
-            let domain: D = serde_json::from_value(domain_value).map_err(|e| {
-                MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
-            })?;
-
-            let latest = V::from_domain(domain);
-            let data_value = serde_json::to_value(&latest).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize latest: {}", e))
-            })?;
-
-            let mut map = serde_json::Map::new();
-            map.insert(
-                vkey.to_string(),
-                serde_json::Value::String(version.to_string()),
-            );
-            map.insert(dkey.to_string(), data_value);
-
-            serde_json::to_string(&map).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
-            })
-        });
-
-        let save_flat_fn: DomainSaveFlatFn = Box::new(move |domain_value, vkey| {
-            let domain: D = serde_json::from_value(domain_value).map_err(|e| {
-                MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
-            })?;
-
-            let latest = V::from_domain(domain);
-            let mut data_value = serde_json::to_value(&latest).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize latest: {}", e))
-            })?;
-
-            let obj = data_value.as_object_mut().ok_or_else(|| {
-                MigrationError::SerializationError(
-                    "Data must serialize to a JSON object for flat format".to_string(),
-                )
-            })?;
-
-            obj.insert(
-                vkey.to_string(),
-                serde_json::Value::String(version.to_string()),
-            );
-
-            serde_json::to_string(&obj).map_err(|e| {
-                MigrationError::SerializationError(format!(
-                    "Failed to serialize flat format: {}",
-                    e
-                ))
-            })
-        });
+        let finalize = try_into_domain_finalizer::<V, D>(self.entity.clone());
+        self.finish(finalize, None)
+    }
 
-        MigrationPath {
-            entity: self.entity,
-            inner: EntityMigrationPath {
-                steps: self.steps,
-                finalize,
-                versions: self.versions.clone(),
-                version_key: self.version_key,
-                data_key: self.data_key,
-            },
-            versions: self.versions,
-            custom_version_key: self.custom_version_key,
-            custom_data_key: self.custom_data_key,
-            save_fn: Some(save_fn),
-            save_flat_fn: Some(save_flat_fn),
-            _phantom: PhantomData,
-        }
+    /// Finalizes the migration path with conversion to domain model and enables domain entity saving.
+    ///
+    /// This variant registers save functions that allow saving domain entities directly by entity name,
+    /// without needing the `VersionMigrate` macro on the entity type.
+    ///
+    /// # Requirements
+    ///
+    /// The latest versioned type (V) must implement `FromDomain<D>` to convert domain entities
+    /// back to the versioned format for saving.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// impl FromDomain<TaskEntity> for TaskV1_1_0 {
+    ///     fn from_domain(entity: TaskEntity) -> Self {
+    ///         TaskV1_1_0 {
+    ///             id: entity.id,
+    ///             title: entity.title,
+    ///             description: entity.description,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let path = Migrator::define("task")
+    ///     .from::<TaskV1_0_0>()
+    ///     .step::<TaskV1_1_0>()
+    ///     .into_with_save::<TaskEntity>();
+    ///
+    /// migrator.register(path)?;
+    ///
+    /// // Now you can save by entity name
+    /// let entity = TaskEntity { ... };
+    /// let json = migrator.save_domain("task", entity)?;
+    /// ```
+    pub fn into_with_save<D: DeserializeOwned + Serialize>(self) -> MigrationPath<D>
+    where
+        V: IntoDomain<D> + crate::FromDomain<D>,
+    {
+        let finalize = into_domain_finalizer::<V, D>(self.entity.clone());
+        self.finish(finalize, Some(domain_savers::<V, D>()))
     }
 }
 
@@ -1936,11 +6843,13 @@ where
         Next: Versioned + DeserializeOwned + Serialize,
     {
         let from_version = V::VERSION.to_string();
-        let migration_fn: MigrationFn = Box::new(move |value| {
+        let entity = self.entity.clone();
+        let migration_fn: MigrationFn = Arc::new(move |value| {
             let from_value: V = serde_json::from_value(value).map_err(|e| {
                 MigrationError::DeserializationError(format!(
-                    "Failed to deserialize version {}: {}",
+                    "Failed to deserialize version {} of entity '{}': {}",
                     V::VERSION,
+                    entity,
                     e
                 ))
             })?;
@@ -1957,6 +6866,11 @@ where
 
         self.steps.insert(from_version, migration_fn);
         self.versions.push(Next::VERSION.to_string());
+        if Next::FROZEN {
+            self.frozen.push(Next::VERSION.to_string());
+        }
+        self.unknown_fields
+            .insert(Next::VERSION.to_string(), unknown_fields_fn::<Next>());
 
         MigrationPathBuilder {
             entity: self.entity,
@@ -1966,50 +6880,47 @@ where
             data_key: self.data_key,
             custom_version_key: self.custom_version_key,
             custom_data_key: self.custom_data_key,
+            ranges: self.ranges,
+            version_pointer: self.version_pointer,
+            data_pointer: self.data_pointer,
+            frozen: self.frozen,
+            unknown_fields: self.unknown_fields,
             _state: PhantomData,
         }
     }
 
+    /// Adds another migration step that also handles every incoming version
+    /// matching the semver `range`.
+    ///
+    /// See `MigrationPathBuilder<HasFrom<V>>::step_range` for details.
+    pub fn step_range<Next>(mut self, range: &str) -> MigrationPathBuilder<HasSteps<Next>>
+    where
+        V: MigratesTo<Next>,
+        Next: Versioned + DeserializeOwned + Serialize,
+    {
+        self.ranges
+            .push((range.to_string(), V::VERSION.to_string()));
+        self.step::<Next>()
+    }
+
     /// Finalizes the migration path with conversion to domain model.
     pub fn into<D: DeserializeOwned + Serialize>(self) -> MigrationPath<D>
     where
         V: IntoDomain<D>,
     {
-        let finalize: Box<
-            dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync,
-        > = Box::new(move |value| {
-            let versioned: V = serde_json::from_value(value).map_err(|e| {
-                MigrationError::DeserializationError(format!(
-                    "Failed to deserialize final version: {}",
-                    e
-                ))
-            })?;
-
-            let domain = versioned.into_domain();
-
-            serde_json::to_value(domain).map_err(|e| MigrationError::MigrationStepFailed {
-                from: V::VERSION.to_string(),
-                to: "domain".to_string(),
-                error: e.to_string(),
-            })
-        });
+        let finalize = into_domain_finalizer::<V, D>(self.entity.clone());
+        self.finish(finalize, None)
+    }
 
-        MigrationPath {
-            entity: self.entity,
-            inner: EntityMigrationPath {
-                steps: self.steps,
-                finalize,
-                versions: self.versions.clone(),
-                version_key: self.version_key,
-                data_key: self.data_key,
-            },
-            versions: self.versions,
-            custom_version_key: self.custom_version_key,
-            custom_data_key: self.custom_data_key,
-            save_fn: None,
-            save_flat_fn: None,
-            _phantom: PhantomData,
-        }
+    /// Finalizes the migration path with a fallible conversion to domain model.
+    ///
+    /// See `MigrationPathBuilder<HasFrom<V>>::try_into` for details.
+    pub fn try_into<D: DeserializeOwned + Serialize>(self) -> MigrationPath<D>
+    where
+        V: TryIntoDomain<D>,
+    {
+        let finalize = try_into_domain_finalizer::<V, D>(self.entity.clone());
+        self.finish(finalize, None)
     }
 
     /// Finalizes the migration path with conversion to domain model and enables domain entity saving.
@@ -2019,95 +6930,8 @@ where
     where
         V: IntoDomain<D> + crate::FromDomain<D>,
     {
-        let finalize: Box<
-            dyn Fn(serde_json::Value) -> Result<serde_json::Value, MigrationError> + Send + Sync,
-        > = Box::new(move |value| {
-            let versioned: V = serde_json::from_value(value).map_err(|e| {
-                MigrationError::DeserializationError(format!(
-                    "Failed to deserialize final version: {}",
-                    e
-                ))
-            })?;
-
-            let domain = versioned.into_domain();
-
-            serde_json::to_value(domain).map_err(|e| MigrationError::MigrationStepFailed {
-                from: V::VERSION.to_string(),
-                to: "domain".to_string(),
-                error: e.to_string(),
-            })
-        });
-
-        // Create save function for domain entities
-        let version = V::VERSION;
-
-        let save_fn: DomainSaveFn = Box::new(move |domain_value, vkey, dkey| {
-            let domain: D = serde_json::from_value(domain_value).map_err(|e| {
-                MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
-            })?;
-
-            let latest = V::from_domain(domain);
-            let data_value = serde_json::to_value(&latest).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize latest: {}", e))
-            })?;
-
-            let mut map = serde_json::Map::new();
-            map.insert(
-                vkey.to_string(),
-                serde_json::Value::String(version.to_string()),
-            );
-            map.insert(dkey.to_string(), data_value);
-
-            serde_json::to_string(&map).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize wrapper: {}", e))
-            })
-        });
-
-        let save_flat_fn: DomainSaveFlatFn = Box::new(move |domain_value, vkey| {
-            let domain: D = serde_json::from_value(domain_value).map_err(|e| {
-                MigrationError::DeserializationError(format!("Failed to deserialize domain: {}", e))
-            })?;
-
-            let latest = V::from_domain(domain);
-            let mut data_value = serde_json::to_value(&latest).map_err(|e| {
-                MigrationError::SerializationError(format!("Failed to serialize latest: {}", e))
-            })?;
-
-            let obj = data_value.as_object_mut().ok_or_else(|| {
-                MigrationError::SerializationError(
-                    "Data must serialize to a JSON object for flat format".to_string(),
-                )
-            })?;
-
-            obj.insert(
-                vkey.to_string(),
-                serde_json::Value::String(version.to_string()),
-            );
-
-            serde_json::to_string(&obj).map_err(|e| {
-                MigrationError::SerializationError(format!(
-                    "Failed to serialize flat format: {}",
-                    e
-                ))
-            })
-        });
-
-        MigrationPath {
-            entity: self.entity,
-            inner: EntityMigrationPath {
-                steps: self.steps,
-                finalize,
-                versions: self.versions.clone(),
-                version_key: self.version_key,
-                data_key: self.data_key,
-            },
-            versions: self.versions,
-            custom_version_key: self.custom_version_key,
-            custom_data_key: self.custom_data_key,
-            save_fn: Some(save_fn),
-            save_flat_fn: Some(save_flat_fn),
-            _phantom: PhantomData,
-        }
+        let finalize = into_domain_finalizer::<V, D>(self.entity.clone());
+        self.finish(finalize, Some(domain_savers::<V, D>()))
     }
 }
 
@@ -2117,6 +6941,13 @@ pub struct MigrationPath<D> {
     inner: EntityMigrationPath,
     /// List of versions in the migration path for validation
     versions: Vec<String>,
+    /// Semver ranges registered with `step_range`, parsed into `inner` when
+    /// the path is registered
+    ranges: Vec<(String, String)>,
+    /// Pointers set with `with_version_pointer` / `with_data_pointer`,
+    /// installed as a `VersionExtractor::JsonPointer` when registered
+    version_pointer: Option<String>,
+    data_pointer: Option<String>,
     /// Custom version key override (takes precedence over Migrator defaults)
     custom_version_key: Option<String>,
     /// Custom data key override (takes precedence over Migrator defaults)
@@ -2125,9 +6956,190 @@ pub struct MigrationPath<D> {
     save_fn: Option<DomainSaveFn>,
     /// Function to save domain entities in flat format (if FromDomain is implemented)
     save_flat_fn: Option<DomainSaveFlatFn>,
+    /// Versions whose DTO is marked `FROZEN`
+    frozen: Vec<String>,
     _phantom: PhantomData<D>,
 }
 
+impl<D: DeserializeOwned> MigrationPath<D> {
+    /// Checks every loaded domain value with `validator` after finalization.
+    ///
+    /// A returned `Err(reason)` fails the load with `ValidationFailed`.
+    /// Validators registered by repeated calls run in registration order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let path = Migrator::define("task")
+    ///     .from::<TaskV1>()
+    ///     .into::<TaskEntity>()
+    ///     .validate(|task: &TaskEntity| {
+    ///         if task.title.trim().is_empty() {
+    ///             return Err("title must not be empty".to_string());
+    ///         }
+    ///         Ok(())
+    ///     });
+    /// ```
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&D) -> Result<(), String> + Send + Sync + 'static,
+    {
+        let entity = self.entity.clone();
+        let finalize = self.inner.finalize;
+        self.inner.finalize = Box::new(move |value| {
+            let domain_value = finalize(value)?;
+            let domain: D = domain_from_value(&entity, domain_value.clone())?;
+            validator(&domain).map_err(|reason| MigrationError::ValidationFailed {
+                entity: entity.clone(),
+                reason,
+            })?;
+            Ok(domain_value)
+        });
+        self
+    }
+}
+
+/// Two migrators run back to back, created by [`Migrator::chain_migrators`].
+pub struct ChainedMigrator {
+    first: Migrator,
+    second: Migrator,
+}
+
+impl ChainedMigrator {
+    /// Loads data through both migrators.
+    ///
+    /// The first migrator migrates `json` to its domain value, which is fed
+    /// to the second migrator as the first version of `entity`'s chain there.
+    /// The intermediate value is only handled as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `entity` is not registered in either migrator or if
+    /// either stage fails.
+    pub fn load<D: DeserializeOwned>(&self, entity: &str, json: &str) -> Result<D, MigrationError> {
+        let intermediate: serde_json::Value = self.first.load(entity, json)?;
+
+        let path = self
+            .second
+            .entity_path(entity)
+            .ok_or_else(|| MigrationError::EntityNotFound(entity.to_string()))?;
+        let first_version = path.versions.first().cloned().unwrap_or_default();
+
+        let mut wrapper = serde_json::Map::new();
+        wrapper.insert(
+            path.version_key.clone(),
+            serde_json::Value::String(first_version),
+        );
+        wrapper.insert(path.data_key.clone(), intermediate);
+
+        self.second
+            .load_from(entity, serde_json::Value::Object(wrapper))
+    }
+}
+
+/// A forward and a backward migration path for the same entity.
+///
+/// Created with [`Migrator::bidirectional`] and registered with
+/// [`Migrator::register_bidirectional`].
+pub struct BidirectionalPath<D, Old> {
+    forward: MigrationPath<D>,
+    backward: MigrationPath<Old>,
+}
+
+/// Field-level differences between two domain values, produced by
+/// [`Migrator::load_and_diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EntityDiff {
+    /// Top-level fields whose values differ, mapped to `(old, new)`.
+    ///
+    /// A field missing on one side is reported as `serde_json::Value::Null`.
+    pub changed_fields: HashMap<String, (serde_json::Value, serde_json::Value)>,
+}
+
+impl EntityDiff {
+    /// Returns `true` if no field changed.
+    pub fn is_empty(&self) -> bool {
+        self.changed_fields.is_empty()
+    }
+}
+
+/// Field-level differences between the data of two versions, produced by
+/// [`Migrator::diff_entity_versions`]. Every list is sorted by field name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VersionDiff {
+    /// Fields present only in the newer version.
+    pub added_fields: Vec<String>,
+    /// Fields present only in the older version.
+    pub removed_fields: Vec<String>,
+    /// Fields present in both with different values, as `(field, old, new)`.
+    pub changed_fields: Vec<(String, serde_json::Value, serde_json::Value)>,
+    /// Fields present in both with equal values.
+    pub unchanged_fields: Vec<String>,
+}
+
+/// Top-level differences between two configs, produced by
+/// [`ConfigMigrator::diff`]. Entries are in key order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigDiff {
+    /// Keys present only in the other config, with their values.
+    pub added: std::collections::BTreeMap<String, serde_json::Value>,
+    /// Keys present only in this config, with their values.
+    pub removed: std::collections::BTreeMap<String, serde_json::Value>,
+    /// Keys present in both whose values differ, mapped to `(before, after)`.
+    pub changed: std::collections::BTreeMap<String, (serde_json::Value, serde_json::Value)>,
+}
+
+impl ConfigDiff {
+    /// Returns `true` if the configs are identical at the top level.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A snapshot of one registered migration path, produced by
+/// [`Migrator::inspect_registered_entities`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntityInspection {
+    /// The name the path was registered under.
+    pub entity_name: String,
+    /// Registered versions, oldest first.
+    pub version_chain: Vec<String>,
+    /// The key holding the version in serialized data.
+    pub version_key: String,
+    /// The key holding the data in wrapped serialized data.
+    pub data_key: String,
+    /// Whether domain entities can be saved by name (`into_with_save`).
+    pub has_domain_saver: bool,
+    /// Number of migration steps in the chain.
+    pub step_count: usize,
+}
+
+/// Returns a copy of `value` with `null` object fields removed, for TOML output.
+fn without_nulls(value: &serde_json::Value) -> Result<serde_json::Value, MigrationError> {
+    match value {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .filter(|(_, v)| !v.is_null())
+            .map(|(k, v)| Ok((k.clone(), without_nulls(v)?)))
+            .collect::<Result<_, MigrationError>>()
+            .map(serde_json::Value::Object),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|v| {
+                if v.is_null() {
+                    Err(MigrationError::TomlSerializeError(
+                        "null array elements cannot be represented in TOML".to_string(),
+                    ))
+                } else {
+                    without_nulls(v)
+                }
+            })
+            .collect::<Result<_, MigrationError>>()
+            .map(serde_json::Value::Array),
+        other => Ok(other.clone()),
+    }
+}
+
 /// A wrapper around JSON data that provides convenient query and update methods
 /// for partial updates with automatic migration.
 ///
@@ -2170,12 +7182,34 @@ pub struct ConfigMigrator {
 impl ConfigMigrator {
     /// Creates a new `ConfigMigrator` from a JSON string and a `Migrator`.
     ///
+    /// A leading UTF-8 byte order mark is ignored, and the migrator's
+    /// `LoadLimits` apply to the input.
+    ///
     /// # Errors
     ///
-    /// Returns `MigrationError::DeserializationError` if the JSON is invalid.
+    /// Returns `MigrationError::DeserializationError` if the JSON is invalid,
+    /// or `InputTooLarge`/`TooDeep` if it exceeds the load limits.
     pub fn from(json: &str, migrator: Migrator) -> Result<Self, MigrationError> {
-        let root = serde_json::from_str(json)
-            .map_err(|e| MigrationError::DeserializationError(e.to_string()))?;
+        let root = migrator.parse_json(json, "JSON")?;
+        Ok(Self { root, migrator })
+    }
+
+    /// Creates a new `ConfigMigrator` from a JSON5 string and a `Migrator`.
+    ///
+    /// Accepts comments and trailing commas, so config files can be edited
+    /// and annotated by hand. The comments are not kept when the config is
+    /// written back. A leading UTF-8 byte order mark is ignored, and the
+    /// migrator's `LoadLimits` apply to the input.
+    ///
+    /// Requires the `json5` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::DeserializationError` if the JSON5 is invalid,
+    /// or `InputTooLarge`/`TooDeep` if it exceeds the load limits.
+    #[cfg(feature = "json5")]
+    pub fn from_json5(text: &str, migrator: Migrator) -> Result<Self, MigrationError> {
+        let root = migrator.parse_json5(text)?;
         Ok(Self { root, migrator })
     }
 
@@ -2225,6 +7259,102 @@ impl ConfigMigrator {
         }
     }
 
+    /// Queries the records stored under `T::CONFIG_KEY`.
+    ///
+    /// Same as `query(T::CONFIG_KEY)`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `query`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Queryable)]
+    /// #[queryable(entity = "task", key = "open_tasks")]
+    /// struct TaskEntity { ... }
+    ///
+    /// let tasks: Vec<TaskEntity> = config.query_default()?;
+    /// ```
+    pub fn query_default<T>(&self) -> Result<Vec<T>, MigrationError>
+    where
+        T: crate::QueryableKey + for<'de> serde::Deserialize<'de>,
+    {
+        self.query(T::CONFIG_KEY)
+    }
+
+    /// Like `query`, but skips records stored with the entity's tombstone
+    /// version (see `Migrator::register_tombstone_version`).
+    ///
+    /// Tombstoned records are filtered out before migration, so they never
+    /// need to be loadable. Without a registered tombstone this behaves
+    /// exactly like `query`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `query`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let live_tasks: Vec<TaskEntity> = config.query_live("tasks")?;
+    /// ```
+    pub fn query_live<T>(&self, key: &str) -> Result<Vec<T>, MigrationError>
+    where
+        T: crate::Queryable + for<'de> serde::Deserialize<'de>,
+    {
+        let path = match self.migrator.entity_path(T::ENTITY_NAME) {
+            Some(path) if path.options.tombstone.is_some() => path,
+            _ => return self.query(key),
+        };
+
+        let Some(array) = self.root[key].as_array() else {
+            return self.query(key);
+        };
+
+        let live = array
+            .iter()
+            .filter(|item| {
+                item.get(&path.version_key).and_then(|v| v.as_str())
+                    != path.options.tombstone.as_deref()
+            })
+            .cloned()
+            .collect();
+
+        self.migrator.load_vec_flat_from(T::ENTITY_NAME, live)
+    }
+
+    /// Loads the single wrapped value stored under `key` as `entity`.
+    ///
+    /// Unlike `query`, the key holds one `{"version": ..., "data": ...}`
+    /// value rather than an array of flat records, so `data` may be a scalar
+    /// (number, string or bool) that migrates into a primitive domain type.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `key` is missing or null.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Migrator::load_from`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // {"max_items": {"version": "1.0.0", "data": 21}}
+    /// let max_items: Option<i64> = config.query_value("limit", "max_items")?;
+    /// ```
+    pub fn query_value<D: DeserializeOwned>(
+        &self,
+        entity: &str,
+        key: &str,
+    ) -> Result<Option<D>, MigrationError> {
+        match self.root.get(key) {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => self.migrator.load_from(entity, value).map(Some),
+        }
+    }
+
     /// Updates a specific key in the JSON object with new domain entities.
     ///
     /// This method serializes the entities with the latest version (automatically
@@ -2269,12 +7399,66 @@ impl ConfigMigrator {
                     );
                 }
 
-                Ok(obj)
-            })
-            .collect::<Result<Vec<_>, MigrationError>>()?;
+                Ok(obj)
+            })
+            .collect::<Result<Vec<_>, MigrationError>>()?;
+
+        self.root[key] = serde_json::Value::Array(items);
+        Ok(())
+    }
+
+    /// Replaces the records stored under `T::CONFIG_KEY`.
+    ///
+    /// Same as `update(T::CONFIG_KEY, data)`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `update`.
+    pub fn update_default<T>(&mut self, data: Vec<T>) -> Result<(), MigrationError>
+    where
+        T: serde::Serialize + crate::QueryableKey,
+    {
+        self.update(T::CONFIG_KEY, data)
+    }
+
+    /// Moves the value stored under `from` to `to` without migrating it.
+    ///
+    /// Use this for config-layout changes, such as renaming a section, that
+    /// are independent of the data versions stored inside it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `from` existed and was moved, `false` if there was nothing
+    /// to move.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::KeyAlreadyExists` if `from` exists and `to` is
+    /// already occupied. The config is left unchanged in that case.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// config.rename_key("todos", "tasks")?;
+    /// ```
+    pub fn rename_key(&mut self, from: &str, to: &str) -> Result<bool, MigrationError> {
+        let Some(root) = self.root.as_object_mut() else {
+            return Ok(false);
+        };
+        if !root.contains_key(from) {
+            return Ok(false);
+        }
+        if from == to {
+            return Ok(true);
+        }
+        if root.contains_key(to) {
+            return Err(MigrationError::KeyAlreadyExists(to.to_string()));
+        }
 
-        self.root[key] = serde_json::Value::Array(items);
-        Ok(())
+        if let Some(value) = root.remove(from) {
+            root.insert(to.to_string(), value);
+        }
+        Ok(true)
     }
 
     /// Converts the entire JSON object back to a pretty-printed string.
@@ -2297,10 +7481,173 @@ impl ConfigMigrator {
             .map_err(|e| MigrationError::SerializationError(e.to_string()))
     }
 
+    /// Converts the entire JSON object to a pretty-printed TOML string.
+    ///
+    /// TOML has no null, so `null` object fields are omitted and `null`
+    /// array elements are rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::TomlSerializeError` if the root is not an
+    /// object, contains a `null` array element, or otherwise cannot be
+    /// represented as a TOML document.
+    pub fn to_toml(&self) -> Result<String, MigrationError> {
+        if !self.root.is_object() {
+            return Err(MigrationError::TomlSerializeError(
+                "top-level value must be an object to be written as TOML".to_string(),
+            ));
+        }
+        let tv = local_store::json_to_toml(&without_nulls(&self.root)?)
+            .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))?;
+        toml::to_string_pretty(&tv).map_err(|e| MigrationError::TomlSerializeError(e.to_string()))
+    }
+
+    /// Converts the entire JSON object to a YAML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::SerializationError` if serialization fails.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, MigrationError> {
+        serde_yaml::to_string(&self.root)
+            .map_err(|e| MigrationError::SerializationError(e.to_string()))
+    }
+
     /// Returns a reference to the underlying JSON value.
     pub fn as_value(&self) -> &serde_json::Value {
         &self.root
     }
+
+    /// Returns a mutable reference to the underlying JSON value.
+    ///
+    /// This is an escape hatch for edits the typed `query`/`update` API does
+    /// not cover. Changes made here bypass migration and versioning entirely:
+    /// nothing is validated, and entity collections edited this way are not
+    /// re-stamped with a version.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// config.as_value_mut()["app_name"] = serde_json::json!("Renamed");
+    /// ```
+    pub fn as_value_mut(&mut self) -> &mut serde_json::Value {
+        &mut self.root
+    }
+
+    /// Returns a clone of the underlying JSON value.
+    pub fn clone_value(&self) -> serde_json::Value {
+        self.root.clone()
+    }
+
+    /// Consumes the config and returns the underlying JSON value.
+    pub fn into_value(self) -> serde_json::Value {
+        self.root
+    }
+
+    /// Compares the top-level keys of this config (before) with `other`
+    /// (after).
+    ///
+    /// Values are compared as raw JSON, without migrating them, so a
+    /// collection re-stamped with a newer version counts as changed. A root
+    /// that is not an object is treated as empty.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut edited = ConfigMigrator::from(&config.to_string()?, migrator)?;
+    /// edited.update("tasks", tasks)?;
+    /// for (key, (before, after)) in &config.diff(&edited).changed {
+    ///     println!("{key}: {before} -> {after}");
+    /// }
+    /// ```
+    pub fn diff(&self, other: &ConfigMigrator) -> ConfigDiff {
+        let empty = serde_json::Map::new();
+        let before = self.root.as_object().unwrap_or(&empty);
+        let after = other.root.as_object().unwrap_or(&empty);
+
+        let mut diff = ConfigDiff::default();
+        for (key, old) in before {
+            match after.get(key) {
+                None => {
+                    diff.removed.insert(key.clone(), old.clone());
+                }
+                Some(new) if new != old => {
+                    diff.changed.insert(key.clone(), (old.clone(), new.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, new) in after {
+            if !before.contains_key(key) {
+                diff.added.insert(key.clone(), new.clone());
+            }
+        }
+        diff
+    }
+
+    /// Replaces the underlying JSON value.
+    pub(crate) fn set_root(&mut self, root: serde_json::Value) {
+        self.root = root;
+    }
+
+    /// Removes every top-level key not listed in `keep_keys`.
+    ///
+    /// Returns the removed keys in lexicographic order.
+    pub(crate) fn retain_keys(&mut self, keep_keys: &[&str]) -> Vec<String> {
+        let mut removed = Vec::new();
+        if let Some(obj) = self.root.as_object_mut() {
+            removed = obj
+                .keys()
+                .filter(|k| !keep_keys.contains(&k.as_str()))
+                .cloned()
+                .collect();
+            for key in &removed {
+                obj.remove(key);
+            }
+        }
+        removed.sort();
+        removed
+    }
+
+    /// Resolves the registered entity whose items are stored under `key`.
+    ///
+    /// Matches the key itself first, then the plural of each entity name.
+    pub(crate) fn entity_for_key(&self, key: &str) -> Option<&str> {
+        self.migrator.entity_for_key(key)
+    }
+
+    /// Migrates every item of the entity array stored under `key` to the
+    /// latest version, in place, without converting to the domain model.
+    ///
+    /// Returns the number of items whose version changed, or `None` when the
+    /// key does not hold an array of a registered entity.
+    pub(crate) fn migrate_key_to_latest(
+        &mut self,
+        key: &str,
+    ) -> Result<Option<usize>, MigrationError> {
+        let entity = match self.entity_for_key(key) {
+            Some(entity) => entity.to_string(),
+            None => return Ok(None),
+        };
+        let items = match self.root.get(key).and_then(|v| v.as_array()) {
+            Some(items) => items.clone(),
+            None => return Ok(None),
+        };
+
+        let path = &self.migrator.paths[&entity];
+        let latest = path.versions.last().cloned().unwrap_or_default();
+        let mut migrated_count = 0;
+        let mut migrated = Vec::with_capacity(items.len());
+        for item in items {
+            if item.get(&path.version_key).and_then(|v| v.as_str()) != Some(latest.as_str()) {
+                migrated_count += 1;
+            }
+            migrated.push(self.migrator.migrate_flat_to_latest(&entity, item)?);
+        }
+
+        self.root[key] = serde_json::Value::Array(migrated);
+        Ok(Some(migrated_count))
+    }
 }
 
 #[cfg(test)]
@@ -2388,6 +7735,39 @@ mod tests {
         assert_eq!(migrator.paths.len(), 0);
     }
 
+    #[test]
+    fn test_migrator_with_capacity() {
+        let mut migrator = Migrator::with_capacity(8);
+        assert!(migrator.is_empty());
+        assert!(migrator.paths.capacity() >= 8);
+
+        for entity in ["a", "b", "c"] {
+            let path = Migrator::define(entity)
+                .from::<V1>()
+                .step::<V2>()
+                .step::<V3>()
+                .into::<Domain>();
+            migrator.register(path).unwrap();
+        }
+        assert_eq!(migrator.len(), 3);
+
+        let json = r#"{"version":"1.0.0","data":{"value":"cap"}}"#;
+        let domain: Domain = migrator.load("b", json).unwrap();
+        assert_eq!(domain.value, "cap");
+    }
+
+    #[test]
+    fn test_builder_expected_entities() {
+        let migrator = Migrator::builder()
+            .default_version_key("v")
+            .expected_entities(16)
+            .build();
+
+        assert!(migrator.paths.capacity() >= 16);
+        assert_eq!(migrator.default_version_key.as_deref(), Some("v"));
+        assert_eq!(migrator.len(), 0);
+    }
+
     #[test]
     fn test_single_step_migration() {
         let path = Migrator::define("test")
@@ -2616,6 +7996,119 @@ mod tests {
         assert!(json.contains("\"version\":\"2.0.0\""));
     }
 
+    #[test]
+    fn test_save_checked_accepts_version_in_chain() {
+        let path = Migrator::define("test")
+            .from::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        let v2 = V2 {
+            value: "checked".to_string(),
+            count: 1,
+        };
+        let expected = migrator
+            .save(V2 {
+                value: "checked".to_string(),
+                count: 1,
+            })
+            .unwrap();
+
+        assert_eq!(migrator.save_checked("test", v2).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_save_checked_rejects_version_outside_chain() {
+        let path = Migrator::define("test")
+            .from::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        let v1 = V1 {
+            value: "stray".to_string(),
+        };
+
+        let err = migrator.save_checked("test", v1).unwrap_err();
+        assert!(matches!(
+            err,
+            MigrationError::MigrationPathNotDefined { ref entity, ref version }
+                if entity == "test" && version == "1.0.0"
+        ));
+
+        let v3 = V3 {
+            value: "x".to_string(),
+            count: 0,
+            enabled: true,
+        };
+        assert!(matches!(
+            migrator.save_checked("missing", v3),
+            Err(MigrationError::EntityNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_assert_latest_version_and_chain_order_pass() {
+        let path = Migrator::define("test")
+            .from::<V1>()
+            .step::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        migrator.assert_latest_version_matches::<V3>("test");
+        migrator.assert_migration_chain_order("test");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected '2.0.0'")]
+    fn test_assert_latest_version_matches_panics_on_stale_type() {
+        let path = Migrator::define("test")
+            .from::<V1>()
+            .step::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        migrator.assert_latest_version_matches::<V2>("test");
+    }
+
+    #[test]
+    fn test_coercion_rule_apply() {
+        use serde_json::json;
+
+        assert_eq!(
+            CoercionRule::BoolToString.apply(&json!(true)),
+            Some(json!("true"))
+        );
+        assert_eq!(
+            CoercionRule::StringToBool.apply(&json!("false")),
+            Some(json!(false))
+        );
+        assert_eq!(CoercionRule::StringToBool.apply(&json!("yes")), None);
+        assert_eq!(CoercionRule::IntToString.apply(&json!(7)), Some(json!("7")));
+        assert_eq!(
+            CoercionRule::StringToInt.apply(&json!("-4")),
+            Some(json!(-4))
+        );
+        assert_eq!(CoercionRule::StringToInt.apply(&json!("abc")), None);
+        assert_eq!(
+            CoercionRule::StringToFloat.apply(&json!("1.5")),
+            Some(json!(1.5))
+        );
+        assert_eq!(CoercionRule::IntToFloat.apply(&json!(2)), Some(json!(2.0)));
+        assert_eq!(CoercionRule::IntToFloat.apply(&json!(2.5)), None);
+    }
+
     #[test]
     fn test_validation_invalid_version_order() {
         // Manually construct a path with invalid version ordering
@@ -2870,6 +8363,125 @@ mod tests {
         assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
     }
 
+    fn limited_migrator(limits: LoadLimits) -> Migrator {
+        let path = Migrator::define("test")
+            .from::<V1>()
+            .step::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+        migrator.with_limits(limits)
+    }
+
+    #[test]
+    fn test_load_rejects_input_over_byte_limit() {
+        let migrator = limited_migrator(LoadLimits {
+            max_bytes: 64,
+            ..LoadLimits::default()
+        });
+
+        let json = format!(
+            r#"{{"version":"1.0.0","data":{{"value":"{}"}}}}"#,
+            "x".repeat(100)
+        );
+        let result: Result<Domain, MigrationError> = migrator.load("test", &json);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::InputTooLarge { max_bytes: 64, .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_rejects_input_over_depth_limit() {
+        let migrator = limited_migrator(LoadLimits {
+            max_bytes: usize::MAX,
+            max_depth: 8,
+        });
+
+        let nested = format!("{}1{}", r#"{"a":"#.repeat(20), "}".repeat(20));
+        let json = format!(r#"{{"version":"1.0.0","data":{}}}"#, nested);
+        let result: Result<Domain, MigrationError> = migrator.load("test", &json);
+        assert!(matches!(
+            result,
+            Err(MigrationError::TooDeep { max_depth: 8 })
+        ));
+
+        let result: Result<Vec<Domain>, MigrationError> =
+            migrator.load_vec_flat("test", &format!("[{}]", nested));
+        assert!(matches!(result, Err(MigrationError::TooDeep { .. })));
+    }
+
+    #[test]
+    fn test_load_limits_ignore_brackets_inside_strings() {
+        let migrator = limited_migrator(LoadLimits {
+            max_bytes: 1024,
+            max_depth: 2,
+        });
+
+        let json = r#"{"version":"1.0.0","data":{"value":"[[[{{{\"]]]"}}"#;
+        let domain: Domain = migrator.load("test", json).unwrap();
+
+        assert_eq!(domain.value, r#"[[[{{{"]]]"#);
+    }
+
+    #[test]
+    fn test_load_vec_reports_failing_element_index() {
+        let path = Migrator::define("test")
+            .from::<V1>()
+            .step::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        let json = r#"[
+            {"version":"1.0.0","data":{"value":"a"}},
+            {"version":"2.0.0","data":{"value":"b","count":1}},
+            {"version":"1.0.0","data":{"value":"c"}},
+            {"version":"9.9.9","data":{"value":"d"}},
+            {"version":"3.0.0","data":{"value":"e","count":2,"enabled":true}}
+        ]"#;
+        let result: Result<Vec<Domain>, MigrationError> = migrator.load_vec("test", json);
+
+        match result {
+            Err(MigrationError::ElementFailed { index, source }) => {
+                assert_eq!(index, 3);
+                assert!(matches!(*source, MigrationError::DeserializationError(_)));
+            }
+            other => panic!("expected ElementFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_vec_flat_reports_failing_element_index() {
+        let path = Migrator::define("test")
+            .from::<V1>()
+            .step::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        let json = r#"[
+            {"version":"1.0.0","value":"a"},
+            {"version":"1.0.0","value":"b"},
+            {"version":"1.0.0","value":"c"},
+            {"version":"9.9.9","value":"d"},
+            {"version":"1.0.0","value":"e"}
+        ]"#;
+        let result: Result<Vec<Domain>, MigrationError> = migrator.load_vec_flat("test", json);
+
+        assert!(matches!(
+            result,
+            Err(MigrationError::ElementFailed { index: 3, .. })
+        ));
+    }
+
     #[test]
     fn test_save_vec_latest_version() {
         let migrator = Migrator::new();
@@ -3258,6 +8870,33 @@ mod tests {
         assert!(result.enabled); // Default from V2->V3 migration
     }
 
+    #[test]
+    fn test_load_from_with_fallback_numeric_version_alias() {
+        let path = Migrator::define("test")
+            .from::<V1>()
+            .step::<V2>()
+            .step::<V3>()
+            .into::<Domain>();
+
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+        migrator
+            .register_version_alias("test", "2", "2.0.0")
+            .unwrap();
+
+        // A numeric version mapped by an alias is versioned data, not legacy
+        let json_value: serde_json::Value = serde_json::json!({
+            "version": 2,
+            "data": {"value": "aliased", "count": 7}
+        });
+
+        let result: Domain = migrator
+            .load_from_with_fallback("test", json_value)
+            .unwrap();
+        assert_eq!(result.value, "aliased");
+        assert_eq!(result.count, 7);
+    }
+
     #[test]
     fn test_load_from_with_fallback_missing_data_field() {
         let path = Migrator::define("test")
@@ -3354,4 +8993,15 @@ mod tests {
             err
         );
     }
+
+    #[test]
+    fn test_key_case_conversions() {
+        assert_eq!(camel_to_snake("userId"), "user_id");
+        assert_eq!(camel_to_snake("HTTPServer"), "http_server");
+        assert_eq!(camel_to_snake("item2Count"), "item2_count");
+        assert_eq!(camel_to_snake("already_snake"), "already_snake");
+        assert_eq!(snake_to_camel("user_id"), "userId");
+        assert_eq!(snake_to_camel("_private_key"), "_privateKey");
+        assert_eq!(snake_to_camel("plain"), "plain");
+    }
 }
@@ -0,0 +1,249 @@
+//! Config-driven registration of migration paths.
+//!
+//! Migration paths are declared next to their types with
+//! [`submit_registration!`](crate::submit_registration), which collects them
+//! at link time through the `inventory` crate. A config file then selects
+//! which of them a [`Migrator`] gets, and pins each entity's version chain so
+//! a mismatch between the file and the code is caught at startup.
+//!
+//! Requires the `registry` feature.
+//!
+//! # Two-crate setup
+//!
+//! The crate that owns the types submits their paths:
+//!
+//! ```ignore
+//! // crate `tasks`
+//! version_migrate::submit_registration!("task", TaskEntity, || {
+//!     Migrator::define("task")
+//!         .from::<TaskV1>()
+//!         .step::<TaskV2>()
+//!         .into_with_save::<TaskEntity>()
+//! });
+//! ```
+//!
+//! The application depends on `tasks` and applies a config file:
+//!
+//! ```toml
+//! [[entity]]
+//! name = "task"
+//! type = "tasks::TaskEntity"
+//! versions = ["1.0.0", "2.0.0"]
+//! ```
+//!
+//! ```ignore
+//! // crate `app`
+//! let plan = Migrator::register_from_config(Path::new("migrations.toml"))?;
+//! let mut migrator = Migrator::new();
+//! plan.apply(&mut migrator)?;
+//! ```
+//!
+//! Submissions from a dependency are only linked in if the application
+//! references that crate; an otherwise unused dependency needs a
+//! `use tasks as _;`.
+
+use crate::{MigrationError, Migrator};
+use serde::Deserialize;
+use std::path::Path;
+
+/// A migration path submitted with [`submit_registration!`](crate::submit_registration).
+pub struct EntityRegistration {
+    entity: &'static str,
+    type_name: fn() -> &'static str,
+    register: fn(&mut Migrator) -> Result<(), MigrationError>,
+}
+
+impl EntityRegistration {
+    /// Creates a registration. Prefer the `submit_registration!` macro.
+    ///
+    /// # Arguments
+    ///
+    /// * `entity` - The entity name the path is registered under
+    /// * `type_name` - Returns the full path of the domain type
+    /// * `register` - Registers the path with a `Migrator`
+    pub const fn new(
+        entity: &'static str,
+        type_name: fn() -> &'static str,
+        register: fn(&mut Migrator) -> Result<(), MigrationError>,
+    ) -> Self {
+        Self {
+            entity,
+            type_name,
+            register,
+        }
+    }
+
+    /// Returns the entity name.
+    pub fn entity(&self) -> &'static str {
+        self.entity
+    }
+
+    /// Returns the full path of the domain type, e.g. `"tasks::TaskEntity"`.
+    pub fn type_name(&self) -> &'static str {
+        (self.type_name)()
+    }
+
+    /// Returns `true` if `declared` names this registration's domain type,
+    /// either by its full path or by a trailing part of it.
+    fn matches_type(&self, declared: &str) -> bool {
+        let full = self.type_name();
+        full == declared || full.ends_with(&format!("::{}", declared))
+    }
+}
+
+inventory::collect!(EntityRegistration);
+
+/// Submits a migration path for config-driven registration.
+///
+/// # Arguments
+///
+/// * `$entity` - The entity name, as a string literal
+/// * `$domain` - The domain type of the path
+/// * `$path` - A non-capturing closure returning the `MigrationPath<$domain>`
+///
+/// # Example
+///
+/// ```ignore
+/// version_migrate::submit_registration!("task", TaskEntity, || {
+///     Migrator::define("task").from::<TaskV1>().into::<TaskEntity>()
+/// });
+/// ```
+#[macro_export]
+macro_rules! submit_registration {
+    ($entity:literal, $domain:ty, $path:expr) => {
+        $crate::inventory::submit! {
+            $crate::EntityRegistration::new(
+                $entity,
+                ::std::any::type_name::<$domain>,
+                |migrator| migrator.register::<$domain>(($path)()),
+            )
+        }
+    };
+}
+
+/// One entity declared in a registration config file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PlannedEntity {
+    /// The entity name.
+    pub name: String,
+    /// Path of the domain type; a trailing part such as `"TaskEntity"` is
+    /// enough. When omitted, any registration for `name` matches.
+    #[serde(rename = "type", default)]
+    pub type_path: Option<String>,
+    /// The expected version chain, oldest first. When empty, the chain is not
+    /// checked.
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+/// The entities a config file asks to register, produced by
+/// [`Migrator::register_from_config`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MigratorRegistrationPlan {
+    /// Declared entities, in file order.
+    #[serde(rename = "entity", default)]
+    pub entities: Vec<PlannedEntity>,
+}
+
+impl MigratorRegistrationPlan {
+    /// Registers every planned entity with `migrator`.
+    ///
+    /// The whole plan is checked first, by registering each path with a
+    /// scratch `Migrator` and comparing its chain with the declared
+    /// `versions`. `migrator` is only changed once every entity passed, so a
+    /// failing plan leaves it untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No submitted registration matches an entity's name and type
+    ///   (`EntityNotFound`)
+    /// - Registering a path fails
+    /// - A registered chain differs from the declared `versions`
+    ///   (`VersionChainMismatch`)
+    pub fn apply(&self, migrator: &mut Migrator) -> Result<(), MigrationError> {
+        let mut scratch = Migrator::new();
+        let mut registrations = Vec::with_capacity(self.entities.len());
+        for planned in &self.entities {
+            let registration = Self::find_registration(planned)?;
+            (registration.register)(&mut scratch)?;
+
+            let registered = scratch.versions_of(&planned.name).unwrap_or_default();
+            if !planned.versions.is_empty() && planned.versions != registered {
+                return Err(MigrationError::VersionChainMismatch {
+                    entity: planned.name.clone(),
+                    registered: registered.to_vec(),
+                    declared: planned.versions.clone(),
+                });
+            }
+            registrations.push(registration);
+        }
+
+        for registration in registrations {
+            (registration.register)(migrator)?;
+        }
+        Ok(())
+    }
+
+    /// Finds the submitted registration matching `planned`'s name and type.
+    fn find_registration(
+        planned: &PlannedEntity,
+    ) -> Result<&'static EntityRegistration, MigrationError> {
+        inventory::iter::<EntityRegistration>
+            .into_iter()
+            .find(|r| {
+                r.entity == planned.name
+                    && match planned.type_path.as_deref() {
+                        Some(declared) => r.matches_type(declared),
+                        None => true,
+                    }
+            })
+            .ok_or_else(|| {
+                MigrationError::EntityNotFound(format!(
+                    "{} (no submitted registration{})",
+                    planned.name,
+                    planned
+                        .type_path
+                        .as_deref()
+                        .map(|t| format!(" for type '{}'", t))
+                        .unwrap_or_default()
+                ))
+            })
+    }
+}
+
+impl Migrator {
+    /// Reads a registration plan from a TOML or JSON config file.
+    ///
+    /// Files ending in `.toml` are parsed as TOML, anything else as JSON. The
+    /// plan is applied with [`MigratorRegistrationPlan::apply`]; see the
+    /// [`registry`](crate::registry) module for the expected file layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let plan = Migrator::register_from_config(Path::new("migrations.toml"))?;
+    /// plan.apply(&mut migrator)?;
+    /// ```
+    pub fn register_from_config(path: &Path) -> Result<MigratorRegistrationPlan, MigrationError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            MigrationError::Store(local_store::StoreError::IoError {
+                operation: local_store::IoOperationKind::Read,
+                path: path.display().to_string(),
+                context: Some("registration config".to_string()),
+                error: e.to_string(),
+            })
+        })?;
+
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            toml::from_str(&content).map_err(|e| MigrationError::TomlParseError(e.to_string()))
+        } else {
+            serde_json::from_str(&content)
+                .map_err(|e| MigrationError::DeserializationError(e.to_string()))
+        }
+    }
+}
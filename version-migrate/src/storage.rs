@@ -6,7 +6,37 @@
 use crate::{ConfigMigrator, MigrationError, Migrator, Queryable};
 use local_store::{FileStorageStrategy, FormatStrategy, LoadBehavior};
 use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+/// File size and modification time, as reported by `local_store::FileStorage::stamp`.
+type FileStamp = (u64, SystemTime);
+
+/// Summary of a [`FileStorage::compact_by_keys`] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactByKeysReport {
+    /// Top-level keys that were removed, in lexicographic order.
+    pub removed_keys: Vec<String>,
+    /// Number of items upgraded to the latest version, per kept entity key.
+    pub migrated_counts: BTreeMap<String, usize>,
+    /// Location of the backup written before compaction, if the file existed.
+    pub backup_path: Option<PathBuf>,
+}
+
+/// Result of [`FileStorage::save_with_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveStatus {
+    /// The configuration was written to the file.
+    Written,
+    /// The file could not be written and the configuration is kept in memory
+    /// only, because `FileStorageStrategy::in_memory_fallback` is enabled.
+    InMemory {
+        /// Why the write failed.
+        reason: String,
+    },
+}
 
 /// File storage with ACID guarantees and automatic migrations.
 ///
@@ -25,6 +55,14 @@ pub struct FileStorage {
     config: ConfigMigrator,
     /// Strategy governing format, load behaviour, etc.
     strategy: FileStorageStrategy,
+    /// Raw TOML text as loaded, used to preserve comments and key order on save.
+    #[cfg(feature = "toml-preserve")]
+    original_toml: Option<String>,
+    /// File size, mtime and parsed content as of the last load or save,
+    /// letting `save_if_changed` skip re-reading an untouched file.
+    disk_snapshot: Mutex<Option<(FileStamp, JsonValue)>>,
+    /// Reason the last save fell back to memory, if it did.
+    fallback_reason: Mutex<Option<String>>,
 }
 
 impl FileStorage {
@@ -44,6 +82,10 @@ impl FileStorage {
     /// - `CreateIfMissing`: Creates empty config if file doesn't exist
     /// - `SaveIfMissing`: Creates empty config and saves it if file doesn't exist
     /// - `ErrorIfMissing`: Returns error if file doesn't exist
+    ///
+    /// With `strategy.in_memory_fallback`, a `SaveIfMissing` write that fails
+    /// (e.g. on a read-only filesystem) does not fail construction; the
+    /// storage starts in memory and `is_in_memory` returns `true`.
     pub fn new(
         path: PathBuf,
         migrator: Migrator,
@@ -61,6 +103,9 @@ impl FileStorage {
         let inner = local_store::FileStorage::new(path.clone(), inner_strategy)
             .map_err(MigrationError::Store)?;
 
+        #[cfg(feature = "toml-preserve")]
+        let mut original_toml = None;
+
         // Determine the JSON string we hand to ConfigMigrator.
         let json_string = if !file_was_missing {
             // File existed: read it and convert to JSON.
@@ -73,6 +118,10 @@ impl FileStorage {
                         let tv: toml::Value = toml::from_str(&raw)
                             .map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
                         let jv = toml_to_json(tv)?;
+                        #[cfg(feature = "toml-preserve")]
+                        {
+                            original_toml = Some(raw.clone());
+                        }
                         serde_json::to_string(&jv)
                             .map_err(|e| MigrationError::SerializationError(e.to_string()))?
                     }
@@ -106,11 +155,20 @@ impl FileStorage {
             inner,
             config,
             strategy,
+            #[cfg(feature = "toml-preserve")]
+            original_toml,
+            disk_snapshot: Mutex::new(None),
+            fallback_reason: Mutex::new(None),
         };
+        if !file_was_missing {
+            storage.record_snapshot(storage.config.clone_value());
+        }
 
         // When SaveIfMissing is set and the file was absent, persist now.
+        // With `in_memory_fallback`, a failed write leaves the storage in
+        // memory (see `is_in_memory`) instead of failing construction.
         if file_was_missing && storage.strategy.load_behavior == LoadBehavior::SaveIfMissing {
-            storage.save()?;
+            storage.save_with_status()?;
         }
 
         Ok(storage)
@@ -121,24 +179,160 @@ impl FileStorage {
     /// Serialises the `ConfigMigrator` value to the configured format (TOML or
     /// JSON) and delegates the atomic write (tmp file + fsync + rename) to
     /// `local_store::FileStorage::write_string`.
+    ///
+    /// With the `toml-preserve` feature, a TOML file that existed at load
+    /// time is edited in place: top-level keys whose values did not change
+    /// keep their original text, comments and position.
+    ///
+    /// With `strategy.in_memory_fallback`, a failed write is not an error;
+    /// use `save_with_status` to find out whether the file was written.
     pub fn save(&self) -> Result<(), MigrationError> {
+        self.save_with_status().map(|_| ())
+    }
+
+    /// Save like `save`, reporting whether the file was actually written.
+    ///
+    /// When `strategy.in_memory_fallback` is enabled and the file cannot be
+    /// written (e.g. on a read-only filesystem), the configuration stays in
+    /// memory and `SaveStatus::InMemory` is returned instead of an error.
+    /// Every save tries the file again, so a filesystem that becomes
+    /// writable is picked up.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be serialised, or if the
+    /// write fails and the fallback is disabled.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let SaveStatus::InMemory { reason } = storage.save_with_status()? {
+    ///     log::warn!("config not persisted: {}", reason);
+    /// }
+    /// ```
+    pub fn save_with_status(&self) -> Result<SaveStatus, MigrationError> {
         let json_value = self.config.as_value();
 
         let content = match self.strategy.format {
-            FormatStrategy::Toml => {
-                let tv = local_store::json_to_toml(json_value).map_err(|e| {
-                    MigrationError::Store(local_store::StoreError::FormatConvert(e))
-                })?;
-                toml::to_string_pretty(&tv)
-                    .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))?
-            }
+            FormatStrategy::Toml => self.render_toml(json_value)?,
             FormatStrategy::Json => serde_json::to_string_pretty(json_value)
                 .map_err(|e| MigrationError::SerializationError(e.to_string()))?,
         };
 
-        self.inner
-            .write_string(&content)
-            .map_err(MigrationError::Store)
+        match self.inner.write_string(&content) {
+            Ok(()) => {
+                *self.lock_fallback() = None;
+                self.record_snapshot(json_value.clone());
+                Ok(SaveStatus::Written)
+            }
+            Err(e) if self.strategy.in_memory_fallback => {
+                let reason = e.to_string();
+                *self.lock_fallback() = Some(reason.clone());
+                Ok(SaveStatus::InMemory { reason })
+            }
+            Err(e) => Err(MigrationError::Store(e)),
+        }
+    }
+
+    /// Returns `true` if the last save fell back to memory.
+    pub fn is_in_memory(&self) -> bool {
+        self.lock_fallback().is_some()
+    }
+
+    /// Lock the in-memory fallback state.
+    fn lock_fallback(&self) -> std::sync::MutexGuard<'_, Option<String>> {
+        self.fallback_reason
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Save current state only if it differs from the file on disk.
+    ///
+    /// Contents are compared as parsed values, so key order and whitespace
+    /// differences do not count as changes. When the file's size and mtime
+    /// match the last load or save, the file is not re-read.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the file was written, `false` if the save was skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the existing file cannot be read or parsed, or if
+    /// the save fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if storage.save_if_changed()? {
+    ///     println!("config written");
+    /// }
+    /// ```
+    pub fn save_if_changed(&self) -> Result<bool, MigrationError> {
+        if self.disk_value()?.as_ref() == Some(self.config.as_value()) {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Parsed content of the file on disk, or `None` if it does not exist.
+    fn disk_value(&self) -> Result<Option<JsonValue>, MigrationError> {
+        let Some(stamp) = self.inner.stamp().map_err(MigrationError::Store)? else {
+            return Ok(None);
+        };
+
+        if let Some((seen, value)) = &*self.lock_snapshot() {
+            if *seen == stamp {
+                return Ok(Some(value.clone()));
+            }
+        }
+
+        let raw = self.inner.read_string().map_err(MigrationError::Store)?;
+        let value = if raw.trim().is_empty() {
+            JsonValue::Object(serde_json::Map::new())
+        } else {
+            match self.strategy.format {
+                FormatStrategy::Toml => toml_to_json(
+                    toml::from_str(&raw)
+                        .map_err(|e| MigrationError::TomlParseError(e.to_string()))?,
+                )?,
+                FormatStrategy::Json => serde_json::from_str(&raw)
+                    .map_err(|e| MigrationError::DeserializationError(e.to_string()))?,
+            }
+        };
+        *self.lock_snapshot() = Some((stamp, value.clone()));
+        Ok(Some(value))
+    }
+
+    /// Remember `value` as the file content at its current size and mtime.
+    fn record_snapshot(&self, value: JsonValue) {
+        *self.lock_snapshot() = self
+            .inner
+            .stamp()
+            .ok()
+            .flatten()
+            .map(|stamp| (stamp, value));
+    }
+
+    /// Lock the disk snapshot cache.
+    fn lock_snapshot(&self) -> std::sync::MutexGuard<'_, Option<(FileStamp, JsonValue)>> {
+        // The snapshot is only a cache, so a poisoned lock is still usable.
+        self.disk_snapshot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Serialise `json_value` as TOML, editing the loaded document in place
+    /// when the `toml-preserve` feature is enabled.
+    fn render_toml(&self, json_value: &JsonValue) -> Result<String, MigrationError> {
+        #[cfg(feature = "toml-preserve")]
+        if let Some(raw) = &self.original_toml {
+            return toml_preserve::render(raw, json_value);
+        }
+
+        let tv = local_store::json_to_toml(json_value).map_err(MigrationError::from)?;
+        toml::to_string_pretty(&tv).map_err(|e| MigrationError::TomlSerializeError(e.to_string()))
     }
 
     /// Get immutable reference to the ConfigMigrator.
@@ -180,6 +374,23 @@ impl FileStorage {
         self.save()
     }
 
+    /// Reset the in-memory configuration to an empty object and save it.
+    ///
+    /// Every top-level key is dropped, including ones restored from
+    /// `strategy.default_value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::Store` if the save fails.
+    pub fn reset(&mut self) -> Result<(), MigrationError> {
+        self.config.retain_keys(&[]);
+        #[cfg(feature = "toml-preserve")]
+        {
+            self.original_toml = None;
+        }
+        self.save()
+    }
+
     /// Returns a reference to the storage file path.
     ///
     /// # Returns
@@ -188,6 +399,379 @@ impl FileStorage {
     pub fn path(&self) -> &Path {
         self.inner.path()
     }
+
+    /// Drop every top-level key not in `keep_keys`, migrate the remaining
+    /// entity arrays to their latest version, and save atomically.
+    ///
+    /// Before anything is changed, the current file is copied to `<path>.bak`.
+    /// A kept key is treated as an entity array when it is a registered entity
+    /// name or that name's plural as generated by `queryable_plural` (e.g.
+    /// `"tasks"` for `"task"`, `"categories"` for `"category"`); other keys are
+    /// kept verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::Store` if the backup or the save fails, or a
+    /// migration error if an entity item cannot be migrated. On a migration
+    /// error the in-memory state is restored and nothing is written besides
+    /// the backup.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let report = storage.compact_by_keys(&["app_name", "tasks"])?;
+    /// println!("removed: {:?}", report.removed_keys);
+    /// ```
+    pub fn compact_by_keys(
+        &mut self,
+        keep_keys: &[&str],
+    ) -> Result<CompactByKeysReport, MigrationError> {
+        let backup_path = self.backup()?;
+        let snapshot = self.config.as_value().clone();
+
+        let removed_keys = self.config.retain_keys(keep_keys);
+        let mut migrated_counts = BTreeMap::new();
+        for key in keep_keys {
+            match self.config.migrate_key_to_latest(key) {
+                Ok(Some(count)) => {
+                    migrated_counts.insert(key.to_string(), count);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    // Leave the in-memory state untouched on failure.
+                    self.config.set_root(snapshot);
+                    return Err(e);
+                }
+            }
+        }
+
+        self.save()?;
+
+        Ok(CompactByKeysReport {
+            removed_keys,
+            migrated_counts,
+            backup_path,
+        })
+    }
+
+    /// Copy the current file to `<path>.bak` with an atomic write, returning
+    /// the backup path.
+    ///
+    /// Returns `Ok(None)` when the file does not exist yet.
+    fn backup(&self) -> Result<Option<PathBuf>, MigrationError> {
+        let path = self.path();
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(".bak");
+        let backup = PathBuf::from(backup);
+
+        let content = self.inner.read_string().map_err(MigrationError::Store)?;
+        let backup_strategy = FileStorageStrategy {
+            load_behavior: LoadBehavior::CreateIfMissing,
+            ..self.strategy.clone()
+        };
+        local_store::FileStorage::new(backup.clone(), backup_strategy)
+            .and_then(|store| store.write_string(&content))
+            .map_err(MigrationError::Store)?;
+
+        Ok(Some(backup))
+    }
+}
+
+/// Configuration storage that keeps each top-level key in its own file.
+///
+/// A directory such as `config.d/` holds one shard per key (`tasks.toml`,
+/// `users.toml`, ...). Each shard is a document with that single key at its
+/// root, so `tasks.toml` contains the `[[tasks]]` array of tables. Shards are
+/// merged into one `ConfigMigrator` on load, and `save` only rewrites the
+/// shards whose key changed, avoiding write amplification for large configs.
+///
+/// Every shard write is atomic (tmp file + fsync + rename via
+/// `local_store::FileStorage`); a save touching several shards is not.
+pub struct ShardedFileStorage {
+    /// Directory holding the shard files.
+    dir: PathBuf,
+    /// Merged, in-memory versioned configuration.
+    config: ConfigMigrator,
+    /// Strategy governing format, load behaviour, etc.
+    strategy: FileStorageStrategy,
+    /// Value of each key as last loaded from or written to its shard.
+    shards: Mutex<BTreeMap<String, JsonValue>>,
+}
+
+impl ShardedFileStorage {
+    /// Create a new `ShardedFileStorage` and load every shard in `dir`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory holding the shard files
+    /// * `migrator` - Migrator instance with registered migration paths
+    /// * `strategy` - Storage strategy; `format` selects the shard extension
+    ///
+    /// # Behavior
+    ///
+    /// Files whose extension does not match the format, and hidden files,
+    /// are ignored. If `dir` does not exist, `strategy.load_behavior` applies
+    /// as for `FileStorage`: `SaveIfMissing` writes `default_value` out as
+    /// shards right away.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` is missing under `ErrorIfMissing`, a shard
+    /// cannot be read or parsed, or a shard contains a key other than its
+    /// file stem.
+    pub fn new(
+        dir: PathBuf,
+        migrator: Migrator,
+        strategy: FileStorageStrategy,
+    ) -> Result<Self, MigrationError> {
+        let dir_was_missing = !dir.is_dir();
+
+        let mut root = serde_json::Map::new();
+        if dir_was_missing {
+            match strategy.load_behavior {
+                LoadBehavior::ErrorIfMissing => {
+                    return Err(MigrationError::Store(local_store::StoreError::IoError {
+                        operation: local_store::IoOperationKind::Read,
+                        path: dir.display().to_string(),
+                        context: Some("shard directory".to_string()),
+                        error: "Directory not found".to_string(),
+                    }));
+                }
+                LoadBehavior::CreateIfMissing | LoadBehavior::SaveIfMissing => {
+                    if let Some(JsonValue::Object(default)) = &strategy.default_value {
+                        root = default.clone();
+                    }
+                }
+            }
+        }
+
+        let mut storage = Self {
+            dir,
+            config: migrator.into_empty_config(),
+            strategy,
+            shards: Mutex::new(BTreeMap::new()),
+        };
+
+        if !dir_was_missing {
+            let loaded = storage.read_shards()?;
+            root.extend(loaded.clone());
+            *storage.lock_shards() = loaded.into_iter().collect();
+        }
+        storage.config.set_root(JsonValue::Object(root));
+
+        if dir_was_missing && storage.strategy.load_behavior == LoadBehavior::SaveIfMissing {
+            storage.save()?;
+        }
+
+        Ok(storage)
+    }
+
+    /// Write every shard whose key changed since the last load or save.
+    ///
+    /// Keys that were removed from the config have their shard deleted. The
+    /// directory is created if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config root is not an object, a key cannot be
+    /// used as a file name, or a shard cannot be written or deleted. Shards
+    /// written before the failure stay on disk.
+    pub fn save(&self) -> Result<(), MigrationError> {
+        let root = self.config.as_value().as_object().ok_or_else(|| {
+            MigrationError::SerializationError(
+                "Sharded storage requires a JSON object at the root".to_string(),
+            )
+        })?;
+
+        local_store::atomic_io::ensure_dir(&self.dir).map_err(MigrationError::Store)?;
+
+        let mut shards = self.lock_shards();
+
+        for (key, value) in root {
+            if shards.get(key) == Some(value) {
+                continue;
+            }
+            self.write_shard(key, value)?;
+            shards.insert(key.clone(), value.clone());
+        }
+
+        let removed: Vec<String> = shards
+            .keys()
+            .filter(|key| !root.contains_key(*key))
+            .cloned()
+            .collect();
+        for key in removed {
+            self.shard_file(self.shard_path(&key)?)?
+                .delete()
+                .map_err(MigrationError::Store)?;
+            shards.remove(&key);
+        }
+
+        Ok(())
+    }
+
+    /// Get immutable reference to the ConfigMigrator.
+    pub fn config(&self) -> &ConfigMigrator {
+        &self.config
+    }
+
+    /// Get mutable reference to the ConfigMigrator.
+    pub fn config_mut(&mut self) -> &mut ConfigMigrator {
+        &mut self.config
+    }
+
+    /// Query entities from storage.
+    ///
+    /// Delegates to `ConfigMigrator::query()`.
+    pub fn query<T>(&self, key: &str) -> Result<Vec<T>, MigrationError>
+    where
+        T: Queryable + for<'de> serde::Deserialize<'de>,
+    {
+        self.config.query(key)
+    }
+
+    /// Update entities in memory (does not save to disk).
+    ///
+    /// Delegates to `ConfigMigrator::update()`.
+    pub fn update<T>(&mut self, key: &str, value: Vec<T>) -> Result<(), MigrationError>
+    where
+        T: Queryable + serde::Serialize,
+    {
+        self.config.update(key, value)
+    }
+
+    /// Update entities and immediately save the changed shards.
+    pub fn update_and_save<T>(&mut self, key: &str, value: Vec<T>) -> Result<(), MigrationError>
+    where
+        T: Queryable + serde::Serialize,
+    {
+        self.update(key, value)?;
+        self.save()
+    }
+
+    /// Returns the directory holding the shard files.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the shard file for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MigrationError::FilenameEncoding` if `key` cannot be used as
+    /// a file stem.
+    pub fn shard_path(&self, key: &str) -> Result<PathBuf, MigrationError> {
+        if key.is_empty() || key.starts_with('.') || key.contains(['/', '\\']) || key.contains('\0')
+        {
+            return Err(MigrationError::FilenameEncoding {
+                id: key.to_string(),
+                reason: "shard keys must be non-empty, not start with '.', and not contain path separators".to_string(),
+            });
+        }
+        Ok(self.dir.join(format!("{}.{}", key, self.extension())))
+    }
+
+    /// Read and parse every shard file in the directory.
+    fn read_shards(&self) -> Result<serde_json::Map<String, JsonValue>, MigrationError> {
+        let files = local_store::atomic_io::list_files(&self.dir, self.extension())
+            .map_err(MigrationError::Store)?;
+
+        let mut root = serde_json::Map::new();
+        for path in files {
+            let Some(stem) = path.file_stem() else {
+                continue;
+            };
+            let stem = stem.to_string_lossy().into_owned();
+
+            let raw = self
+                .shard_file(path.clone())?
+                .read_string()
+                .map_err(MigrationError::Store)?;
+            if raw.trim().is_empty() {
+                continue;
+            }
+
+            let value = match self.strategy.format {
+                FormatStrategy::Toml => toml_to_json(
+                    toml::from_str(&raw)
+                        .map_err(|e| MigrationError::TomlParseError(e.to_string()))?,
+                )?,
+                FormatStrategy::Json => serde_json::from_str(&raw)
+                    .map_err(|e| MigrationError::DeserializationError(e.to_string()))?,
+            };
+            let JsonValue::Object(mut doc) = value else {
+                return Err(MigrationError::DeserializationError(format!(
+                    "Shard '{}' must contain an object",
+                    path.display()
+                )));
+            };
+            if let Some(other) = doc.keys().find(|k| **k != stem) {
+                return Err(MigrationError::DeserializationError(format!(
+                    "Shard '{}' contains key '{}'; each shard may only hold the key '{}'",
+                    path.display(),
+                    other,
+                    stem
+                )));
+            }
+            if let Some(value) = doc.remove(&stem) {
+                root.insert(stem, value);
+            }
+        }
+
+        Ok(root)
+    }
+
+    /// Atomically write the shard holding `key`.
+    fn write_shard(&self, key: &str, value: &JsonValue) -> Result<(), MigrationError> {
+        let path = self.shard_path(key)?;
+
+        let mut doc = serde_json::Map::new();
+        doc.insert(key.to_string(), value.clone());
+        let doc = JsonValue::Object(doc);
+
+        let content = match self.strategy.format {
+            FormatStrategy::Toml => {
+                let tv = local_store::json_to_toml(&doc).map_err(MigrationError::from)?;
+                toml::to_string_pretty(&tv)
+                    .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))?
+            }
+            FormatStrategy::Json => serde_json::to_string_pretty(&doc)
+                .map_err(|e| MigrationError::SerializationError(e.to_string()))?,
+        };
+
+        self.shard_file(path)?
+            .write_string(&content)
+            .map_err(MigrationError::Store)
+    }
+
+    /// Raw file store for the shard at `path`.
+    fn shard_file(&self, path: PathBuf) -> Result<local_store::FileStorage, MigrationError> {
+        let inner_strategy = FileStorageStrategy {
+            load_behavior: LoadBehavior::CreateIfMissing,
+            ..self.strategy.clone()
+        };
+        local_store::FileStorage::new(path, inner_strategy).map_err(MigrationError::Store)
+    }
+
+    /// File extension of shard files.
+    fn extension(&self) -> &'static str {
+        match self.strategy.format {
+            FormatStrategy::Toml => "toml",
+            FormatStrategy::Json => "json",
+        }
+    }
+
+    /// Lock the per-shard snapshot.
+    fn lock_shards(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, JsonValue>> {
+        // The snapshot only decides what to rewrite, so a poisoned lock is still usable.
+        self.shards
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 // ============================================================================
@@ -203,6 +787,148 @@ fn toml_to_json(toml_value: toml::Value) -> Result<JsonValue, MigrationError> {
     Ok(json_value)
 }
 
+/// Comment- and order-preserving TOML rendering (`toml-preserve` feature).
+#[cfg(feature = "toml-preserve")]
+pub(crate) mod toml_preserve {
+    use super::*;
+    use toml_edit::{DocumentMut, Item, Table};
+
+    /// Re-render `json_value` on top of the originally loaded TOML text.
+    ///
+    /// Unchanged top-level keys are left untouched, changed keys are replaced
+    /// in place (keeping their leading comments), removed keys are dropped and
+    /// new keys are appended at the end of the document.
+    pub(crate) fn render(raw: &str, json_value: &JsonValue) -> Result<String, MigrationError> {
+        let mut doc: DocumentMut = raw
+            .parse()
+            .map_err(|e: toml_edit::TomlError| MigrationError::TomlParseError(e.to_string()))?;
+        let original: toml::Value =
+            toml::from_str(raw).map_err(|e| MigrationError::TomlParseError(e.to_string()))?;
+        let original = toml_to_json(original)?;
+
+        let JsonValue::Object(new_map) = json_value else {
+            return Err(MigrationError::TomlSerializeError(
+                "root value must be a table".to_string(),
+            ));
+        };
+
+        let stale: Vec<String> = doc
+            .iter()
+            .map(|(k, _)| k.to_string())
+            .filter(|k| !new_map.contains_key(k))
+            .collect();
+        for key in stale {
+            doc.remove(&key);
+        }
+
+        let mut next_position = max_position(doc.as_item()).map_or(0, |p| p + 1);
+        for (key, value) in new_map {
+            if original.get(key) == Some(value) {
+                continue;
+            }
+
+            let mut item = fresh_item(key, value)?;
+            match doc.get_mut(key) {
+                Some(existing) => {
+                    let position = first_table(existing)
+                        .and_then(Table::position)
+                        .unwrap_or(next_position);
+                    carry_decor(existing, &mut item);
+                    set_positions(&mut item, position);
+                    *existing = item;
+                }
+                None => {
+                    set_positions(&mut item, next_position);
+                    doc.insert(key, item);
+                }
+            }
+            next_position += 1;
+        }
+
+        Ok(doc.to_string())
+    }
+
+    /// Render a single key/value pair the same way the plain TOML path would.
+    fn fresh_item(key: &str, value: &JsonValue) -> Result<Item, MigrationError> {
+        let single = serde_json::json!({ key: value });
+        let tv = local_store::json_to_toml(&single).map_err(MigrationError::from)?;
+        let text = toml::to_string_pretty(&tv)
+            .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))?;
+        let mut doc: DocumentMut = text
+            .parse()
+            .map_err(|e: toml_edit::TomlError| MigrationError::TomlParseError(e.to_string()))?;
+        Ok(doc.remove(key).unwrap_or_default())
+    }
+
+    /// Keep the comments attached to the item being replaced.
+    fn carry_decor(old: &Item, new: &mut Item) {
+        match (old, new) {
+            (Item::Value(old), Item::Value(new)) => {
+                *new.decor_mut() = old.decor().clone();
+            }
+            (old, new) => {
+                if let (Some(old), Some(new)) = (first_table(old), first_table_mut(new)) {
+                    *new.decor_mut() = old.decor().clone();
+                }
+            }
+        }
+    }
+
+    fn first_table(item: &Item) -> Option<&Table> {
+        match item {
+            Item::Table(t) => Some(t),
+            Item::ArrayOfTables(a) => a.iter().next(),
+            _ => None,
+        }
+    }
+
+    fn first_table_mut(item: &mut Item) -> Option<&mut Table> {
+        match item {
+            Item::Table(t) => Some(t),
+            Item::ArrayOfTables(a) => a.iter_mut().next(),
+            _ => None,
+        }
+    }
+
+    /// Pin every table header in `item` to `position` so it renders where
+    /// the replaced key used to be.
+    fn set_positions(item: &mut Item, position: usize) {
+        match item {
+            Item::Table(t) => {
+                t.set_position(position);
+                for (_, child) in t.iter_mut() {
+                    set_positions(child, position);
+                }
+            }
+            Item::ArrayOfTables(a) => {
+                for t in a.iter_mut() {
+                    t.set_position(position);
+                    for (_, child) in t.iter_mut() {
+                        set_positions(child, position);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn max_position(item: &Item) -> Option<usize> {
+        let tables: Vec<&Table> = match item {
+            Item::Table(t) => vec![t],
+            Item::ArrayOfTables(a) => a.iter().collect(),
+            _ => return None,
+        };
+        tables
+            .into_iter()
+            .flat_map(|t| {
+                std::iter::once(t.position())
+                    .chain(t.iter().map(|(_, child)| max_position(child)))
+                    .flatten()
+            })
+            .max()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -652,6 +1378,237 @@ mod tests {
         assert!(!file_path.exists());
     }
 
+    #[test]
+    fn test_save_with_fsync_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("fsync_dir.toml");
+        let strategy = FileStorageStrategy::new().with_fsync_dir(true);
+
+        let mut storage = FileStorage::new(file_path.clone(), setup_migrator(), strategy).unwrap();
+        storage
+            .update_and_save(
+                "test",
+                vec![TestEntity {
+                    name: "durable".to_string(),
+                    count: 1,
+                }],
+            )
+            .unwrap();
+
+        let reloaded =
+            FileStorage::new(file_path, setup_migrator(), FileStorageStrategy::default()).unwrap();
+        let loaded: Vec<TestEntity> = reloaded.query("test").unwrap();
+        assert_eq!(loaded[0].name, "durable");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_with_explicit_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("secret.toml");
+        let mut strategy = FileStorageStrategy::new();
+        strategy.atomic_write.mode = Some(0o600);
+
+        let mut storage = FileStorage::new(file_path.clone(), setup_migrator(), strategy).unwrap();
+        storage
+            .update_and_save(
+                "test",
+                vec![TestEntity {
+                    name: "secret".to_string(),
+                    count: 1,
+                }],
+            )
+            .unwrap();
+
+        let mode = std::fs::metadata(&file_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_reset_empties_config_and_saves() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("reset.json");
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+
+        let mut storage =
+            FileStorage::new(file_path.clone(), setup_migrator(), strategy.clone()).unwrap();
+        storage
+            .update_and_save(
+                "test",
+                vec![TestEntity {
+                    name: "gone".to_string(),
+                    count: 1,
+                }],
+            )
+            .unwrap();
+
+        storage.reset().unwrap();
+        assert_eq!(storage.config().as_value(), &serde_json::json!({}));
+
+        let reloaded = FileStorage::new(file_path, setup_migrator(), strategy).unwrap();
+        assert!(reloaded.query::<TestEntity>("test").unwrap().is_empty());
+        assert_eq!(reloaded.config().as_value(), &serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_in_memory_fallback_on_write_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("config");
+        let file_path = dir.join("app.json");
+        let strategy = FileStorageStrategy::new()
+            .with_format(FormatStrategy::Json)
+            .with_in_memory_fallback(true);
+
+        let mut storage =
+            FileStorage::new(file_path.clone(), setup_migrator(), strategy.clone()).unwrap();
+
+        // A regular file where the directory should be makes every write
+        // fail, even when running as root.
+        std::fs::write(&dir, "not a directory").unwrap();
+
+        let entity = TestEntity {
+            name: "kept".to_string(),
+            count: 1,
+        };
+        storage.update("test", vec![entity]).unwrap();
+
+        let status = storage.save_with_status().unwrap();
+        assert!(matches!(status, SaveStatus::InMemory { .. }));
+        assert!(storage.is_in_memory());
+        assert!(storage.save().is_ok());
+        assert_eq!(storage.query::<TestEntity>("test").unwrap()[0].name, "kept");
+
+        // Once the filesystem is writable again, saves go to disk
+        std::fs::remove_file(&dir).unwrap();
+        assert_eq!(storage.save_with_status().unwrap(), SaveStatus::Written);
+        assert!(!storage.is_in_memory());
+        assert!(file_path.exists());
+    }
+
+    #[test]
+    fn test_in_memory_fallback_at_construction() {
+        let temp_dir = TempDir::new().unwrap();
+        let blocked = temp_dir.path().join("config");
+        std::fs::write(&blocked, "not a directory").unwrap();
+        let file_path = blocked.join("app.json");
+        let strategy = FileStorageStrategy::new()
+            .with_format(FormatStrategy::Json)
+            .with_load_behavior(LoadBehavior::SaveIfMissing)
+            .with_default_value(serde_json::json!({"test": []}));
+
+        assert!(FileStorage::new(file_path.clone(), setup_migrator(), strategy.clone()).is_err());
+
+        let storage = FileStorage::new(
+            file_path.clone(),
+            setup_migrator(),
+            strategy.with_in_memory_fallback(true),
+        )
+        .unwrap();
+        assert!(storage.is_in_memory());
+        assert!(!file_path.exists());
+        assert!(storage.query::<TestEntity>("test").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_failure_is_an_error_without_fallback() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("config");
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+
+        let storage = FileStorage::new(dir.join("app.json"), setup_migrator(), strategy).unwrap();
+        std::fs::write(&dir, "not a directory").unwrap();
+
+        assert!(matches!(
+            storage.save_with_status(),
+            Err(MigrationError::Store(_))
+        ));
+        assert!(!storage.is_in_memory());
+    }
+
+    #[test]
+    fn test_save_if_changed_skips_identical_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("unchanged.json");
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+
+        let mut storage =
+            FileStorage::new(file_path.clone(), setup_migrator(), strategy.clone()).unwrap();
+        storage
+            .update(
+                "test",
+                vec![TestEntity {
+                    name: "once".to_string(),
+                    count: 1,
+                }],
+            )
+            .unwrap();
+
+        // Missing file counts as a change
+        assert!(storage.save_if_changed().unwrap());
+        let written = std::fs::metadata(&file_path).unwrap().modified().unwrap();
+
+        // Idempotent save does not touch the file
+        assert!(!storage.save_if_changed().unwrap());
+        assert_eq!(
+            std::fs::metadata(&file_path).unwrap().modified().unwrap(),
+            written
+        );
+
+        // Reformatting on disk is not a change either
+        let compact = storage.config().to_string_compact().unwrap();
+        std::fs::write(&file_path, compact).unwrap();
+        let reloaded = FileStorage::new(file_path.clone(), setup_migrator(), strategy).unwrap();
+        std::fs::write(&file_path, reloaded.config().to_string().unwrap()).unwrap();
+        assert!(!reloaded.save_if_changed().unwrap());
+
+        storage
+            .update(
+                "test",
+                vec![TestEntity {
+                    name: "twice".to_string(),
+                    count: 2,
+                }],
+            )
+            .unwrap();
+        assert!(storage.save_if_changed().unwrap());
+    }
+
+    #[test]
+    fn test_save_if_changed_detects_edit_with_same_mtime() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("coarse.json");
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+
+        let mut storage = FileStorage::new(file_path.clone(), setup_migrator(), strategy).unwrap();
+        storage
+            .update(
+                "test",
+                vec![TestEntity {
+                    name: "mine".to_string(),
+                    count: 1,
+                }],
+            )
+            .unwrap();
+        storage.save().unwrap();
+        let written = std::fs::metadata(&file_path).unwrap().modified().unwrap();
+
+        // Another writer replaces the file within the same timestamp tick
+        std::fs::write(&file_path, r#"{"test": []}"#).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_modified(written)
+            .unwrap();
+
+        assert!(storage.save_if_changed().unwrap());
+        assert!(std::fs::read_to_string(&file_path)
+            .unwrap()
+            .contains("mine"));
+    }
+
     #[test]
     fn test_atomic_write_config_default() {
         let config = local_store::AtomicWriteConfig::default();
@@ -673,4 +1630,268 @@ mod tests {
         assert_eq!(LoadBehavior::ErrorIfMissing, LoadBehavior::ErrorIfMissing);
         assert_ne!(LoadBehavior::CreateIfMissing, LoadBehavior::ErrorIfMissing);
     }
+
+    #[test]
+    fn test_compact_by_keys_removes_keys_and_writes_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("compact.json");
+        std::fs::write(
+            &file_path,
+            r#"{
+                "app_name": "MyApp",
+                "legacy_feature": {"enabled": true},
+                "obsolete": 1,
+                "tests": [
+                    {"version": "1.0.0", "name": "old"},
+                    {"version": "2.0.0", "name": "new", "count": 7}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+        let mut storage = FileStorage::new(file_path.clone(), setup_migrator(), strategy).unwrap();
+
+        let report = storage.compact_by_keys(&["app_name", "tests"]).unwrap();
+
+        assert_eq!(report.removed_keys, vec!["legacy_feature", "obsolete"]);
+        assert_eq!(report.migrated_counts.get("tests"), Some(&1));
+
+        // Backup holds the original content
+        let backup_path = temp_dir.path().join("compact.json.bak");
+        assert_eq!(report.backup_path.as_deref(), Some(backup_path.as_path()));
+        let backup: JsonValue =
+            serde_json::from_str(&std::fs::read_to_string(&backup_path).unwrap()).unwrap();
+        assert_eq!(backup["obsolete"], 1);
+
+        // Compacted file lacks removed keys and holds latest versions
+        let saved: JsonValue =
+            serde_json::from_str(&std::fs::read_to_string(&file_path).unwrap()).unwrap();
+        assert!(saved.get("legacy_feature").is_none());
+        assert!(saved.get("obsolete").is_none());
+        assert_eq!(saved["app_name"], "MyApp");
+        assert_eq!(saved["tests"][0]["version"], "2.0.0");
+        assert_eq!(saved["tests"][0]["count"], 0);
+        assert_eq!(saved["tests"][1]["count"], 7);
+    }
+
+    #[test]
+    fn test_compact_by_keys_without_existing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("fresh.toml");
+        let mut storage = FileStorage::new(
+            file_path.clone(),
+            setup_migrator(),
+            FileStorageStrategy::default(),
+        )
+        .unwrap();
+
+        let report = storage.compact_by_keys(&["test"]).unwrap();
+
+        assert!(report.removed_keys.is_empty());
+        assert!(report.backup_path.is_none());
+        assert!(file_path.exists());
+    }
+
+    #[test]
+    fn test_compact_by_keys_migration_failure_keeps_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("broken.json");
+        let original = r#"{"obsolete":1,"test":[{"version":"1.0.0"}]}"#;
+        std::fs::write(&file_path, original).unwrap();
+
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+        let mut storage = FileStorage::new(file_path.clone(), setup_migrator(), strategy).unwrap();
+
+        assert!(storage.compact_by_keys(&["test"]).is_err());
+        assert_eq!(storage.config().as_value()["obsolete"], 1);
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), original);
+    }
+
+    #[cfg(feature = "toml-preserve")]
+    #[test]
+    fn test_toml_preserve_keeps_comments_and_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("commented.toml");
+        let original = r#"# Application settings
+app_name = "MyApp" # display name
+
+# Tasks tracked by the app
+[[test]]
+version = "2.0.0"
+name = "old"
+count = 1
+
+# Owner information
+[owner]
+# Full name
+name = "Alice"
+"#;
+        std::fs::write(&file_path, original).unwrap();
+
+        let mut storage = FileStorage::new(
+            file_path.clone(),
+            setup_migrator(),
+            FileStorageStrategy::default(),
+        )
+        .unwrap();
+        storage
+            .update_and_save(
+                "test",
+                vec![TestEntity {
+                    name: "new".to_string(),
+                    count: 2,
+                }],
+            )
+            .unwrap();
+
+        let saved = std::fs::read_to_string(&file_path).unwrap();
+        for comment in [
+            "# Application settings",
+            "# display name",
+            "# Tasks tracked by the app",
+            "# Owner information",
+            "# Full name",
+        ] {
+            assert!(saved.contains(comment), "missing {comment:?} in:\n{saved}");
+        }
+
+        let app = saved.find("app_name").unwrap();
+        let test = saved.find("[[test]]").unwrap();
+        let owner = saved.find("[owner]").unwrap();
+        assert!(app < test && test < owner, "order changed:\n{saved}");
+
+        let reloaded: Vec<TestEntity> =
+            FileStorage::new(file_path, setup_migrator(), FileStorageStrategy::default())
+                .unwrap()
+                .query("test")
+                .unwrap();
+        assert_eq!(reloaded[0].name, "new");
+        assert_eq!(reloaded[0].count, 2);
+    }
+
+    fn shard_mtime(path: &Path) -> SystemTime {
+        std::fs::metadata(path).unwrap().modified().unwrap()
+    }
+
+    #[test]
+    fn test_sharded_save_rewrites_only_changed_shard() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("config.d");
+
+        let mut storage =
+            ShardedFileStorage::new(dir.clone(), setup_migrator(), FileStorageStrategy::new())
+                .unwrap();
+        storage
+            .update(
+                "tasks",
+                vec![TestEntity {
+                    name: "a".to_string(),
+                    count: 1,
+                }],
+            )
+            .unwrap();
+        storage
+            .update(
+                "users",
+                vec![TestEntity {
+                    name: "alice".to_string(),
+                    count: 2,
+                }],
+            )
+            .unwrap();
+        storage.save().unwrap();
+
+        let tasks_path = dir.join("tasks.toml");
+        let users_path = dir.join("users.toml");
+        assert!(std::fs::read_to_string(&tasks_path)
+            .unwrap()
+            .contains("[[tasks]]"));
+
+        // Pin both mtimes to a known past instant
+        let past = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000_000);
+        for path in [&tasks_path, &users_path] {
+            std::fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(past)
+                .unwrap();
+        }
+
+        storage
+            .update(
+                "tasks",
+                vec![TestEntity {
+                    name: "b".to_string(),
+                    count: 3,
+                }],
+            )
+            .unwrap();
+        storage.save().unwrap();
+
+        assert_ne!(shard_mtime(&tasks_path), past);
+        assert_eq!(shard_mtime(&users_path), past);
+
+        // Both shards merge back into one config
+        let reloaded =
+            ShardedFileStorage::new(dir, setup_migrator(), FileStorageStrategy::new()).unwrap();
+        let tasks: Vec<TestEntity> = reloaded.query("tasks").unwrap();
+        let users: Vec<TestEntity> = reloaded.query("users").unwrap();
+        assert_eq!(tasks[0].name, "b");
+        assert_eq!(users[0].name, "alice");
+    }
+
+    #[test]
+    fn test_sharded_save_deletes_removed_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("config.d");
+        let strategy = FileStorageStrategy::new().with_format(FormatStrategy::Json);
+
+        let mut storage =
+            ShardedFileStorage::new(dir.clone(), setup_migrator(), strategy.clone()).unwrap();
+        storage.config_mut().as_value_mut()["app_name"] = serde_json::json!("demo");
+        storage.config_mut().as_value_mut()["theme"] = serde_json::json!("dark");
+        storage.save().unwrap();
+        assert!(dir.join("theme.json").exists());
+
+        storage
+            .config_mut()
+            .as_value_mut()
+            .as_object_mut()
+            .unwrap()
+            .remove("theme");
+        storage.save().unwrap();
+
+        assert!(!dir.join("theme.json").exists());
+        let reloaded = ShardedFileStorage::new(dir, setup_migrator(), strategy).unwrap();
+        assert_eq!(
+            reloaded.config().as_value(),
+            &serde_json::json!({"app_name": "demo"})
+        );
+    }
+
+    #[test]
+    fn test_sharded_load_rejects_foreign_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("config.d");
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("tasks.toml"), "app_name = \"oops\"\n").unwrap();
+
+        let result = ShardedFileStorage::new(dir, setup_migrator(), FileStorageStrategy::new());
+        assert!(matches!(
+            result,
+            Err(MigrationError::DeserializationError(msg)) if msg.contains("app_name")
+        ));
+    }
+
+    #[test]
+    fn test_sharded_missing_dir_with_error_behavior() {
+        let temp_dir = TempDir::new().unwrap();
+        let strategy = FileStorageStrategy::new().with_load_behavior(LoadBehavior::ErrorIfMissing);
+
+        let result =
+            ShardedFileStorage::new(temp_dir.path().join("absent"), setup_migrator(), strategy);
+        assert!(matches!(result, Err(MigrationError::Store(_))));
+    }
 }
@@ -201,9 +201,8 @@ impl VersionedDirStorage {
             FormatStrategy::Json => serde_json::to_string_pretty(value)
                 .map_err(|e| MigrationError::SerializationError(e.to_string())),
             FormatStrategy::Toml => {
-                let tv = local_store::format_convert::json_to_toml(value).map_err(|e| {
-                    MigrationError::Store(local_store::StoreError::FormatConvert(e))
-                })?;
+                let tv = local_store::format_convert::json_to_toml(value)
+                    .map_err(MigrationError::from)?;
                 toml::to_string_pretty(&tv)
                     .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))
             }
@@ -436,9 +435,8 @@ mod async_impl {
                 FormatStrategy::Json => serde_json::to_string_pretty(value)
                     .map_err(|e| MigrationError::SerializationError(e.to_string())),
                 FormatStrategy::Toml => {
-                    let tv = local_store::format_convert::json_to_toml(value).map_err(|e| {
-                        MigrationError::Store(local_store::StoreError::FormatConvert(e))
-                    })?;
+                    let tv = local_store::format_convert::json_to_toml(value)
+                        .map_err(MigrationError::from)?;
                     toml::to_string_pretty(&tv)
                         .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))
                 }
@@ -137,9 +137,8 @@ impl VersionedFileStorage {
 
         let content = match self.strategy.format {
             FormatStrategy::Toml => {
-                let tv = local_store::format_convert::json_to_toml(json_value).map_err(|e| {
-                    MigrationError::Store(local_store::StoreError::FormatConvert(e))
-                })?;
+                let tv = local_store::format_convert::json_to_toml(json_value)
+                    .map_err(MigrationError::from)?;
                 toml::to_string_pretty(&tv)
                     .map_err(|e| MigrationError::TomlSerializeError(e.to_string()))?
             }
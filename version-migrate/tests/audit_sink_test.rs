@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use version_migrate::{AuditRecord, IntoDomain, MigratesTo, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.1.0")]
+struct TaskV1_1 {
+    id: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    id: String,
+    done: bool,
+    tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Task {
+    id: String,
+    done: bool,
+    tags: Vec<String>,
+}
+
+impl MigratesTo<TaskV1_1> for TaskV1 {
+    fn migrate(self) -> TaskV1_1 {
+        TaskV1_1 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl MigratesTo<TaskV2> for TaskV1_1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: self.done,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<Task> for TaskV2 {
+    fn into_domain(self) -> Task {
+        Task {
+            id: self.id,
+            done: self.done,
+            tags: self.tags,
+        }
+    }
+}
+
+fn register(migrator: &mut Migrator) {
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV1_1>()
+                .step::<TaskV2>()
+                .into::<Task>(),
+        )
+        .unwrap();
+}
+
+fn recording_sink() -> (Arc<Mutex<Vec<AuditRecord>>>, version_migrate::AuditSink) {
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let sink_records = Arc::clone(&records);
+    let sink = Arc::new(move |record: AuditRecord| sink_records.lock().unwrap().push(record));
+    (records, sink)
+}
+
+#[test]
+fn test_one_record_per_applied_step() {
+    let (records, sink) = recording_sink();
+    let mut migrator = Migrator::new();
+    register(&mut migrator);
+    let migrator = migrator.with_audit_sink(sink);
+
+    let json = r#"{"version":"1.0.0","data":{"id":"task-1"}}"#;
+    let _: Task = migrator.load("task", json).unwrap();
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0].entity, "task");
+    assert_eq!(records[0].id.as_deref(), Some("task-1"));
+    assert_eq!(records[0].from, "1.0.0");
+    assert_eq!(records[0].to, "1.1.0");
+
+    assert_eq!(records[1].entity, "task");
+    assert_eq!(records[1].id.as_deref(), Some("task-1"));
+    assert_eq!(records[1].from, "1.1.0");
+    assert_eq!(records[1].to, "2.0.0");
+
+    assert!(records[0].timestamp_ms > 0);
+    assert!(records[1].timestamp_ms >= records[0].timestamp_ms);
+}
+
+#[test]
+fn test_sink_applies_to_paths_registered_later() {
+    let (records, sink) = recording_sink();
+    let mut migrator = Migrator::new().with_audit_sink(sink);
+    register(&mut migrator);
+
+    let json = r#"{"version":"1.1.0","data":{"id":"task-2","done":true}}"#;
+    let task: Task = migrator.load("task", json).unwrap();
+    assert!(task.done);
+
+    let records = records.lock().unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].from, "1.1.0");
+    assert_eq!(records[0].to, "2.0.0");
+}
+
+#[test]
+fn test_latest_version_produces_no_records() {
+    let (records, sink) = recording_sink();
+    let mut migrator = Migrator::new();
+    register(&mut migrator);
+    let migrator = migrator.with_audit_sink(sink);
+
+    let json = r#"{"version":"2.0.0","data":{"id":"task-3","done":false,"tags":[]}}"#;
+    let _: Task = migrator.load("task", json).unwrap();
+
+    assert!(records.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_record_round_trips_through_json() {
+    let record = AuditRecord {
+        entity: "task".to_string(),
+        id: None,
+        from: "1.0.0".to_string(),
+        to: "1.1.0".to_string(),
+        timestamp_ms: 1_700_000_000_000,
+    };
+
+    let json = serde_json::to_string(&record).unwrap();
+    let parsed: AuditRecord = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, record);
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    identity_into_domain, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl MigratesTo<TaskV1> for TaskV2 {
+    fn migrate(self) -> TaskV1 {
+        TaskV1 {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        self.migrate().into_domain()
+    }
+}
+
+identity_into_domain!(TaskV1);
+
+fn setup_migrator() -> Migrator {
+    let forward = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    let backward = Migrator::define("task")
+        .from::<TaskV2>()
+        .step::<TaskV1>()
+        .into::<TaskV1>();
+
+    let mut migrator = Migrator::new();
+    migrator
+        .register_bidirectional(Migrator::bidirectional(forward, backward))
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_bidirectional_upgrade_then_downgrade_roundtrip() {
+    let migrator = setup_migrator();
+    let original = TaskV1 {
+        id: "task-1".to_string(),
+        title: "Roundtrip".to_string(),
+    };
+
+    let stored = migrator.save(original.clone()).unwrap();
+    let entity: TaskEntity = migrator.load("task", &stored).unwrap();
+    assert!(!entity.done);
+
+    // Plain domain JSON is treated as the latest version
+    let domain_json = serde_json::to_string(&entity).unwrap();
+    let downgraded: TaskV1 = migrator.load_downgraded("task", &domain_json).unwrap();
+    assert_eq!(downgraded, original);
+
+    // Versioned latest JSON works as well
+    let latest = migrator
+        .save(TaskV2 {
+            id: entity.id,
+            title: entity.title,
+            done: entity.done,
+        })
+        .unwrap();
+    let downgraded: TaskV1 = migrator.load_downgraded("task", &latest).unwrap();
+    assert_eq!(downgraded, original);
+}
+
+#[test]
+fn test_bidirectional_registers_backward_entity_name() {
+    let migrator = setup_migrator();
+
+    assert_eq!(migrator.get_latest_version("task"), Some("2.0.0"));
+    assert_eq!(migrator.get_latest_version("task_backward"), Some("1.0.0"));
+}
+
+#[test]
+fn test_load_downgraded_without_backward_path() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let result: Result<TaskV1, _> =
+        migrator.load_downgraded("task", r#"{"id":"t","title":"x","done":true}"#);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    ConfigMigrator, DeriveQueryable as Queryable, Forwardable, IntoDomain, LoadLimits, MigratesTo,
+    Migrator, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Queryable)]
+#[queryable(entity = "task")]
+struct Task {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<Task> for TaskV2 {
+    fn into_domain(self) -> Task {
+        Task {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<Task>(),
+        )
+        .unwrap();
+    migrator
+}
+
+fn task(id: &str) -> Task {
+    Task {
+        id: id.to_string(),
+        done: false,
+    }
+}
+
+#[test]
+fn test_load_strips_bom() {
+    let migrator = setup_migrator();
+    let json = "\u{FEFF}{\"version\":\"1.0.0\",\"data\":{\"id\":\"task-1\"}}";
+
+    let loaded: Task = migrator.load("task", json).unwrap();
+    assert_eq!(loaded, task("task-1"));
+}
+
+#[test]
+fn test_load_tolerates_leading_whitespace() {
+    let migrator = setup_migrator();
+    let json = "\n\n  \t{\"version\":\"1.0.0\",\"data\":{\"id\":\"task-1\"}}";
+
+    let loaded: Task = migrator.load("task", json).unwrap();
+    assert_eq!(loaded, task("task-1"));
+}
+
+#[test]
+fn test_load_vec_strips_bom() {
+    let migrator = setup_migrator();
+    let json =
+        "\u{FEFF}\n[{\"version\":\"1.0.0\",\"data\":{\"id\":\"a\"}},{\"version\":\"1.0.0\",\"data\":{\"id\":\"b\"}}]";
+
+    let loaded: Vec<Task> = migrator.load_vec("task", json).unwrap();
+    assert_eq!(loaded, vec![task("a"), task("b")]);
+}
+
+#[test]
+fn test_config_migrator_strips_bom() {
+    let json = "\u{FEFF}{\"tasks\":[{\"version\":\"1.0.0\",\"id\":\"a\"}]}";
+
+    let config = ConfigMigrator::from(json, setup_migrator()).unwrap();
+    let tasks: Vec<Task> = config.query("tasks").unwrap();
+    assert_eq!(tasks, vec![task("a")]);
+}
+
+const BOM_WRAPPED: &str = "\u{FEFF}{\"version\":\"1.0.0\",\"data\":{\"id\":\"task-1\"}}";
+const BOM_FLAT: &str = "\u{FEFF}{\"version\":\"1.0.0\",\"id\":\"task-1\"}";
+const BOM_ARRAY: &str = "\u{FEFF}[{\"version\":\"1.0.0\",\"data\":{\"id\":\"a\"}}]";
+const BOM_FLAT_ARRAY: &str = "\u{FEFF}[{\"version\":\"1.0.0\",\"id\":\"a\"}]";
+
+#[test]
+fn test_wrapped_loaders_strip_bom() {
+    let migrator = setup_migrator();
+
+    let (loaded, extras): (Task, _) = migrator.load_with_extras("task", BOM_WRAPPED).unwrap();
+    assert_eq!(loaded, task("task-1"));
+    assert!(extras.is_empty());
+
+    let loaded: Task = migrator
+        .load_with_strict_version_check("task", BOM_WRAPPED)
+        .unwrap();
+    assert_eq!(loaded, task("task-1"));
+
+    let upgraded: TaskV2 = migrator
+        .load_upgrading_to_version("task", BOM_WRAPPED, "2.0.0")
+        .unwrap();
+    assert_eq!(upgraded.id, "task-1");
+
+    assert!(migrator.can_load("task", BOM_WRAPPED).unwrap());
+    assert_eq!(migrator.candidate_entities(BOM_WRAPPED).unwrap(), ["task"]);
+
+    let mut json = BOM_WRAPPED.to_string();
+    let loaded: Task = migrator.load_upgrading_in_place("task", &mut json).unwrap();
+    assert_eq!(loaded, task("task-1"));
+    assert!(!json.starts_with('\u{FEFF}'));
+
+    let loaded: Task = migrator.load_with_fallback("task", BOM_WRAPPED).unwrap();
+    assert_eq!(loaded, task("task-1"));
+
+    let loaded: Forwardable<Task> = migrator.load_forward("task", BOM_WRAPPED).unwrap();
+    assert_eq!(loaded.into_inner(), task("task-1"));
+}
+
+#[test]
+fn test_flat_loaders_strip_bom() {
+    let migrator = setup_migrator();
+
+    let loaded: Task = migrator.load_flat("task", BOM_FLAT).unwrap();
+    assert_eq!(loaded, task("task-1"));
+
+    let loaded: Forwardable<Task> = migrator.load_forward_flat("task", BOM_FLAT).unwrap();
+    assert_eq!(loaded.into_inner(), task("task-1"));
+}
+
+#[test]
+fn test_array_loaders_strip_bom() {
+    let migrator = setup_migrator();
+
+    let envelope = "\u{FEFF}{\"items\":[{\"version\":\"1.0.0\",\"data\":{\"id\":\"a\"}}]}";
+    let loaded: Vec<Task> = migrator
+        .load_vec_enveloped("task", envelope, "items")
+        .unwrap();
+    assert_eq!(loaded, vec![task("a")]);
+
+    let loaded: Vec<Task> = migrator.load_vec_flat("task", BOM_FLAT_ARRAY).unwrap();
+    assert_eq!(loaded, vec![task("a")]);
+
+    let ids: Vec<String> = migrator.load_field_vec("task", BOM_ARRAY, "id").unwrap();
+    assert_eq!(ids, ["a"]);
+}
+
+#[test]
+fn test_json_map_loader_strips_bom() {
+    let migrator = setup_migrator();
+    let backup = "\u{FEFF}{\"tasks\":[{\"version\":\"1.0.0\",\"id\":\"a\"}]}";
+
+    let restored = migrator.load_all_to_json_map(backup).unwrap();
+    assert_eq!(restored["tasks"][0]["id"], "a");
+    assert_eq!(restored["tasks"][0]["version"], "2.0.0");
+}
+
+#[test]
+fn test_load_limits_apply_after_stripping_bom() {
+    let migrator = setup_migrator().with_limits(LoadLimits {
+        max_bytes: BOM_WRAPPED.len() - '\u{FEFF}'.len_utf8(),
+        max_depth: 4,
+    });
+
+    let loaded: Task = migrator.load_flat("task", BOM_FLAT).unwrap();
+    assert_eq!(loaded, task("task-1"));
+    let loaded: Task = migrator
+        .load_with_strict_version_check("task", BOM_WRAPPED)
+        .unwrap();
+    assert_eq!(loaded, task("task-1"));
+}
+
+#[cfg(feature = "json5")]
+#[test]
+fn test_json5_loaders_strip_bom() {
+    let migrator = setup_migrator();
+    let text = "\u{FEFF}{ version: '1.0.0', data: { id: 'task-1', }, }";
+
+    let loaded: Task = migrator.load_json5("task", text).unwrap();
+    assert_eq!(loaded, task("task-1"));
+
+    let config = ConfigMigrator::from_json5(
+        "\u{FEFF}{ tasks: [{ version: '1.0.0', id: 'a' }] }",
+        setup_migrator(),
+    )
+    .unwrap();
+    let tasks: Vec<Task> = config.query("tasks").unwrap();
+    assert_eq!(tasks, vec![task("a")]);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_load_async_strips_bom() {
+    let migrator = setup_migrator();
+
+    let loaded: Task = migrator.load_async("task", BOM_WRAPPED).await.unwrap();
+    assert_eq!(loaded, task("task-1"));
+}
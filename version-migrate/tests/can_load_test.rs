@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_can_load_known_version() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+
+    assert!(migrator.can_load("task", json).unwrap());
+}
+
+#[test]
+fn test_can_load_does_not_run_migration() {
+    let migrator = setup_migrator();
+    // Data would fail the V1 -> V2 step, but the version is in the chain
+    let json = r#"{"version":"1.0.0","data":{}}"#;
+
+    assert!(migrator.can_load("task", json).unwrap());
+    assert!(migrator.load::<TaskEntity>("task", json).is_err());
+}
+
+#[test]
+fn test_can_load_unknown_version_or_entity() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"3.0.0","data":{"id":"t1","done":true}}"#;
+
+    assert!(!migrator.can_load("task", json).unwrap());
+    assert!(!migrator
+        .can_load("user", r#"{"version":"1.0.0","data":{}}"#)
+        .unwrap());
+}
+
+#[test]
+fn test_can_load_malformed_json() {
+    let migrator = setup_migrator();
+
+    let result = migrator.can_load("task", r#"{"version":"1.0.0","data":"#);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(_))
+    ));
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    title: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    title: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Task {
+    title: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct NoteV1 {
+    text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Note {
+    text: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "3.1.0")]
+struct UserV3 {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct User {
+    name: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<Task> for TaskV2 {
+    fn into_domain(self) -> Task {
+        Task {
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<Note> for NoteV1 {
+    fn into_domain(self) -> Note {
+        Note { text: self.text }
+    }
+}
+
+impl IntoDomain<User> for UserV3 {
+    fn into_domain(self) -> User {
+        User { name: self.name }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<Task>(),
+        )
+        .unwrap();
+    migrator
+        .register(Migrator::define("note").from::<NoteV1>().into::<Note>())
+        .unwrap();
+    migrator
+        .register(Migrator::define("user").from::<UserV3>().into::<User>())
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_shared_version_matches_every_entity_with_it() {
+    let migrator = setup_migrator();
+
+    let candidates = migrator
+        .candidate_entities(r#"{"version":"1.0.0","data":{"text":"hi"}}"#)
+        .unwrap();
+    assert_eq!(candidates, vec!["note", "task"]);
+}
+
+#[test]
+fn test_unique_version_matches_one_entity() {
+    let migrator = setup_migrator();
+
+    let candidates = migrator
+        .candidate_entities(r#"{"version":"3.1.0","data":{"name":"ann"}}"#)
+        .unwrap();
+    assert_eq!(candidates, vec!["user"]);
+
+    let candidates = migrator
+        .candidate_entities(r#"{"version":"2.0.0","title":"flat","done":true}"#)
+        .unwrap();
+    assert_eq!(candidates, vec!["task"]);
+}
+
+#[test]
+fn test_unknown_or_missing_version_matches_nothing() {
+    let migrator = setup_migrator();
+
+    assert!(migrator
+        .candidate_entities(r#"{"version":"9.0.0","data":{}}"#)
+        .unwrap()
+        .is_empty());
+    assert!(migrator
+        .candidate_entities(r#"{"data":{}}"#)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_invalid_json_is_an_error() {
+    let migrator = setup_migrator();
+
+    assert!(matches!(
+        migrator.candidate_entities("{not json"),
+        Err(MigrationError::DeserializationError(_))
+    ));
+}
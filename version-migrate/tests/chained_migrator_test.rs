@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+// ===== Stage 1: ingestion (RawV1 -> RawV2 -> RawV3 -> Record) =====
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RawV1 {
+    name: String,
+}
+
+impl Versioned for RawV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RawV2 {
+    name: String,
+    source: String,
+}
+
+impl Versioned for RawV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RawV3 {
+    name: String,
+    source: String,
+    tags: Vec<String>,
+}
+
+impl Versioned for RawV3 {
+    const VERSION: &'static str = "3.0.0";
+}
+
+/// Ingestion domain type, and the first stored version.
+#[derive(Serialize, Deserialize, Debug)]
+struct RecordV1 {
+    name: String,
+    source: String,
+    tags: Vec<String>,
+}
+
+impl Versioned for RecordV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+impl MigratesTo<RawV2> for RawV1 {
+    fn migrate(self) -> RawV2 {
+        RawV2 {
+            name: self.name,
+            source: "legacy".to_string(),
+        }
+    }
+}
+
+impl MigratesTo<RawV3> for RawV2 {
+    fn migrate(self) -> RawV3 {
+        RawV3 {
+            name: self.name,
+            source: self.source,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<RecordV1> for RawV3 {
+    fn into_domain(self) -> RecordV1 {
+        RecordV1 {
+            name: self.name,
+            source: self.source,
+            tags: self.tags,
+        }
+    }
+}
+
+// ===== Stage 2: storage (RecordV1 -> RecordV2 -> StoredRecord) =====
+
+#[derive(Serialize, Deserialize, Debug)]
+struct RecordV2 {
+    name: String,
+    source: String,
+    tags: Vec<String>,
+    archived: bool,
+}
+
+impl Versioned for RecordV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct StoredRecord {
+    name: String,
+    source: String,
+    tags: Vec<String>,
+    archived: bool,
+}
+
+impl MigratesTo<RecordV2> for RecordV1 {
+    fn migrate(self) -> RecordV2 {
+        RecordV2 {
+            name: self.name,
+            source: self.source,
+            tags: self.tags,
+            archived: false,
+        }
+    }
+}
+
+impl IntoDomain<StoredRecord> for RecordV2 {
+    fn into_domain(self) -> StoredRecord {
+        StoredRecord {
+            name: self.name,
+            source: self.source,
+            tags: self.tags,
+            archived: self.archived,
+        }
+    }
+}
+
+fn ingest_migrator() -> Migrator {
+    let path = Migrator::define("record")
+        .from::<RawV1>()
+        .step::<RawV2>()
+        .step::<RawV3>()
+        .into::<RecordV1>();
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn storage_migrator() -> Migrator {
+    let path = Migrator::define("record")
+        .from::<RecordV1>()
+        .step::<RecordV2>()
+        .into::<StoredRecord>();
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_chained_migrator_runs_both_stages() {
+    let pipeline = Migrator::chain_migrators(ingest_migrator(), storage_migrator());
+
+    let json = r#"{"version":"1.0.0","data":{"name":"alpha"}}"#;
+    let record: StoredRecord = pipeline.load("record", json).unwrap();
+
+    assert_eq!(
+        record,
+        StoredRecord {
+            name: "alpha".to_string(),
+            source: "legacy".to_string(),
+            tags: Vec::new(),
+            archived: false,
+        }
+    );
+}
+
+#[test]
+fn test_chained_migrator_unknown_entity_in_second_stage() {
+    let pipeline = Migrator::chain_migrators(ingest_migrator(), Migrator::new());
+
+    let json = r#"{"version":"3.0.0","data":{"name":"beta","source":"api","tags":["x"]}}"#;
+    let result: Result<StoredRecord, _> = pipeline.load("record", json);
+
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
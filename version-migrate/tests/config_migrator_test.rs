@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use version_migrate::{
-    ConfigMigrator, DeriveQueryable as Queryable, IntoDomain, MigratesTo, Migrator, Versioned,
+    ConfigMigrator, DeriveQueryable as Queryable, IntoDomain, MigratesTo, MigrationError, Migrator,
+    Versioned,
 };
 
 // Task V1
@@ -252,6 +253,79 @@ fn test_config_migrator_as_value() {
     assert!(value["tasks"].is_array());
 }
 
+#[test]
+fn test_config_migrator_clone_value_and_into_value() {
+    let migrator = setup_migrator();
+
+    let config_json =
+        r#"{"app_name":"MyApp","tasks":[{"version":"1.0.0","id":"t1","title":"Task"}]}"#;
+    let config = ConfigMigrator::from(config_json, migrator).unwrap();
+
+    let expected: serde_json::Value =
+        serde_json::from_str(&config.to_string_compact().unwrap()).unwrap();
+
+    assert_eq!(config.clone_value(), expected);
+    assert_eq!(config.into_value(), expected);
+}
+
+#[test]
+fn test_config_migrator_as_value_mut_persists() {
+    let migrator = setup_migrator();
+
+    let config_json =
+        r#"{"app_name":"MyApp","tasks":[{"version":"1.0.0","id":"t1","title":"Task"}]}"#;
+    let mut config = ConfigMigrator::from(config_json, migrator).unwrap();
+
+    config.as_value_mut()["app_name"] = serde_json::json!("Renamed");
+
+    let output: serde_json::Value = serde_json::from_str(&config.to_string().unwrap()).unwrap();
+    assert_eq!(output["app_name"], "Renamed");
+    assert_eq!(output["tasks"][0]["id"], "t1");
+}
+
+#[test]
+fn test_config_migrator_to_toml_roundtrip() {
+    let migrator = setup_migrator();
+
+    let config_json = r#"{"app_name":"MyApp","theme":null,"tasks":[{"version":"1.0.0","id":"t1","title":"Task"}]}"#;
+    let config = ConfigMigrator::from(config_json, migrator).unwrap();
+
+    let toml_str = config.to_toml().unwrap();
+    let parsed: toml::Value = toml::from_str(&toml_str).unwrap();
+
+    assert_eq!(parsed["app_name"].as_str(), Some("MyApp"));
+    assert!(parsed.get("theme").is_none());
+    assert_eq!(parsed["tasks"][0]["version"].as_str(), Some("1.0.0"));
+    assert_eq!(parsed["tasks"][0]["id"].as_str(), Some("t1"));
+}
+
+#[test]
+fn test_config_migrator_to_toml_rejects_null_array_element() {
+    let migrator = setup_migrator();
+
+    let config = ConfigMigrator::from(r#"{"tags":["a",null]}"#, migrator).unwrap();
+
+    assert!(matches!(
+        config.to_toml(),
+        Err(version_migrate::MigrationError::TomlSerializeError(_))
+    ));
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_config_migrator_to_yaml_roundtrip() {
+    let migrator = setup_migrator();
+
+    let config_json =
+        r#"{"app_name":"MyApp","tasks":[{"version":"1.0.0","id":"t1","title":"Task"}]}"#;
+    let config = ConfigMigrator::from(config_json, migrator).unwrap();
+
+    let yaml_str = config.to_yaml().unwrap();
+    let parsed: serde_json::Value = serde_yaml::from_str(&yaml_str).unwrap();
+
+    assert_eq!(&parsed, config.as_value());
+}
+
 #[test]
 fn test_config_migrator_query_non_array_error() {
     let migrator = setup_migrator();
@@ -278,3 +352,209 @@ fn test_config_migrator_invalid_json() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_migrator_into_empty_config() {
+    let mut config = setup_migrator().into_empty_config();
+
+    let tasks: Vec<TaskEntity> = config.query("tasks").unwrap();
+    assert!(tasks.is_empty());
+    assert_eq!(config.as_value(), &serde_json::json!({}));
+
+    config
+        .update(
+            "tasks",
+            vec![TaskEntity {
+                id: "1".to_string(),
+                title: "Task 1".to_string(),
+                description: None,
+            }],
+        )
+        .unwrap();
+
+    let tasks: Vec<TaskEntity> = config.query("tasks").unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(config.as_value()["tasks"][0]["version"], "2.0.0");
+}
+
+#[test]
+fn test_migrator_into_config_from_str() {
+    let config_json = r#"{
+        "app_name": "MyApp",
+        "tasks": [
+            {"version": "1.0.0", "id": "1", "title": "Task 1"}
+        ]
+    }"#;
+
+    let mut config = setup_migrator().into_config_from_str(config_json).unwrap();
+    let mut tasks: Vec<TaskEntity> = config.query("tasks").unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].description, None);
+
+    tasks[0].title = "Updated".to_string();
+    config.update("tasks", tasks).unwrap();
+
+    let expected = ConfigMigrator::from(config_json, setup_migrator()).unwrap();
+    assert_eq!(
+        config.as_value()["app_name"],
+        expected.as_value()["app_name"]
+    );
+    assert_eq!(config.as_value()["tasks"][0]["title"], "Updated");
+    assert_eq!(config.as_value()["tasks"][0]["version"], "2.0.0");
+}
+
+#[test]
+fn test_migrator_into_config_from_str_invalid_json() {
+    let result = setup_migrator().into_config_from_str(r#"{"app_name": invalid}"#);
+    assert!(matches!(
+        result,
+        Err(version_migrate::MigrationError::DeserializationError(_))
+    ));
+}
+
+const TOMBSTONED_CONFIG: &str = r#"{
+    "tasks": [
+        {"version": "1.0.0", "id": "1", "title": "Task 1"},
+        {"version": "deleted", "id": "2"},
+        {"version": "2.0.0", "id": "3", "title": "Task 3", "description": "Kept"},
+        {"version": "deleted", "id": "4"}
+    ]
+}"#;
+
+#[test]
+fn test_config_migrator_query_live_skips_tombstones() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_tombstone_version("task", "deleted")
+        .unwrap();
+
+    let config = ConfigMigrator::from(TOMBSTONED_CONFIG, migrator).unwrap();
+    let tasks: Vec<TaskEntity> = config.query_live("tasks").unwrap();
+
+    let ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "3"]);
+    assert_eq!(tasks[1].description, Some("Kept".to_string()));
+
+    // The underlying document is left untouched
+    assert_eq!(config.as_value()["tasks"].as_array().unwrap().len(), 4);
+}
+
+#[test]
+fn test_config_migrator_query_includes_tombstones() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_tombstone_version("task", "deleted")
+        .unwrap();
+
+    let config = ConfigMigrator::from(TOMBSTONED_CONFIG, migrator).unwrap();
+    assert!(config.query::<TaskEntity>("tasks").is_err());
+}
+
+#[test]
+fn test_config_migrator_query_live_without_tombstone() {
+    let config = ConfigMigrator::from(TOMBSTONED_CONFIG, setup_migrator()).unwrap();
+    assert!(config.query_live::<TaskEntity>("tasks").is_err());
+
+    let config_json = r#"{"tasks": [{"version": "1.0.0", "id": "1", "title": "Task 1"}]}"#;
+    let config = ConfigMigrator::from(config_json, setup_migrator()).unwrap();
+    let tasks: Vec<TaskEntity> = config.query_live("tasks").unwrap();
+    assert_eq!(tasks.len(), 1);
+}
+
+#[test]
+fn test_register_tombstone_version_unknown_entity() {
+    let mut migrator = setup_migrator();
+    assert!(migrator
+        .register_tombstone_version("note", "deleted")
+        .is_err());
+}
+
+#[test]
+fn test_config_migrator_diff() {
+    let before = ConfigMigrator::from(
+        r#"{"app_name": "demo", "theme": "dark", "tags": ["a", "b"], "limit": 3}"#,
+        setup_migrator(),
+    )
+    .unwrap();
+    let after = ConfigMigrator::from(
+        r#"{"app_name": "demo", "tags": ["a", "c"], "limit": 3, "locale": "en"}"#,
+        setup_migrator(),
+    )
+    .unwrap();
+
+    let diff = before.diff(&after);
+    assert!(!diff.is_empty());
+    assert_eq!(
+        diff.added.into_iter().collect::<Vec<_>>(),
+        vec![("locale".to_string(), serde_json::json!("en"))]
+    );
+    assert_eq!(
+        diff.removed.into_iter().collect::<Vec<_>>(),
+        vec![("theme".to_string(), serde_json::json!("dark"))]
+    );
+    assert_eq!(
+        diff.changed.into_iter().collect::<Vec<_>>(),
+        vec![(
+            "tags".to_string(),
+            (serde_json::json!(["a", "b"]), serde_json::json!(["a", "c"]))
+        )]
+    );
+}
+
+#[test]
+fn test_config_migrator_diff_after_update() {
+    let config_json =
+        r#"{"tasks": [{"version": "2.0.0", "id": "1", "title": "Task 1", "description": null}]}"#;
+    let before = ConfigMigrator::from(config_json, setup_migrator()).unwrap();
+    let mut after = ConfigMigrator::from(config_json, setup_migrator()).unwrap();
+    assert!(before.diff(&after).is_empty());
+
+    let mut tasks: Vec<TaskEntity> = after.query("tasks").unwrap();
+    tasks[0].title = "Renamed".to_string();
+    after.update("tasks", tasks).unwrap();
+
+    let diff = before.diff(&after);
+    let (old, new) = &diff.changed["tasks"];
+    assert_eq!(old[0]["title"], "Task 1");
+    assert_eq!(new[0]["title"], "Renamed");
+    assert!(diff.added.is_empty() && diff.removed.is_empty());
+}
+
+#[test]
+fn test_config_migrator_rename_key() {
+    let config_json =
+        r#"{"todos": [{"version": "1.0.0", "id": "1", "title": "Task 1"}], "theme": "dark"}"#;
+    let mut config = ConfigMigrator::from(config_json, setup_migrator()).unwrap();
+
+    assert!(config.rename_key("todos", "tasks").unwrap());
+    assert!(config.as_value().get("todos").is_none());
+    assert_eq!(config.as_value()["theme"], "dark");
+
+    // The moved section is untouched and still migrates on query
+    let tasks: Vec<TaskEntity> = config.query("tasks").unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].title, "Task 1");
+}
+
+#[test]
+fn test_config_migrator_rename_missing_key() {
+    let mut config = ConfigMigrator::from(r#"{"theme": "dark"}"#, setup_migrator()).unwrap();
+
+    assert!(!config.rename_key("todos", "tasks").unwrap());
+    assert_eq!(config.as_value(), &serde_json::json!({"theme": "dark"}));
+}
+
+#[test]
+fn test_config_migrator_rename_onto_occupied_key() {
+    let config_json =
+        r#"{"todos": [], "tasks": [{"version": "1.0.0", "id": "1", "title": "Task 1"}]}"#;
+    let mut config = ConfigMigrator::from(config_json, setup_migrator()).unwrap();
+
+    let result = config.rename_key("todos", "tasks");
+    assert!(matches!(
+        result,
+        Err(MigrationError::KeyAlreadyExists(key)) if key == "tasks"
+    ));
+    assert_eq!(config.as_value()["todos"], serde_json::json!([]));
+    assert_eq!(config.as_value()["tasks"][0]["id"], "1");
+}
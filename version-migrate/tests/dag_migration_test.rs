@@ -0,0 +1,321 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{Forwardable, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+// Diamond: 1.0.0 branches into 1.1.0 and 2.0.0, both converge at 3.0.0.
+// Each step appends the version it migrated from to `via`.
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct NoteV1_0_0 {
+    title: String,
+    #[serde(default)]
+    via: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.1.0")]
+struct NoteV1_1_0 {
+    title: String,
+    via: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct NoteV2_0_0 {
+    heading: String,
+    via: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.5.0", frozen = true)]
+struct NoteV2_5_0 {
+    heading: String,
+    via: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "3.0.0")]
+struct NoteV3_0_0 {
+    heading: String,
+    via: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Note {
+    heading: String,
+    via: Vec<String>,
+}
+
+fn push(mut via: Vec<String>, version: &str) -> Vec<String> {
+    via.push(version.to_string());
+    via
+}
+
+impl MigratesTo<NoteV1_1_0> for NoteV1_0_0 {
+    fn migrate(self) -> NoteV1_1_0 {
+        NoteV1_1_0 {
+            title: self.title,
+            via: push(self.via, "1.0.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV2_0_0> for NoteV1_0_0 {
+    fn migrate(self) -> NoteV2_0_0 {
+        NoteV2_0_0 {
+            heading: self.title,
+            via: push(self.via, "1.0.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV3_0_0> for NoteV1_0_0 {
+    fn migrate(self) -> NoteV3_0_0 {
+        NoteV3_0_0 {
+            heading: self.title,
+            via: push(self.via, "1.0.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV3_0_0> for NoteV1_1_0 {
+    fn migrate(self) -> NoteV3_0_0 {
+        NoteV3_0_0 {
+            heading: self.title,
+            via: push(self.via, "1.1.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV3_0_0> for NoteV2_0_0 {
+    fn migrate(self) -> NoteV3_0_0 {
+        NoteV3_0_0 {
+            heading: self.heading,
+            via: push(self.via, "2.0.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV3_0_0> for NoteV2_5_0 {
+    fn migrate(self) -> NoteV3_0_0 {
+        NoteV3_0_0 {
+            heading: self.heading,
+            via: push(self.via, "2.5.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV1_1_0> for NoteV2_0_0 {
+    fn migrate(self) -> NoteV1_1_0 {
+        NoteV1_1_0 {
+            title: self.heading,
+            via: push(self.via, "2.0.0"),
+        }
+    }
+}
+
+impl MigratesTo<NoteV1_0_0> for NoteV3_0_0 {
+    fn migrate(self) -> NoteV1_0_0 {
+        NoteV1_0_0 {
+            title: self.heading,
+            via: push(self.via, "3.0.0"),
+        }
+    }
+}
+
+impl IntoDomain<Note> for NoteV3_0_0 {
+    fn into_domain(self) -> Note {
+        Note {
+            heading: self.heading,
+            via: self.via,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("note")
+                .from::<NoteV1_0_0>()
+                .step::<NoteV1_1_0>()
+                .step::<NoteV3_0_0>()
+                .into::<Note>(),
+        )
+        .unwrap();
+    migrator
+        .register_branch::<NoteV1_0_0, NoteV2_0_0>("note")
+        .unwrap();
+    migrator
+        .register_branch::<NoteV2_0_0, NoteV3_0_0>("note")
+        .unwrap();
+    migrator
+}
+
+fn note(heading: &str, via: &[&str]) -> Note {
+    Note {
+        heading: heading.to_string(),
+        via: via.iter().map(|v| v.to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_diamond_loads_from_every_entry_point() {
+    let migrator = setup_migrator();
+
+    let from_root: Note = migrator
+        .load("note", r#"{"version":"1.0.0","data":{"title":"a"}}"#)
+        .unwrap();
+    assert_eq!(from_root, note("a", &["1.0.0", "1.1.0"]));
+
+    let from_left: Note = migrator
+        .load(
+            "note",
+            r#"{"version":"1.1.0","data":{"title":"b","via":[]}}"#,
+        )
+        .unwrap();
+    assert_eq!(from_left, note("b", &["1.1.0"]));
+
+    let from_right: Note = migrator
+        .load(
+            "note",
+            r#"{"version":"2.0.0","data":{"heading":"c","via":[]}}"#,
+        )
+        .unwrap();
+    assert_eq!(from_right, note("c", &["2.0.0"]));
+
+    let latest: Note = migrator
+        .load(
+            "note",
+            r#"{"version":"3.0.0","data":{"heading":"d","via":[]}}"#,
+        )
+        .unwrap();
+    assert_eq!(latest, note("d", &[]));
+}
+
+#[test]
+fn test_branch_versions_are_loadable() {
+    let migrator = setup_migrator();
+
+    assert!(migrator
+        .can_load("note", r#"{"version":"2.0.0","data":{}}"#)
+        .unwrap());
+    assert!(!migrator
+        .can_load("note", r#"{"version":"2.5.0","data":{}}"#)
+        .unwrap());
+}
+
+#[test]
+fn test_shortest_route_is_taken() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_branch::<NoteV1_0_0, NoteV3_0_0>("note")
+        .unwrap();
+
+    let loaded: Note = migrator
+        .load("note", r#"{"version":"1.0.0","data":{"title":"a"}}"#)
+        .unwrap();
+    assert_eq!(loaded, note("a", &["1.0.0"]));
+}
+
+#[test]
+fn test_branch_closing_a_cycle_is_rejected() {
+    let mut migrator = setup_migrator();
+
+    let err = migrator
+        .register_branch::<NoteV3_0_0, NoteV1_0_0>("note")
+        .unwrap_err();
+    match err {
+        MigrationError::CircularMigrationPath { entity, path } => {
+            assert_eq!(entity, "note");
+            assert_eq!(path, "3.0.0 -> 1.0.0 -> 1.1.0 -> 3.0.0");
+        }
+        other => panic!("expected CircularMigrationPath, got {other:?}"),
+    }
+
+    // The rejected branch is not registered
+    let loaded: Note = migrator
+        .load(
+            "note",
+            r#"{"version":"3.0.0","data":{"heading":"d","via":[]}}"#,
+        )
+        .unwrap();
+    assert_eq!(loaded, note("d", &[]));
+}
+
+#[test]
+fn test_branch_on_unknown_entity_fails() {
+    let mut migrator = Migrator::new();
+
+    let result = migrator.register_branch::<NoteV1_0_0, NoteV2_0_0>("note");
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
+
+#[test]
+fn test_branch_out_of_frozen_version_is_rejected() {
+    let mut migrator = setup_migrator();
+
+    let result = migrator.register_branch::<NoteV2_5_0, NoteV3_0_0>("note");
+    assert!(matches!(
+        result,
+        Err(MigrationError::FrozenVersion { ref version, .. }) if version == "2.5.0"
+    ));
+}
+
+#[test]
+fn test_branch_to_older_version_is_rejected() {
+    let mut migrator = setup_migrator();
+
+    let result = migrator.register_branch::<NoteV2_0_0, NoteV1_1_0>("note");
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidVersionOrder { .. })
+    ));
+}
+
+#[test]
+fn test_load_forward_migrates_branch_version() {
+    let migrator = setup_migrator();
+
+    let loaded: Forwardable<Note> = migrator
+        .load_forward(
+            "note",
+            r#"{"version":"2.0.0","data":{"heading":"c","via":[],"extra":1}}"#,
+        )
+        .unwrap();
+    assert!(!loaded.was_lossy());
+    assert_eq!(loaded.original_version(), "2.0.0");
+    assert_eq!(loaded.into_inner(), note("c", &["2.0.0"]));
+}
+
+#[test]
+fn test_branch_versions_pass_version_checks() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"2.0.0","data":{"heading":"c","via":[]}}"#;
+
+    let strict: Note = migrator
+        .load_with_strict_version_check("note", json)
+        .unwrap();
+    assert_eq!(strict, note("c", &["2.0.0"]));
+
+    let upgraded: NoteV2_0_0 = migrator
+        .load_upgrading_to_version(
+            "note",
+            r#"{"version":"1.0.0","data":{"title":"a"}}"#,
+            "2.0.0",
+        )
+        .unwrap();
+    assert_eq!(upgraded.heading, "a");
+    assert_eq!(upgraded.via, vec!["1.0.0".to_string()]);
+
+    // 1.1.0 has no route to the 2.0.0 branch
+    let result: Result<NoteV2_0_0, _> = migrator.load_upgrading_to_version(
+        "note",
+        r#"{"version":"1.1.0","data":{"title":"b","via":[]}}"#,
+        "2.0.0",
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidVersionOrder { .. })
+    ));
+}
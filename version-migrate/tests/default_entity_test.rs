@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{FromDomain, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: String::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+impl FromDomain<TaskEntity> for TaskV2 {
+    fn from_domain(entity: TaskEntity) -> Self {
+        TaskV2 {
+            id: entity.id,
+            title: entity.title,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into_with_save::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn task() -> TaskEntity {
+    TaskEntity {
+        id: "task-1".to_string(),
+        title: "Write docs".to_string(),
+    }
+}
+
+#[test]
+fn test_round_trip_through_default_entity() {
+    let mut migrator = setup_migrator();
+    migrator.set_default_entity("task").unwrap();
+
+    let json = migrator.save_default(task()).unwrap();
+    assert_eq!(json, migrator.save_domain("task", task()).unwrap());
+
+    let loaded: TaskEntity = migrator.load_default(&json).unwrap();
+    assert_eq!(loaded, task());
+
+    let old: TaskEntity = migrator
+        .load_default(r#"{"version":"1.0.0","data":{"id":"task-0"}}"#)
+        .unwrap();
+    assert_eq!(old.id, "task-0");
+}
+
+#[test]
+fn test_no_default_entity_configured() {
+    let migrator = setup_migrator();
+
+    let result: Result<TaskEntity, _> =
+        migrator.load_default(r#"{"version":"2.0.0","data":{"id":"1","title":"t"}}"#);
+    assert!(matches!(result, Err(MigrationError::NoDefaultEntity)));
+    assert!(matches!(
+        migrator.save_default(task()),
+        Err(MigrationError::NoDefaultEntity)
+    ));
+}
+
+#[test]
+fn test_unknown_default_entity_is_rejected() {
+    let mut migrator = setup_migrator();
+    let result = migrator.set_default_entity("user");
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(name)) if name == "user"));
+}
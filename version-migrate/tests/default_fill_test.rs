@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    title: String,
+    priority: u8,
+    labels: Vec<String>,
+}
+
+impl Default for TaskV1 {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            priority: 3,
+            labels: vec!["inbox".to_string()],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Versioned, Default)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    title: String,
+    priority: u8,
+    labels: Vec<String>,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Task {
+    title: String,
+    priority: u8,
+    labels: Vec<String>,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            title: self.title,
+            priority: self.priority,
+            labels: self.labels,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<Task> for TaskV2 {
+    fn into_domain(self) -> Task {
+        Task {
+            title: self.title,
+            priority: self.priority,
+            labels: self.labels,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<Task>(),
+        )
+        .unwrap();
+    migrator
+}
+
+const INCOMPLETE_V1: &str = r#"{"version":"1.0.0","data":{"title":"Write docs"}}"#;
+
+#[test]
+fn test_incomplete_data_fails_without_default_fill() {
+    let migrator = setup_migrator();
+
+    let result: Result<Task, _> = migrator.load("task", INCOMPLETE_V1);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("priority")
+    ));
+}
+
+#[test]
+fn test_default_fill_completes_incomplete_v1() {
+    let mut migrator = setup_migrator();
+    migrator.register_default_fill::<TaskV1>("task").unwrap();
+
+    let task: Task = migrator.load("task", INCOMPLETE_V1).unwrap();
+    assert_eq!(
+        task,
+        Task {
+            title: "Write docs".to_string(),
+            priority: 3,
+            labels: vec!["inbox".to_string()],
+            done: false,
+        }
+    );
+}
+
+#[test]
+fn test_stored_fields_win_over_defaults() {
+    let mut migrator = setup_migrator();
+    migrator.register_default_fill::<TaskV1>("task").unwrap();
+
+    let json = r#"{"version":"1.0.0","data":{"title":"Ship","priority":1,"labels":[]}}"#;
+    let task: Task = migrator.load("task", json).unwrap();
+    assert_eq!(task.priority, 1);
+    assert!(task.labels.is_empty());
+}
+
+#[test]
+fn test_default_fill_applies_to_latest_version() {
+    let mut migrator = setup_migrator();
+    migrator.register_default_fill::<TaskV2>("task").unwrap();
+
+    let json = r#"{"version":"2.0.0","data":{"title":"Ship","priority":1,"labels":[]}}"#;
+    let task: Task = migrator.load("task", json).unwrap();
+    assert!(!task.done);
+}
+
+#[test]
+fn test_default_fill_requires_registered_entity_and_version() {
+    #[derive(Serialize, Default, Versioned)]
+    #[versioned(version = "9.0.0")]
+    struct TaskV9 {
+        title: String,
+    }
+
+    let mut migrator = setup_migrator();
+
+    assert!(matches!(
+        migrator.register_default_fill::<TaskV1>("user"),
+        Err(MigrationError::EntityNotFound(_))
+    ));
+    assert!(matches!(
+        migrator.register_default_fill::<TaskV9>("task"),
+        Err(MigrationError::MigrationPathNotDefined { .. })
+    ));
+}
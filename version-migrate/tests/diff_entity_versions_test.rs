@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    IntoDomain, MigratesTo, MigrationError, Migrator, VersionExtractor, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+    priority: u8,
+    legacy_flag: bool,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    priority: String,
+    tags: Vec<String>,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    tags: Vec<String>,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            priority: self.priority.to_string(),
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            tags: self.tags,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn v1() -> TaskV1 {
+    TaskV1 {
+        id: "task-1".to_string(),
+        title: "Write docs".to_string(),
+        priority: 2,
+        legacy_flag: true,
+    }
+}
+
+fn v2() -> TaskV2 {
+    TaskV2 {
+        id: "task-1".to_string(),
+        title: "Write docs".to_string(),
+        priority: "high".to_string(),
+        tags: vec!["docs".to_string()],
+    }
+}
+
+#[test]
+fn test_diff_between_versions() {
+    let migrator = setup_migrator();
+    let v1_json = migrator.save(v1()).unwrap();
+    let v2_json = migrator.save(v2()).unwrap();
+
+    let diff = migrator
+        .diff_entity_versions::<TaskV1, TaskV2>("task", &v1_json, &v2_json)
+        .unwrap();
+
+    assert_eq!(diff.added_fields, vec!["tags"]);
+    assert_eq!(diff.removed_fields, vec!["legacy_flag"]);
+    assert_eq!(
+        diff.changed_fields,
+        vec![(
+            "priority".to_string(),
+            serde_json::json!(2),
+            serde_json::json!("high")
+        )]
+    );
+    assert_eq!(diff.unchanged_fields, vec!["id", "title"]);
+}
+
+#[test]
+fn test_diff_reads_versions_like_the_loaders() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_version_extractor(
+            "task",
+            VersionExtractor::JsonPointer {
+                version: "/meta/schema".to_string(),
+                data: "/payload".to_string(),
+            },
+        )
+        .unwrap();
+    migrator
+        .register_version_alias("task", "1", "1.0.0")
+        .unwrap();
+    let v1_json = serde_json::json!({"meta": {"schema": 1}, "payload": v1()}).to_string();
+    let v2_json = serde_json::json!({"meta": {"schema": "2.0.0"}, "payload": v2()}).to_string();
+
+    let diff = migrator
+        .diff_entity_versions::<TaskV1, TaskV2>("task", &v1_json, &v2_json)
+        .unwrap();
+
+    assert_eq!(diff.added_fields, vec!["tags"]);
+    assert_eq!(diff.removed_fields, vec!["legacy_flag"]);
+}
+
+#[test]
+fn test_json_at_wrong_version_is_rejected() {
+    let migrator = setup_migrator();
+    let v1_json = migrator.save(v1()).unwrap();
+
+    let result = migrator.diff_entity_versions::<TaskV1, TaskV2>("task", &v1_json, &v1_json);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("'2.0.0'")
+    ));
+}
+
+#[test]
+fn test_unknown_entity() {
+    let migrator = setup_migrator();
+    let v1_json = migrator.save(v1()).unwrap();
+    let v2_json = migrator.save(v2()).unwrap();
+
+    let result = migrator.diff_entity_versions::<TaskV1, TaskV2>("user", &v1_json, &v2_json);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -235,3 +235,99 @@ fn test_save_domain_flat_roundtrip() {
     assert_eq!(loaded.title, entity.title);
     assert_eq!(loaded.description, entity.description);
 }
+
+#[test]
+fn test_save_domain_to_value_embeds_in_document() {
+    let path = Migrator::define("task")
+        .from::<TaskV1_0_0>()
+        .step::<TaskV1_1_0>()
+        .into_with_save::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let entity = TaskEntity {
+        id: "task-1".to_string(),
+        title: "My Task".to_string(),
+        description: None,
+    };
+
+    let response = serde_json::json!({
+        "status": "ok",
+        "task": migrator.save_domain_to_value("task", entity.clone()).unwrap(),
+    });
+
+    assert_eq!(
+        response,
+        serde_json::json!({
+            "status": "ok",
+            "task": {
+                "version": "1.1.0",
+                "data": {"id": "task-1", "title": "My Task", "description": null}
+            }
+        })
+    );
+
+    // The embedded value matches what save_domain produces
+    let json = migrator.save_domain("task", entity).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(response["task"], parsed);
+}
+
+#[test]
+fn test_save_domain_flat_to_value_embeds_in_document() {
+    let path = Migrator::define("task")
+        .from::<TaskV1_0_0>()
+        .step::<TaskV1_1_0>()
+        .into_with_save::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let items = ["a", "b"]
+        .into_iter()
+        .map(|id| {
+            migrator.save_domain_flat_to_value(
+                "task",
+                TaskEntity {
+                    id: id.to_string(),
+                    title: id.to_uppercase(),
+                    description: None,
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let document = serde_json::json!({ "items": items });
+
+    assert_eq!(
+        document,
+        serde_json::json!({
+            "items": [
+                {"version": "1.1.0", "id": "a", "title": "A", "description": null},
+                {"version": "1.1.0", "id": "b", "title": "B", "description": null}
+            ]
+        })
+    );
+
+    // Each element loads back through the flat loader
+    let loaded: TaskEntity = migrator
+        .load_flat_from("task", document["items"][1].clone())
+        .unwrap();
+    assert_eq!(loaded.id, "b");
+}
+
+#[test]
+fn test_save_domain_to_value_unregistered_entity() {
+    let migrator = Migrator::new();
+    let entity = TaskEntity {
+        id: "task-1".to_string(),
+        title: "My Task".to_string(),
+        description: None,
+    };
+
+    assert!(migrator
+        .save_domain_to_value("task", entity.clone())
+        .is_err());
+    assert!(migrator.save_domain_flat_to_value("task", entity).is_err());
+}
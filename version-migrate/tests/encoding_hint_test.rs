@@ -0,0 +1,172 @@
+#![cfg(feature = "compression")]
+
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    EncodingHint, IntoDomain, LoadLimits, MigratesTo, MigrationError, Migrator, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    notes: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    notes: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            notes: String::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            notes: self.notes,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn sample() -> TaskV2 {
+    TaskV2 {
+        id: "task-1".to_string(),
+        notes: "repeat ".repeat(200),
+    }
+}
+
+#[test]
+fn test_gzip_roundtrip() {
+    let migrator = setup_migrator();
+    let bytes = migrator
+        .save_with_encoding_hint(sample(), EncodingHint::GzipJson)
+        .unwrap();
+
+    let raw = String::from_utf8(bytes.clone()).unwrap();
+    assert!(raw.contains(r#""_encoding":"gzip""#));
+    assert!(raw.contains(r#""version":"2.0.0""#));
+    assert!(!raw.contains("repeat"));
+
+    let task: TaskEntity = migrator.load_detecting_encoding("task", &bytes).unwrap();
+    assert_eq!(task.id, "task-1");
+    assert_eq!(task.notes, sample().notes);
+}
+
+#[test]
+fn test_zstd_roundtrip() {
+    let migrator = setup_migrator();
+    let bytes = migrator
+        .save_with_encoding_hint(sample(), EncodingHint::ZstdJson)
+        .unwrap();
+
+    let raw = String::from_utf8(bytes.clone()).unwrap();
+    assert!(raw.contains(r#""_encoding":"zstd""#));
+
+    let task: TaskEntity = migrator.load_detecting_encoding("task", &bytes).unwrap();
+    assert_eq!(task.notes, sample().notes);
+}
+
+#[test]
+fn test_plain_json_roundtrip() {
+    let migrator = setup_migrator();
+    let bytes = migrator
+        .save_with_encoding_hint(sample(), EncodingHint::PlainJson)
+        .unwrap();
+
+    let raw = String::from_utf8(bytes.clone()).unwrap();
+    assert!(raw.contains(r#""_encoding":"json""#));
+    assert!(raw.contains("repeat"));
+
+    let task: TaskEntity = migrator.load_detecting_encoding("task", &bytes).unwrap();
+    assert_eq!(task.id, "task-1");
+}
+
+#[test]
+fn test_compressed_old_version_is_migrated() {
+    let migrator = setup_migrator();
+    let bytes = migrator
+        .save_with_encoding_hint(
+            TaskV1 {
+                id: "old".to_string(),
+            },
+            EncodingHint::GzipJson,
+        )
+        .unwrap();
+
+    let task: TaskEntity = migrator.load_detecting_encoding("task", &bytes).unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "old".to_string(),
+            notes: String::new(),
+        }
+    );
+}
+
+#[test]
+fn test_missing_marker_is_plain_json() {
+    let migrator = setup_migrator();
+    let bytes = br#"{"version":"1.0.0","data":{"id":"legacy"}}"#;
+
+    let task: TaskEntity = migrator.load_detecting_encoding("task", bytes).unwrap();
+    assert_eq!(task.id, "legacy");
+}
+
+#[test]
+fn test_unknown_encoding_is_rejected() {
+    let migrator = setup_migrator();
+    let bytes = br#"{"_encoding":"brotli","version":"1.0.0","data":"AAAA"}"#;
+
+    let result: Result<TaskEntity, _> = migrator.load_detecting_encoding("task", bytes);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("brotli")
+    ));
+}
+
+#[test]
+fn test_decompressed_payload_respects_limits() {
+    let bytes = setup_migrator()
+        .save_with_encoding_hint(sample(), EncodingHint::GzipJson)
+        .unwrap();
+    let migrator = setup_migrator().with_limits(LoadLimits {
+        max_bytes: 512,
+        max_depth: 16,
+    });
+
+    // The compressed wrapper fits, but the inflated payload does not.
+    assert!(bytes.len() < 512);
+    let result: Result<TaskEntity, _> = migrator.load_detecting_encoding("task", &bytes);
+    assert!(matches!(result, Err(MigrationError::InputTooLarge { .. })));
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator.register_entity_alias("todo", "task").unwrap();
+    migrator
+}
+
+const JSON: &str = r#"{"version":"1.0.0","data":{"id":"task-1"}}"#;
+
+#[test]
+fn test_load_routes_alias_to_same_path() {
+    let migrator = setup_migrator();
+
+    let by_alias: TaskEntity = migrator.load("todo", JSON).unwrap();
+    let by_name: TaskEntity = migrator.load("task", JSON).unwrap();
+    assert_eq!(by_alias, by_name);
+    assert_eq!(
+        by_alias,
+        TaskEntity {
+            id: "task-1".to_string(),
+            done: false,
+        }
+    );
+    assert_eq!(migrator.get_latest_version("todo"), Some("2.0.0"));
+}
+
+#[test]
+fn test_load_with_entity_rename() {
+    let migrator = setup_migrator();
+
+    let task: TaskEntity = migrator.load_with_entity_rename("todo", JSON).unwrap();
+    assert_eq!(task.id, "task-1");
+
+    let task: TaskEntity = migrator.load_with_entity_rename("task", JSON).unwrap();
+    assert!(!task.done);
+
+    let result: Result<TaskEntity, _> = migrator.load_with_entity_rename("chore", JSON);
+    assert!(matches!(
+        result,
+        Err(MigrationError::EntityNotFound(name)) if name == "chore"
+    ));
+}
+
+#[test]
+fn test_register_entity_alias_errors() {
+    let mut migrator = setup_migrator();
+
+    let result = migrator.register_entity_alias("old", "missing");
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(name)) if name == "missing"));
+
+    let result = migrator.register_entity_alias("task", "task");
+    assert!(matches!(
+        result,
+        Err(MigrationError::EntityAlreadyRegistered(name)) if name == "task"
+    ));
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_and_diff_reports_changed_title_only() {
+    let migrator = setup_migrator();
+
+    let stored = r#"{"version":"1.0.0","data":{"id":"task-1","title":"Old title"}}"#;
+    let candidate =
+        r#"{"version":"2.0.0","data":{"id":"task-1","title":"New title","done":false}}"#;
+
+    let diff = migrator
+        .load_and_diff::<TaskEntity>("task", stored, candidate)
+        .unwrap();
+
+    assert_eq!(diff.changed_fields.len(), 1);
+    assert_eq!(
+        diff.changed_fields["title"],
+        (json!("Old title"), json!("New title"))
+    );
+}
+
+#[test]
+fn test_load_and_diff_identical_after_migration() {
+    let migrator = setup_migrator();
+
+    let stored = r#"{"version":"1.0.0","data":{"id":"task-1","title":"Same"}}"#;
+    let candidate = r#"{"version":"2.0.0","data":{"id":"task-1","title":"Same","done":false}}"#;
+
+    let diff = migrator
+        .load_and_diff::<TaskEntity>("task", stored, candidate)
+        .unwrap();
+
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn test_load_and_diff_invalid_candidate() {
+    let migrator = setup_migrator();
+
+    let stored = r#"{"version":"1.0.0","data":{"id":"task-1","title":"Old"}}"#;
+    let result = migrator.load_and_diff::<TaskEntity>("task", stored, "not json");
+
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(_))
+    ));
+}
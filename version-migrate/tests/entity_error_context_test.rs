@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: String::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+/// Unrelated domain shape, used to make the final conversion fail.
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct UserEntity {
+    email: String,
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn deserialization_message<T: std::fmt::Debug>(result: Result<T, MigrationError>) -> String {
+    match result {
+        Err(MigrationError::DeserializationError(msg)) => msg,
+        other => panic!("expected DeserializationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_finalize_failure_names_entity() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"2.0.0","data":{"id":"1"}}"#;
+
+    let msg = deserialization_message(migrator.load::<TaskEntity>("task", json));
+    assert!(msg.contains("entity 'task'"), "{}", msg);
+    assert!(msg.contains("final version"), "{}", msg);
+}
+
+#[test]
+fn test_step_failure_names_entity() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"1.0.0","data":{"title":"no id"}}"#;
+
+    let msg = deserialization_message(migrator.load::<TaskEntity>("task", json));
+    assert!(msg.contains("version 1.0.0 of entity 'task'"), "{}", msg);
+}
+
+#[test]
+fn test_domain_conversion_failure_names_entity() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"2.0.0","data":{"id":"1","title":"t"}}"#;
+
+    let msg = deserialization_message(migrator.load::<UserEntity>("task", json));
+    assert!(msg.contains("entity 'task'"), "{}", msg);
+
+    let flat = serde_json::json!({"version": "2.0.0", "id": "1", "title": "t"});
+    let msg = deserialization_message(migrator.load_flat_from::<UserEntity, _>("task", flat));
+    assert!(msg.contains("entity 'task'"), "{}", msg);
+}
@@ -254,3 +254,181 @@ fn test_from_domain_to_latest() {
     assert_eq!(latest.title, "Convert Test");
     assert_eq!(latest.description, Some("Test conversion".to_string()));
 }
+
+fn sample_entity() -> TaskEntity {
+    TaskEntity {
+        id: "task-1".to_string(),
+        title: "Streamed".to_string(),
+        description: Some("via writer".to_string()),
+    }
+}
+
+fn setup_save_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1_0_0>()
+        .step::<TaskV1_1_0>()
+        .into_with_save::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_save_entity_to_writer_matches_save_entity() {
+    let migrator = Migrator::new();
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    migrator
+        .save_entity_to_writer(sample_entity(), &mut cursor)
+        .unwrap();
+
+    let expected = migrator.save_entity(sample_entity()).unwrap();
+    assert_eq!(String::from_utf8(cursor.into_inner()).unwrap(), expected);
+}
+
+#[test]
+fn test_save_flat_to_writer_matches_save_flat() {
+    let migrator = Migrator::new();
+    let latest = sample_entity().to_latest();
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    migrator
+        .save_flat_to_writer(latest.clone(), &mut cursor)
+        .unwrap();
+
+    let expected = migrator.save_flat(latest).unwrap();
+    assert_eq!(String::from_utf8(cursor.into_inner()).unwrap(), expected);
+}
+
+#[test]
+fn test_save_domain_to_writer_matches_save_domain() {
+    let migrator = setup_save_migrator();
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    migrator
+        .save_domain_to_writer("task", sample_entity(), &mut cursor)
+        .unwrap();
+
+    let expected = migrator.save_domain("task", sample_entity()).unwrap();
+    assert_eq!(String::from_utf8(cursor.into_inner()).unwrap(), expected);
+}
+
+#[test]
+fn test_save_domain_to_writer_unregistered_entity() {
+    let migrator = Migrator::new();
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    let result = migrator.save_domain_to_writer("task", sample_entity(), &mut cursor);
+
+    assert!(result.is_err());
+    assert!(cursor.into_inner().is_empty());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_save_to_async_writer_matches_string_variants() {
+    let migrator = setup_save_migrator();
+
+    let mut buf = Vec::new();
+    migrator
+        .save_entity_to_async_writer(sample_entity(), &mut buf)
+        .await
+        .unwrap();
+    assert_eq!(
+        buf,
+        migrator.save_entity(sample_entity()).unwrap().into_bytes()
+    );
+
+    let mut buf = Vec::new();
+    migrator
+        .save_flat_to_async_writer(sample_entity().to_latest(), &mut buf)
+        .await
+        .unwrap();
+    assert_eq!(
+        buf,
+        migrator
+            .save_flat(sample_entity().to_latest())
+            .unwrap()
+            .into_bytes()
+    );
+
+    let mut buf = Vec::new();
+    migrator
+        .save_domain_to_async_writer("task", sample_entity(), &mut buf)
+        .await
+        .unwrap();
+    assert_eq!(
+        buf,
+        migrator
+            .save_domain("task", sample_entity())
+            .unwrap()
+            .into_bytes()
+    );
+}
+
+// ===== Batch conversion hook =====
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct NoteV1 {
+    seq: u32,
+    text: String,
+}
+
+impl Versioned for NoteV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct NoteEntity {
+    text: String,
+}
+
+impl FromDomain<NoteEntity> for NoteV1 {
+    fn from_domain(entity: NoteEntity) -> Self {
+        NoteV1 {
+            seq: 0,
+            text: entity.text,
+        }
+    }
+}
+
+impl LatestVersioned for NoteEntity {
+    type Latest = NoteV1;
+    const ENTITY_NAME: &'static str = "note";
+
+    fn batch_to_latest(entities: Vec<Self>) -> Vec<NoteV1> {
+        entities
+            .into_iter()
+            .zip(1..)
+            .map(|(entity, seq)| NoteV1 {
+                seq,
+                ..entity.to_latest()
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_save_entity_vec_uses_batch_to_latest() {
+    let migrator = Migrator::new();
+    let notes = vec![
+        NoteEntity {
+            text: "first".to_string(),
+        },
+        NoteEntity {
+            text: "second".to_string(),
+        },
+    ];
+
+    let json = migrator.save_entity_vec(notes.clone()).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value[0]["data"]["seq"], 1);
+    assert_eq!(value[1]["data"]["seq"], 2);
+    assert_eq!(value[1]["data"]["text"], "second");
+
+    let json = migrator.save_entity_vec_flat(notes).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value[0]["seq"], 1);
+    assert_eq!(value[1]["seq"], 2);
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tempfile::TempDir;
+use version_migrate::{
+    AppPaths, IntoDomain, MigratesTo, MigrationError, Migrator, PathStrategy, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ConfigV1 {
+    theme: String,
+}
+
+impl Versioned for ConfigV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ConfigV2 {
+    theme: String,
+    font_size: u32,
+}
+
+impl Versioned for ConfigV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct AppConfig {
+    theme: String,
+    font_size: u32,
+}
+
+impl MigratesTo<ConfigV2> for ConfigV1 {
+    fn migrate(self) -> ConfigV2 {
+        ConfigV2 {
+            theme: self.theme,
+            font_size: 12,
+        }
+    }
+}
+
+impl IntoDomain<AppConfig> for ConfigV2 {
+    fn into_domain(self) -> AppConfig {
+        AppConfig {
+            theme: self.theme,
+            font_size: self.font_size,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("config")
+        .from::<ConfigV1>()
+        .step::<ConfigV2>()
+        .into::<AppConfig>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn candidate(base: &Path) -> AppPaths {
+    AppPaths::new("myapp").config_strategy(PathStrategy::CustomBase(base.to_path_buf()))
+}
+
+fn write_config(base: &Path, filename: &str, content: &str) {
+    let dir = base.join("myapp");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join(filename), content).unwrap();
+}
+
+#[test]
+fn test_first_existing_candidate_wins() {
+    let (user, system) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+    let missing = TempDir::new().unwrap();
+    write_config(
+        user.path(),
+        "config.toml",
+        "version = \"1.0.0\"\ntheme = \"dark\"\n",
+    );
+    write_config(
+        system.path(),
+        "config.toml",
+        "version = \"2.0.0\"\ntheme = \"light\"\nfont_size = 14\n",
+    );
+
+    let candidates = [
+        candidate(missing.path()),
+        candidate(user.path()),
+        candidate(system.path()),
+    ];
+    let (config, path): (AppConfig, _) = setup_migrator()
+        .load_from_config_paths("config", &candidates, "config.toml")
+        .unwrap();
+
+    assert_eq!(
+        config,
+        AppConfig {
+            theme: "dark".to_string(),
+            font_size: 12,
+        }
+    );
+    assert_eq!(path, user.path().join("myapp").join("config.toml"));
+    // Probing a missing candidate does not create its directory
+    assert!(!missing.path().join("myapp").exists());
+}
+
+#[test]
+fn test_unloadable_candidate_falls_through() {
+    let (broken, system) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+    write_config(broken.path(), "config.json", "{ not json");
+    write_config(
+        system.path(),
+        "config.json",
+        r#"{"version":"2.0.0","theme":"light","font_size":14}"#,
+    );
+
+    let candidates = [candidate(broken.path()), candidate(system.path())];
+    let (config, path): (AppConfig, _) = setup_migrator()
+        .load_from_config_paths("config", &candidates, "config.json")
+        .unwrap();
+
+    assert_eq!(config.font_size, 14);
+    assert!(path.starts_with(system.path()));
+}
+
+#[test]
+fn test_error_when_no_candidate_loads() {
+    let (broken, empty) = (TempDir::new().unwrap(), TempDir::new().unwrap());
+    write_config(broken.path(), "config.json", "{ not json");
+
+    let candidates = [candidate(broken.path()), candidate(empty.path())];
+    let result: Result<(AppConfig, _), _> =
+        setup_migrator().load_from_config_paths("config", &candidates, "config.json");
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(_))
+    ));
+
+    let result: Result<(AppConfig, _), _> = setup_migrator().load_from_config_paths(
+        "config",
+        &[candidate(empty.path())],
+        "config.json",
+    );
+    match result {
+        Err(MigrationError::Store(e)) => {
+            assert!(e.to_string().contains("config.json"));
+        }
+        other => panic!("expected a not-found error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_environment_config_prefers_xdg_config_home() {
+    let xdg = TempDir::new().unwrap();
+    write_config(
+        xdg.path(),
+        "config.json",
+        r#"{"version":"1.0.0","theme":"solarized"}"#,
+    );
+
+    std::env::set_var("XDG_CONFIG_HOME", xdg.path());
+    let result: Result<(AppConfig, _), _> =
+        setup_migrator().load_from_environment_config("config", "myapp", "config.json");
+    std::env::remove_var("XDG_CONFIG_HOME");
+
+    let (config, path) = result.unwrap();
+    assert_eq!(config.theme, "solarized");
+    assert_eq!(path, xdg.path().join("myapp").join("config.json"));
+}
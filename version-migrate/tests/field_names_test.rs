@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::Versioned;
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0", field_names = true)]
+struct TaskV1 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0", field_names = true)]
+#[serde(rename_all = "camelCase")]
+struct TaskV2 {
+    id: String,
+    due_date: Option<String>,
+    #[serde(rename = "label")]
+    tag_name: String,
+    #[serde(skip)]
+    cached_len: usize,
+    r#type: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0", field_names = true)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+struct EnvV1 {
+    api_key: String,
+    max_retry_count: u32,
+}
+
+/// Top-level keys `value` actually serializes to, in order.
+fn serialized_keys<T: Serialize>(value: &T) -> Vec<String> {
+    let json = serde_json::to_string(value).unwrap();
+    let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&json).unwrap();
+    map.keys().cloned().collect()
+}
+
+#[test]
+fn test_field_names_list_fields_in_order() {
+    assert_eq!(TaskV1::FIELD_NAMES, &["id", "title", "done"]);
+    assert_eq!(TaskV1::VERSION, "1.0.0");
+}
+
+#[test]
+fn test_field_names_respect_serde_renames() {
+    assert_eq!(TaskV2::FIELD_NAMES, &["id", "dueDate", "label", "type"]);
+    assert_eq!(EnvV1::FIELD_NAMES, &["API-KEY", "MAX-RETRY-COUNT"]);
+}
+
+#[test]
+fn test_field_names_match_serialized_keys() {
+    let task = TaskV2 {
+        id: "task-1".to_string(),
+        due_date: None,
+        tag_name: "home".to_string(),
+        cached_len: 3,
+        r#type: "chore".to_string(),
+    };
+
+    let mut expected: Vec<&str> = TaskV2::FIELD_NAMES.to_vec();
+    expected.sort_unstable();
+    assert_eq!(serialized_keys(&task), expected);
+
+    // The skipped field is not stored, so it comes back as its default
+    let json = serde_json::to_string(&task).unwrap();
+    let reloaded: TaskV2 = serde_json::from_str(&json).unwrap();
+    assert_eq!(reloaded.cached_len, 0);
+}
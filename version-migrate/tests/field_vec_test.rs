@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    owner: Owner,
+    tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Owner {
+    name: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            owner: Owner {
+                name: "unassigned".to_string(),
+            },
+            tags: vec!["migrated".to_string()],
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+const TASKS: &str = r#"[
+    {"version":"1.0.0","data":{"id":"1","title":"Write docs"}},
+    {"version":"2.0.0","data":{"id":"2","title":"Fix bug","owner":{"name":"alice"},"tags":["urgent"]}},
+    {"version":"1.0.0","data":{"id":"3","title":"Review PR"}},
+    {"version":"2.0.0","data":{"id":"4","title":"Release","owner":{"name":"bob"},"tags":["ops","release"]}},
+    {"version":"1.0.0","data":{"id":"5","title":"Plan sprint"}}
+]"#;
+
+#[test]
+fn test_load_field_vec_titles() {
+    let migrator = setup_migrator();
+
+    let titles: Vec<String> = migrator.load_field_vec("task", TASKS, "title").unwrap();
+
+    assert_eq!(
+        titles,
+        vec![
+            "Write docs",
+            "Fix bug",
+            "Review PR",
+            "Release",
+            "Plan sprint"
+        ]
+    );
+}
+
+#[test]
+fn test_load_field_vec_nested_path_after_migration() {
+    let migrator = setup_migrator();
+
+    let owners: Vec<String> = migrator
+        .load_field_vec("task", TASKS, "owner.name")
+        .unwrap();
+    assert_eq!(
+        owners,
+        vec!["unassigned", "alice", "unassigned", "bob", "unassigned"]
+    );
+
+    let first_tags: Vec<String> = migrator.load_field_vec("task", TASKS, "tags.0").unwrap();
+    assert_eq!(
+        first_tags,
+        vec!["migrated", "urgent", "migrated", "ops", "migrated"]
+    );
+}
+
+#[test]
+fn test_load_field_vec_missing_field() {
+    let migrator = setup_migrator();
+
+    let result: Result<Vec<String>, _> = migrator.load_field_vec("task", TASKS, "due_date");
+
+    match result {
+        Err(MigrationError::ElementFailed { index, source }) => {
+            assert_eq!(index, 0);
+            assert!(
+                matches!(*source, MigrationError::DeserializationError(ref msg) if msg.contains("due_date"))
+            );
+        }
+        other => panic!("expected ElementFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_load_field_vec_entity_not_found() {
+    let migrator = setup_migrator();
+
+    let result: Result<Vec<String>, _> = migrator.load_field_vec("unknown", TASKS, "title");
+
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -329,3 +329,32 @@ fn test_flat_with_migrator_level_override() {
     assert_eq!(domain.id, "migrator-override");
     assert_eq!(domain.title, "Test");
 }
+
+#[test]
+fn test_load_flat_scalar_and_all() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskDomain>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let json = r#"{
+        "inbox": {"version":"1.0.0","id":"task-1","title":"Old"},
+        "today": {"version":"2.0.0","id":"task-2","title":"New","description":"Desc"}
+    }"#;
+
+    let inbox: TaskDomain = migrator.load_flat_scalar("task", json, "inbox").unwrap();
+    assert_eq!(inbox.id, "task-1");
+    assert_eq!(inbox.description, None);
+
+    let all: std::collections::HashMap<String, TaskDomain> =
+        migrator.load_all_flat_scalars("task", json).unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all["inbox"], inbox);
+    assert_eq!(all["today"].description, Some("Desc".to_string()));
+
+    let missing: Result<TaskDomain, _> = migrator.load_flat_scalar("task", json, "someday");
+    assert!(missing.is_err());
+}
@@ -676,3 +676,27 @@ mod custom_keys {
         assert!(result.is_err());
     }
 }
+
+#[test]
+fn test_load_forward_numeric_version_alias() {
+    let mut migrator = create_migrator();
+    migrator
+        .register_version_alias("task", "1", "1.0.0")
+        .unwrap();
+
+    let task: Forwardable<TaskEntity> = migrator
+        .load_forward(
+            "task",
+            r#"{"version":1,"data":{"id":"1","title":"Task 1"}}"#,
+        )
+        .unwrap();
+    assert_eq!(task.title, "Task 1");
+    assert_eq!(task.original_version(), "1.0.0");
+    assert!(!task.was_lossy());
+
+    let task: Forwardable<TaskEntity> = migrator
+        .load_forward_flat("task", r#"{"version":1,"id":"2","title":"Task 2"}"#)
+        .unwrap();
+    assert_eq!(task.id, "2");
+    assert!(!task.was_lossy());
+}
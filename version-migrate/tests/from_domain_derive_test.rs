@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{FromDomain, IntoDomain, Migrator, Versioned};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    priority: u8,
+}
+
+// Exact-match fields only
+#[derive(Debug, PartialEq, Serialize, Deserialize, Versioned, FromDomain)]
+#[versioned(version = "1.0.0")]
+#[from_domain(TaskEntity)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+// Mix of matched, default and literal-value fields
+#[derive(Debug, PartialEq, Serialize, Deserialize, Versioned, FromDomain)]
+#[versioned(version = "2.0.0")]
+#[from_domain(TaskEntity)]
+struct TaskV2 {
+    priority: u8,
+    id: String,
+    title: String,
+    #[from_domain(default)]
+    tags: Vec<String>,
+    #[from_domain(value = "\"open\"")]
+    status: String,
+    #[from_domain(value = "3")]
+    retries: u32,
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            priority: self.priority,
+        }
+    }
+}
+
+fn entity() -> TaskEntity {
+    TaskEntity {
+        id: "task-1".to_string(),
+        title: "Write docs".to_string(),
+        priority: 2,
+    }
+}
+
+#[test]
+fn test_derive_exact_match_fields() {
+    assert_eq!(
+        TaskV1::from_domain(entity()),
+        TaskV1 {
+            id: "task-1".to_string(),
+            title: "Write docs".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_derive_default_and_value_fields() {
+    assert_eq!(
+        TaskV2::from_domain(entity()),
+        TaskV2 {
+            priority: 2,
+            id: "task-1".to_string(),
+            title: "Write docs".to_string(),
+            tags: Vec::new(),
+            status: "open".to_string(),
+            retries: 3,
+        }
+    );
+}
+
+#[test]
+fn test_derived_impl_works_with_into_with_save() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV2>()
+                .into_with_save::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let json = migrator.save_domain("task", entity()).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["version"], "2.0.0");
+    assert_eq!(value["data"]["status"], "open");
+    assert_eq!(value["data"]["retries"], 3);
+
+    let loaded: TaskEntity = migrator.load("task", &json).unwrap();
+    assert_eq!(loaded, entity());
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned, Debug, PartialEq)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned, Debug, PartialEq)]
+#[versioned(version = "2.0.0", frozen = true)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Versioned, Debug, PartialEq)]
+#[versioned(version = "3.0.0")]
+struct TaskV3 {
+    id: String,
+    done: bool,
+    tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl MigratesTo<TaskV3> for TaskV2 {
+    fn migrate(self) -> TaskV3 {
+        TaskV3 {
+            id: self.id,
+            done: self.done,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV3 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+#[test]
+fn test_step_out_of_frozen_version_is_rejected() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .step::<TaskV3>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    let result = migrator.register(path);
+    assert!(matches!(
+        result,
+        Err(MigrationError::FrozenVersion { entity, version })
+            if entity == "task" && version == "2.0.0"
+    ));
+    assert!(migrator.is_empty());
+}
+
+#[test]
+fn test_frozen_terminal_version_is_accepted() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let task: TaskEntity = migrator
+        .load("task", r#"{"version":"1.0.0","data":{"id":"a"}}"#)
+        .unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "a".to_string(),
+            done: false,
+        }
+    );
+}
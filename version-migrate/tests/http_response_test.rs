@@ -0,0 +1,177 @@
+#![cfg(feature = "reqwest")]
+
+use serde::{Deserialize, Serialize};
+use version_migrate::reqwest;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+async fn respond_with(body: &str, content_type: &str) -> reqwest::Response {
+    respond_with_status(200, body, content_type).await
+}
+
+async fn respond_with_status(status: u16, body: &str, content_type: &str) -> reqwest::Response {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/task"))
+        .respond_with(
+            ResponseTemplate::new(status).set_body_raw(body.as_bytes().to_vec(), content_type),
+        )
+        .mount(&server)
+        .await;
+
+    reqwest::get(format!("{}/task", server.uri()))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_json_response_is_migrated() {
+    let migrator = setup_migrator();
+    let response = respond_with(
+        r#"{"version":"1.0.0","data":{"id":"task-1"}}"#,
+        "application/json; charset=utf-8",
+    )
+    .await;
+
+    let task: TaskEntity = migrator
+        .load_from_http_response("task", response)
+        .await
+        .unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "task-1".to_string(),
+            done: false,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_toml_response_is_migrated() {
+    let migrator = setup_migrator();
+    let response = respond_with(
+        "version = \"2.0.0\"\n\n[data]\nid = \"task-2\"\ndone = true\n",
+        "application/toml",
+    )
+    .await;
+
+    let task: TaskEntity = migrator
+        .load_from_http_response("task", response)
+        .await
+        .unwrap();
+    assert!(task.done);
+}
+
+#[tokio::test]
+async fn test_unsupported_content_type_is_rejected() {
+    let migrator = setup_migrator();
+    let response = respond_with("<html></html>", "text/html").await;
+
+    let result: Result<TaskEntity, _> = migrator.load_from_http_response("task", response).await;
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("text/html")
+    ));
+}
+
+#[tokio::test]
+async fn test_error_status_is_rejected() {
+    let migrator = setup_migrator();
+    // A well-formed body must not be loaded from a failed response.
+    let response = respond_with_status(
+        404,
+        r#"{"version":"1.0.0","data":{"id":"task-1"}}"#,
+        "application/json",
+    )
+    .await;
+
+    let result: Result<TaskEntity, _> = migrator.load_from_http_response("task", response).await;
+    assert!(matches!(
+        result,
+        Err(MigrationError::Http { status: Some(404), ref url, .. }) if url.ends_with("/task")
+    ));
+}
+
+#[tokio::test]
+async fn test_save_to_http_request_sends_versioned_body() {
+    let migrator = setup_migrator();
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/task"))
+        .and(header("content-type", "application/json"))
+        .and(body_json(
+            serde_json::json!({"version": "2.0.0", "data": {"id": "task-1", "done": true}}),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let request = migrator
+        .save_to_http_request(
+            TaskV2 {
+                id: "task-1".to_string(),
+                done: true,
+            },
+            client.post(format!("{}/task", server.uri())),
+        )
+        .unwrap();
+    let response = request.send().await.unwrap();
+    assert_eq!(response.status(), 204);
+}
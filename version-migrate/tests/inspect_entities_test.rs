@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{EntityInspection, FromDomain, IntoDomain, MigratesTo, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskV3 {
+    id: String,
+    done: bool,
+    tags: Vec<String>,
+}
+
+impl Versioned for TaskV3 {
+    const VERSION: &'static str = "3.0.0";
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskEntity {
+    id: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl MigratesTo<TaskV3> for TaskV2 {
+    fn migrate(self) -> TaskV3 {
+        TaskV3 {
+            id: self.id,
+            done: self.done,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV3 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity { id: self.id }
+    }
+}
+
+impl FromDomain<TaskEntity> for TaskV3 {
+    fn from_domain(domain: TaskEntity) -> Self {
+        TaskV3 {
+            id: domain.id,
+            done: false,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity { id: self.id }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity { id: self.id }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .step::<TaskV3>()
+                .into_with_save::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+        .register(
+            Migrator::define("note")
+                .with_keys("schema", "payload")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+        .register(
+            Migrator::define("archive")
+                .from::<TaskV1>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_inspect_registered_entities() {
+    let inspections = setup_migrator().inspect_registered_entities();
+
+    assert_eq!(
+        inspections,
+        vec![
+            EntityInspection {
+                entity_name: "archive".to_string(),
+                version_chain: vec!["1.0.0".to_string()],
+                version_key: "version".to_string(),
+                data_key: "data".to_string(),
+                has_domain_saver: false,
+                step_count: 0,
+            },
+            EntityInspection {
+                entity_name: "note".to_string(),
+                version_chain: vec!["1.0.0".to_string(), "2.0.0".to_string()],
+                version_key: "schema".to_string(),
+                data_key: "payload".to_string(),
+                has_domain_saver: false,
+                step_count: 1,
+            },
+            EntityInspection {
+                entity_name: "task".to_string(),
+                version_chain: vec![
+                    "1.0.0".to_string(),
+                    "2.0.0".to_string(),
+                    "3.0.0".to_string(),
+                ],
+                version_key: "version".to_string(),
+                data_key: "data".to_string(),
+                has_domain_saver: true,
+                step_count: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_inspect_respects_migrator_default_keys() {
+    let mut migrator = Migrator::builder()
+        .default_version_key("v")
+        .default_data_key("d")
+        .build();
+    migrator
+        .register(
+            Migrator::define("archive")
+                .from::<TaskV1>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let inspection = &migrator.inspect_registered_entities()[0];
+    assert_eq!(inspection.version_key, "v");
+    assert_eq!(inspection.data_key, "d");
+}
+
+#[test]
+fn test_inspection_serializes_to_json() {
+    let json = serde_json::to_value(setup_migrator().inspect_registered_entities()).unwrap();
+    assert_eq!(json[2]["entity_name"], "task");
+    assert_eq!(json[2]["version_chain"][2], "3.0.0");
+    assert_eq!(json[2]["has_domain_saver"], true);
+}
+
+#[test]
+fn test_inspect_empty_migrator() {
+    assert!(Migrator::new().inspect_registered_entities().is_empty());
+}
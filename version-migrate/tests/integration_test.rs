@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use version_migrate::{IntoDomain, MigratesTo, Migrator, Versioned, VersionedWrapper};
+use version_migrate::{
+    IntoDomain, MigratesTo, MigrationError, Migrator, Versioned, VersionedWrapper,
+};
 
 // Version 1.0.0 of Task
 #[derive(Serialize, Deserialize, Versioned)]
@@ -402,3 +404,63 @@ data:
     assert_eq!(from_json.id, "multi-format");
     assert_eq!(from_json.title, "Multi Format Test");
 }
+
+#[test]
+fn test_define_accepts_owned_and_borrowed_string() {
+    let json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"Runtime"}}"#;
+    let mut migrator = Migrator::new();
+
+    let owned = format!("{}_{}", "task", "owned");
+    migrator
+        .register(
+            Migrator::define(owned)
+                .from::<TaskV1_0_0>()
+                .step::<TaskV1_1_0>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let borrowed = String::from("task_borrowed");
+    migrator
+        .register(
+            Migrator::define(&borrowed)
+                .from::<TaskV1_0_0>()
+                .step::<TaskV1_1_0>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let from_owned: TaskEntity = migrator.load("task_owned", json).unwrap();
+    let from_borrowed: TaskEntity = migrator.load(&borrowed, json).unwrap();
+    assert_eq!(from_owned, from_borrowed);
+    assert_eq!(from_owned.title, "Runtime");
+}
+
+#[test]
+fn test_register_rejects_empty_entity_name() {
+    let mut migrator = Migrator::new();
+
+    for name in ["", "   "] {
+        let result = migrator.register(
+            Migrator::define(name)
+                .from::<TaskV1_0_0>()
+                .step::<TaskV1_1_0>()
+                .into::<TaskEntity>(),
+        );
+        assert!(matches!(
+            result,
+            Err(MigrationError::InvalidEntityName(n)) if n == name
+        ));
+    }
+    assert!(migrator.is_empty());
+
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1_0_0>()
+                .step::<TaskV1_1_0>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    assert!(!migrator.is_empty());
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "json5")]
+
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    ConfigMigrator, DeriveQueryable as Queryable, IntoDomain, MigratesTo, MigrationError, Migrator,
+    Versioned,
+};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    id: String,
+    title: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Queryable)]
+#[queryable(entity = "task")]
+struct TaskEntity {
+    id: String,
+    title: String,
+    description: Option<String>,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            description: None,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            description: self.description,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_json5_matches_strict_json() {
+    let migrator = setup_migrator();
+    let strict = r#"{"version":"1.0.0","data":{"id":"task-1","title":"Write docs"}}"#;
+    let lenient = r#"{
+        // Hand-edited task
+        version: "1.0.0",
+        data: {
+            id: 'task-1',
+            /* trailing comma below */
+            title: "Write docs",
+        },
+    }"#;
+
+    let expected: TaskEntity = migrator.load("task", strict).unwrap();
+    let loaded: TaskEntity = migrator.load_json5("task", lenient).unwrap();
+    assert_eq!(loaded, expected);
+}
+
+#[test]
+fn test_load_json5_rejects_invalid_input() {
+    let migrator = setup_migrator();
+    let result: Result<TaskEntity, _> = migrator.load_json5("task", "{ version: ");
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("JSON5")
+    ));
+}
+
+#[test]
+fn test_config_migrator_from_json5() {
+    let strict = r#"{"tasks": [
+        {"version": "1.0.0", "id": "1", "title": "Task 1"},
+        {"version": "2.0.0", "id": "2", "title": "Task 2", "description": "New"}
+    ]}"#;
+    let lenient = r#"{
+        // Tasks at mixed versions
+        tasks: [
+            { version: "1.0.0", id: "1", title: "Task 1", },
+            { version: "2.0.0", id: "2", title: "Task 2", description: "New", },
+        ],
+    }"#;
+
+    let expected = ConfigMigrator::from(strict, setup_migrator()).unwrap();
+    let config = ConfigMigrator::from_json5(lenient, setup_migrator()).unwrap();
+    assert_eq!(config.as_value(), expected.as_value());
+
+    let tasks: Vec<TaskEntity> = config.query("tasks").unwrap();
+    assert_eq!(tasks, expected.query::<TaskEntity>("tasks").unwrap());
+    assert_eq!(tasks[0].description, None);
+}
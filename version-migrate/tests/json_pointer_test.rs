@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    IntoDomain, MigratesTo, MigrationError, Migrator, VersionExtractor, Versioned,
+};
+
+#[derive(Serialize, Deserialize)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .with_version_pointer("/meta/schema")
+                .with_data_pointer("/data")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_with_nested_version_pointer() {
+    let json = r#"{"meta":{"schema":"1.0.0","producer":"ext"},"data":{"id":"t1"}}"#;
+    let task: TaskEntity = setup_migrator().load("task", json).unwrap();
+
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "t1".to_string(),
+            done: false,
+        }
+    );
+}
+
+#[test]
+fn test_load_with_nested_data_pointer() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .with_version_pointer("/meta/schema")
+                .with_data_pointer("/payload/body")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let json = r#"{"meta":{"schema":"2.0.0"},"payload":{"body":{"id":"t2","done":true}}}"#;
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+
+    assert_eq!(task.id, "t2");
+    assert!(task.done);
+}
+
+#[test]
+fn test_missing_pointer_target_is_error() {
+    let json = r#"{"meta":{},"data":{"id":"t1"}}"#;
+    let err = setup_migrator()
+        .load::<TaskEntity>("task", json)
+        .unwrap_err();
+
+    match err {
+        MigrationError::DeserializationError(msg) => assert!(msg.contains("/meta/schema")),
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn test_top_level_keys_are_ignored_when_pointer_set() {
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+    assert!(setup_migrator().load::<TaskEntity>("task", json).is_err());
+}
+
+#[test]
+fn test_invalid_pointer_rejected_at_register() {
+    let mut migrator = Migrator::new();
+    let result = migrator.register(
+        Migrator::define("task")
+            .with_version_pointer("meta/schema")
+            .from::<TaskV1>()
+            .into::<TaskEntity>(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidPathConfig { entity, reason })
+            if entity == "task" && reason.contains("meta/schema")
+    ));
+}
+
+#[test]
+fn test_version_pointer_alone_reads_data_key() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .with_version_pointer("/meta/schema")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let json = r#"{"meta":{"schema":"1.0.0"},"data":{"id":"t1"}}"#;
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.id, "t1");
+}
+
+#[test]
+fn test_set_version_extractor_replaces_path_pointers() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_version_extractor("task", VersionExtractor::Wrapped)
+        .unwrap();
+
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.id, "t1");
+}
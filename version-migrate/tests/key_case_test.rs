@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, KeyCase, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Subtask {
+    sub_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    task_id: String,
+    due_date: Option<String>,
+    subtasks: Vec<Subtask>,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    task_id: String,
+    due_date: Option<String>,
+    subtasks: Vec<Subtask>,
+    is_done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    task_id: String,
+    due_date: Option<String>,
+    subtasks: Vec<Subtask>,
+    is_done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            task_id: self.task_id,
+            due_date: self.due_date,
+            subtasks: self.subtasks,
+            is_done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            task_id: self.task_id,
+            due_date: self.due_date,
+            subtasks: self.subtasks,
+            is_done: self.is_done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+const CAMEL_V1: &str = r#"{
+    "version": "1.0.0",
+    "data": {
+        "taskId": "t1",
+        "dueDate": "2024-01-01",
+        "subtasks": [{"subId": "s1"}, {"subId": "s2"}]
+    }
+}"#;
+
+#[test]
+fn test_camel_case_blob_loads_into_snake_case_dtos() {
+    let migrator = setup_migrator().with_key_case(KeyCase::CamelToSnake);
+
+    let task: TaskEntity = migrator.load("task", CAMEL_V1).unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            task_id: "t1".to_string(),
+            due_date: Some("2024-01-01".to_string()),
+            subtasks: vec![
+                Subtask {
+                    sub_id: "s1".to_string()
+                },
+                Subtask {
+                    sub_id: "s2".to_string()
+                },
+            ],
+            is_done: false,
+        }
+    );
+}
+
+#[test]
+fn test_key_case_applies_to_latest_version() {
+    let migrator = setup_migrator().with_key_case(KeyCase::CamelToSnake);
+    let json = r#"{"version":"2.0.0","data":{"taskId":"t1","subtasks":[],"isDone":true}}"#;
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.task_id, "t1");
+    assert!(task.is_done);
+}
+
+#[test]
+fn test_key_case_applies_to_entities_registered_later() {
+    let mut migrator = Migrator::new().with_key_case(KeyCase::CamelToSnake);
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    migrator.register(path).unwrap();
+
+    let task: TaskEntity = migrator.load("task", CAMEL_V1).unwrap();
+    assert_eq!(task.task_id, "t1");
+}
+
+#[test]
+fn test_key_case_is_off_by_default() {
+    let migrator = setup_migrator();
+
+    let result: Result<TaskEntity, _> = migrator.load("task", CAMEL_V1);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_colliding_keys_are_rejected() {
+    let migrator = setup_migrator().with_key_case(KeyCase::CamelToSnake);
+    // Nested objects are checked too.
+    let json = r#"{
+        "version": "1.0.0",
+        "data": {
+            "taskId": "t1",
+            "subtasks": [{"subId": "s1", "sub_id": "s2"}]
+        }
+    }"#;
+
+    let result: Result<TaskEntity, _> = migrator.load("task", json);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(ref msg))
+            if msg.contains("subId") && msg.contains("sub_id")
+    ));
+}
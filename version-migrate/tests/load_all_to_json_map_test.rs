@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct UserV1 {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct UserEntity {
+    name: String,
+}
+
+impl IntoDomain<UserEntity> for UserV1 {
+    fn into_domain(self) -> UserEntity {
+        UserEntity { name: self.name }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+        .register(
+            Migrator::define("user")
+                .from::<UserV1>()
+                .into::<UserEntity>(),
+        )
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_all_migrates_registered_keys() {
+    let backup = r#"{
+        "tasks": [
+            {"version": "1.0.0", "id": "t1"},
+            {"version": "2.0.0", "id": "t2", "done": true}
+        ],
+        "user": [{"version": "1.0.0", "name": "alice"}],
+        "exported_at": "2024-01-01T00:00:00Z",
+        "settings": [{"theme": "dark"}]
+    }"#;
+
+    let map = setup_migrator().load_all_to_json_map(backup).unwrap();
+
+    assert_eq!(map.len(), 4);
+    assert_eq!(
+        map["tasks"],
+        vec![
+            serde_json::json!({"version": "2.0.0", "id": "t1", "done": false}),
+            serde_json::json!({"version": "2.0.0", "id": "t2", "done": true}),
+        ]
+    );
+    assert_eq!(map["user"][0]["version"], "1.0.0");
+    assert_eq!(map["user"][0]["name"], "alice");
+
+    // Unknown keys are copied unchanged
+    assert_eq!(
+        map["exported_at"],
+        vec![serde_json::json!("2024-01-01T00:00:00Z")]
+    );
+    assert_eq!(map["settings"], vec![serde_json::json!({"theme": "dark"})]);
+}
+
+#[test]
+fn test_load_all_reports_failing_element() {
+    let backup = r#"{"tasks": [{"version": "1.0.0", "id": "t1"}, {"id": "t2"}]}"#;
+
+    let err = setup_migrator().load_all_to_json_map(backup).unwrap_err();
+    assert!(matches!(
+        err,
+        MigrationError::ElementFailed { index: 1, .. }
+    ));
+}
+
+#[test]
+fn test_load_all_rejects_non_object() {
+    let err = setup_migrator().load_all_to_json_map("[]").unwrap_err();
+    assert!(matches!(err, MigrationError::DeserializationError(_)));
+}
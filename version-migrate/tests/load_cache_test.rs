@@ -0,0 +1,114 @@
+#![cfg(feature = "cache")]
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use version_migrate::{IntoDomain, MigratesTo, Migrator, NullHandlingMode, Versioned};
+
+/// Each test uses its own entity type so the step counters do not interfere
+/// when tests run in parallel.
+macro_rules! counted_task {
+    ($v1:ident, $v2:ident, $counter:ident) => {
+        static $counter: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct $v1 {
+            id: String,
+        }
+
+        impl Versioned for $v1 {
+            const VERSION: &'static str = "1.0.0";
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct $v2 {
+            id: String,
+            done: bool,
+        }
+
+        impl Versioned for $v2 {
+            const VERSION: &'static str = "2.0.0";
+        }
+
+        impl MigratesTo<$v2> for $v1 {
+            fn migrate(self) -> $v2 {
+                $counter.fetch_add(1, Ordering::SeqCst);
+                $v2 {
+                    id: self.id,
+                    done: false,
+                }
+            }
+        }
+
+        impl IntoDomain<TaskEntity> for $v2 {
+            fn into_domain(self) -> TaskEntity {
+                TaskEntity {
+                    id: self.id,
+                    done: self.done,
+                }
+            }
+        }
+    };
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+counted_task!(RepeatV1, RepeatV2, REPEAT_STEPS);
+counted_task!(InvalidateV1, InvalidateV2, INVALIDATE_STEPS);
+
+const JSON: &str = r#"{"version":"1.0.0","data":{"id":"task-1"}}"#;
+
+#[test]
+fn test_repeat_load_migrates_once() {
+    let mut migrator = Migrator::new().with_load_cache(8);
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<RepeatV1>()
+                .step::<RepeatV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let first: TaskEntity = migrator.load("task", JSON).unwrap();
+    let second: TaskEntity = migrator.load("task", JSON).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(REPEAT_STEPS.load(Ordering::SeqCst), 1);
+    assert_eq!(migrator.load_cache_len(), 1);
+
+    // Different bytes, even if equivalent JSON, are a different entry
+    let spaced = r#"{"version": "1.0.0", "data": {"id": "task-1"}}"#;
+    let third: TaskEntity = migrator.load("task", spaced).unwrap();
+    assert_eq!(third, first);
+    assert_eq!(REPEAT_STEPS.load(Ordering::SeqCst), 2);
+    assert_eq!(migrator.load_cache_len(), 2);
+}
+
+#[test]
+fn test_cache_is_cleared_when_configuration_changes() {
+    let mut migrator = Migrator::new().with_load_cache(8);
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<InvalidateV1>()
+                .step::<InvalidateV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let _: TaskEntity = migrator.load("task", JSON).unwrap();
+    assert_eq!(migrator.load_cache_len(), 1);
+
+    migrator
+        .set_null_handling("task", NullHandlingMode::EmptyStringAsNone)
+        .unwrap();
+    assert_eq!(migrator.load_cache_len(), 0);
+
+    let _: TaskEntity = migrator.load("task", JSON).unwrap();
+    migrator.clear_load_cache();
+    let _: TaskEntity = migrator.load("task", JSON).unwrap();
+    assert_eq!(INVALIDATE_STEPS.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Task {
+    id: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct NoteV1 {
+    text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Note {
+    text: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<Task> for TaskV2 {
+    fn into_domain(self) -> Task {
+        Task {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<Note> for NoteV1 {
+    fn into_domain(self) -> Note {
+        Note { text: self.text }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register_dynamic(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<Task>(),
+        )
+        .unwrap();
+    migrator
+        .register_dynamic(Migrator::define("note").from::<NoteV1>().into::<Note>())
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_dynamic_downcasts_to_domain_type() {
+    let migrator = setup_migrator();
+
+    let task = migrator
+        .load_dynamic("task", r#"{"version":"1.0.0","data":{"id":"task-1"}}"#)
+        .unwrap();
+    let task: Box<Task> = task.downcast().unwrap();
+    assert_eq!(
+        *task,
+        Task {
+            id: "task-1".to_string(),
+            done: false,
+        }
+    );
+
+    let note = migrator
+        .load_dynamic("note", r#"{"version":"1.0.0","data":{"text":"hi"}}"#)
+        .unwrap();
+    assert!(!note.is::<Task>());
+    assert_eq!(
+        note.downcast_ref::<Note>(),
+        Some(&Note {
+            text: "hi".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_dynamic_entities_still_load_statically() {
+    let migrator = setup_migrator();
+
+    let task: Task = migrator
+        .load(
+            "task",
+            r#"{"version":"2.0.0","data":{"id":"task-2","done":true}}"#,
+        )
+        .unwrap();
+    assert!(task.done);
+}
+
+#[test]
+fn test_load_dynamic_requires_dynamic_registration() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register(Migrator::define("note").from::<NoteV1>().into::<Note>())
+        .unwrap();
+
+    let result = migrator.load_dynamic("note", r#"{"version":"1.0.0","data":{"text":"hi"}}"#);
+    assert!(matches!(
+        result,
+        Err(MigrationError::EntityNotFound(msg)) if msg.contains("register_dynamic")
+    ));
+
+    let result = migrator.load_dynamic("user", r#"{"version":"1.0.0","data":{}}"#);
+    assert!(matches!(
+        result,
+        Err(MigrationError::EntityNotFound(name)) if name == "user"
+    ));
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct UserV1 {
+    name: String,
+}
+
+impl Versioned for UserV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct UserEntity {
+    name: String,
+}
+
+impl IntoDomain<UserEntity> for UserV1 {
+    fn into_domain(self) -> UserEntity {
+        UserEntity { name: self.name }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let task_path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    let user_path = Migrator::define("user")
+        .from::<UserV1>()
+        .into::<UserEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(task_path).unwrap();
+    migrator.register(user_path).unwrap();
+    migrator
+}
+
+fn payload() -> serde_json::Value {
+    serde_json::json!({
+        "event": "task.created",
+        "task": {"version": "1.0.0", "data": {"id": "task-1"}},
+        "previous": {"version": "2.0.0", "data": {"id": "task-0", "done": true}},
+        "author": {"version": "1.0.0", "name": "alice"}
+    })
+}
+
+#[test]
+fn test_each_entry_is_migrated_independently() {
+    let migrator = setup_migrator();
+    let payload = payload();
+
+    let task: TaskEntity = migrator
+        .load_from_map_entry("task", &payload, "task")
+        .unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "task-1".to_string(),
+            done: false,
+        }
+    );
+
+    let previous: TaskEntity = migrator
+        .load_from_map_entry("task", &payload, "previous")
+        .unwrap();
+    assert!(previous.done);
+
+    let author: UserEntity = migrator
+        .load_flat_from_map_entry("user", &payload, "author")
+        .unwrap();
+    assert_eq!(author.name, "alice");
+}
+
+#[test]
+fn test_missing_key_and_non_object() {
+    let migrator = setup_migrator();
+
+    let result: Result<TaskEntity, _> = migrator.load_from_map_entry("task", &payload(), "next");
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("'next'")
+    ));
+
+    let result: Result<TaskEntity, _> =
+        migrator.load_flat_from_map_entry("task", &serde_json::json!([1, 2]), "task");
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(_))
+    ));
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "compression")]
+
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, LoadLimits, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    notes: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    notes: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            notes: "migrated".to_string(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            notes: self.notes,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn test_load_gzip_matches_plain_load() {
+    let migrator = setup_migrator();
+    let json = migrator
+        .save(TaskV1 {
+            id: "task-1".to_string(),
+        })
+        .unwrap();
+
+    let plain: TaskEntity = migrator.load("task", &json).unwrap();
+    let compressed: TaskEntity = migrator.load_gzip("task", &gzip(json.as_bytes())).unwrap();
+
+    assert_eq!(compressed, plain);
+    assert_eq!(compressed.notes, "migrated");
+}
+
+#[test]
+fn test_invalid_gzip_is_rejected() {
+    let migrator = setup_migrator();
+    let result: Result<TaskEntity, _> = migrator.load_gzip("task", b"not gzip");
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("decompress")
+    ));
+}
+
+#[test]
+fn test_decompressed_json_respects_limits() {
+    let migrator = setup_migrator().with_limits(LoadLimits {
+        max_bytes: 128,
+        max_depth: 16,
+    });
+    let json = migrator
+        .save(TaskV2 {
+            id: "task-1".to_string(),
+            notes: "x".repeat(1024),
+        })
+        .unwrap();
+
+    let bytes = gzip(json.as_bytes());
+    assert!(bytes.len() < 128);
+    let result: Result<TaskEntity, _> = migrator.load_gzip("task", &bytes);
+    assert!(matches!(result, Err(MigrationError::InputTooLarge { .. })));
+}
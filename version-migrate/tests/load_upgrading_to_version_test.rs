@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV3 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV3 {
+    const VERSION: &'static str = "3.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            title: format!("Task {}", self.id),
+            id: self.id,
+        }
+    }
+}
+
+impl MigratesTo<TaskV3> for TaskV2 {
+    fn migrate(self) -> TaskV3 {
+        TaskV3 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV3 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .step::<TaskV3>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_upgrade_v1_to_intermediate_v2() {
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+    let task: TaskV2 = setup_migrator()
+        .load_upgrading_to_version("task", json, "2.0.0")
+        .unwrap();
+
+    assert_eq!(
+        task,
+        TaskV2 {
+            id: "t1".to_string(),
+            title: "Task t1".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_upgrade_to_latest_version_skips_domain_conversion() {
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+    let task: TaskV3 = setup_migrator()
+        .load_upgrading_to_version("task", json, "3.0.0")
+        .unwrap();
+
+    assert_eq!(task.title, "Task t1");
+    assert!(!task.done);
+}
+
+#[test]
+fn test_data_already_at_target_version() {
+    let json = r#"{"version":"2.0.0","data":{"id":"t2","title":"Kept"}}"#;
+    let task: TaskV2 = setup_migrator()
+        .load_upgrading_to_version("task", json, "2.0.0")
+        .unwrap();
+
+    assert_eq!(task.title, "Kept");
+}
+
+#[test]
+fn test_unknown_target_version_is_error() {
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+    let err = setup_migrator()
+        .load_upgrading_to_version::<TaskV2>("task", json, "2.5.0")
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        MigrationError::MigrationPathNotDefined { version, .. } if version == "2.5.0"
+    ));
+}
+
+#[test]
+fn test_data_newer_than_target_is_error() {
+    let json = r#"{"version":"3.0.0","data":{"id":"t3","title":"New","done":true}}"#;
+    let err = setup_migrator()
+        .load_upgrading_to_version::<TaskV2>("task", json, "2.0.0")
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        MigrationError::InvalidVersionOrder { from, to, .. } if from == "3.0.0" && to == "2.0.0"
+    ));
+}
+
+#[test]
+fn test_unknown_entity_is_error() {
+    let json = r#"{"version":"1.0.0","data":{"id":"t1"}}"#;
+    let err = setup_migrator()
+        .load_upgrading_to_version::<TaskV2>("note", json, "2.0.0")
+        .unwrap_err();
+
+    assert!(matches!(err, MigrationError::EntityNotFound(_)));
+}
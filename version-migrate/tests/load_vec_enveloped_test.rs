@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "2.0.0")]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_vec_enveloped() {
+    let json = r#"{
+        "items": [
+            {"version": "1.0.0", "data": {"id": "t1"}},
+            {"version": "2.0.0", "data": {"id": "t2", "done": true}}
+        ],
+        "next_cursor": "abc",
+        "total": 2
+    }"#;
+
+    let tasks: Vec<TaskEntity> = setup_migrator()
+        .load_vec_enveloped("task", json, "items")
+        .unwrap();
+
+    assert_eq!(
+        tasks,
+        vec![
+            TaskEntity {
+                id: "t1".to_string(),
+                done: false,
+            },
+            TaskEntity {
+                id: "t2".to_string(),
+                done: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_load_vec_enveloped_missing_items_key() {
+    let json = r#"{"results": [], "next_cursor": null}"#;
+
+    let err = setup_migrator()
+        .load_vec_enveloped::<TaskEntity>("task", json, "items")
+        .unwrap_err();
+
+    match err {
+        MigrationError::DeserializationError(msg) => {
+            assert!(msg.contains("Missing 'items'"), "unexpected message: {msg}")
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn test_load_vec_enveloped_items_not_array() {
+    let json = r#"{"items": {"version": "1.0.0", "data": {"id": "t1"}}}"#;
+
+    let err = setup_migrator()
+        .load_vec_enveloped::<TaskEntity>("task", json, "items")
+        .unwrap_err();
+    assert!(matches!(err, MigrationError::DeserializationError(msg) if msg.contains("array")));
+}
+
+#[test]
+fn test_load_vec_enveloped_reports_failing_element() {
+    let json = r#"{"items": [{"version": "1.0.0", "data": {"id": "t1"}}, {"data": {}}]}"#;
+
+    let err = setup_migrator()
+        .load_vec_enveloped::<TaskEntity>("task", json, "items")
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        MigrationError::ElementFailed { index: 1, .. }
+    ));
+}
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::cell::RefCell;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+thread_local! {
+    // Steps applied on this test's thread, as "<from>:<id>".
+    static STEPS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(from: &str, id: &str) {
+    STEPS.with(|steps| steps.borrow_mut().push(format!("{from}:{id}")));
+}
+
+fn take_steps() -> Vec<String> {
+    STEPS.with(|steps| steps.take())
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV3 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV3 {
+    const VERSION: &'static str = "3.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        record("1", &self.id);
+        TaskV2 {
+            title: format!("untitled {}", self.id),
+            id: self.id,
+        }
+    }
+}
+
+impl MigratesTo<TaskV3> for TaskV2 {
+    fn migrate(self) -> TaskV3 {
+        record("2", &self.id);
+        TaskV3 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV3 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .step::<TaskV3>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+fn mixed() -> Vec<serde_json::Value> {
+    vec![
+        json!({"version": "2.0.0", "data": {"id": "a", "title": "A"}}),
+        json!({"version": "1.0.0", "data": {"id": "b"}}),
+        json!({"version": "3.0.0", "data": {"id": "c", "title": "C", "done": true}}),
+        json!({"version": "1.0.0", "data": {"id": "d"}}),
+        json!({"version": "2.0.0", "data": {"id": "e", "title": "E"}}),
+        json!({"version": "1.0.0", "data": {"id": "f"}}),
+    ]
+}
+
+#[test]
+fn test_grouped_matches_load_vec_from() {
+    let migrator = setup_migrator();
+
+    let expected: Vec<TaskEntity> = migrator.load_vec_from("task", mixed()).unwrap();
+    let grouped: Vec<TaskEntity> = migrator.load_vec_grouped("task", mixed()).unwrap();
+
+    assert_eq!(grouped, expected);
+    let ids: Vec<&str> = grouped.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["a", "b", "c", "d", "e", "f"]);
+    assert_eq!(grouped[1].title, "untitled b");
+    assert!(grouped[2].done);
+}
+
+#[test]
+fn test_lowest_failing_index_is_reported() {
+    let migrator = setup_migrator();
+    let mut items = mixed();
+    // Index 4 fails during migration, index 3 while extracting the version.
+    items[4] = json!({"version": "2.0.0", "data": {"id": "e"}});
+    items[3] = json!({"data": {"id": "d"}});
+
+    let result: Result<Vec<TaskEntity>, _> = migrator.load_vec_grouped("task", items);
+    assert!(matches!(
+        result,
+        Err(MigrationError::ElementFailed { index: 3, .. })
+    ));
+}
+
+#[test]
+fn test_each_step_runs_over_the_whole_bucket() {
+    let migrator = setup_migrator();
+
+    let _: Vec<TaskEntity> = migrator.load_vec_grouped("task", mixed()).unwrap();
+    assert_eq!(
+        take_steps(),
+        ["1:b", "1:d", "1:f", "2:b", "2:d", "2:f", "2:a", "2:e"]
+    );
+}
+
+#[test]
+fn test_stops_at_first_failure() {
+    let migrator = setup_migrator();
+    let mut items = mixed();
+    // "d" fails the first step of the 1.0.0 bucket: "f" and the 2.0.0 bucket
+    // are never migrated.
+    items[3] = json!({"version": "1.0.0", "data": {"id": 3}});
+
+    let result: Result<Vec<TaskEntity>, _> = migrator.load_vec_grouped("task", items);
+    assert!(matches!(
+        result,
+        Err(MigrationError::ElementFailed { index: 3, .. })
+    ));
+    assert_eq!(take_steps(), ["1:b"]);
+}
+
+#[test]
+fn test_resolved_versions_share_one_bucket() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step_range::<TaskV2>("^1.0")
+                .step::<TaskV3>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+    migrator
+        .register_version_alias("task", "v1", "1.0.0")
+        .unwrap();
+    // "1.0.5" (via the range) and "v1" (via the alias) both resolve to 1.0.0.
+    let items = vec![
+        json!({"version": "1.0.0", "data": {"id": "b"}}),
+        json!({"version": "2.0.0", "data": {"id": "a", "title": "A"}}),
+        json!({"version": "1.0.5", "data": {"id": "x"}}),
+        json!({"version": "v1", "data": {"id": "y"}}),
+    ];
+
+    let loaded: Vec<TaskEntity> = migrator.load_vec_grouped("task", items).unwrap();
+    assert_eq!(loaded[2].title, "untitled x");
+    assert_eq!(
+        take_steps(),
+        ["1:b", "1:x", "1:y", "2:b", "2:x", "2:y", "2:a"]
+    );
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct LogV9 {
+    id: String,
+}
+
+impl Versioned for LogV9 {
+    const VERSION: &'static str = "9.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct LogV10 {
+    id: String,
+    level: u8,
+}
+
+impl Versioned for LogV10 {
+    const VERSION: &'static str = "10.0.0";
+}
+
+impl MigratesTo<LogV10> for LogV9 {
+    fn migrate(self) -> LogV10 {
+        LogV10 {
+            id: self.id,
+            level: 0,
+        }
+    }
+}
+
+impl IntoDomain<LogV10> for LogV10 {
+    fn into_domain(self) -> LogV10 {
+        self
+    }
+}
+
+#[test]
+fn test_buckets_run_in_semver_order() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("log")
+                .from::<LogV9>()
+                .step::<LogV10>()
+                .into::<LogV10>(),
+        )
+        .unwrap();
+    // Both elements are broken. "9.0.0" is older than "10.0.0" although it
+    // sorts after it as a string, so its bucket runs and fails first.
+    let items = vec![
+        json!({"version": "10.0.0", "data": {"id": "a"}}),
+        json!({"version": "9.0.0", "data": {"id": 1}}),
+    ];
+
+    let result: Result<Vec<LogV10>, _> = migrator.load_vec_grouped("log", items);
+    assert!(matches!(
+        result,
+        Err(MigrationError::ElementFailed { index: 1, .. })
+    ));
+}
+
+#[test]
+fn test_unknown_entity() {
+    let migrator = setup_migrator();
+    let result: Result<Vec<TaskEntity>, _> = migrator.load_vec_grouped("user", mixed());
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_unknown_field_is_reported_with_domain_value() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"2.0.0","data":{"id":"task-1","done":true,"future_field":{"x":1}}}"#;
+
+    let (task, extras): (TaskEntity, _) = migrator.load_with_extras("task", json).unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "task-1".to_string(),
+            done: true,
+        }
+    );
+    assert_eq!(extras.len(), 1);
+    assert_eq!(extras["future_field"], serde_json::json!({"x": 1}));
+}
+
+#[test]
+fn test_extras_are_checked_against_the_stored_version() {
+    let migrator = setup_migrator();
+
+    // `done` belongs to 2.0.0, so a 1.0.0 reader does not consume it
+    let json = r#"{"version":"1.0.0","data":{"id":"task-1","done":true}}"#;
+    let (task, extras): (TaskEntity, _) = migrator.load_with_extras("task", json).unwrap();
+    assert!(!task.done);
+    assert_eq!(extras.keys().collect::<Vec<_>>(), vec!["done"]);
+
+    let json = r#"{"version":"1.0.0","data":{"id":"task-1"}}"#;
+    let (_, extras): (TaskEntity, _) = migrator.load_with_extras("task", json).unwrap();
+    assert!(extras.is_empty());
+}
+
+#[test]
+fn test_unknown_entity() {
+    let migrator = setup_migrator();
+    let result: Result<(TaskEntity, _), _> =
+        migrator.load_with_extras("user", r#"{"version":"1.0.0","data":{"id":"a"}}"#);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
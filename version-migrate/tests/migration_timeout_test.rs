@@ -0,0 +1,129 @@
+#![cfg(feature = "async")]
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct JobV1 {
+    id: String,
+}
+
+impl Versioned for JobV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct JobV2 {
+    id: String,
+    retries: u32,
+}
+
+impl Versioned for JobV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Job {
+    id: String,
+    retries: u32,
+}
+
+impl MigratesTo<JobV2> for JobV1 {
+    fn migrate(self) -> JobV2 {
+        // Stands in for a slow external lookup.
+        std::thread::sleep(Duration::from_millis(200));
+        JobV2 {
+            id: self.id,
+            retries: 3,
+        }
+    }
+}
+
+impl IntoDomain<Job> for JobV2 {
+    fn into_domain(self) -> Job {
+        Job {
+            id: self.id,
+            retries: self.retries,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("job")
+        .from::<JobV1>()
+        .step::<JobV2>()
+        .into::<Job>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+const V1_JSON: &str = r#"{"version":"1.0.0","data":{"id":"job-1"}}"#;
+
+#[tokio::test]
+async fn test_slow_step_times_out() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_migration_timeout("job", Duration::from_millis(100))
+        .unwrap();
+
+    let result: Result<Job, _> = migrator.load_async("job", V1_JSON).await;
+    match result {
+        Err(MigrationError::Timeout {
+            entity,
+            version,
+            elapsed_ms,
+        }) => {
+            assert_eq!(entity, "job");
+            assert_eq!(version, "1.0.0");
+            assert!(elapsed_ms >= 100, "elapsed {}", elapsed_ms);
+        }
+        other => panic!("expected Timeout, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_step_within_timeout_completes() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_migration_timeout("job", Duration::from_secs(5))
+        .unwrap();
+
+    let job: Job = migrator.load_async("job", V1_JSON).await.unwrap();
+    assert_eq!(
+        job,
+        Job {
+            id: "job-1".to_string(),
+            retries: 3,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_load_async_without_timeout_matches_load() {
+    let migrator = setup_migrator();
+
+    let job: Job = migrator.load_async("job", V1_JSON).await.unwrap();
+    assert_eq!(job, migrator.load::<Job>("job", V1_JSON).unwrap());
+}
+
+#[tokio::test]
+async fn test_latest_version_needs_no_step() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_migration_timeout("job", Duration::from_millis(1))
+        .unwrap();
+
+    let json = r#"{"version":"2.0.0","data":{"id":"job-2","retries":1}}"#;
+    let job: Job = migrator.load_async("job", json).await.unwrap();
+    assert_eq!(job.retries, 1);
+}
+
+#[test]
+fn test_register_timeout_for_unknown_entity() {
+    let mut migrator = setup_migrator();
+    let result = migrator.register_migration_timeout("unknown", Duration::from_secs(1));
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+// Child entity: item
+
+thread_local! {
+    // Number of items migrated on this test's thread.
+    static ITEM_MIGRATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ItemV1 {
+    name: String,
+}
+
+impl Versioned for ItemV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ItemV2 {
+    name: String,
+    enabled: bool,
+}
+
+impl Versioned for ItemV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+impl MigratesTo<ItemV2> for ItemV1 {
+    fn migrate(self) -> ItemV2 {
+        ITEM_MIGRATIONS.with(|n| n.set(n.get() + 1));
+        ItemV2 {
+            name: self.name,
+            enabled: true,
+        }
+    }
+}
+
+impl IntoDomain<ItemV2> for ItemV2 {
+    fn into_domain(self) -> ItemV2 {
+        self
+    }
+}
+
+// Parent entity: config
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ConfigV1 {
+    title: String,
+    items: Vec<ItemV1>,
+}
+
+impl Versioned for ConfigV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ConfigV2 {
+    title: String,
+    items: Vec<ItemV2>,
+}
+
+impl Versioned for ConfigV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ConfigEntity {
+    title: String,
+    items: Vec<ItemV2>,
+}
+
+impl MigratesTo<ConfigV2> for ConfigV1 {
+    fn migrate(self) -> ConfigV2 {
+        // Items are migrated by the registered "item" path.
+        ConfigV2 {
+            title: self.title,
+            items: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<ConfigEntity> for ConfigV2 {
+    fn into_domain(self) -> ConfigEntity {
+        ConfigEntity {
+            title: self.title,
+            items: self.items,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let item_path = Migrator::define("item")
+        .from::<ItemV1>()
+        .step::<ItemV2>()
+        .into::<ItemV2>();
+    let config_path = Migrator::define("config")
+        .from::<ConfigV1>()
+        .step::<ConfigV2>()
+        .into::<ConfigEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(item_path).unwrap();
+    migrator.register(config_path).unwrap();
+    migrator
+}
+
+const CONFIG_V1: &str = r#"{
+    "version": "1.0.0",
+    "data": {"title": "main", "items": [{"name": "a"}, {"name": "b"}]}
+}"#;
+
+#[test]
+fn test_children_are_migrated_by_nested_path() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_nested_collection(
+            "config",
+            "items",
+            "item",
+            &[("1.0.0", "1.0.0"), ("2.0.0", "2.0.0")],
+        )
+        .unwrap();
+
+    let config: ConfigEntity = migrator.load("config", CONFIG_V1).unwrap();
+    assert_eq!(config.title, "main");
+    assert_eq!(
+        config.items,
+        vec![
+            ItemV2 {
+                name: "a".to_string(),
+                enabled: true,
+            },
+            ItemV2 {
+                name: "b".to_string(),
+                enabled: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_without_registration_parent_step_output_is_kept() {
+    let migrator = setup_migrator();
+
+    let config: ConfigEntity = migrator.load("config", CONFIG_V1).unwrap();
+    assert!(config.items.is_empty());
+}
+
+#[test]
+fn test_latest_parent_version_is_untouched() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_nested_collection(
+            "config",
+            "items",
+            "item",
+            &[("1.0.0", "1.0.0"), ("2.0.0", "2.0.0")],
+        )
+        .unwrap();
+    let json = r#"{"version":"2.0.0","data":{"title":"t","items":[{"name":"c","enabled":false}]}}"#;
+
+    let config: ConfigEntity = migrator.load("config", json).unwrap();
+    assert!(!config.items[0].enabled);
+}
+
+#[test]
+fn test_registering_same_field_again_replaces_delegation() {
+    let mut migrator = setup_migrator();
+    // The first registration says items are already at 2.0.0 and would copy
+    // them unmigrated; the second replaces it rather than running alongside.
+    migrator
+        .register_nested_collection(
+            "config",
+            "items",
+            "item",
+            &[("1.0.0", "2.0.0"), ("2.0.0", "2.0.0")],
+        )
+        .unwrap();
+    migrator
+        .register_nested_collection(
+            "config",
+            "items",
+            "item",
+            &[("1.0.0", "1.0.0"), ("2.0.0", "2.0.0")],
+        )
+        .unwrap();
+    migrator
+        .register_nested_collection(
+            "config",
+            "items",
+            "item",
+            &[("1.0.0", "1.0.0"), ("2.0.0", "2.0.0")],
+        )
+        .unwrap();
+
+    let config: ConfigEntity = migrator.load("config", CONFIG_V1).unwrap();
+    assert!(config.items.iter().all(|item| item.enabled));
+    // Each item is migrated once, not once per registration.
+    assert_eq!(ITEM_MIGRATIONS.with(Cell::get), 2);
+}
+
+#[test]
+fn test_register_nested_collection_errors() {
+    let mut migrator = setup_migrator();
+
+    let result = migrator.register_nested_collection("config", "items", "missing", &[]);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(e)) if e == "missing"));
+
+    let result =
+        migrator.register_nested_collection("config", "items", "item", &[("1.0.0", "9.0.0")]);
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { entity, version })
+            if entity == "item" && version == "9.0.0"
+    ));
+
+    let result = migrator.register_nested_collection(
+        "config",
+        "items",
+        "item",
+        &[("1.0.0", "2.0.0"), ("2.0.0", "1.0.0")],
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidVersionOrder { .. })
+    ));
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    IntoDomain, MigratesTo, MigrationError, Migrator, NullHandlingMode, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    description: Option<String>,
+    #[serde(default)]
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    description: Option<String>,
+    title: String,
+    priority: Option<u32>,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    description: Option<String>,
+    title: String,
+    priority: Option<u32>,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            description: self.description,
+            title: self.title,
+            priority: None,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            description: self.description,
+            title: self.title,
+            priority: self.priority,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_strict_null_is_default() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"1.0.0","data":{"id":"t1","description":"","title":"a"}}"#;
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.description, Some(String::new()));
+}
+
+#[test]
+fn test_empty_string_as_none() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_null_handling("task", NullHandlingMode::EmptyStringAsNone)
+        .unwrap();
+    let json = r#"{"version":"1.0.0","data":{"id":"t1","description":"","title":"a"}}"#;
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.description, None);
+    assert_eq!(task.title, "a");
+}
+
+#[test]
+fn test_empty_string_as_none_applies_to_latest_version() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_null_handling("task", NullHandlingMode::EmptyStringAsNone)
+        .unwrap();
+    let json =
+        r#"{"version":"2.0.0","data":{"id":"t1","description":"","title":"a","priority":null}}"#;
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.description, None);
+}
+
+#[test]
+fn test_zero_as_none() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_null_handling("task", NullHandlingMode::ZeroAsNone)
+        .unwrap();
+    let json =
+        r#"{"version":"2.0.0","data":{"id":"t1","description":"","title":"a","priority":0}}"#;
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.priority, None);
+    assert_eq!(task.description, Some(String::new()));
+}
+
+#[test]
+fn test_null_or_empty_string_as_none_removes_fields() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_null_handling("task", NullHandlingMode::NullOrEmptyStringAsNone)
+        .unwrap();
+    // `title` is null, which only a missing field (and its serde default) can satisfy.
+    let json = r#"{"version":"1.0.0","data":{"id":"t1","description":"","title":null}}"#;
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.description, None);
+    assert_eq!(task.title, "");
+}
+
+#[test]
+fn test_set_null_handling_unknown_entity() {
+    let mut migrator = setup_migrator();
+    let result = migrator.set_null_handling("missing", NullHandlingMode::EmptyStringAsNone);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(e)) if e == "missing"));
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_numeric_version_without_alias_is_a_precise_error() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":2,"data":{"id":"a","done":true}}"#;
+
+    let result: Result<TaskEntity, _> = migrator.load("task", json);
+    let Err(MigrationError::DeserializationError(msg)) = result else {
+        panic!("expected DeserializationError, got {:?}", result);
+    };
+    assert!(msg.contains("'version' field is a number (2)"), "{}", msg);
+}
+
+#[test]
+fn test_missing_and_invalid_version_errors_differ() {
+    let migrator = setup_migrator();
+
+    let missing: Result<TaskEntity, _> = migrator.load("task", r#"{"data":{"id":"a"}}"#);
+    assert!(matches!(
+        missing,
+        Err(MigrationError::DeserializationError(msg)) if msg == "Missing 'version' field"
+    ));
+
+    let invalid: Result<TaskEntity, _> =
+        migrator.load("task", r#"{"version":true,"data":{"id":"a"}}"#);
+    assert!(matches!(
+        invalid,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("Invalid 'version' field type")
+    ));
+}
+
+#[test]
+fn test_numeric_version_with_alias_is_migrated() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_version_alias("task", "1", "1.0.0")
+        .unwrap();
+    migrator
+        .register_version_alias("task", "2", "2.0.0")
+        .unwrap();
+
+    let from_v1: TaskEntity = migrator
+        .load("task", r#"{"version":1,"data":{"id":"a"}}"#)
+        .unwrap();
+    assert_eq!(
+        from_v1,
+        TaskEntity {
+            id: "a".to_string(),
+            done: false,
+        }
+    );
+
+    let from_v2: TaskEntity = migrator
+        .load("task", r#"{"version":2,"data":{"id":"b","done":true}}"#)
+        .unwrap();
+    assert!(from_v2.done);
+}
+
+#[test]
+fn test_alias_applies_to_flat_format_and_strings() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_version_alias("task", "1", "1.0.0")
+        .unwrap();
+    migrator
+        .register_version_alias("task", "v1", "1.0.0")
+        .unwrap();
+
+    let numeric: TaskEntity = migrator
+        .load_flat("task", r#"{"version":1,"id":"a"}"#)
+        .unwrap();
+    assert_eq!(numeric.id, "a");
+
+    let named: TaskEntity = migrator
+        .load("task", r#"{"version":"v1","data":{"id":"b"}}"#)
+        .unwrap();
+    assert!(!named.done);
+}
+
+#[test]
+fn test_register_version_alias_errors() {
+    let mut migrator = setup_migrator();
+
+    let result = migrator.register_version_alias("user", "1", "1.0.0");
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+
+    let result = migrator.register_version_alias("task", "3", "3.0.0");
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { version, .. }) if version == "3.0.0"
+    ));
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{passthrough, IntoDomain, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "3.1.0")]
+struct SettingsV3 {
+    theme: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    theme: String,
+}
+
+impl IntoDomain<Settings> for SettingsV3 {
+    fn into_domain(self) -> Settings {
+        Settings { theme: self.theme }
+    }
+}
+
+#[test]
+fn test_register_passthrough_loads_single_version() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register_passthrough::<SettingsV3, Settings>("settings")
+        .unwrap();
+
+    let settings: Settings = migrator
+        .load("settings", r#"{"version":"3.1.0","data":{"theme":"dark"}}"#)
+        .unwrap();
+
+    assert_eq!(
+        settings,
+        Settings {
+            theme: "dark".to_string(),
+        }
+    );
+    assert_eq!(migrator.get_latest_version("settings"), Some("3.1.0"));
+}
+
+#[test]
+fn test_passthrough_macro() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(passthrough!("settings", SettingsV3, Settings))
+        .unwrap();
+
+    let settings: Settings = migrator
+        .load_flat("settings", r#"{"version":"3.1.0","theme":"light"}"#)
+        .unwrap();
+    assert_eq!(settings.theme, "light");
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    ConfigMigrator, DeriveQueryable as Queryable, IntoDomain, Migrator, Queryable as _,
+    QueryableKey, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Queryable)]
+#[queryable(entity = "task", key = "open_tasks")]
+struct TaskEntity {
+    id: String,
+    title: String,
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+fn setup_config() -> ConfigMigrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let config_json = r#"{
+        "open_tasks": [{"version": "1.0.0", "id": "1", "title": "Open"}],
+        "tasks": [{"version": "1.0.0", "id": "2", "title": "Other"}]
+    }"#;
+    ConfigMigrator::from(config_json, migrator).unwrap()
+}
+
+#[test]
+fn test_derive_sets_entity_and_config_key() {
+    assert_eq!(TaskEntity::ENTITY_NAME, "task");
+    assert_eq!(TaskEntity::CONFIG_KEY, "open_tasks");
+}
+
+#[test]
+fn test_query_default_reads_config_key() {
+    let config = setup_config();
+    let tasks: Vec<TaskEntity> = config.query_default().unwrap();
+
+    assert_eq!(
+        tasks,
+        vec![TaskEntity {
+            id: "1".to_string(),
+            title: "Open".to_string(),
+        }]
+    );
+
+    // An explicit key still works
+    let other: Vec<TaskEntity> = config.query("tasks").unwrap();
+    assert_eq!(other[0].id, "2");
+}
+
+#[test]
+fn test_update_default_writes_config_key() {
+    let mut config = setup_config();
+    config
+        .update_default(vec![TaskEntity {
+            id: "3".to_string(),
+            title: "New".to_string(),
+        }])
+        .unwrap();
+
+    let value = config.as_value();
+    assert_eq!(value["open_tasks"][0]["id"], "3");
+    assert_eq!(value["open_tasks"][0]["version"], "1.0.0");
+    assert_eq!(value["tasks"][0]["id"], "2");
+}
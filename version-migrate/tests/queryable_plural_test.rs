@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    ConfigMigrator, IntoDomain, Migrator, Queryable as _, QueryableKey, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Versioned)]
+#[versioned(
+    version = "1.0.0",
+    queryable = true,
+    queryable_key = "task",
+    queryable_plural = true
+)]
+struct TaskEntity {
+    id: String,
+    title: String,
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(
+    version = "1.0.0",
+    queryable = true,
+    queryable_key = "category",
+    queryable_plural = true
+)]
+struct Category {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(
+    version = "1.0.0",
+    queryable = true,
+    queryable_key = "inbox",
+    queryable_plural = true
+)]
+struct Inbox {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(
+    version = "1.0.0",
+    queryable = true,
+    queryable_key = "key",
+    queryable_plural = true
+)]
+struct Key {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0", queryable = true, queryable_plural = true)]
+struct Branch {
+    id: String,
+}
+
+#[test]
+fn test_plural_config_key_is_generated() {
+    assert_eq!(TaskEntity::ENTITY_NAME, "task");
+    assert_eq!(TaskEntity::CONFIG_KEY, "tasks");
+}
+
+#[test]
+fn test_plural_suffix_rules() {
+    assert_eq!(Category::CONFIG_KEY, "categories");
+    assert_eq!(Inbox::CONFIG_KEY, "inboxes");
+    assert_eq!(Key::CONFIG_KEY, "keys");
+    // Without queryable_key the lowercased type name is pluralized
+    assert_eq!(Branch::ENTITY_NAME, "branch");
+    assert_eq!(Branch::CONFIG_KEY, "branches");
+}
+
+#[test]
+fn test_query_default_reads_plural_key() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let config_json = r#"{"tasks": [{"version": "1.0.0", "id": "1", "title": "Plural"}]}"#;
+    let config = ConfigMigrator::from(config_json, migrator).unwrap();
+
+    let tasks: Vec<TaskEntity> = config.query_default().unwrap();
+    assert_eq!(
+        tasks,
+        vec![TaskEntity {
+            id: "1".to_string(),
+            title: "Plural".to_string(),
+        }]
+    );
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(version = "1.0.0")]
+struct CategoryV1 {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned)]
+#[versioned(
+    version = "2.0.0",
+    queryable = true,
+    queryable_key = "category",
+    queryable_plural = true
+)]
+struct CategoryV2 {
+    name: String,
+    #[serde(default)]
+    hidden: bool,
+}
+
+impl version_migrate::MigratesTo<CategoryV2> for CategoryV1 {
+    fn migrate(self) -> CategoryV2 {
+        CategoryV2 {
+            name: self.name,
+            hidden: false,
+        }
+    }
+}
+
+impl IntoDomain<CategoryV2> for CategoryV2 {
+    fn into_domain(self) -> CategoryV2 {
+        self
+    }
+}
+
+#[test]
+fn test_plural_config_key_resolves_to_entity() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("category")
+                .from::<CategoryV1>()
+                .step::<CategoryV2>()
+                .into::<CategoryV2>(),
+        )
+        .unwrap();
+
+    let json = format!(
+        r#"{{"{}": [{{"version": "1.0.0", "name": "inbox"}}]}}"#,
+        CategoryV2::CONFIG_KEY
+    );
+    let map = migrator.load_all_to_json_map(&json).unwrap();
+
+    assert_eq!(map["categories"][0]["version"], "2.0.0");
+}
@@ -0,0 +1,238 @@
+#![cfg(feature = "registry")]
+
+//! Config-driven registration. The `tasks` module stands in for the crate that
+//! owns the entity types and submits their migration paths; the tests play
+//! the application that applies a config file.
+
+use std::path::PathBuf;
+use tempfile::TempDir;
+use version_migrate::{MigrationError, Migrator, MigratorRegistrationPlan, PlannedEntity};
+
+mod tasks {
+    use serde::{Deserialize, Serialize};
+    use version_migrate::{FromDomain, IntoDomain, MigratesTo, Migrator, Versioned};
+
+    #[derive(Serialize, Deserialize, Versioned)]
+    #[versioned(version = "1.0.0")]
+    pub struct TaskV1 {
+        pub id: String,
+    }
+
+    #[derive(Serialize, Deserialize, Versioned)]
+    #[versioned(version = "2.0.0")]
+    pub struct TaskV2 {
+        pub id: String,
+        pub done: bool,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TaskEntity {
+        pub id: String,
+        pub done: bool,
+    }
+
+    impl MigratesTo<TaskV2> for TaskV1 {
+        fn migrate(self) -> TaskV2 {
+            TaskV2 {
+                id: self.id,
+                done: false,
+            }
+        }
+    }
+
+    impl IntoDomain<TaskEntity> for TaskV2 {
+        fn into_domain(self) -> TaskEntity {
+            TaskEntity {
+                id: self.id,
+                done: self.done,
+            }
+        }
+    }
+
+    impl FromDomain<TaskEntity> for TaskV2 {
+        fn from_domain(domain: TaskEntity) -> Self {
+            TaskV2 {
+                id: domain.id,
+                done: domain.done,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    pub struct NoteEntity {
+        pub text: String,
+    }
+
+    #[derive(Serialize, Deserialize, Versioned)]
+    #[versioned(version = "1.0.0")]
+    pub struct NoteV1 {
+        pub text: String,
+    }
+
+    impl IntoDomain<NoteEntity> for NoteV1 {
+        fn into_domain(self) -> NoteEntity {
+            NoteEntity { text: self.text }
+        }
+    }
+
+    version_migrate::submit_registration!("task", TaskEntity, || {
+        Migrator::define("task")
+            .from::<TaskV1>()
+            .step::<TaskV2>()
+            .into_with_save::<TaskEntity>()
+    });
+
+    version_migrate::submit_registration!("note", NoteEntity, || {
+        Migrator::define("note")
+            .from::<NoteV1>()
+            .into::<NoteEntity>()
+    });
+}
+
+fn write_config(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_register_from_toml_config() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(
+        &dir,
+        "migrations.toml",
+        r#"
+            [[entity]]
+            name = "task"
+            type = "tasks::TaskEntity"
+            versions = ["1.0.0", "2.0.0"]
+
+            [[entity]]
+            name = "note"
+        "#,
+    );
+
+    let plan = Migrator::register_from_config(&path).unwrap();
+    assert_eq!(plan.entities.len(), 2);
+    assert_eq!(plan.entities[1].type_path, None);
+
+    let mut migrator = Migrator::new();
+    plan.apply(&mut migrator).unwrap();
+
+    let task: tasks::TaskEntity = migrator
+        .load("task", r#"{"version":"1.0.0","data":{"id":"t1"}}"#)
+        .unwrap();
+    assert_eq!(
+        task,
+        tasks::TaskEntity {
+            id: "t1".to_string(),
+            done: false,
+        }
+    );
+    assert!(migrator.save_domain("task", task).is_ok());
+
+    let note: tasks::NoteEntity = migrator
+        .load("note", r#"{"version":"1.0.0","data":{"text":"hi"}}"#)
+        .unwrap();
+    assert_eq!(note.text, "hi");
+}
+
+#[test]
+fn test_register_from_json_config_registers_only_listed_entities() {
+    let dir = TempDir::new().unwrap();
+    let path = write_config(
+        &dir,
+        "migrations.json",
+        r#"{"entity": [{"name": "note", "type": "NoteEntity", "versions": ["1.0.0"]}]}"#,
+    );
+
+    let mut migrator = Migrator::new();
+    Migrator::register_from_config(&path)
+        .unwrap()
+        .apply(&mut migrator)
+        .unwrap();
+
+    assert_eq!(migrator.get_latest_version("note"), Some("1.0.0"));
+    assert_eq!(migrator.get_latest_version("task"), None);
+}
+
+#[test]
+fn test_apply_rejects_version_chain_mismatch() {
+    let plan = MigratorRegistrationPlan {
+        entities: vec![PlannedEntity {
+            name: "task".to_string(),
+            type_path: None,
+            versions: vec!["1.0.0".to_string(), "3.0.0".to_string()],
+        }],
+    };
+
+    let err = plan.apply(&mut Migrator::new()).unwrap_err();
+    match err {
+        MigrationError::VersionChainMismatch {
+            entity,
+            registered,
+            declared,
+        } => {
+            assert_eq!(entity, "task");
+            assert_eq!(registered, ["1.0.0", "2.0.0"]);
+            assert_eq!(declared, ["1.0.0", "3.0.0"]);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn test_failed_apply_leaves_migrator_untouched() {
+    let plan = MigratorRegistrationPlan {
+        entities: vec![
+            PlannedEntity {
+                name: "note".to_string(),
+                type_path: None,
+                versions: vec!["1.0.0".to_string()],
+            },
+            PlannedEntity {
+                name: "task".to_string(),
+                type_path: None,
+                versions: vec!["9.9.9".to_string()],
+            },
+        ],
+    };
+
+    let mut migrator = Migrator::new();
+    assert!(plan.apply(&mut migrator).is_err());
+    assert!(migrator.is_empty());
+}
+
+#[test]
+fn test_apply_rejects_unknown_entity_or_type() {
+    let unknown_entity = MigratorRegistrationPlan {
+        entities: vec![PlannedEntity {
+            name: "user".to_string(),
+            type_path: None,
+            versions: Vec::new(),
+        }],
+    };
+    assert!(matches!(
+        unknown_entity.apply(&mut Migrator::new()),
+        Err(MigrationError::EntityNotFound(_))
+    ));
+
+    let wrong_type = MigratorRegistrationPlan {
+        entities: vec![PlannedEntity {
+            name: "task".to_string(),
+            type_path: Some("NoteEntity".to_string()),
+            versions: Vec::new(),
+        }],
+    };
+    assert!(matches!(
+        wrong_type.apply(&mut Migrator::new()),
+        Err(MigrationError::EntityNotFound(_))
+    ));
+}
+
+#[test]
+fn test_register_from_config_missing_file() {
+    let dir = TempDir::new().unwrap();
+    let result = Migrator::register_from_config(&dir.path().join("missing.toml"));
+    assert!(matches!(result, Err(MigrationError::Store(_))));
+}
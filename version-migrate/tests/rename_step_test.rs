@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV3 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV3 {
+    const VERSION: &'static str = "3.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV3> for TaskV2 {
+    fn migrate(self) -> TaskV3 {
+        TaskV3 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV3 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .rename_step("0.9.0", "1.0.0", &[("key", "id")])
+        .rename_step("1.0.0", "2.0.0", &[("name", "title")])
+        .from::<TaskV2>()
+        .step::<TaskV3>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_rename_step_produces_new_shape() {
+    let migrator = setup_migrator();
+
+    let task: TaskEntity = migrator
+        .load(
+            "task",
+            r#"{"version":"1.0.0","data":{"id":"task-1","name":"Write docs"}}"#,
+        )
+        .unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "task-1".to_string(),
+            title: "Write docs".to_string(),
+            done: false,
+        }
+    );
+}
+
+#[test]
+fn test_rename_steps_chain_from_oldest_version() {
+    let migrator = setup_migrator();
+
+    let task: TaskEntity = migrator
+        .load_flat("task", r#"{"version":"0.9.0","key":"task-0","name":"Old"}"#)
+        .unwrap();
+    assert_eq!(task.id, "task-0");
+    assert_eq!(task.title, "Old");
+    assert_eq!(migrator.get_latest_version("task"), Some("3.0.0"));
+}
+
+#[test]
+fn test_gap_between_rename_and_typed_version_is_rejected() {
+    let path = Migrator::define("task")
+        .rename_step("1.0.0", "1.5.0", &[("name", "title")])
+        .from::<TaskV2>()
+        .step::<TaskV3>()
+        .into::<TaskEntity>();
+
+    let result = Migrator::new().register(path);
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { version, .. }) if version == "1.5.0"
+    ));
+}
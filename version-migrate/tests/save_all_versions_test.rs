@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV3 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV3 {
+    const VERSION: &'static str = "3.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: String::new(),
+        }
+    }
+}
+
+impl MigratesTo<TaskV3> for TaskV2 {
+    fn migrate(self) -> TaskV3 {
+        TaskV3 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl MigratesTo<TaskV2> for TaskV3 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+impl MigratesTo<TaskV1> for TaskV2 {
+    fn migrate(self) -> TaskV1 {
+        TaskV1 { id: self.id }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV3 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        let v2: TaskV2 = self.migrate();
+        let v3: TaskV3 = v2.migrate();
+        v3.into_domain()
+    }
+}
+
+impl IntoDomain<TaskV1> for TaskV1 {
+    fn into_domain(self) -> TaskV1 {
+        self
+    }
+}
+
+fn forward_path() -> version_migrate::MigrationPath<TaskEntity> {
+    Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .step::<TaskV3>()
+        .into::<TaskEntity>()
+}
+
+fn setup_forward_only() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator.register(forward_path()).unwrap();
+    migrator
+}
+
+fn setup_bidirectional() -> Migrator {
+    let backward = Migrator::define("task")
+        .from::<TaskV3>()
+        .step::<TaskV2>()
+        .step::<TaskV1>()
+        .into::<TaskV1>();
+
+    let mut migrator = Migrator::new();
+    migrator
+        .register_bidirectional(Migrator::bidirectional(forward_path(), backward))
+        .unwrap();
+    migrator
+}
+
+fn sample() -> TaskV3 {
+    TaskV3 {
+        id: "task-1".to_string(),
+        title: "Write docs".to_string(),
+        done: true,
+    }
+}
+
+#[test]
+fn test_forward_only_returns_current_version() {
+    let migrator = setup_forward_only();
+
+    let all = migrator.save_all_versions("task", sample()).unwrap();
+    assert_eq!(all.len(), 1);
+
+    let task: TaskEntity = migrator.load("task", &all["3.0.0"]).unwrap();
+    assert_eq!(task.title, "Write docs");
+    assert!(task.done);
+}
+
+#[test]
+fn test_backward_path_produces_every_version() {
+    let migrator = setup_bidirectional();
+
+    let all = migrator.save_all_versions("task", sample()).unwrap();
+    assert_eq!(all.len(), 3);
+
+    let v1: serde_json::Value = serde_json::from_str(&all["1.0.0"]).unwrap();
+    assert_eq!(
+        v1,
+        serde_json::json!({"version": "1.0.0", "data": {"id": "task-1"}})
+    );
+    let v2: serde_json::Value = serde_json::from_str(&all["2.0.0"]).unwrap();
+    assert_eq!(v2["data"]["title"], "Write docs");
+
+    // Each snapshot loads back through the forward chain.
+    let from_v2: TaskEntity = migrator.load("task", &all["2.0.0"]).unwrap();
+    assert_eq!(from_v2.title, "Write docs");
+    assert!(!from_v2.done);
+}
+
+#[test]
+fn test_older_data_skips_newer_versions() {
+    let migrator = setup_bidirectional();
+
+    let all = migrator
+        .save_all_versions(
+            "task",
+            TaskV2 {
+                id: "task-2".to_string(),
+                title: "Old".to_string(),
+            },
+        )
+        .unwrap();
+
+    let mut versions: Vec<_> = all.keys().cloned().collect();
+    versions.sort();
+    assert_eq!(versions, vec!["1.0.0".to_string(), "2.0.0".to_string()]);
+}
+
+#[test]
+fn test_unknown_entity_and_version() {
+    let migrator = setup_forward_only();
+
+    let result = migrator.save_all_versions("missing", sample());
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+
+    #[derive(Serialize)]
+    struct Other;
+    impl Versioned for Other {
+        const VERSION: &'static str = "9.0.0";
+    }
+    let result = migrator.save_all_versions("task", Other);
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { version, .. }) if version == "9.0.0"
+    ));
+}
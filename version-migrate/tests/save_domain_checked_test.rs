@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{FromDomain, IntoDomain, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+impl FromDomain<TaskEntity> for TaskV1 {
+    fn from_domain(entity: TaskEntity) -> Self {
+        TaskV1 {
+            id: entity.id,
+            title: entity.title,
+            done: entity.done,
+        }
+    }
+}
+
+/// Same shape as `TaskV1`, but its `FromDomain` drops `done`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct LossyTaskV1 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for LossyTaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+impl IntoDomain<TaskEntity> for LossyTaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+impl FromDomain<TaskEntity> for LossyTaskV1 {
+    fn from_domain(entity: TaskEntity) -> Self {
+        LossyTaskV1 {
+            id: entity.id,
+            title: entity.title,
+            done: false,
+        }
+    }
+}
+
+fn entity() -> TaskEntity {
+    TaskEntity {
+        id: "task-1".to_string(),
+        title: "Write docs".to_string(),
+        done: true,
+    }
+}
+
+#[test]
+fn test_inverse_pair_passes() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .into_with_save::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let json = migrator.save_domain_checked("task", &entity()).unwrap();
+    assert_eq!(json, migrator.save_domain("task", entity()).unwrap());
+}
+
+#[test]
+fn test_lossy_from_domain_fails() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<LossyTaskV1>()
+                .into_with_save::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let result = migrator.save_domain_checked("task", &entity());
+    let Err(MigrationError::RoundTripMismatch { entity, details }) = result else {
+        panic!("expected RoundTripMismatch, got {:?}", result);
+    };
+    assert_eq!(entity, "task");
+    assert_eq!(details, "'done': saved true but loaded false");
+}
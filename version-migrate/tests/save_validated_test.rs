@@ -0,0 +1,148 @@
+#![cfg(feature = "schema")]
+
+use serde::{Deserialize, Serialize, Serializer};
+use version_migrate::schemars::JsonSchema;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+/// Declares the `TaskV2` schema but serializes without `done`.
+#[derive(JsonSchema)]
+struct BrokenTaskV2 {
+    id: String,
+    #[allow(dead_code)]
+    done: bool,
+}
+
+impl Serialize for BrokenTaskV2 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut s = serializer.serialize_struct("BrokenTaskV2", 1)?;
+        s.serialize_field("id", &self.id)?;
+        s.end()
+    }
+}
+
+impl Versioned for BrokenTaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_valid_value_passes() {
+    let migrator = setup_migrator();
+    let task = TaskV2 {
+        id: "task-1".to_string(),
+        done: true,
+    };
+
+    let json = migrator.save_validated("task", task).unwrap();
+    assert_eq!(
+        json,
+        migrator
+            .save(TaskV2 {
+                id: "task-1".to_string(),
+                done: true,
+            })
+            .unwrap()
+    );
+
+    let loaded: TaskEntity = migrator.load("task", &json).unwrap();
+    assert!(loaded.done);
+}
+
+#[test]
+fn test_missing_required_field_fails_validation() {
+    let migrator = setup_migrator();
+    let task = BrokenTaskV2 {
+        id: "task-1".to_string(),
+        done: true,
+    };
+
+    let result = migrator.save_validated("task", task);
+    let Err(MigrationError::SchemaValidationFailed {
+        entity,
+        version,
+        details,
+    }) = result
+    else {
+        panic!("expected SchemaValidationFailed, got {:?}", result);
+    };
+    assert_eq!(entity, "task");
+    assert_eq!(version, "2.0.0");
+    assert!(details.contains("done"), "{}", details);
+}
+
+#[test]
+fn test_version_outside_chain_is_rejected() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let result = migrator.save_validated(
+        "task",
+        TaskV1 {
+            id: "task-1".to_string(),
+        },
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { .. })
+    ));
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use version_migrate::{migrate_path, IntoDomain, MigratesTo, Migrator, Versioned};
+use version_migrate::{
+    identity_into_domain, migrate_path, IntoDomain, MigratesTo, Migrator, Versioned,
+};
 
 // Simple test versions for Vec notation
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -262,6 +264,9 @@ struct UserEntity {
     updated_at: String,
 }
 
+// Identity conversions for versions that are also used as domain targets
+identity_into_domain!(V2, V3, V6, V7, V8, V9, V10);
+
 // Implement IntoDomain for migration chain
 impl IntoDomain<V2> for V1 {
     fn into_domain(self) -> V2 {
@@ -269,24 +274,12 @@ impl IntoDomain<V2> for V1 {
     }
 }
 
-impl IntoDomain<V2> for V2 {
-    fn into_domain(self) -> V2 {
-        self
-    }
-}
-
 impl IntoDomain<V3> for V2 {
     fn into_domain(self) -> V3 {
         self.migrate()
     }
 }
 
-impl IntoDomain<V3> for V3 {
-    fn into_domain(self) -> V3 {
-        self
-    }
-}
-
 impl IntoDomain<V4> for V3 {
     fn into_domain(self) -> V4 {
         self.migrate()
@@ -305,60 +298,30 @@ impl IntoDomain<V6> for V5 {
     }
 }
 
-impl IntoDomain<V6> for V6 {
-    fn into_domain(self) -> V6 {
-        self
-    }
-}
-
 impl IntoDomain<V7> for V6 {
     fn into_domain(self) -> V7 {
         self.migrate()
     }
 }
 
-impl IntoDomain<V7> for V7 {
-    fn into_domain(self) -> V7 {
-        self
-    }
-}
-
 impl IntoDomain<V8> for V7 {
     fn into_domain(self) -> V8 {
         self.migrate()
     }
 }
 
-impl IntoDomain<V8> for V8 {
-    fn into_domain(self) -> V8 {
-        self
-    }
-}
-
 impl IntoDomain<V9> for V8 {
     fn into_domain(self) -> V9 {
         self.migrate()
     }
 }
 
-impl IntoDomain<V9> for V9 {
-    fn into_domain(self) -> V9 {
-        self
-    }
-}
-
 impl IntoDomain<V10> for V9 {
     fn into_domain(self) -> V10 {
         self.migrate()
     }
 }
 
-impl IntoDomain<V10> for V10 {
-    fn into_domain(self) -> V10 {
-        self
-    }
-}
-
 impl IntoDomain<UserEntity> for V9 {
     fn into_domain(self) -> UserEntity {
         // V9 -> V10 -> UserEntity
@@ -544,4 +507,27 @@ mod tests {
         assert!(result.active); // Added in V5
         assert_eq!(result.tags, Vec::<String>::new()); // Added in V6
     }
+
+    #[test]
+    fn test_identity_into_domain_macro() {
+        let v2 = V2 {
+            id: "id-1".to_string(),
+            name: "Identity".to_string(),
+        };
+        let converted: V2 = v2.clone().into_domain();
+        assert_eq!(converted, v2);
+
+        // The generated impl is what lets a path finish on its own latest version.
+        let path = migrate_path!("test", [V1, V2]);
+        let mut migrator = Migrator::new();
+        migrator.register(path).unwrap();
+
+        let json = migrator
+            .save(V1 {
+                id: "id-2".to_string(),
+            })
+            .unwrap();
+        let loaded: V2 = migrator.load("test", &json).unwrap();
+        assert_eq!(loaded.id, "id-2");
+    }
 }
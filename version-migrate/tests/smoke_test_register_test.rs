@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, TryIntoDomain, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: String::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+// A finalize that rejects exactly what the migration step produces
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct StrictTask {
+    id: String,
+}
+
+impl TryIntoDomain<StrictTask> for TaskV2 {
+    fn try_into_domain(self) -> Result<StrictTask, MigrationError> {
+        if self.title.is_empty() {
+            return Err(MigrationError::DeserializationError(
+                "title must not be empty".to_string(),
+            ));
+        }
+        Ok(StrictTask { id: self.id })
+    }
+}
+
+#[test]
+fn test_smoke_test_passes_for_working_path() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator
+        .register_with_smoke_test(path, json!({"id": "t1"}))
+        .unwrap();
+
+    let task: TaskEntity = migrator
+        .load("task", r#"{"version":"1.0.0","data":{"id":"t1"}}"#)
+        .unwrap();
+    assert_eq!(task.id, "t1");
+}
+
+#[test]
+fn test_smoke_test_reports_validation_errors_first() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step_range::<TaskV2>("not a range")
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    let result = migrator.register_with_smoke_test(path, json!({"id": "t1"}));
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidPathConfig { reason, .. }) if reason.contains("not a range")
+    ));
+    assert!(migrator.is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(
+    expected = "Smoke test failed for entity 'task': sample at version 1.0.0 did not migrate to the domain model"
+)]
+fn test_smoke_test_catches_broken_finalize() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .try_into::<StrictTask>();
+
+    let mut migrator = Migrator::new();
+    let _ = migrator.register_with_smoke_test(path, json!({"id": "t1"}));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Failed to deserialize version 1.0.0")]
+fn test_smoke_test_catches_sample_not_matching_first_version() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    // A 2.0.0-shaped sample lacking the 1.0.0 `id` field
+    let mut migrator = Migrator::new();
+    let _ = migrator.register_with_smoke_test(path, json!({"title": "no id"}));
+}
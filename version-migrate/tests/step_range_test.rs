@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    priority: u8,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    priority: u8,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            priority: 1,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            priority: self.priority,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step_range::<TaskV2>("^1.0")
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_patch_versions_route_through_range_step() {
+    let migrator = setup_migrator();
+
+    for version in ["1.0.5", "1.0.99"] {
+        let json = format!(r#"{{"version":"{}","data":{{"id":"task-1"}}}}"#, version);
+        let task: TaskEntity = migrator.load("task", &json).unwrap();
+        assert_eq!(
+            task,
+            TaskEntity {
+                id: "task-1".to_string(),
+                priority: 1,
+            }
+        );
+    }
+}
+
+#[test]
+fn test_exact_versions_still_load() {
+    let migrator = setup_migrator();
+
+    let v1: TaskEntity = migrator
+        .load("task", r#"{"version":"1.0.0","data":{"id":"a"}}"#)
+        .unwrap();
+    assert_eq!(v1.priority, 1);
+
+    let v2: TaskEntity = migrator
+        .load(
+            "task",
+            r#"{"version":"2.0.0","data":{"id":"b","priority":5}}"#,
+        )
+        .unwrap();
+    assert_eq!(v2.priority, 5);
+}
+
+#[test]
+fn test_flat_format_uses_range_step() {
+    let migrator = setup_migrator();
+
+    let task: TaskEntity = migrator
+        .load_flat("task", r#"{"version":"1.0.7","id":"flat"}"#)
+        .unwrap();
+    assert_eq!(task.priority, 1);
+}
+
+#[test]
+fn test_can_load_accepts_versions_in_range() {
+    let migrator = setup_migrator();
+
+    assert!(migrator
+        .can_load("task", r#"{"version":"1.0.42","data":{}}"#)
+        .unwrap());
+    assert!(!migrator
+        .can_load("task", r#"{"version":"3.0.0","data":{}}"#)
+        .unwrap());
+}
+
+#[test]
+fn test_invalid_range_is_rejected_on_register() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step_range::<TaskV2>("not a range")
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    let result = migrator.register(path);
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidPathConfig { entity, reason })
+            if entity == "task" && reason.contains("not a range")
+    ));
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_versions_in_chain_are_loaded() {
+    let migrator = setup_migrator();
+
+    let old: TaskEntity = migrator
+        .load_with_strict_version_check("task", r#"{"version":"1.0.0","data":{"id":"a"}}"#)
+        .unwrap();
+    assert_eq!(old.id, "a");
+    assert!(!old.done);
+
+    // The latest version has no step but is part of the chain.
+    let latest: TaskEntity = migrator
+        .load_with_strict_version_check(
+            "task",
+            r#"{"version":"2.0.0","data":{"id":"b","done":true}}"#,
+        )
+        .unwrap();
+    assert!(latest.done);
+}
+
+#[test]
+fn test_unknown_version_is_rejected() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"0.9.0","data":{"id":"a","done":true}}"#;
+
+    // Plain `load` treats the unknown version as already migrated.
+    assert!(migrator.load::<TaskEntity>("task", json).is_ok());
+
+    let result: Result<TaskEntity, _> = migrator.load_with_strict_version_check("task", json);
+    assert!(matches!(
+        result,
+        Err(MigrationError::UnknownVersion { entity, version })
+            if entity == "task" && version == "0.9.0"
+    ));
+}
+
+#[test]
+fn test_unregistered_entity() {
+    let migrator = setup_migrator();
+    let result: Result<TaskEntity, _> =
+        migrator.load_with_strict_version_check("user", r#"{"version":"1.0.0","data":{"id":"a"}}"#);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{FromDomain, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Default for TaskEntity {
+    fn default() -> Self {
+        TaskEntity {
+            id: "fixture".to_string(),
+            title: "Fixture task".to_string(),
+            done: true,
+        }
+    }
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl MigratesTo<TaskV1> for TaskV2 {
+    fn migrate(self) -> TaskV1 {
+        TaskV1 {
+            id: self.id,
+            title: self.title,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV1 {
+    fn into_domain(self) -> TaskEntity {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+        .into_domain()
+    }
+}
+
+impl IntoDomain<TaskV1> for TaskV1 {
+    fn into_domain(self) -> TaskV1 {
+        self
+    }
+}
+
+impl FromDomain<TaskEntity> for TaskV2 {
+    fn from_domain(domain: TaskEntity) -> Self {
+        TaskV2 {
+            id: domain.id,
+            title: domain.title,
+            done: domain.done,
+        }
+    }
+}
+
+fn forward_path() -> version_migrate::MigrationPath<TaskEntity> {
+    Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into_with_save::<TaskEntity>()
+}
+
+fn setup_bidirectional() -> Migrator {
+    let backward = Migrator::define("task")
+        .from::<TaskV2>()
+        .step::<TaskV1>()
+        .into::<TaskV1>();
+
+    let mut migrator = Migrator::new();
+    migrator
+        .register_bidirectional(Migrator::bidirectional(forward_path(), backward))
+        .unwrap();
+    migrator
+}
+
+fn setup_forward_only() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator.register(forward_path()).unwrap();
+    migrator
+}
+
+#[test]
+fn test_fixture_for_latest_version() {
+    let migrator = setup_forward_only();
+
+    let json = migrator
+        .generate_test_fixture::<TaskEntity>("task", "2.0.0")
+        .unwrap();
+    let task: TaskEntity = migrator.load("task", &json).unwrap();
+    assert_eq!(task, TaskEntity::default());
+}
+
+#[test]
+fn test_fixture_downgraded_through_backward_path() {
+    let migrator = setup_bidirectional();
+
+    let json = migrator
+        .generate_test_fixture::<TaskEntity>("task", "1.0.0")
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["version"], "1.0.0");
+    assert_eq!(
+        value["data"],
+        serde_json::json!({"id": "fixture", "title": "Fixture task"})
+    );
+
+    let task: TaskEntity = migrator.load("task", &json).unwrap();
+    assert_eq!(task.id, "fixture");
+    assert!(!task.done);
+}
+
+#[test]
+fn test_fixture_without_backward_path_is_zero_valued() {
+    let migrator = setup_forward_only();
+
+    let json = migrator
+        .generate_test_fixture::<TaskEntity>("task", "1.0.0")
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["version"], "1.0.0");
+    assert_eq!(value["data"]["id"], "");
+    assert_eq!(value["data"]["done"], false);
+
+    let task: TaskEntity = migrator.load("task", &json).unwrap();
+    assert_eq!(task.title, "");
+}
+
+#[test]
+fn test_fixture_for_unknown_version() {
+    let migrator = setup_forward_only();
+
+    let result = migrator.generate_test_fixture::<TaskEntity>("task", "9.9.9");
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { .. })
+    ));
+}
+
+#[test]
+fn test_fixture_requires_domain_save_support() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let result = migrator.generate_test_fixture::<TaskEntity>("task", "1.0.0");
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
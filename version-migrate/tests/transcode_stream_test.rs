@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    FormatStrategy, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    tags: Vec<String>,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    tags: Vec<String>,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            title: format!("Task {}", self.id),
+            id: self.id,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            tags: self.tags,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct TomlDocument {
+    task: Vec<TaskEntity>,
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+const INPUT: &str = r#"[
+    {"version": "1.0.0", "data": {"id": "1"}},
+    {"version": "2.0.0", "data": {"id": "2", "title": "Second", "tags": ["a", "b"]}},
+    {"version": "1.0.0", "data": {"id": "3"}}
+]"#;
+
+fn expected() -> Vec<TaskEntity> {
+    vec![
+        TaskEntity {
+            id: "1".to_string(),
+            title: "Task 1".to_string(),
+            tags: vec![],
+        },
+        TaskEntity {
+            id: "2".to_string(),
+            title: "Second".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+        },
+        TaskEntity {
+            id: "3".to_string(),
+            title: "Task 3".to_string(),
+            tags: vec![],
+        },
+    ]
+}
+
+#[test]
+fn test_transcode_to_toml_array_of_tables() {
+    let migrator = setup_migrator();
+    let mut output = Vec::new();
+
+    let count = migrator
+        .transcode_stream("task", INPUT.as_bytes(), &mut output, FormatStrategy::Toml)
+        .unwrap();
+    assert_eq!(count, 3);
+
+    let toml_str = String::from_utf8(output).unwrap();
+    assert_eq!(toml_str.matches("[[task]]").count(), 3);
+
+    let document: TomlDocument = toml::from_str(&toml_str).unwrap();
+    assert_eq!(document.task, expected());
+}
+
+#[test]
+fn test_transcode_to_json_array() {
+    let migrator = setup_migrator();
+    let mut output = Vec::new();
+
+    migrator
+        .transcode_stream("task", INPUT.as_bytes(), &mut output, FormatStrategy::Json)
+        .unwrap();
+
+    let tasks: Vec<TaskEntity> = serde_json::from_slice(&output).unwrap();
+    assert_eq!(tasks, expected());
+}
+
+#[test]
+fn test_failing_record_reports_its_index() {
+    let migrator = setup_migrator();
+    let input =
+        r#"[{"version": "1.0.0", "data": {"id": "1"}}, {"version": "2.0.0", "data": {"id": "2"}}]"#;
+
+    let result =
+        migrator.transcode_stream("task", input.as_bytes(), Vec::new(), FormatStrategy::Toml);
+    assert!(matches!(
+        result,
+        Err(MigrationError::ElementFailed { index: 1, .. })
+    ));
+}
+
+#[test]
+fn test_non_array_input_is_rejected() {
+    let migrator = setup_migrator();
+    let input = r#"{"version": "1.0.0", "data": {"id": "1"}}"#;
+
+    let result =
+        migrator.transcode_stream("task", input.as_bytes(), Vec::new(), FormatStrategy::Json);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(_))
+    ));
+}
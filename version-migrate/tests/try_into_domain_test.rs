@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{MigratesTo, MigrationError, Migrator, TryIntoDomain, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct AccountV1 {
+    email: String,
+}
+
+impl Versioned for AccountV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct AccountV2 {
+    email: String,
+    active: bool,
+}
+
+impl Versioned for AccountV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Account {
+    email: String,
+    active: bool,
+}
+
+impl MigratesTo<AccountV2> for AccountV1 {
+    fn migrate(self) -> AccountV2 {
+        AccountV2 {
+            email: self.email,
+            active: true,
+        }
+    }
+}
+
+impl TryIntoDomain<Account> for AccountV1 {
+    fn try_into_domain(self) -> Result<Account, MigrationError> {
+        AccountV2 {
+            email: self.email,
+            active: true,
+        }
+        .try_into_domain()
+    }
+}
+
+impl TryIntoDomain<Account> for AccountV2 {
+    fn try_into_domain(self) -> Result<Account, MigrationError> {
+        if !self.email.contains('@') {
+            return Err(MigrationError::DeserializationError(format!(
+                "invalid email '{}'",
+                self.email
+            )));
+        }
+        Ok(Account {
+            email: self.email,
+            active: self.active,
+        })
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("account")
+        .from::<AccountV1>()
+        .step::<AccountV2>()
+        .try_into::<Account>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_try_into_succeeds_for_valid_data() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"1.0.0","data":{"email":"a@example.com"}}"#;
+
+    let account: Account = migrator.load("account", json).unwrap();
+    assert_eq!(
+        account,
+        Account {
+            email: "a@example.com".to_string(),
+            active: true,
+        }
+    );
+}
+
+#[test]
+fn test_try_into_error_is_propagated_by_load() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"2.0.0","data":{"email":"not-an-email","active":false}}"#;
+
+    let result: Result<Account, _> = migrator.load("account", json);
+    match result {
+        Err(MigrationError::DeserializationError(msg)) => {
+            assert_eq!(msg, "invalid email 'not-an-email'");
+        }
+        other => panic!("expected the domain validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_try_into_error_after_migration_steps() {
+    let migrator = setup_migrator();
+    let json = r#"{"version":"1.0.0","data":{"email":"nobody"}}"#;
+
+    let result: Result<Account, _> = migrator.load("account", json);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("nobody")
+    ));
+}
+
+#[test]
+fn test_try_into_on_single_version_path() {
+    let path = Migrator::define("account")
+        .from::<AccountV1>()
+        .try_into::<Account>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let ok: Account = migrator
+        .load("account", r#"{"version":"1.0.0","data":{"email":"x@y"}}"#)
+        .unwrap();
+    assert!(ok.active);
+
+    let err: Result<Account, _> =
+        migrator.load("account", r#"{"version":"1.0.0","data":{"email":"xy"}}"#);
+    assert!(err.is_err());
+}
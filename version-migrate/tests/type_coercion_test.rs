@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use version_migrate::{CoercionRule, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+    priority: u32,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+    priority: u32,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: self.done,
+            priority: 0,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+            priority: self.priority,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_string_field_coerced_to_bool_before_migration() {
+    let mut migrator = setup_migrator();
+    let json = r#"{"version":"1.0.0","data":{"id":"t1","done":"true"}}"#;
+
+    // Without coercion the intermediate version fails to deserialize
+    assert!(migrator.load::<TaskEntity>("task", json).is_err());
+
+    migrator
+        .register_type_coercion(
+            "task",
+            "1.0.0",
+            HashMap::from([("done".to_string(), CoercionRule::StringToBool)]),
+        )
+        .unwrap();
+
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "t1".to_string(),
+            done: true,
+            priority: 0,
+        }
+    );
+}
+
+#[test]
+fn test_coercion_applies_to_latest_version() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_type_coercion(
+            "task",
+            "2.0.0",
+            HashMap::from([("priority".to_string(), CoercionRule::StringToInt)]),
+        )
+        .unwrap();
+
+    let json = r#"{"version":"2.0.0","data":{"id":"t2","done":false,"priority":"3"}}"#;
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.priority, 3);
+
+    // Values already of the target type are left as-is
+    let json = r#"{"version":"2.0.0","data":{"id":"t3","done":false,"priority":5}}"#;
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert_eq!(task.priority, 5);
+}
+
+#[test]
+fn test_register_type_coercion_rejects_unknown_version() {
+    let mut migrator = setup_migrator();
+
+    let result = migrator.register_type_coercion(
+        "task",
+        "9.9.9",
+        HashMap::from([("done".to_string(), CoercionRule::StringToBool)]),
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationPathNotDefined { .. })
+    ));
+
+    let result = migrator.register_type_coercion("missing", "1.0.0", HashMap::new());
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
+
+#[test]
+fn test_coercions_survive_re_registration() {
+    let mut migrator = setup_migrator();
+    migrator
+        .register_type_coercion(
+            "task",
+            "1.0.0",
+            HashMap::from([("done".to_string(), CoercionRule::StringToBool)]),
+        )
+        .unwrap();
+
+    migrator
+        .register(
+            Migrator::define("task")
+                .from::<TaskV1>()
+                .step::<TaskV2>()
+                .into::<TaskEntity>(),
+        )
+        .unwrap();
+
+    let json = r#"{"version":"1.0.0","data":{"id":"t1","done":"true"}}"#;
+    let task: TaskEntity = migrator.load("task", json).unwrap();
+    assert!(task.done);
+}
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+use version_migrate::Versioned;
+
+#[derive(Versioned)]
+#[versioned(version = "1.0.0", auto_tag = true)]
+struct Settings {
+    name: String,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+use version_migrate::Versioned;
+
+#[derive(Versioned)]
+#[versioned(version = "1.0.0", field_names = true)]
+struct Settings {
+    name: String,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+fn main() {}
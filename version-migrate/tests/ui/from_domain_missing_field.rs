@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{FromDomain, Versioned};
+
+struct TaskEntity {
+    id: String,
+}
+
+#[derive(Serialize, Deserialize, Versioned, FromDomain)]
+#[versioned(version = "1.0.0")]
+#[from_domain(TaskEntity)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+fn main() {}
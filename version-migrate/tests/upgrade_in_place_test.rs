@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, Migrator, VersionExtractor, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_upgrading_in_place_rewrites_to_latest() {
+    let migrator = setup_migrator();
+    let mut json = r#"{"version":"1.0.0","data":{"id":"task-1","title":"Write docs"}}"#.to_string();
+
+    let task: TaskEntity = migrator.load_upgrading_in_place("task", &mut json).unwrap();
+
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "task-1".to_string(),
+            title: "Write docs".to_string(),
+            done: false,
+        }
+    );
+
+    let expected = migrator
+        .save(TaskV2 {
+            id: "task-1".to_string(),
+            title: "Write docs".to_string(),
+            done: false,
+        })
+        .unwrap();
+    assert_eq!(json, expected);
+}
+
+#[test]
+fn test_load_upgrading_in_place_latest_is_stable() {
+    let migrator = setup_migrator();
+    let latest = migrator
+        .save(TaskV2 {
+            id: "task-2".to_string(),
+            title: "Ship".to_string(),
+            done: true,
+        })
+        .unwrap();
+    let mut json = latest.clone();
+
+    let task: TaskEntity = migrator.load_upgrading_in_place("task", &mut json).unwrap();
+
+    assert!(task.done);
+    assert_eq!(json, latest);
+}
+
+#[test]
+fn test_load_upgrading_in_place_leaves_input_on_error() {
+    let migrator = setup_migrator();
+    let original = r#"{"version":"1.0.0","data":{"id":"task-3"}}"#.to_string();
+    let mut json = original.clone();
+
+    let result: Result<TaskEntity, _> = migrator.load_upgrading_in_place("task", &mut json);
+
+    assert!(result.is_err());
+    assert_eq!(json, original);
+}
+
+#[test]
+fn test_load_upgrading_in_place_reads_versions_like_load() {
+    let mut migrator = setup_migrator();
+    migrator
+        .set_version_extractor(
+            "task",
+            VersionExtractor::JsonPointer {
+                version: "/meta/schema".to_string(),
+                data: "/payload".to_string(),
+            },
+        )
+        .unwrap();
+    migrator
+        .register_version_alias("task", "1", "1.0.0")
+        .unwrap();
+    let mut json =
+        r#"{"meta":{"schema":1},"payload":{"id":"task-4","title":"Review"}}"#.to_string();
+
+    let task: TaskEntity = migrator.load_upgrading_in_place("task", &mut json).unwrap();
+
+    assert_eq!(task.id, "task-4");
+    let expected = migrator
+        .save(TaskV2 {
+            id: "task-4".to_string(),
+            title: "Review".to_string(),
+            done: false,
+        })
+        .unwrap();
+    assert_eq!(json, expected);
+}
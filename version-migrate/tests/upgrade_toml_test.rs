@@ -0,0 +1,108 @@
+#![cfg(feature = "toml-preserve")]
+
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    priority: u32,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    priority: u32,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            priority: 1,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            priority: self.priority,
+        }
+    }
+}
+
+const SETTINGS: &str = r#"# Application settings
+[settings]
+theme = "dark"   # user choice
+font_size = 14
+"#;
+
+#[test]
+fn test_upgrade_toml_migrates_collection_and_keeps_other_sections() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let original = format!(
+        r#"app_name = "MyApp"
+
+{SETTINGS}
+[[tasks]]
+version = "1.0.0"
+id = "t1"
+title = "Write docs"
+
+[[tasks]]
+version = "2.0.0"
+id = "t2"
+title = "Ship"
+priority = 3
+"#
+    );
+
+    let upgraded = migrator
+        .upgrade_toml(&original, &[("tasks", "task")])
+        .unwrap();
+
+    assert!(upgraded.starts_with("app_name = \"MyApp\"\n"));
+    assert!(upgraded.contains(SETTINGS));
+
+    let parsed: toml::Value = toml::from_str(&upgraded).unwrap();
+    let tasks = parsed["tasks"].as_array().unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0]["version"].as_str(), Some("2.0.0"));
+    assert_eq!(tasks[0]["priority"].as_integer(), Some(1));
+    assert_eq!(tasks[1]["id"].as_str(), Some("t2"));
+    assert_eq!(tasks[1]["priority"].as_integer(), Some(3));
+}
+
+#[test]
+fn test_upgrade_toml_rejects_non_array_mapping() {
+    let migrator = Migrator::new();
+
+    let result = migrator.upgrade_toml("tasks = \"oops\"\n", &[("tasks", "task")]);
+    assert!(result.is_err());
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{IntoDomain, MigratesTo, MigrationError, Migrator, Versioned};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+    title: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    title: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            title: self.title,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            title: self.title,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>()
+        .validate(|task: &TaskEntity| {
+            if task.title.trim().is_empty() {
+                return Err("title must not be empty".to_string());
+            }
+            Ok(())
+        });
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator
+}
+
+#[test]
+fn test_valid_data_passes() {
+    let migrator = setup_migrator();
+
+    let task: TaskEntity = migrator
+        .load(
+            "task",
+            r#"{"version":"1.0.0","data":{"id":"a","title":"Write docs"}}"#,
+        )
+        .unwrap();
+    assert_eq!(task.title, "Write docs");
+}
+
+#[test]
+fn test_blank_title_is_rejected() {
+    let migrator = setup_migrator();
+
+    let result: Result<TaskEntity, _> = migrator.load(
+        "task",
+        r#"{"version":"1.0.0","data":{"id":"a","title":"  "}}"#,
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::ValidationFailed { entity, reason })
+            if entity == "task" && reason == "title must not be empty"
+    ));
+
+    // Every loader goes through the validator, including the flat format
+    let result: Result<TaskEntity, _> = migrator.load_flat(
+        "task",
+        r#"{"version":"2.0.0","id":"a","title":"","done":true}"#,
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::ValidationFailed { .. })
+    ));
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use version_migrate::{
+    IntoDomain, MigratesTo, MigrationError, Migrator, VersionExtractor, Versioned,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn setup_migrator(extractor: VersionExtractor) -> Migrator {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+    migrator.set_version_extractor("task", extractor).unwrap();
+    migrator
+}
+
+fn expected() -> TaskEntity {
+    TaskEntity {
+        id: "task-1".to_string(),
+        done: false,
+    }
+}
+
+/// Loads `json` through both `load` and `load_flat` and checks they agree.
+fn assert_loads(migrator: &Migrator, json: &str) {
+    let wrapped: TaskEntity = migrator.load("task", json).unwrap();
+    let flat: TaskEntity = migrator.load_flat("task", json).unwrap();
+    assert_eq!(wrapped, expected());
+    assert_eq!(flat, expected());
+}
+
+#[test]
+fn test_wrapped_extractor() {
+    let migrator = setup_migrator(VersionExtractor::Wrapped);
+    assert_loads(&migrator, r#"{"version":"1.0.0","data":{"id":"task-1"}}"#);
+}
+
+#[test]
+fn test_flat_extractor() {
+    let migrator = setup_migrator(VersionExtractor::Flat);
+    assert_loads(&migrator, r#"{"version":"1.0.0","id":"task-1"}"#);
+}
+
+#[test]
+fn test_json_pointer_extractor() {
+    let migrator = setup_migrator(VersionExtractor::JsonPointer {
+        version: "/meta/schema".to_string(),
+        data: "/payload".to_string(),
+    });
+    assert_loads(
+        &migrator,
+        r#"{"meta":{"schema":"1.0.0"},"payload":{"id":"task-1"}}"#,
+    );
+
+    let result: Result<TaskEntity, _> = migrator.load("task", r#"{"payload":{"id":"a"}}"#);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("/meta/schema")
+    ));
+}
+
+#[test]
+fn test_invalid_json_pointer_extractor_is_rejected() {
+    let mut migrator = setup_migrator(VersionExtractor::Wrapped);
+
+    let result = migrator.set_version_extractor(
+        "task",
+        VersionExtractor::JsonPointer {
+            version: "meta/schema".to_string(),
+            data: "/payload".to_string(),
+        },
+    );
+    assert!(matches!(
+        result,
+        Err(MigrationError::InvalidPathConfig { reason, .. }) if reason.contains("meta/schema")
+    ));
+
+    // The previous extractor stays in place
+    assert_loads(&migrator, r#"{"version":"1.0.0","data":{"id":"task-1"}}"#);
+}
+
+#[test]
+fn test_custom_extractor() {
+    // Producer sends "<version>|<json>" packed into a single string field
+    let migrator = setup_migrator(VersionExtractor::Custom(Arc::new(|value| {
+        let packed = value["packed"].as_str().ok_or_else(|| {
+            MigrationError::DeserializationError("Missing 'packed' field".to_string())
+        })?;
+        let (version, data) = packed.split_once('|').unwrap();
+        let data = serde_json::from_str(data)
+            .map_err(|e| MigrationError::DeserializationError(e.to_string()))?;
+        Ok((format!("{}.0.0", version), data))
+    })));
+    assert_loads(&migrator, r#"{"packed":"1|{\"id\":\"task-1\"}"}"#);
+}
+
+#[test]
+fn test_default_keeps_method_formats() {
+    let path = Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>();
+    let mut migrator = Migrator::new();
+    migrator.register(path).unwrap();
+
+    let wrapped: TaskEntity = migrator
+        .load("task", r#"{"version":"1.0.0","data":{"id":"task-1"}}"#)
+        .unwrap();
+    let flat: TaskEntity = migrator
+        .load_flat("task", r#"{"version":"1.0.0","id":"task-1"}"#)
+        .unwrap();
+    assert_eq!(wrapped, flat);
+
+    let result = migrator.set_version_extractor("user", VersionExtractor::Flat);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    ConfigMigrator, IntoDomain, MigratesTo, MigrationError, Migrator, Versioned,
+};
+
+/// Limit stored in units.
+#[derive(Serialize, Deserialize)]
+struct LimitV1(i64);
+
+impl Versioned for LimitV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+/// Limit stored in half-units.
+#[derive(Serialize, Deserialize)]
+struct LimitV2(i64);
+
+impl Versioned for LimitV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+impl MigratesTo<LimitV2> for LimitV1 {
+    fn migrate(self) -> LimitV2 {
+        LimitV2(self.0 * 2)
+    }
+}
+
+impl IntoDomain<i64> for LimitV2 {
+    fn into_domain(self) -> i64 {
+        self.0
+    }
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("limit")
+                .from::<LimitV1>()
+                .step::<LimitV2>()
+                .into::<i64>(),
+        )
+        .unwrap();
+    migrator
+}
+
+#[test]
+fn test_load_versioned_integer() {
+    let migrator = setup_migrator();
+
+    let limit: i64 = migrator
+        .load("limit", r#"{"version":"1.0.0","data":21}"#)
+        .unwrap();
+    assert_eq!(limit, 42);
+}
+
+#[test]
+fn test_load_vec_of_versioned_integers() {
+    let migrator = setup_migrator();
+
+    let limits: Vec<i64> = migrator
+        .load_vec(
+            "limit",
+            r#"[{"version":"1.0.0","data":1},{"version":"2.0.0","data":5}]"#,
+        )
+        .unwrap();
+    assert_eq!(limits, vec![2, 5]);
+}
+
+#[test]
+fn test_config_query_value_migrates_scalar() {
+    let json = r#"{"max_items":{"version":"1.0.0","data":21},"unset":null}"#;
+    let config = ConfigMigrator::from(json, setup_migrator()).unwrap();
+
+    let max_items: Option<i64> = config.query_value("limit", "max_items").unwrap();
+    assert_eq!(max_items, Some(42));
+
+    assert_eq!(config.query_value::<i64>("limit", "unset").unwrap(), None);
+    assert_eq!(config.query_value::<i64>("limit", "missing").unwrap(), None);
+}
+
+#[test]
+fn test_scalar_of_wrong_type_is_rejected() {
+    let migrator = setup_migrator();
+
+    let result: Result<i64, _> = migrator.load("limit", r#"{"version":"1.0.0","data":"21"}"#);
+    assert!(matches!(
+        result,
+        Err(MigrationError::DeserializationError(msg)) if msg.contains("'limit'")
+    ));
+}
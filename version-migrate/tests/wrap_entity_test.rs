@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use version_migrate::{
+    IntoDomain, MigratesTo, MigrationError, Migrator, Versioned, VersionedWrapper,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV1 {
+    id: String,
+}
+
+impl Versioned for TaskV1 {
+    const VERSION: &'static str = "1.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskV2 {
+    id: String,
+    done: bool,
+}
+
+impl Versioned for TaskV2 {
+    const VERSION: &'static str = "2.0.0";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TaskEntity {
+    id: String,
+    done: bool,
+}
+
+impl MigratesTo<TaskV2> for TaskV1 {
+    fn migrate(self) -> TaskV2 {
+        TaskV2 {
+            id: self.id,
+            done: false,
+        }
+    }
+}
+
+impl IntoDomain<TaskEntity> for TaskV2 {
+    fn into_domain(self) -> TaskEntity {
+        TaskEntity {
+            id: self.id,
+            done: self.done,
+        }
+    }
+}
+
+fn define_path() -> version_migrate::MigrationPath<TaskEntity> {
+    Migrator::define("task")
+        .from::<TaskV1>()
+        .step::<TaskV2>()
+        .into::<TaskEntity>()
+}
+
+fn setup_migrator() -> Migrator {
+    let mut migrator = Migrator::new();
+    migrator.register(define_path()).unwrap();
+    migrator
+}
+
+#[test]
+fn test_wrap_entity_builds_wrapper_in_memory() {
+    let migrator = setup_migrator();
+
+    let wrapper = migrator
+        .wrap_entity(TaskV2 {
+            id: "task-1".to_string(),
+            done: true,
+        })
+        .unwrap();
+    assert_eq!(wrapper.version, "2.0.0");
+    assert_eq!(
+        wrapper.data,
+        serde_json::json!({"id": "task-1", "done": true})
+    );
+}
+
+#[test]
+fn test_round_trip_migrates_old_versions() {
+    let migrator = setup_migrator();
+
+    let wrapper = migrator
+        .wrap_entity(TaskV1 {
+            id: "task-1".to_string(),
+        })
+        .unwrap();
+    let task: TaskEntity = migrator.unwrap_entity("task", wrapper).unwrap();
+    assert_eq!(
+        task,
+        TaskEntity {
+            id: "task-1".to_string(),
+            done: false,
+        }
+    );
+}
+
+#[test]
+fn test_unwrap_entity_uses_custom_keys() {
+    let mut migrator = Migrator::builder()
+        .default_version_key("schema_version")
+        .default_data_key("payload")
+        .build();
+    migrator.register(define_path()).unwrap();
+
+    let wrapper = VersionedWrapper::new(
+        "2.0.0".to_string(),
+        serde_json::json!({"id": "a", "done": true}),
+    );
+    let task: TaskEntity = migrator.unwrap_entity("task", wrapper).unwrap();
+    assert!(task.done);
+}
+
+#[test]
+fn test_unwrap_entity_unknown_entity() {
+    let migrator = setup_migrator();
+    let wrapper = migrator
+        .wrap_entity(TaskV1 {
+            id: "task-1".to_string(),
+        })
+        .unwrap();
+
+    let result: Result<TaskEntity, _> = migrator.unwrap_entity("user", wrapper);
+    assert!(matches!(result, Err(MigrationError::EntityNotFound(_))));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Versioned)]
+#[versioned(version = "1.0.0", version_key = "schema", data_key = "payload")]
+struct NoteV1 {
+    text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Versioned)]
+#[versioned(version = "2.0.0", version_key = "schema", data_key = "payload")]
+struct NoteV2 {
+    text: String,
+    pinned: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Note {
+    text: String,
+    pinned: bool,
+}
+
+impl MigratesTo<NoteV2> for NoteV1 {
+    fn migrate(self) -> NoteV2 {
+        NoteV2 {
+            text: self.text,
+            pinned: false,
+        }
+    }
+}
+
+impl IntoDomain<Note> for NoteV2 {
+    fn into_domain(self) -> Note {
+        Note {
+            text: self.text,
+            pinned: self.pinned,
+        }
+    }
+}
+
+#[test]
+fn test_wrap_entity_round_trips_with_type_keys() {
+    let mut migrator = Migrator::new();
+    migrator
+        .register(
+            Migrator::define("note")
+                .from::<NoteV1>()
+                .step::<NoteV2>()
+                .into::<Note>(),
+        )
+        .unwrap();
+
+    let wrapper = migrator
+        .wrap_entity(NoteV1 {
+            text: "hi".to_string(),
+        })
+        .unwrap();
+    assert_eq!(wrapper.keys(), Some(("schema", "payload")));
+
+    let saved = migrator
+        .save(NoteV1 {
+            text: "hi".to_string(),
+        })
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&wrapper).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&saved).unwrap()
+    );
+
+    let json = serde_json::to_string(&wrapper).unwrap();
+    let loaded: Note = migrator.load("note", &json).unwrap();
+    let note: Note = migrator.unwrap_entity("note", wrapper).unwrap();
+    let expected = Note {
+        text: "hi".to_string(),
+        pinned: false,
+    };
+    assert_eq!(note, expected);
+    assert_eq!(loaded, expected);
+}